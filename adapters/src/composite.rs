@@ -0,0 +1,222 @@
+//! Composite slots → hypercube → holistic session.
+//!
+//! Runs the three generative engines as one pipeline: mine sequences in a
+//! slots session, compile the best sequences through the hypercube compiler,
+//! then finalize families through the holistic matrix. Every compiled
+//! candidate keeps a lineage record back to the sequence it came from.
+
+use qops_core::{
+    FinalizedFamily, HolisticConfig, HolisticMatrix, HolisticStats, MatrixOutput,
+    OperatorCandidate, QopsError, Result,
+};
+use qops_hypercube::{CompilationConfig, CompilationResult, HypercubeCompiler};
+use qops_slots::{MiningResult, SlotsSession, SlotsSessionConfig};
+use serde::{Deserialize, Serialize};
+
+/// Composite session configuration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeConfig {
+    /// Slots session (spinning and sequence mining)
+    pub slots: SlotsSessionConfig,
+    /// Hypercube compilation of mined sequences
+    pub compilation: CompilationConfig,
+    /// Holistic matrix used for family finalization
+    pub holistic: HolisticConfig,
+    /// Number of top sequences compiled
+    pub compile_top: usize,
+}
+
+impl Default for CompositeConfig {
+    fn default() -> Self {
+        Self {
+            slots: SlotsSessionConfig::default(),
+            compilation: CompilationConfig::default(),
+            holistic: HolisticConfig::default(),
+            compile_top: 5,
+        }
+    }
+}
+
+impl CompositeConfig {
+    /// Quick composite run
+    pub fn quick() -> Self {
+        Self {
+            slots: SlotsSessionConfig::quick(),
+            compilation: CompilationConfig::fast(),
+            compile_top: 3,
+            ..Default::default()
+        }
+    }
+}
+
+/// Provenance of one compiled candidate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LineageRecord {
+    /// Mined slots sequence ID
+    pub sequence_id: String,
+    /// Resonance of the mined sequence
+    pub sequence_resonance: f64,
+    /// Holistic candidate ID
+    pub candidate_id: String,
+    /// Resonance after hypercube compilation
+    pub compiled_resonance: f64,
+    /// Whether the candidate survived the Kosmokrator filter
+    pub survived: bool,
+    /// Finalized family the candidate was grouped into, if any
+    pub family_id: Option<String>,
+}
+
+/// Combined result of a composite run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompositeResult {
+    /// Slots mining stage
+    pub mining: MiningResult,
+    /// Hypercube compilation of each compiled sequence, in lineage order
+    pub compilations: Vec<CompilationResult>,
+    /// Holistic matrix output
+    pub matrix_output: MatrixOutput,
+    /// Holistic matrix statistics
+    pub holistic_stats: HolisticStats,
+    /// Finalized operator families
+    pub families: Vec<FinalizedFamily>,
+    /// Sequence → compilation → family lineage
+    pub lineage: Vec<LineageRecord>,
+    /// Best compiled resonance
+    pub best_resonance: f64,
+}
+
+/// Orchestrates slots, hypercube and holistic stages end to end
+pub struct CompositeSession {
+    config: CompositeConfig,
+}
+
+impl CompositeSession {
+    /// Create a new composite session
+    pub fn new(config: CompositeConfig) -> Self {
+        Self { config }
+    }
+
+    /// Configuration
+    pub fn config(&self) -> &CompositeConfig {
+        &self.config
+    }
+
+    /// Run all three stages
+    pub fn run(&mut self) -> Result<CompositeResult> {
+        // Stage 1: mine sequences in slots
+        let mut slots = SlotsSession::new(self.config.slots.clone());
+        slots.run_spins(self.config.slots.spins_before_mine).map_err(stage_error("slots"))?;
+        let mining = slots.run_mining().map_err(stage_error("slots"))?;
+
+        let mut sequences = mining.top_sequences.clone();
+        if sequences.is_empty() {
+            sequences.push(mining.best_sequence.clone());
+        }
+        sequences.sort_by(|a, b| b.resonance.total_cmp(&a.resonance));
+        sequences.truncate(self.config.compile_top.max(1));
+
+        // Stage 2: compile each sequence's coordinate in the hypercube
+        let mut compiler = HypercubeCompiler::new(self.config.compilation.clone());
+        let mut compilations = Vec::with_capacity(sequences.len());
+        let mut lineage = Vec::with_capacity(sequences.len());
+        let mut candidates = Vec::with_capacity(sequences.len());
+
+        for (i, sequence) in sequences.iter().enumerate() {
+            let compiled = compiler
+                .compile_coordinate(sequence.to_hypercube_coord())
+                .map_err(stage_error("hypercube"))?;
+
+            let candidate_id = format!("composite_{}", i);
+            candidates.push(OperatorCandidate {
+                id: candidate_id.clone(),
+                signature: compiled.output.to_signature(),
+                phase: compiled.resonance * std::f64::consts::PI,
+                resonance: compiled.resonance,
+                stability: compiled.phase_coherence.unwrap_or(0.5),
+                is_mandorla: compiled.threshold_met,
+                node_index: i,
+                discovered_at: i as f64,
+            });
+            lineage.push(LineageRecord {
+                sequence_id: sequence.id.clone(),
+                sequence_resonance: sequence.resonance,
+                candidate_id,
+                compiled_resonance: compiled.resonance,
+                survived: false,
+                family_id: None,
+            });
+            compilations.push(compiled);
+        }
+
+        // Stage 3: finalize families through the holistic matrix
+        let mut matrix = HolisticMatrix::new(self.config.holistic.clone());
+        let matrix_output = matrix.process(candidates.clone(), 0.0);
+        let families = matrix.families().to_vec();
+
+        for record in lineage.iter_mut() {
+            record.survived = matrix
+                .kosmokrator
+                .survivors
+                .iter()
+                .any(|s| s.id == record.candidate_id);
+            if record.survived {
+                record.family_id = families
+                    .iter()
+                    .find(|f| f.member_ids.contains(&record.candidate_id))
+                    .map(|f| f.id.clone());
+            }
+        }
+
+        let best_resonance = compilations
+            .iter()
+            .map(|c| c.resonance)
+            .fold(0.0, f64::max);
+
+        Ok(CompositeResult {
+            mining,
+            compilations,
+            matrix_output,
+            holistic_stats: matrix.stats(),
+            families,
+            lineage,
+            best_resonance,
+        })
+    }
+}
+
+impl Default for CompositeSession {
+    fn default() -> Self {
+        Self::new(CompositeConfig::default())
+    }
+}
+
+/// Wrap a stage error as a pipeline error naming the stage
+fn stage_error<E: std::fmt::Display>(stage: &'static str) -> impl Fn(E) -> QopsError {
+    move |e| QopsError::Pipeline(format!("{} stage failed: {}", stage, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_composite_run() {
+        let mut session = CompositeSession::new(CompositeConfig::quick());
+        let result = session.run().unwrap();
+
+        assert!(!result.compilations.is_empty());
+        assert!(result.compilations.len() <= 3);
+        assert_eq!(result.lineage.len(), result.compilations.len());
+        assert!(result.best_resonance > 0.0);
+
+        for record in &result.lineage {
+            assert!(result.mining.top_sequences.iter().any(|s| s.id == record.sequence_id)
+                || result.mining.best_sequence.id == record.sequence_id);
+            if let Some(family) = &record.family_id {
+                assert!(record.survived);
+                let family = result.families.iter().find(|f| &f.id == family).unwrap();
+                assert!(family.member_ids.contains(&record.candidate_id));
+            }
+        }
+    }
+}
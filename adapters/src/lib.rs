@@ -5,12 +5,15 @@
 //! - **Genesis-Quantum Bridge**: Connect S7 and Cube-13 topologies
 //! - **Seraphic Integration**: Connect calibration to pipelines
 //! - **MEF Adapter**: Interface with MEF pipeline systems
+//! - **Composite Session**: Slots → Hypercube → Holistic end to end
 
 pub mod genesis_quantum;
 pub mod seraphic_bridge;
+pub mod composite;
 
 pub use genesis_quantum::GenesisQuantumBridge;
 pub use seraphic_bridge::SeraphicBridge;
+pub use composite::{CompositeConfig, CompositeResult, CompositeSession, LineageRecord};
 
 /// Adapters version
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -0,0 +1,235 @@
+//! Quantum Amplitude Estimation (QAE)
+//!
+//! Estimates the probability a of measuring a marked state after a state
+//! preparation A, quadratically faster than sampling.
+//!
+//! ## Algorithm
+//! The Grover operator Q = A S₀ A† S_χ rotates by 2θ in the plane spanned
+//! by the marked and unmarked parts of A|0⟩, where a = sin²(θ). Phase
+//! estimation of Q with m evaluation qubits reads y ≈ 2^m θ/π, giving
+//! ã = sin²(πy/2^m) with error O(1/2^m) instead of O(1/√shots).
+//!
+//! ## Applications
+//! - Grover counting (a = M/N for a uniform A)
+//! - Monte-Carlo integration
+
+use crate::{AlgorithmError, Oracle, Result, IQFT};
+use nalgebra::DMatrix;
+use qops_circuits::{Circuit, Complex, Measurement, QuantumRegister, StateVector};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::f64::consts::PI;
+
+/// Result of amplitude estimation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AmplitudeEstimationResult {
+    /// Estimated marked amplitude ã ∈ [0, 1]
+    pub estimate: f64,
+    /// Bound on |ã - a| holding with probability at least 8/π²
+    pub error_bound: f64,
+    /// Evaluation register outcome y the estimate came from
+    pub measurement: usize,
+    /// Fraction of shots giving this estimate (y and 2^m - y agree)
+    pub confidence: f64,
+    /// Number of evaluation qubits used
+    pub eval_qubits: usize,
+    /// Evaluation register measurement counts
+    pub counts: HashMap<String, usize>,
+}
+
+impl AmplitudeEstimationResult {
+    /// Estimated number of marked states out of `2^num_qubits` (Grover counting)
+    pub fn num_solutions(&self, num_qubits: usize) -> f64 {
+        self.estimate * (1 << num_qubits) as f64
+    }
+}
+
+/// Canonical QPE-based amplitude estimation
+///
+/// A is the uniform superposition H⊗n, so the estimated amplitude is the
+/// fraction of basis states the oracle marks.
+pub struct AmplitudeEstimation {
+    /// Oracle marking the good states
+    pub oracle: Oracle,
+    /// Number of evaluation (phase estimation) qubits
+    pub eval_qubits: usize,
+}
+
+impl AmplitudeEstimation {
+    /// Create amplitude estimation for an oracle with `eval_qubits` of precision
+    ///
+    /// Duplicate marked states are dropped; marked states outside the
+    /// `2^n` state space are rejected.
+    pub fn new(mut oracle: Oracle, eval_qubits: usize) -> Result<Self> {
+        if eval_qubits == 0 {
+            return Err(AlgorithmError::InvalidQubitCount(
+                "Amplitude estimation needs at least one evaluation qubit".to_string()
+            ));
+        }
+        if oracle.num_qubits == 0 {
+            return Err(AlgorithmError::InvalidQubitCount(
+                "Oracle must act on at least one qubit".to_string()
+            ));
+        }
+        let dim = 1usize << oracle.num_qubits;
+        if let Some(&state) = oracle.marked_states.iter().find(|&&s| s >= dim) {
+            return Err(AlgorithmError::InvalidParameter(format!(
+                "Marked state {} is outside the {}-qubit state space", state, oracle.num_qubits
+            )));
+        }
+        oracle.marked_states.sort_unstable();
+        oracle.marked_states.dedup();
+        Ok(Self { oracle, eval_qubits })
+    }
+
+    /// Total number of simulated qubits
+    pub fn total_qubits(&self) -> usize {
+        self.eval_qubits + self.oracle.num_qubits
+    }
+
+    /// Error bound 2π√(a(1-a))/M + π²/M² for M = 2^m evaluation states
+    pub fn error_bound(&self, amplitude: f64) -> f64 {
+        let m = (1 << self.eval_qubits) as f64;
+        let a = amplitude.clamp(0.0, 1.0);
+        2.0 * PI * (a * (1.0 - a)).sqrt() / m + PI * PI / (m * m)
+    }
+
+    /// Grover operator Q = (2|s⟩⟨s| - I) S_χ on the state qubits
+    fn grover_operator(&self) -> DMatrix<Complex> {
+        let dim = 1 << self.oracle.num_qubits;
+        let uniform = Complex::new(2.0 / dim as f64, 0.0);
+        let mut q = DMatrix::from_element(dim, dim, uniform);
+        for i in 0..dim {
+            q[(i, i)] -= Complex::new(1.0, 0.0);
+        }
+        for &marked in &self.oracle.marked_states {
+            q.column_mut(marked).neg_mut();
+        }
+        q
+    }
+
+    /// Register after phase estimation of Q, before measurement
+    ///
+    /// Evaluation qubits are 0..m, with qubit k controlling Q^{2^k}; the
+    /// state qubits sit above them.
+    pub fn prepare_register(&self) -> QuantumRegister {
+        let m = self.eval_qubits;
+        let eval_dim = 1 << m;
+        let state_dim = 1 << self.oracle.num_qubits;
+
+        // H⊗m on the evaluation register and A = H⊗n on the state register
+        let norm = Complex::new(1.0 / ((eval_dim * state_dim) as f64).sqrt(), 0.0);
+        let mut amplitudes = vec![norm; eval_dim * state_dim];
+
+        let mut power = self.grover_operator();
+        for k in 0..m {
+            for eval in (0..eval_dim).filter(|e| (e >> k) & 1 == 1) {
+                let block = DMatrix::from_fn(state_dim, 1, |s, _| amplitudes[(s << m) | eval]);
+                let rotated = &power * block;
+                for s in 0..state_dim {
+                    amplitudes[(s << m) | eval] = rotated[(s, 0)];
+                }
+            }
+            power = &power * &power;
+        }
+
+        let state = StateVector::from_amplitudes(amplitudes).expect("dimension is a power of 2");
+        let mut iqft = Circuit::with_name(self.total_qubits(), "IQFT");
+        for instruction in IQFT::new(m).build_circuit().instructions {
+            iqft.add_gate(instruction.gate, instruction.qubits).expect("IQFT acts on evaluation qubits");
+        }
+        let mut register = QuantumRegister::from_state(state);
+        register.apply_circuit(&iqft).expect("IQFT acts on evaluation qubits");
+        register
+    }
+
+    /// Run amplitude estimation
+    pub fn run(&self) -> AmplitudeEstimationResult {
+        self.run_with_shots(1024)
+    }
+
+    /// Run with the given number of measurement shots
+    pub fn run_with_shots(&self, shots: usize) -> AmplitudeEstimationResult {
+        let m = self.eval_qubits;
+        let register = self.prepare_register();
+        let eval: Vec<usize> = (0..m).collect();
+        let stats = Measurement::measure_qubits(&register, &eval, shots)
+            .expect("evaluation qubits are in range");
+
+        // y and 2^m - y give the same amplitude, so pool their counts
+        let mut pooled: HashMap<usize, usize> = HashMap::new();
+        for (bitstring, &count) in &stats.counts {
+            let y = usize::from_str_radix(bitstring, 2).unwrap_or(0);
+            *pooled.entry(y.min((1 << m) - y)).or_insert(0) += count;
+        }
+        let (measurement, best_count) = pooled
+            .into_iter()
+            .max_by_key(|&(y, count)| (count, std::cmp::Reverse(y)))
+            .unwrap_or_default();
+
+        let estimate = (PI * measurement as f64 / (1 << m) as f64).sin().powi(2);
+
+        AmplitudeEstimationResult {
+            estimate,
+            error_bound: self.error_bound(estimate),
+            measurement,
+            confidence: best_count as f64 / shots.max(1) as f64,
+            eval_qubits: m,
+            counts: stats.counts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_half_amplitude() {
+        // a = 1/2 means θ = π/4, which 5 evaluation qubits represent exactly
+        let qae = AmplitudeEstimation::new(Oracle::marked_state(1, 1), 5).unwrap();
+        let result = qae.run_with_shots(200);
+
+        assert_eq!(result.measurement, 8);
+        assert!((result.estimate - 0.5).abs() < 1e-10);
+        assert!((result.confidence - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_quarter_amplitude_within_bound() {
+        // One of four states marked: a = 0.25
+        let qae = AmplitudeEstimation::new(Oracle::marked_state(2, 0b10), 5).unwrap();
+        let result = qae.run_with_shots(2000);
+
+        assert!(
+            (result.estimate - 0.25).abs() <= qae.error_bound(0.25),
+            "estimate {} outside ±{}", result.estimate, qae.error_bound(0.25)
+        );
+        assert!(result.error_bound < 0.1);
+
+        // Grover counting: two of eight states marked
+        let qae = AmplitudeEstimation::new(Oracle::marked_states(3, &[1, 6]), 6).unwrap();
+        let result = qae.run_with_shots(2000);
+        assert!((result.num_solutions(3) - 2.0).abs() < 8.0 * qae.error_bound(0.25));
+
+        assert!(AmplitudeEstimation::new(Oracle::marked_state(2, 0), 0).is_err());
+    }
+
+    #[test]
+    fn test_marked_states_are_validated() {
+        assert!(matches!(
+            AmplitudeEstimation::new(Oracle::marked_state(2, 4), 3),
+            Err(AlgorithmError::InvalidParameter(_))
+        ));
+
+        // A duplicated mark still counts once: a = 1/2
+        let oracle = Oracle {
+            num_qubits: 1,
+            marked_states: vec![1, 1],
+            name: "duplicate".to_string(),
+        };
+        let qae = AmplitudeEstimation::new(oracle, 5).unwrap();
+        assert_eq!(qae.oracle.marked_states, vec![1]);
+        assert!((qae.run_with_shots(200).estimate - 0.5).abs() < 1e-10);
+    }
+}
@@ -0,0 +1,211 @@
+//! Pluggable VQE Ansätze
+//!
+//! An [`AnsatzBuilder`] turns a parameter vector into a state-preparation
+//! circuit, so problem-specific circuits can be used by VQE without adding
+//! a variant to [`crate::Ansatz`].
+//!
+//! ## Provided Ansätze
+//! - [`HardwareEfficient`]: Rx-Ry-Rz layers with a CZ ladder
+//! - [`UccsdLite`]: Hartree-Fock reference with Givens single and double
+//!   excitations (no Jordan-Wigner strings)
+
+use qops_circuits::{Circuit, Complex, Gate};
+use nalgebra::DMatrix;
+
+/// Builds a parameterized state-preparation circuit
+pub trait AnsatzBuilder: std::fmt::Debug + Send + Sync {
+    /// Build the circuit on `qubits` qubits
+    ///
+    /// `params.len()` is what [`AnsatzBuilder::num_parameters`] returned, so
+    /// layered ansätze can recover the layer count from it.
+    fn build(&self, params: &[f64], qubits: usize) -> Circuit;
+
+    /// Number of parameters for `qubits` qubits and `layers` layers
+    fn num_parameters(&self, qubits: usize, layers: usize) -> usize;
+}
+
+// ==================== Hardware Efficient ====================
+
+/// Rx-Ry-Rz rotations on every qubit followed by a CZ ladder, per layer
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HardwareEfficient;
+
+impl AnsatzBuilder for HardwareEfficient {
+    fn build(&self, params: &[f64], qubits: usize) -> Circuit {
+        let mut circuit = Circuit::with_name(qubits, "HardwareEfficient");
+        if qubits == 0 {
+            return circuit;
+        }
+
+        for layer in params.chunks_exact(3 * qubits) {
+            for (i, angles) in layer.chunks_exact(3).enumerate() {
+                circuit = circuit.rx(angles[0], i).ry(angles[1], i).rz(angles[2], i);
+            }
+            for i in 0..qubits - 1 {
+                circuit = circuit.cz(i, i + 1);
+            }
+        }
+
+        circuit
+    }
+
+    fn num_parameters(&self, qubits: usize, layers: usize) -> usize {
+        3 * qubits * layers
+    }
+}
+
+// ==================== UCCSD-lite ====================
+
+/// Particle-conserving UCCSD-style ansatz
+///
+/// Starts from the Hartree-Fock state with the first `num_electrons` qubits
+/// occupied, then applies a Givens rotation for every single excitation
+/// (i → a) and every double excitation (i, j → a, b) from occupied to virtual
+/// qubits, repeated once per layer. Fermionic sign strings are dropped, which
+/// is what makes it "lite": the circuit conserves particle number but is not
+/// an exact unitary coupled cluster operator.
+#[derive(Debug, Clone, Copy)]
+pub struct UccsdLite {
+    /// Number of occupied qubits in the reference state
+    pub num_electrons: usize,
+}
+
+impl UccsdLite {
+    /// Create with `num_electrons` occupied orbitals
+    pub fn new(num_electrons: usize) -> Self {
+        Self { num_electrons }
+    }
+
+    /// Single excitations (occupied, virtual)
+    fn singles(&self, qubits: usize) -> Vec<(usize, usize)> {
+        let occupied = self.num_electrons.min(qubits);
+        (0..occupied)
+            .flat_map(|i| (occupied..qubits).map(move |a| (i, a)))
+            .collect()
+    }
+
+    /// Double excitations (occupied pair, virtual pair)
+    fn doubles(&self, qubits: usize) -> Vec<[usize; 4]> {
+        let occupied = self.num_electrons.min(qubits);
+        let mut doubles = Vec::new();
+        for i in 0..occupied {
+            for j in i + 1..occupied {
+                for a in occupied..qubits {
+                    for b in a + 1..qubits {
+                        doubles.push([i, j, a, b]);
+                    }
+                }
+            }
+        }
+        doubles
+    }
+
+    fn parameters_per_layer(&self, qubits: usize) -> usize {
+        self.singles(qubits).len() + self.doubles(qubits).len()
+    }
+}
+
+impl AnsatzBuilder for UccsdLite {
+    fn build(&self, params: &[f64], qubits: usize) -> Circuit {
+        let mut circuit = Circuit::with_name(qubits, "UCCSD-lite");
+
+        // Hartree-Fock reference
+        for i in 0..self.num_electrons.min(qubits) {
+            circuit = circuit.x(i);
+        }
+
+        let per_layer = self.parameters_per_layer(qubits);
+        if per_layer == 0 {
+            return circuit;
+        }
+
+        let singles = self.singles(qubits);
+        let doubles = self.doubles(qubits);
+        for layer in params.chunks_exact(per_layer) {
+            let (single_params, double_params) = layer.split_at(singles.len());
+            for (&(i, a), &theta) in singles.iter().zip(single_params) {
+                circuit = single_excitation(circuit, theta, i, a);
+            }
+            for (&qubits, &theta) in doubles.iter().zip(double_params) {
+                circuit.add_gate(double_excitation(theta), qubits.to_vec()).ok();
+            }
+        }
+
+        circuit
+    }
+
+    fn num_parameters(&self, qubits: usize, layers: usize) -> usize {
+        self.parameters_per_layer(qubits) * layers
+    }
+}
+
+/// Givens rotation |10⟩ → cos(θ/2)|10⟩ + sin(θ/2)|01⟩ moving an electron from `i` to `a`
+fn single_excitation(circuit: Circuit, theta: f64, i: usize, a: usize) -> Circuit {
+    // CNOT(a, i) · CRy(θ)(i → a) · CNOT(a, i), with CRy from two CNOTs
+    circuit
+        .cnot(a, i)
+        .ry(theta / 2.0, a)
+        .cnot(i, a)
+        .ry(-theta / 2.0, a)
+        .cnot(i, a)
+        .cnot(a, i)
+}
+
+/// Givens rotation between |1100⟩ and |0011⟩ on qubits (i, j, a, b)
+fn double_excitation(theta: f64) -> Gate {
+    let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+    let mut matrix = DMatrix::<Complex>::identity(16, 16);
+    let (occupied, excited) = (0b1100, 0b0011);
+    matrix[(occupied, occupied)] = Complex::new(c, 0.0);
+    matrix[(excited, excited)] = Complex::new(c, 0.0);
+    matrix[(excited, occupied)] = Complex::new(s, 0.0);
+    matrix[(occupied, excited)] = Complex::new(-s, 0.0);
+    Gate::custom("G2", matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qops_circuits::QuantumRegister;
+
+    fn probabilities(circuit: &Circuit) -> Vec<f64> {
+        let mut register = QuantumRegister::new(circuit.num_qubits);
+        register.apply_circuit(circuit).unwrap();
+        register.state.probabilities()
+    }
+
+    #[test]
+    fn test_hardware_efficient_layers() {
+        let ansatz = HardwareEfficient;
+        assert_eq!(ansatz.num_parameters(3, 2), 18);
+
+        let circuit = ansatz.build(&[0.1; 18], 3);
+        // 9 rotations and 2 CZs per layer
+        assert_eq!(circuit.gate_count(), 22);
+    }
+
+    #[test]
+    fn test_uccsd_lite_conserves_particles() {
+        let ansatz = UccsdLite::new(2);
+        // 2·2 singles and 1 double on 4 qubits
+        assert_eq!(ansatz.num_parameters(4, 1), 5);
+
+        let params = [0.4, -0.9, 1.3, 0.2, 0.8];
+        let probs = probabilities(&ansatz.build(&params, 4));
+        let total: f64 = probs.iter().sum();
+        assert!((total - 1.0).abs() < 1e-10);
+        for (state, p) in probs.iter().enumerate() {
+            if (state as u32).count_ones() != 2 {
+                assert!(*p < 1e-12, "state {:04b} has probability {}", state, p);
+            }
+        }
+
+        // Zero amplitudes leave the Hartree-Fock state
+        let probs = probabilities(&ansatz.build(&[0.0; 5], 4));
+        assert!((probs[0b0011] - 1.0).abs() < 1e-12);
+
+        // A π double excitation moves both electrons
+        let probs = probabilities(&ansatz.build(&[0.0, 0.0, 0.0, 0.0, std::f64::consts::PI], 4));
+        assert!((probs[0b1100] - 1.0).abs() < 1e-12);
+    }
+}
@@ -24,6 +24,9 @@ pub enum AlgorithmError {
 
     #[error("Numerical error: {0}")]
     NumericalError(String),
+
+    #[error("Search space of {0} qubits exceeds the enumeration limit of {1} qubits")]
+    SearchSpaceTooLarge(usize, usize),
 }
 
 pub type Result<T> = std::result::Result<T, AlgorithmError>;
@@ -13,10 +13,14 @@
 //!    b. Diffusion: Reflect about the mean
 //! 3. Measure to find marked state
 
+use crate::{AlgorithmError, Result};
 use qops_circuits::{Circuit, QuantumRegister, Measurement};
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
+/// Largest register `Grover::from_predicate` will enumerate by default
+pub const DEFAULT_MAX_PREDICATE_QUBITS: usize = 16;
+
 /// Oracle function type
 pub type OracleFunction = Box<dyn Fn(usize) -> bool + Send + Sync>;
 
@@ -42,11 +46,27 @@ impl Oracle {
     }
 
     /// Create oracle for multiple marked states
-    pub fn marked_states(num_qubits: usize, targets: Vec<usize>) -> Self {
+    ///
+    /// Every listed basis state gets its phase flipped. Duplicates are marked
+    /// once (flipping twice would undo the mark) and states outside the
+    /// 2^n space are ignored.
+    pub fn marked_states(num_qubits: usize, targets: &[usize]) -> Self {
+        let mut marked_states: Vec<usize> = targets
+            .iter()
+            .copied()
+            .filter(|&t| t < 1 << num_qubits)
+            .collect();
+        marked_states.sort_unstable();
+        marked_states.dedup();
+
+        let labels: Vec<String> = marked_states
+            .iter()
+            .map(|t| format!("|{:0width$b}⟩", t, width = num_qubits))
+            .collect();
         Self {
             num_qubits,
-            marked_states: targets,
-            name: "Multi-state oracle".to_string(),
+            marked_states,
+            name: format!("Multi-state oracle: {}", labels.join(", ")),
         }
     }
 
@@ -125,20 +145,9 @@ impl Oracle {
         circuit
     }
 
-    /// Add multi-controlled X gate using linear CNOT cascade
-    /// For n qubits, applies controlled-NOT from each control qubit to the target
-    fn add_multi_controlled_x(&self, mut circuit: Circuit, n: usize) -> Circuit {
-        if n == 3 {
-            // Use native Toffoli gate for 3 qubits
-            circuit = circuit.toffoli(0, 1, 2);
-        } else {
-            // Linear CNOT cascade: approximates multi-controlled X
-            // Each control qubit applies CNOT to the target (last qubit)
-            for i in 0..n-1 {
-                circuit = circuit.cnot(i, n - 1);
-            }
-        }
-        circuit
+    /// Add multi-controlled X from qubits 0..n-1 onto the last qubit
+    fn add_multi_controlled_x(&self, circuit: Circuit, n: usize) -> Circuit {
+        circuit.mcx((0..n - 1).collect(), n - 1)
     }
 }
 
@@ -179,6 +188,33 @@ impl Grover {
         }
     }
 
+    /// Search for the basis states satisfying a predicate
+    ///
+    /// The marked set is found by evaluating `f` on all 2^n indices, so the
+    /// classical cost is exponential in `num_qubits`. Registers larger than
+    /// [`DEFAULT_MAX_PREDICATE_QUBITS`] are rejected with
+    /// `AlgorithmError::SearchSpaceTooLarge`; see
+    /// [`Grover::from_predicate_with_limit`] to raise the cap.
+    pub fn from_predicate<F>(num_qubits: usize, f: F) -> Result<Self>
+    where
+        F: Fn(usize) -> bool,
+    {
+        Self::from_predicate_with_limit(num_qubits, f, DEFAULT_MAX_PREDICATE_QUBITS)
+    }
+
+    /// Search for the basis states satisfying a predicate, enumerating at most
+    /// `max_qubits` qubits
+    pub fn from_predicate_with_limit<F>(num_qubits: usize, f: F, max_qubits: usize) -> Result<Self>
+    where
+        F: Fn(usize) -> bool,
+    {
+        if num_qubits > max_qubits {
+            return Err(AlgorithmError::SearchSpaceTooLarge(num_qubits, max_qubits));
+        }
+        let oracle = Oracle::from_function(num_qubits, f, "Predicate oracle");
+        Ok(Self::new(num_qubits, oracle))
+    }
+
     /// Set the number of iterations explicitly
     pub fn with_iterations(mut self, iterations: usize) -> Self {
         self.iterations = Some(iterations);
@@ -186,6 +222,9 @@ impl Grover {
     }
 
     /// Calculate optimal number of iterations
+    ///
+    /// ⌊π/4 · √(N/M)⌋ for M marked states out of N; rounding down keeps the
+    /// rotation from overshooting the marked subspace.
     pub fn optimal_iterations(&self) -> usize {
         let n = 1 << self.num_qubits;  // N = 2^n
         let m = self.oracle.num_solutions() as f64;
@@ -194,9 +233,7 @@ impl Grover {
             return 0;
         }
 
-        // Optimal iterations ≈ π/4 * √(N/M)
-        let optimal = (PI / 4.0 * (n as f64 / m).sqrt()).round() as usize;
-        optimal.max(1)
+        (PI / 4.0 * (n as f64 / m).sqrt()).floor() as usize
     }
 
     /// Build the diffusion operator circuit
@@ -217,19 +254,11 @@ impl Grover {
         // Multi-controlled Z (reflects about |0⟩)
         if n == 1 {
             circuit = circuit.z(0);
-        } else if n == 2 {
-            circuit = circuit.h(1).cnot(0, 1).h(1);
         } else {
-            circuit = circuit.h(n - 1);
-            if n == 3 {
-                circuit = circuit.toffoli(0, 1, 2);
-            } else {
-                // Simplified for larger circuits
-                for i in 0..n-1 {
-                    circuit = circuit.cnot(i, n - 1);
-                }
-            }
-            circuit = circuit.h(n - 1);
+            circuit = circuit
+                .h(n - 1)
+                .mcx((0..n - 1).collect(), n - 1)
+                .h(n - 1);
         }
 
         // X⊗n
@@ -349,7 +378,9 @@ impl AmplitudeAmplification {
 
         // Q = A S₀ A† Sχ iterations
         let oracle_circuit = self.oracle.to_circuit();
-        let a_dagger = self.preparation.inverse();
+        let mut preparation = self.preparation.clone();
+        preparation.measurements.clear();
+        let a_dagger = preparation.inverse().expect("measurement-free preparation is invertible");
 
         // Reflection about |0⟩
         let mut s0 = Circuit::new(n);
@@ -358,13 +389,10 @@ impl AmplitudeAmplification {
         }
         // Add multi-controlled Z
         if n >= 2 {
-            s0 = s0.h(n - 1);
-            if n == 2 {
-                s0 = s0.cnot(0, 1);
-            } else if n == 3 {
-                s0 = s0.toffoli(0, 1, 2);
-            }
-            s0 = s0.h(n - 1);
+            s0 = s0
+                .h(n - 1)
+                .mcx((0..n - 1).collect(), n - 1)
+                .h(n - 1);
         }
         for i in 0..n {
             s0 = s0.x(i);
@@ -421,10 +449,57 @@ mod tests {
         assert!(optimal >= 2 && optimal <= 4);
     }
 
+    #[test]
+    fn test_large_grover_amplifies_marked_state() {
+        // Needs a true multi-controlled X in both the oracle and diffusion
+        let grover = Grover::new(6, Oracle::marked_state(6, 0b101101));
+        let mut reg = QuantumRegister::new(6);
+        reg.apply_circuit(&grover.build_circuit()).unwrap();
+
+        assert!(reg.state.probability(0b101101) > 0.9);
+        assert!((grover.theoretical_success_probability() - reg.state.probability(0b101101)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_two_marked_states_in_four_qubits() {
+        let oracle = Oracle::marked_states(4, &[0b0011, 0b1100, 0b0011]);
+        assert_eq!(oracle.num_solutions(), 2);
+
+        let grover = Grover::new(4, oracle);
+        // ⌊π/4 · √(16/2)⌋ = 2
+        assert_eq!(grover.optimal_iterations(), 2);
+
+        let result = grover.run_with_shots(2000);
+        assert!(result.is_solution);
+        for peak in ["0011", "1100"] {
+            let count = *result.counts.get(peak).unwrap_or(&0);
+            assert!(count > 700, "peak {} has {} counts", peak, count);
+        }
+        assert!(grover.theoretical_success_probability() > 0.9);
+    }
+
+    #[test]
+    fn test_predicate_oracle() {
+        // Multiples of 5 below 16: M = 4 of N = 16, so one iteration is exact
+        let grover = Grover::from_predicate(4, |x| x % 5 == 0).unwrap();
+        assert_eq!(grover.oracle.marked_states, vec![0, 5, 10, 15]);
+        assert_eq!(grover.optimal_iterations(), 1);
+        assert!((grover.theoretical_success_probability() - 1.0).abs() < 1e-10);
+
+        let result = grover.run_with_shots(200);
+        assert!(result.is_solution);
+
+        assert!(matches!(
+            Grover::from_predicate(DEFAULT_MAX_PREDICATE_QUBITS + 1, |_| true),
+            Err(AlgorithmError::SearchSpaceTooLarge(_, _))
+        ));
+        assert!(Grover::from_predicate_with_limit(5, |x| x == 3, 4).is_err());
+    }
+
     #[test]
     fn test_multiple_solutions() {
         // Search for |00⟩ or |11⟩ in 2-qubit space
-        let oracle = Oracle::marked_states(2, vec![0b00, 0b11]);
+        let oracle = Oracle::marked_states(2, &[0b00, 0b11]);
 
         // Verify oracle correctly identifies solutions
         assert!(oracle.is_marked(0b00));
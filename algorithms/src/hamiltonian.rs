@@ -21,10 +21,54 @@ pub enum TrotterOrder {
     First,
     /// Second-order (symmetric): exp(-iHt) ≈ Π exp(-iH_k t/2) Π exp(-iH_k t/2)†
     Second,
-    /// Fourth-order
+    /// Fourth-order Suzuki: five second-order steps of lengths p, p, 1-4p, p, p
+    #[serde(alias = "Suzuki4")]
     Fourth,
 }
 
+impl TrotterOrder {
+    /// Order k of the product formula; one step of length Δt has error O(Δt^(k+1))
+    pub fn order(&self) -> usize {
+        match self {
+            Self::First => 1,
+            Self::Second => 2,
+            Self::Fourth => 4,
+        }
+    }
+
+    /// Upper bound on the simulation error for `steps` Trotter steps
+    ///
+    /// `norm` bounds the Hamiltonian's operator norm, e.g.
+    /// [`PauliSum::one_norm`]. First order uses the commutator bound
+    /// `(Λt)² / 2r`; second and fourth order use the Suzuki bound
+    /// `2 (2·5^(k-1) Λt)^(2k+1) / ((2k+1)! r^(2k))`.
+    pub fn error_bound(&self, norm: f64, time: f64, steps: usize) -> f64 {
+        let x = norm * time.abs();
+        let r = steps.max(1) as f64;
+        match self {
+            Self::First => x.powi(2) / (2.0 * r),
+            Self::Second => 2.0 * (2.0 * x).powi(3) / (6.0 * r.powi(2)),
+            Self::Fourth => 2.0 * (10.0 * x).powi(5) / (120.0 * r.powi(4)),
+        }
+    }
+
+    /// Smallest step count whose [`error_bound`](Self::error_bound) is at most `epsilon`
+    pub fn steps_for_error(&self, norm: f64, time: f64, epsilon: f64) -> usize {
+        let x = norm * time.abs();
+        let estimate = match self {
+            Self::First => x.powi(2) / (2.0 * epsilon),
+            Self::Second => (2.0 * (2.0 * x).powi(3) / (6.0 * epsilon)).sqrt(),
+            Self::Fourth => (2.0 * (10.0 * x).powi(5) / (120.0 * epsilon)).powf(0.25),
+        };
+        let mut steps = (estimate.ceil() as usize).max(1);
+        // Guard against rounding in the closed form
+        while self.error_bound(norm, time, steps) > epsilon {
+            steps += 1;
+        }
+        steps
+    }
+}
+
 /// Trotter decomposition for Hamiltonian simulation
 #[derive(Debug, Clone)]
 pub struct TrotterDecomposition {
@@ -114,13 +158,17 @@ impl TrotterDecomposition {
     }
 
     /// Add rotation for a Pauli string: exp(-iθP)
+    ///
+    /// The first character acts on the highest qubit, matching
+    /// `QuantumRegister::expectation_pauli` and [`HamiltonianSimulation::exact_evolution`].
     fn add_pauli_rotation(&self, mut circuit: Circuit, pauli: &str, theta: f64) -> Circuit {
-        let _n = pauli.len();
+        let n = pauli.len();
 
-        // Find non-identity positions
+        // Find non-identity positions as qubit indices
         let non_identity: Vec<(usize, char)> = pauli.chars()
             .enumerate()
             .filter(|(_, c)| *c != 'I' && *c != 'i')
+            .map(|(i, c)| (n - 1 - i, c))
             .collect();
 
         if non_identity.is_empty() {
@@ -167,17 +215,9 @@ impl TrotterDecomposition {
         circuit
     }
 
-    /// Estimate error bound
+    /// Upper bound on the simulation error, see [`TrotterOrder::error_bound`]
     pub fn error_bound(&self, time: f64) -> f64 {
-        let dt = time / self.steps as f64;
-        let num_terms = self.hamiltonian.terms.len() as f64;
-
-        // Rough error bounds (assuming unit-norm terms)
-        match self.order {
-            TrotterOrder::First => num_terms.powi(2) * dt.powi(2) * self.steps as f64,
-            TrotterOrder::Second => num_terms.powi(3) * dt.powi(3) * self.steps as f64,
-            TrotterOrder::Fourth => num_terms.powi(5) * dt.powi(5) * self.steps as f64,
-        }
+        self.order.error_bound(self.hamiltonian.one_norm(), time, self.steps)
     }
 }
 
@@ -187,6 +227,8 @@ pub struct HamiltonianSimulation {
     pub hamiltonian: PauliSum,
     /// Simulation method
     pub method: SimulationMethod,
+    /// Target simulation error used to choose the step count
+    pub error_target: Option<f64>,
 }
 
 /// Simulation method
@@ -198,7 +240,7 @@ pub enum SimulationMethod {
 
 impl HamiltonianSimulation {
     pub fn new(hamiltonian: PauliSum, method: SimulationMethod) -> Self {
-        Self { hamiltonian, method }
+        Self { hamiltonian, method, error_target: None }
     }
 
     /// Create using Trotter decomposition
@@ -210,18 +252,63 @@ impl HamiltonianSimulation {
     pub fn evolve(&self, initial_state: &mut QuantumRegister, time: f64, steps: usize) -> Result<()> {
         match self.method {
             SimulationMethod::Trotter(order) => {
-                let trotter = TrotterDecomposition::new(
-                    self.hamiltonian.clone(),
-                    order,
-                    steps,
-                );
-                let circuit = trotter.build_circuit(time);
-                initial_state.apply_circuit(&circuit)
-                    .map_err(|e| AlgorithmError::CircuitError(e.to_string()))
+                self.evolve_trotter(initial_state, time, order, steps)
             }
         }
     }
 
+    /// Simulate time evolution with a Trotter formula of the given order
+    pub fn evolve_trotter(
+        &self,
+        initial_state: &mut QuantumRegister,
+        time: f64,
+        order: TrotterOrder,
+        steps: usize,
+    ) -> Result<()> {
+        if steps == 0 {
+            return Err(AlgorithmError::InvalidParameter(
+                "Trotter evolution needs at least one step".to_string()
+            ));
+        }
+        let trotter = TrotterDecomposition::new(self.hamiltonian.clone(), order, steps);
+        let circuit = trotter.build_circuit(time);
+        initial_state.apply_circuit(&circuit)
+            .map_err(|e| AlgorithmError::CircuitError(e.to_string()))
+    }
+
+    /// Choose the step count automatically to keep the error below `epsilon`
+    pub fn with_error_target(mut self, epsilon: f64) -> Self {
+        self.error_target = Some(epsilon);
+        self
+    }
+
+    /// Step count meeting the error target for evolution time `time`
+    pub fn trotter_steps(&self, time: f64) -> Result<usize> {
+        let epsilon = self.error_target.ok_or_else(|| {
+            AlgorithmError::InvalidParameter("No error target set".to_string())
+        })?;
+        if epsilon.is_nan() || epsilon <= 0.0 {
+            return Err(AlgorithmError::InvalidParameter(format!(
+                "Error target must be positive, got {}",
+                epsilon
+            )));
+        }
+        match self.method {
+            SimulationMethod::Trotter(order) => {
+                Ok(order.steps_for_error(self.hamiltonian.one_norm(), time, epsilon))
+            }
+        }
+    }
+
+    /// Evolve with the step count chosen from the error target
+    ///
+    /// Returns the number of Trotter steps used.
+    pub fn evolve_to_target(&self, initial_state: &mut QuantumRegister, time: f64) -> Result<usize> {
+        let steps = self.trotter_steps(time)?;
+        self.evolve(initial_state, time, steps)?;
+        Ok(steps)
+    }
+
     /// Compute exact evolution operator (for small systems)
     pub fn exact_evolution(&self, time: f64) -> Result<DMatrix<Complex>> {
         let n = self.hamiltonian.num_qubits();
@@ -386,6 +473,90 @@ mod tests {
         assert!(error > 0.0);
     }
 
+    #[test]
+    fn test_error_target_chooses_steps() {
+        let hamiltonian = PauliSum::transverse_ising(2, 1.0, 0.5);
+        for order in [TrotterOrder::First, TrotterOrder::Second, TrotterOrder::Fourth] {
+            let loose = HamiltonianSimulation::trotter(hamiltonian.clone(), order)
+                .with_error_target(1e-1)
+                .trotter_steps(1.0)
+                .unwrap();
+            let tight = HamiltonianSimulation::trotter(hamiltonian.clone(), order)
+                .with_error_target(1e-3)
+                .trotter_steps(1.0)
+                .unwrap();
+            assert!(tight > loose);
+            assert!(order.error_bound(hamiltonian.one_norm(), 1.0, tight) <= 1e-3);
+        }
+
+        let unset = HamiltonianSimulation::trotter(hamiltonian, TrotterOrder::First);
+        assert!(unset.trotter_steps(1.0).is_err());
+    }
+
+    #[test]
+    fn test_error_target_met_on_exact_system() {
+        let hamiltonian = PauliSum::transverse_ising(2, 1.0, 0.7);
+        let time = 0.8;
+        let epsilon = 0.02;
+
+        for order in [TrotterOrder::First, TrotterOrder::Second, TrotterOrder::Fourth] {
+            let sim = HamiltonianSimulation::trotter(hamiltonian.clone(), order)
+                .with_error_target(epsilon);
+
+            let mut register = QuantumRegister::new(2);
+            let steps = sim.evolve_to_target(&mut register, time).unwrap();
+            assert!(steps >= 1);
+
+            let exact = sim.exact_evolution(time).unwrap();
+            let overlap: Complex = (0..4)
+                .map(|i| exact[(i, 0)].conj() * register.state.amplitude(i))
+                .sum();
+            // State distance minimized over global phase
+            let error = (2.0 - 2.0 * overlap.norm()).max(0.0).sqrt();
+            assert!(error <= epsilon, "{:?}: error {} with {} steps", order, error, steps);
+        }
+    }
+
+    /// Distance between one Trotter step of length `dt` and exact evolution
+    fn step_error(sim: &HamiltonianSimulation, order: TrotterOrder, dt: f64) -> f64 {
+        let n = sim.hamiltonian.num_qubits();
+        let exact = sim.exact_evolution(dt).unwrap();
+        let mut error: f64 = 0.0;
+        for column in 0..1 << n {
+            let mut register = QuantumRegister::from_state(
+                qops_circuits::StateVector::basis_state(n, column).unwrap()
+            );
+            sim.evolve_trotter(&mut register, dt, order, 1).unwrap();
+            let distance: f64 = (0..1 << n)
+                .map(|row| (exact[(row, column)] - register.state.amplitude(row)).norm_sqr())
+                .sum();
+            error = error.max(distance.sqrt());
+        }
+        error
+    }
+
+    #[test]
+    fn test_trotter_error_scaling() {
+        // Two non-commuting terms; the asymmetric strings check the qubit order
+        let hamiltonian = PauliSum::from_terms(vec![(1.0, "ZI"), (0.7, "XZ")]);
+        let sim = HamiltonianSimulation::trotter(hamiltonian, TrotterOrder::First);
+
+        for order in [TrotterOrder::First, TrotterOrder::Second, TrotterOrder::Fourth] {
+            let coarse = step_error(&sim, order, 0.1);
+            let fine = step_error(&sim, order, 0.05);
+            // Halving Δt shrinks an O(Δt^(k+1)) error by 2^(k+1)
+            let exponent = (coarse / fine).log2();
+            let expected = (order.order() + 1) as f64;
+            assert!(
+                (exponent - expected).abs() < 0.3,
+                "{:?}: error scales as Δt^{:.2}, expected Δt^{}", order, exponent, expected
+            );
+        }
+
+        let mut register = QuantumRegister::new(2);
+        assert!(sim.evolve_trotter(&mut register, 1.0, TrotterOrder::Second, 0).is_err());
+    }
+
     #[test]
     fn test_quantum_walk_hamiltonian() {
         let edges = vec![(0, 1), (1, 2), (2, 3), (3, 0)];
@@ -7,6 +7,7 @@
 //! ### Search & Optimization
 //! - **Grover's Algorithm**: Quadratic speedup for unstructured search
 //! - **QAOA**: Quantum Approximate Optimization Algorithm
+//! - **Amplitude Estimation (QAE)**: Grover counting and Monte-Carlo integration
 //!
 //! ### Number Theory
 //! - **Shor's Algorithm**: Integer factorization
@@ -31,21 +32,25 @@
 //! ```
 
 pub mod grover;
+pub mod amplitude_estimation;
 pub mod qft;
 pub mod qpe;
 pub mod shor;
 pub mod vqe;
+pub mod ansatz;
 pub mod qaoa;
 pub mod hamiltonian;
 pub mod error;
 
-pub use grover::{Grover, Oracle, GroverResult};
+pub use grover::{Grover, Oracle, GroverResult, DEFAULT_MAX_PREDICATE_QUBITS};
+pub use amplitude_estimation::{AmplitudeEstimation, AmplitudeEstimationResult};
 pub use qft::{QuantumFourierTransform, IQFT};
 pub use qpe::{QuantumPhaseEstimation, QPEResult};
-pub use shor::{Shor, ShorResult, FactorizationMethod};
-pub use vqe::{VQE, VQEConfig, VQEResult, Ansatz};
+pub use shor::{Shor, ShorResult, ShorAttempt, AttemptOutcome, FactorizationMethod};
+pub use vqe::{VQE, VQEConfig, VQEResult, Ansatz, Optimizer};
+pub use ansatz::{AnsatzBuilder, HardwareEfficient, UccsdLite};
 pub use qaoa::{QAOA, QAOAConfig, QAOAResult, CostFunction};
-pub use hamiltonian::{HamiltonianSimulation, TrotterDecomposition};
+pub use hamiltonian::{HamiltonianSimulation, TrotterDecomposition, TrotterOrder, SimulationMethod};
 pub use error::{AlgorithmError, Result};
 
 /// Module version
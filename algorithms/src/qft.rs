@@ -55,9 +55,13 @@ impl QuantumFourierTransform {
         let n = self.num_qubits;
         let mut circuit = Circuit::with_name(n, "QFT");
 
+        // Textbook circuit with the most significant qubit first; register
+        // qubit 0 is the least significant bit, so position i is qubit n-1-i
+        let q = |i: usize| n - 1 - i;
+
         for i in 0..n {
             // Hadamard on qubit i
-            circuit = circuit.h(i);
+            circuit = circuit.h(q(i));
 
             // Controlled rotations
             for j in (i + 1)..n {
@@ -70,7 +74,7 @@ impl QuantumFourierTransform {
 
                 // Controlled-R_k rotation
                 let theta = PI / (1 << (k - 1)) as f64;
-                circuit = circuit.cphase(theta, j, i);
+                circuit = circuit.cphase(theta, q(j), q(i));
             }
         }
 
@@ -158,17 +162,18 @@ impl IQFT {
             }
         }
 
-        // Reverse order of operations
+        // Reverse order of operations, with the same qubit mapping as the QFT
+        let q = |i: usize| n - 1 - i;
         for i in (0..n).rev() {
             // Controlled rotations (with negative angles)
             for j in ((i + 1)..n).rev() {
                 let k = j - i + 1;
                 let theta = -PI / (1 << (k - 1)) as f64;
-                circuit = circuit.cphase(theta, j, i);
+                circuit = circuit.cphase(theta, q(j), q(i));
             }
 
             // Hadamard on qubit i
-            circuit = circuit.h(i);
+            circuit = circuit.h(q(i));
         }
 
         circuit
@@ -278,6 +283,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_qft_matches_dft() {
+        let n = 3;
+        let qft = QuantumFourierTransform::new(n);
+        let matrix = qft.matrix();
+
+        for (j, row) in matrix.iter().enumerate() {
+            let mut reg = QuantumRegister::from_state(
+                qops_circuits::StateVector::basis_state(n, j).unwrap()
+            );
+            qft.apply(&mut reg).unwrap();
+            for (k, expected) in row.iter().enumerate() {
+                assert_relative_eq!(reg.state.amplitude(k).re, expected.re, epsilon = 1e-10);
+                assert_relative_eq!(reg.state.amplitude(k).im, expected.im, epsilon = 1e-10);
+            }
+
+            IQFT::new(n).apply(&mut reg).unwrap();
+            assert_relative_eq!(reg.state.probability(j), 1.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_circuit_qft_matches_algorithm_qft() {
+        let n = 3;
+        let pairs = [
+            (Circuit::qft(n), QuantumFourierTransform::new(n).build_circuit()),
+            (Circuit::iqft(n), IQFT::new(n).build_circuit()),
+        ];
+
+        for (library, algorithm) in &pairs {
+            for j in 0..(1 << n) {
+                let basis = || QuantumRegister::from_state(
+                    qops_circuits::StateVector::basis_state(n, j).unwrap()
+                );
+                let mut a = basis();
+                a.apply_circuit(library).unwrap();
+                let mut b = basis();
+                b.apply_circuit(algorithm).unwrap();
+                for k in 0..(1 << n) {
+                    assert_relative_eq!(a.state.amplitude(k).re, b.state.amplitude(k).re, epsilon = 1e-10);
+                    assert_relative_eq!(a.state.amplitude(k).im, b.state.amplitude(k).im, epsilon = 1e-10);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_approximate_qft() {
         let n = 4;
@@ -10,7 +10,7 @@
 //! - Quantum chemistry (energy estimation)
 //! - HHL algorithm (linear systems)
 
-use qops_circuits::{Circuit, Gate, QuantumRegister, Measurement};
+use qops_circuits::{Circuit, Gate, QuantumRegister, Measurement, StateVector};
 use crate::{AlgorithmError, Result, IQFT};
 use nalgebra::DMatrix;
 use num_complex::Complex64;
@@ -59,6 +59,10 @@ pub struct QuantumPhaseEstimation {
     controlled_u_powers: Vec<Circuit>,
     /// Name of the unitary
     pub unitary_name: String,
+    /// The unitary U itself
+    unitary: DMatrix<Complex64>,
+    /// Eigenstate of U used by the iterative path (None = |1...1⟩)
+    eigenstate: Option<StateVector>,
 }
 
 impl QuantumPhaseEstimation {
@@ -85,9 +89,57 @@ impl QuantumPhaseEstimation {
             state_qubits,
             controlled_u_powers,
             unitary_name: "U".to_string(),
+            unitary,
+            eigenstate: None,
         })
     }
 
+    /// Create iterative QPE for a gate, estimating `bits` bits of phase
+    ///
+    /// Iterative QPE reads one bit per round with a single ancilla, so it
+    /// needs `1 + state_qubits` qubits regardless of precision instead of
+    /// `bits + state_qubits`. Controlled powers are applied as matrices, so
+    /// multi-qubit gates are supported as well. Run it with
+    /// [`QuantumPhaseEstimation::estimate_iterative`].
+    pub fn iterative(unitary_gate: &Gate, bits: usize) -> Result<Self> {
+        let unitary = unitary_gate.matrix();
+        let dim = unitary.nrows();
+        if dim == 0 || (dim & (dim - 1)) != 0 || unitary.ncols() != dim {
+            return Err(AlgorithmError::InvalidParameter(
+                "Unitary dimension must be a power of 2".to_string()
+            ));
+        }
+        if bits == 0 {
+            return Err(AlgorithmError::InvalidParameter(
+                "Iterative QPE needs at least one bit".to_string()
+            ));
+        }
+
+        Ok(Self {
+            precision_qubits: bits,
+            state_qubits: (dim as f64).log2() as usize,
+            controlled_u_powers: Vec::new(),
+            unitary_name: unitary_gate.name.clone(),
+            unitary,
+            eigenstate: None,
+        })
+    }
+
+    /// Set the eigenstate the iterative path kicks the phase back from
+    ///
+    /// Defaults to |1...1⟩, the eigenstate carrying the phase of diagonal
+    /// phase gates such as T, S and U1.
+    pub fn with_eigenstate(mut self, eigenstate: StateVector) -> Result<Self> {
+        if eigenstate.num_qubits != self.state_qubits {
+            return Err(AlgorithmError::InvalidQubitCount(format!(
+                "Eigenstate has {} qubits, unitary acts on {}",
+                eigenstate.num_qubits, self.state_qubits
+            )));
+        }
+        self.eigenstate = Some(eigenstate);
+        Ok(self)
+    }
+
     /// Create QPE for a single-qubit gate
     pub fn for_gate(precision_qubits: usize, gate: &Gate) -> Result<Self> {
         if gate.num_qubits != 1 {
@@ -313,6 +365,93 @@ impl QuantumPhaseEstimation {
             counts: stats.counts,
         }
     }
+
+    // ==================== Iterative Estimation ====================
+
+    /// Estimate the phase bit by bit with a single ancilla
+    ///
+    /// Round k (from the least significant bit up) prepares the ancilla in
+    /// |+⟩, applies controlled-U^{2^k}, cancels the contribution of the bits
+    /// already read with a phase correction and measures in the X basis; the
+    /// majority outcome over `shots` runs is taken as the bit. `confidence` is
+    /// the product of the per-round majority fractions, and `counts` holds
+    /// the reconstructed bitstring with the smallest majority count, since no
+    /// register of all bits is ever measured.
+    pub fn estimate_iterative(&self, shots: usize) -> Result<QPEResult> {
+        let bits = self.precision_qubits;
+        let shots = shots.max(1);
+        let eigenstate = self.eigenstate.clone().unwrap_or_else(|| {
+            let dim = 1 << self.state_qubits;
+            StateVector::basis_state(self.state_qubits, dim - 1).expect("index is in range")
+        });
+
+        // U^{2^k} for every round
+        let mut powers = Vec::with_capacity(bits);
+        let mut current = self.unitary.clone();
+        for _ in 0..bits {
+            let next = &current * &current;
+            powers.push(current);
+            current = next;
+        }
+
+        // Binary fraction 0.b_{k+1}...b_m of the bits read so far
+        let mut phase = 0.0;
+        let mut measurement = 0;
+        let mut confidence = 1.0;
+        let mut weakest = shots;
+
+        let circuit_error = |e: qops_circuits::CircuitError| AlgorithmError::CircuitError(e.to_string());
+        for k in (0..bits).rev() {
+            let mut register = QuantumRegister::from_state(self.with_ancilla(&eigenstate));
+            register.apply_single_gate(&Gate::h(), 0).map_err(circuit_error)?;
+            register.state.apply_matrix(&Self::controlled_on_ancilla(&powers[k]));
+            register.apply_single_gate(&Gate::u1(-PI * phase), 0).map_err(circuit_error)?;
+            register.apply_single_gate(&Gate::h(), 0).map_err(circuit_error)?;
+
+            let stats = Measurement::measure_qubits(&register, &[0], shots).map_err(circuit_error)?;
+            let ones = stats.counts.get("1").copied().unwrap_or(0);
+            let bit = usize::from(2 * ones > shots);
+            let majority = if bit == 1 { ones } else { shots - ones };
+
+            confidence *= majority as f64 / shots as f64;
+            weakest = weakest.min(majority);
+            measurement |= bit << (bits - 1 - k);
+            phase = (bit as f64 + phase) / 2.0;
+        }
+
+        let mut counts = std::collections::HashMap::new();
+        counts.insert(format!("{:0width$b}", measurement, width = bits), weakest);
+
+        Ok(QPEResult {
+            phase,
+            confidence,
+            measurement,
+            precision_qubits: bits,
+            counts,
+        })
+    }
+
+    /// |0⟩ ancilla on qubit 0 tensored with the eigenstate on the qubits above it
+    fn with_ancilla(&self, eigenstate: &StateVector) -> StateVector {
+        let mut amplitudes = vec![Complex64::new(0.0, 0.0); 2 * eigenstate.dimension()];
+        for (i, &a) in eigenstate.amplitudes().iter().enumerate() {
+            amplitudes[i << 1] = a;
+        }
+        StateVector::from_amplitudes(amplitudes).expect("dimension is a power of 2")
+    }
+
+    /// Full-space matrix applying `u` to the state qubits when the ancilla is |1⟩
+    fn controlled_on_ancilla(u: &DMatrix<Complex64>) -> DMatrix<Complex64> {
+        let dim = u.nrows();
+        let mut matrix = DMatrix::from_element(2 * dim, 2 * dim, Complex64::new(0.0, 0.0));
+        for row in 0..dim {
+            matrix[(row << 1, row << 1)] = Complex64::new(1.0, 0.0);
+            for col in 0..dim {
+                matrix[((row << 1) | 1, (col << 1) | 1)] = u[(row, col)];
+            }
+        }
+        matrix
+    }
 }
 
 /// Iterative Phase Estimation (uses single ancilla)
@@ -328,45 +467,10 @@ impl IterativePhaseEstimation {
         Self { iterations, unitary }
     }
 
-    /// Run iterative phase estimation
-    pub fn run(&self, shots_per_iteration: usize) -> f64 {
-        let mut phase_estimate = 0.0;
-
-        for k in (0..self.iterations).rev() {
-            // For each bit, measure in rotated basis
-            let mut zero_count = 0;
-
-            for _ in 0..shots_per_iteration {
-                let mut reg = QuantumRegister::new(2);
-
-                // Hadamard on ancilla
-                reg.apply_single_gate(&Gate::h(), 0).ok();
-
-                // Controlled-U^{2^k}
-                for _ in 0..(1 << k) {
-                    reg.apply_two_qubit_gate(&Gate::cnot(), 0, 1).ok();
-                }
-
-                // Phase correction from previous bits
-                let correction = 2.0 * PI * phase_estimate * (1 << k) as f64;
-                reg.apply_single_gate(&Gate::rz(-correction), 0).ok();
-
-                // Hadamard
-                reg.apply_single_gate(&Gate::h(), 0).ok();
-
-                // Measure
-                let result = reg.measure(0).unwrap();
-                if !result {
-                    zero_count += 1;
-                }
-            }
-
-            // Update phase estimate
-            let bit = if zero_count > shots_per_iteration / 2 { 0.0 } else { 1.0 };
-            phase_estimate += bit / (1 << (self.iterations - k)) as f64;
-        }
-
-        phase_estimate
+    /// Run iterative phase estimation, returning φ
+    pub fn run(&self, shots_per_iteration: usize) -> Result<f64> {
+        let qpe = QuantumPhaseEstimation::iterative(&self.unitary, self.iterations)?;
+        Ok(qpe.estimate_iterative(shots_per_iteration)?.phase)
     }
 }
 
@@ -398,6 +502,44 @@ mod tests {
         assert!(result.precision_qubits == 4);
     }
 
+    #[test]
+    fn test_iterative_matches_standard_for_t_gate() {
+        let standard = QuantumPhaseEstimation::for_gate(3, &Gate::t()).unwrap();
+        let standard = standard.estimate_known_phase(0.125, 1000);
+
+        let iterative = QuantumPhaseEstimation::iterative(&Gate::t(), 3).unwrap();
+        let result = iterative.estimate_iterative(200).unwrap();
+
+        assert_relative_eq!(result.phase, 0.125, epsilon = 1e-12);
+        assert_eq!(result.measurement, standard.measurement);
+        assert_relative_eq!(result.phase, standard.phase, epsilon = 1e-12);
+        assert_relative_eq!(result.confidence, 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_iterative_high_precision() {
+        // φ = 0.0101101 in binary, read exactly with 2 qubits instead of 8
+        let phase = 45.0 / 128.0;
+        let qpe = QuantumPhaseEstimation::iterative(&Gate::u1(2.0 * PI * phase), 7).unwrap();
+        let result = qpe.estimate_iterative(100).unwrap();
+        assert_relative_eq!(result.phase, phase, epsilon = 1e-12);
+        assert_eq!(result.measurement, 45);
+        assert_eq!(result.counts.get("0101101"), Some(&100));
+
+        // The phase of |0⟩ under T is zero
+        let qpe = QuantumPhaseEstimation::iterative(&Gate::t(), 4).unwrap()
+            .with_eigenstate(StateVector::new(1))
+            .unwrap();
+        assert_eq!(qpe.estimate_iterative(50).unwrap().phase, 0.0);
+        assert!(QuantumPhaseEstimation::iterative(&Gate::t(), 4).unwrap()
+            .with_eigenstate(StateVector::new(2))
+            .is_err());
+
+        // Errors reach the caller instead of reading as φ = 0
+        assert_relative_eq!(IterativePhaseEstimation::new(3, Gate::t()).run(20).unwrap(), 0.125, epsilon = 1e-12);
+        assert!(IterativePhaseEstimation::new(0, Gate::t()).run(20).is_err());
+    }
+
     #[test]
     fn test_qpe_result() {
         let result = QPEResult {
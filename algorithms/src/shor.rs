@@ -13,10 +13,15 @@
 //! 4. If r is even and a^{r/2} ≠ -1 (mod N):
 //!    - gcd(a^{r/2} ± 1, N) are factors
 
-use qops_circuits::Circuit;
-use rand::Rng;
+use crate::IQFT;
+use qops_circuits::{Circuit, Complex, Gate, Measurement, QuantumRegister};
+use nalgebra::DMatrix;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
-use std::f64::consts::PI;
+
+/// Largest order-finding register `FactorizationMethod::QuantumCircuit` simulates
+pub const MAX_CIRCUIT_QUBITS: usize = 18;
 
 /// Factorization method
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -27,6 +32,46 @@ pub enum FactorizationMethod {
     Simulated,
     /// Hybrid classical-quantum approach
     Hybrid,
+    /// Order-finding circuit run on the state-vector simulator
+    ///
+    /// Falls back to `Simulated` when the register would exceed
+    /// [`MAX_CIRCUIT_QUBITS`]; the result's `method` then reports `Simulated`.
+    QuantumCircuit,
+}
+
+/// Why a single Shor attempt succeeded or failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AttemptOutcome {
+    /// gcd(a, N) was already a non-trivial factor
+    SharedFactor,
+    /// An even period gave a non-trivial factor
+    Factored,
+    /// Period finding returned no usable period
+    NoPeriod,
+    /// The period was odd
+    OddPeriod,
+    /// a^(r/2) ≡ -1 (mod N)
+    TrivialSquareRoot,
+    /// gcd(a^(r/2) ± 1, N) were both trivial
+    TrivialGcd,
+}
+
+impl AttemptOutcome {
+    /// Whether this attempt produced a factor
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::SharedFactor | Self::Factored)
+    }
+}
+
+/// Record of one base tried by Shor's algorithm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShorAttempt {
+    /// Base `a` chosen for this attempt
+    pub base: u64,
+    /// Period measured for `a`, if period finding ran
+    pub period: Option<u64>,
+    /// Outcome of the attempt
+    pub outcome: AttemptOutcome,
 }
 
 /// Result of Shor's algorithm
@@ -46,6 +91,9 @@ pub struct ShorResult {
     pub success: bool,
     /// Method used
     pub method: FactorizationMethod,
+    /// Every attempt made, in order
+    #[serde(default)]
+    pub attempt_log: Vec<ShorAttempt>,
 }
 
 impl ShorResult {
@@ -74,6 +122,8 @@ pub struct Shor {
     pub max_attempts: usize,
     /// Number of qubits for precision
     pub precision_qubits: usize,
+    /// RNG seed (`None` draws from entropy)
+    pub seed: Option<u64>,
 }
 
 impl Shor {
@@ -87,6 +137,7 @@ impl Shor {
             method: FactorizationMethod::Simulated,
             max_attempts: 10,
             precision_qubits: precision.min(20), // Cap for simulation
+            seed: None,
         }
     }
 
@@ -102,109 +153,80 @@ impl Shor {
         self
     }
 
+    /// Set a seed so base choices and measurement noise are reproducible
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Method actually used, after any fallback for oversized circuits
+    pub fn effective_method(&self) -> FactorizationMethod {
+        match self.method {
+            FactorizationMethod::QuantumCircuit if self.circuit_qubits() > MAX_CIRCUIT_QUBITS => {
+                FactorizationMethod::Simulated
+            }
+            method => method,
+        }
+    }
+
+    /// Qubits in the order-finding circuit: precision plus work register
+    pub fn circuit_qubits(&self) -> usize {
+        self.precision_qubits + work_qubits(self.n)
+    }
+
     /// Run Shor's algorithm
     pub fn run(&self) -> ShorResult {
+        let method = self.effective_method();
+        let trivial = |factors: Vec<u64>, success: bool| ShorResult {
+            n: self.n,
+            factors,
+            base: 0,
+            period: None,
+            attempts: 0,
+            success,
+            method,
+            attempt_log: Vec::new(),
+        };
+
         // Check for trivial cases
         if self.n < 2 {
-            return ShorResult {
-                n: self.n,
-                factors: vec![self.n],
-                base: 0,
-                period: None,
-                attempts: 0,
-                success: false,
-                method: self.method,
-            };
+            return trivial(vec![self.n], false);
         }
 
         if self.n % 2 == 0 {
-            return ShorResult {
-                n: self.n,
-                factors: vec![2, self.n / 2],
-                base: 0,
-                period: None,
-                attempts: 0,
-                success: true,
-                method: self.method,
-            };
+            return trivial(vec![2, self.n / 2], true);
         }
 
         // Check if N is a prime power
         if let Some(factors) = self.check_prime_power() {
-            return ShorResult {
-                n: self.n,
-                factors,
-                base: 0,
-                period: None,
-                attempts: 0,
-                success: true,
-                method: self.method,
-            };
+            return trivial(factors, true);
         }
 
         // Main Shor's algorithm loop
-        let mut rng = rand::thread_rng();
+        let mut rng = match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        let mut attempt_log = Vec::with_capacity(self.max_attempts);
 
         for attempt in 0..self.max_attempts {
             // Choose random a in [2, N-1]
             let a = rng.gen_range(2..self.n);
+            let (outcome, period, factor) = self.attempt(a, method, &mut rng);
+            attempt_log.push(ShorAttempt { base: a, period, outcome });
 
-            // Check if we got lucky with gcd
-            let g = gcd(a, self.n);
-            if g > 1 && g < self.n {
+            if let Some(f) = factor {
                 return ShorResult {
                     n: self.n,
-                    factors: vec![g, self.n / g],
+                    factors: vec![f, self.n / f],
                     base: a,
-                    period: None,
+                    period,
                     attempts: attempt + 1,
                     success: true,
-                    method: self.method,
+                    method,
+                    attempt_log,
                 };
             }
-
-            // Find the period of a^x mod N
-            let period = match self.method {
-                FactorizationMethod::Full => self.quantum_period_finding(a),
-                FactorizationMethod::Simulated => self.simulated_period_finding(a),
-                FactorizationMethod::Hybrid => self.hybrid_period_finding(a),
-            };
-
-            if let Some(r) = period {
-                if r > 0 && r % 2 == 0 {
-                    // Try to extract factors
-                    let ar2 = mod_pow(a, r / 2, self.n);
-
-                    if ar2 != self.n - 1 {
-                        let factor1 = gcd(ar2 + 1, self.n);
-                        let factor2 = gcd(ar2 + self.n - 1, self.n); // ar2 - 1 mod n
-
-                        if factor1 > 1 && factor1 < self.n {
-                            return ShorResult {
-                                n: self.n,
-                                factors: vec![factor1, self.n / factor1],
-                                base: a,
-                                period: Some(r),
-                                attempts: attempt + 1,
-                                success: true,
-                                method: self.method,
-                            };
-                        }
-
-                        if factor2 > 1 && factor2 < self.n {
-                            return ShorResult {
-                                n: self.n,
-                                factors: vec![factor2, self.n / factor2],
-                                base: a,
-                                period: Some(r),
-                                attempts: attempt + 1,
-                                success: true,
-                                method: self.method,
-                            };
-                        }
-                    }
-                }
-            }
         }
 
         // Failed to factor
@@ -215,10 +237,57 @@ impl Shor {
             period: None,
             attempts: self.max_attempts,
             success: false,
-            method: self.method,
+            method,
+            attempt_log,
         }
     }
 
+    /// Run one attempt with base `a`, returning its outcome, period and factor
+    fn attempt(
+        &self,
+        a: u64,
+        method: FactorizationMethod,
+        rng: &mut StdRng,
+    ) -> (AttemptOutcome, Option<u64>, Option<u64>) {
+        // Check if we got lucky with gcd
+        let g = gcd(a, self.n);
+        if g > 1 && g < self.n {
+            return (AttemptOutcome::SharedFactor, None, Some(g));
+        }
+
+        // Find the period of a^x mod N
+        let period = match method {
+            FactorizationMethod::Full => self.quantum_period_finding(a),
+            FactorizationMethod::Simulated => self.simulated_period_finding(a),
+            FactorizationMethod::Hybrid => self.hybrid_period_finding(a, rng),
+            FactorizationMethod::QuantumCircuit => self.circuit_period_finding(a, rng),
+        };
+
+        let r = match period {
+            Some(r) if r > 0 => r,
+            _ => return (AttemptOutcome::NoPeriod, period, None),
+        };
+        if r % 2 != 0 {
+            return (AttemptOutcome::OddPeriod, period, None);
+        }
+
+        // Try to extract factors
+        let ar2 = mod_pow(a, r / 2, self.n);
+        if ar2 == self.n - 1 {
+            return (AttemptOutcome::TrivialSquareRoot, period, None);
+        }
+
+        let factor1 = gcd(ar2 + 1, self.n);
+        let factor2 = gcd(ar2 + self.n - 1, self.n); // ar2 - 1 mod n
+
+        for f in [factor1, factor2] {
+            if f > 1 && f < self.n {
+                return (AttemptOutcome::Factored, period, Some(f));
+            }
+        }
+        (AttemptOutcome::TrivialGcd, period, None)
+    }
+
     /// Check if N is a prime power (a^k for some prime a)
     fn check_prime_power(&self) -> Option<Vec<u64>> {
         let n = self.n;
@@ -241,6 +310,30 @@ impl Shor {
         self.simulated_period_finding(a)
     }
 
+    /// Period finding by running the order-finding circuit
+    ///
+    /// One shot of the precision register gives y ≈ s·2^t/r; continued
+    /// fractions turn y/2^t into a candidate denominator, and small
+    /// multiples are tried in case s and r share a factor.
+    fn circuit_period_finding(&self, a: u64, rng: &mut StdRng) -> Option<u64> {
+        let circuit = OrderFindingCircuit::new(self.n, a, self.precision_qubits).build();
+        let mut register = QuantumRegister::new(circuit.num_qubits);
+        register.apply_circuit(&circuit).ok()?;
+
+        let precision: Vec<usize> = (0..self.precision_qubits).collect();
+        let stats = Measurement::measure_qubits_with_rng(&register, &precision, 1, rng).ok()?;
+        let y = stats.counts.keys().next().and_then(|b| u64::from_str_radix(b, 2).ok())?;
+        if y == 0 {
+            return None;
+        }
+
+        let phase = y as f64 / (1u64 << self.precision_qubits) as f64;
+        let candidate = self.continued_fractions(phase, self.n)?;
+        (1..=self.n / candidate.max(1))
+            .map(|k| k * candidate)
+            .find(|&r| mod_pow(a, r, self.n) == 1)
+    }
+
     /// Simulated quantum period finding
     fn simulated_period_finding(&self, a: u64) -> Option<u64> {
         let n = self.n;
@@ -258,14 +351,13 @@ impl Shor {
     }
 
     /// Hybrid period finding (quantum simulation + classical post-processing)
-    fn hybrid_period_finding(&self, a: u64) -> Option<u64> {
+    fn hybrid_period_finding(&self, a: u64, rng: &mut StdRng) -> Option<u64> {
         // Simulate phase estimation
         let true_period = self.simulated_period_finding(a)?;
         let phase = 1.0 / true_period as f64;
 
         // Simulate noisy measurement
         let precision = self.precision_qubits;
-        let mut rng = rand::thread_rng();
 
         // Add some noise to simulate quantum measurement
         let noise: f64 = rng.gen_range(-0.5..0.5) / (1 << precision) as f64;
@@ -362,39 +454,71 @@ impl OrderFindingCircuit {
         Self { n, a, precision_qubits }
     }
 
-    /// Build the order finding circuit (simplified version)
+    /// Number of work qubits, enough to hold values below N
+    pub fn work_qubits(&self) -> usize {
+        work_qubits(self.n)
+    }
+
+    /// Controlled-U^{2^k} with U|x⟩ = |a·x mod N⟩, on (control, work MSB..LSB)
+    ///
+    /// Work values x ≥ N are left unchanged so the gate is a permutation.
+    pub fn controlled_power(&self, k: usize) -> Gate {
+        let work = self.work_qubits();
+        let dim = 1 << work;
+        let multiplier = mod_pow(self.a, 1u64 << k, self.n) as usize;
+        let n = self.n as usize;
+
+        let mut matrix = DMatrix::from_element(2 * dim, 2 * dim, Complex::new(0.0, 0.0));
+        for x in 0..dim {
+            matrix[(x, x)] = Complex::new(1.0, 0.0);
+            let image = if x < n { multiplier * x % n } else { x };
+            matrix[(dim | image, dim | x)] = Complex::new(1.0, 0.0);
+        }
+        Gate::custom(&format!("C-U^{}", 1u64 << k), matrix)
+    }
+
+    /// Build the order finding circuit
+    ///
+    /// Precision qubits are 0..t, with qubit k controlling U^{2^k}; the work
+    /// register above them starts in |1⟩. After the inverse QFT the precision
+    /// register holds y ≈ s·2^t/r for a random s.
     pub fn build(&self) -> Circuit {
-        let work_qubits = (self.n as f64).log2().ceil() as usize;
-        let total = self.precision_qubits + work_qubits;
+        let t = self.precision_qubits;
+        let work = self.work_qubits();
+        let total = t + work;
 
         let mut circuit = Circuit::with_name(total, "OrderFinding");
 
         // Initialize precision register in superposition
-        for i in 0..self.precision_qubits {
+        for i in 0..t {
             circuit = circuit.h(i);
         }
 
         // Initialize work register to |1⟩
-        circuit = circuit.x(self.precision_qubits);
-
-        // Modular exponentiation via phase kickback simulation
-        // The order-finding subroutine encodes the order r in the phases
-        // of the precision qubits after applying controlled-U^{2^k} operations.
-        // Here we use the simulated factorization approach for efficiency.
+        circuit = circuit.x(t);
+
+        // Modular exponentiation: controlled-U^{2^k} from precision qubit k
+        let work_msb_first: Vec<usize> = (t..total).rev().collect();
+        for k in 0..t {
+            let mut qubits = vec![k];
+            qubits.extend(&work_msb_first);
+            circuit.add_gate(self.controlled_power(k), qubits).ok();
+        }
 
         // Inverse QFT on precision register
-        for i in 0..self.precision_qubits {
-            for j in 0..i {
-                let theta = -PI / (1 << (i - j)) as f64;
-                circuit = circuit.cphase(theta, j, i);
-            }
-            circuit = circuit.h(i);
+        for instruction in IQFT::new(t).build_circuit().instructions {
+            circuit.add_gate(instruction.gate, instruction.qubits).ok();
         }
 
         circuit
     }
 }
 
+/// Qubits needed to hold values below `n`
+fn work_qubits(n: u64) -> usize {
+    (64 - n.saturating_sub(1).leading_zeros() as usize).max(1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -427,6 +551,73 @@ mod tests {
         assert_eq!(result.factors[0], 2);
     }
 
+    #[test]
+    fn test_seeded_attempt_log() {
+        let run = || Shor::new(21)
+            .with_method(FactorizationMethod::Hybrid)
+            .with_max_attempts(20)
+            .with_seed(5)
+            .run();
+        let a = run();
+        let b = run();
+
+        assert_eq!(a.attempts, a.attempt_log.len());
+        assert_eq!(a.factors, b.factors);
+        let bases = |r: &ShorResult| r.attempt_log.iter().map(|t| t.base).collect::<Vec<_>>();
+        assert_eq!(bases(&a), bases(&b));
+
+        let last = a.attempt_log.last().unwrap();
+        assert_eq!(last.outcome.is_success(), a.success);
+        assert!(a.attempt_log[..a.attempts - 1].iter().all(|t| !t.outcome.is_success()));
+    }
+
+    #[test]
+    fn test_order_finding_circuit() {
+        // 7 has order 4 mod 15, so y is a multiple of 2^t / 4
+        let order = OrderFindingCircuit::new(15, 7, 6);
+        let circuit = order.build();
+        assert_eq!(circuit.num_qubits, 10);
+
+        let mut register = QuantumRegister::new(circuit.num_qubits);
+        register.apply_circuit(&circuit).unwrap();
+        let probs = register.probabilities();
+        for y in 0..64 {
+            let p: f64 = (0..16).map(|w| probs[(w << 6) | y]).sum();
+            let expected = if y % 16 == 0 { 0.25 } else { 0.0 };
+            assert!((p - expected).abs() < 1e-9, "P(y = {}) = {}", y, p);
+        }
+    }
+
+    #[test]
+    fn test_factor_with_circuit() {
+        for n in [15, 21] {
+            let shor = Shor::new(n)
+                .with_method(FactorizationMethod::QuantumCircuit)
+                .with_max_attempts(30)
+                .with_seed(11);
+            assert_eq!(shor.effective_method(), FactorizationMethod::QuantumCircuit);
+
+            let result = shor.run();
+            assert!(result.success, "failed to factor {}", n);
+            assert!(result.verify());
+            if let Some(r) = result.period {
+                assert_eq!(mod_pow(result.base, r, n), 1);
+            }
+        }
+
+        // 2 has order 6 mod 21; each shot yields 6 or a divisor's multiple that checks out
+        let shor = Shor::new(21);
+        let mut rng = StdRng::seed_from_u64(3);
+        let periods: Vec<Option<u64>> = (0..5).map(|_| shor.circuit_period_finding(2, &mut rng)).collect();
+        assert!(periods.iter().flatten().all(|&r| r == 6));
+        assert!(periods.iter().any(|r| r.is_some()));
+
+        // 2·ceil(log2 N) + 1 precision qubits no longer fit for N = 1001
+        let shor = Shor::new(1001).with_method(FactorizationMethod::QuantumCircuit).with_seed(1);
+        assert!(shor.circuit_qubits() > MAX_CIRCUIT_QUBITS);
+        assert_eq!(shor.run().method, FactorizationMethod::Simulated);
+    }
+
     #[test]
     fn test_mod_pow() {
         assert_eq!(mod_pow(2, 10, 1000), 24);
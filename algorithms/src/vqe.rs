@@ -8,10 +8,12 @@
 //! 3. Classical optimizer updates θ to minimize energy
 //! 4. Repeat until convergence
 
-use qops_circuits::{Circuit, QuantumRegister, Measurement};
+use crate::ansatz::{AnsatzBuilder, HardwareEfficient, UccsdLite};
+use qops_circuits::{Circuit, Gate, QuantumRegister};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
+use std::sync::Arc;
 
 /// VQE configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +32,9 @@ pub struct VQEConfig {
     pub convergence_threshold: f64,
     /// Number of measurement shots per evaluation
     pub shots: usize,
+    /// User-supplied ansatz, used instead of `ansatz` when set
+    #[serde(skip)]
+    pub ansatz_builder: Option<Arc<dyn AnsatzBuilder>>,
 }
 
 impl Default for VQEConfig {
@@ -42,10 +47,20 @@ impl Default for VQEConfig {
             max_iterations: 100,
             convergence_threshold: 1e-6,
             shots: 1024,
+            ansatz_builder: None,
         }
     }
 }
 
+impl VQEConfig {
+    /// Use a custom ansatz; sets `ansatz` to [`Ansatz::Custom`]
+    pub fn with_ansatz_builder(mut self, builder: Box<dyn AnsatzBuilder>) -> Self {
+        self.ansatz = Ansatz::Custom;
+        self.ansatz_builder = Some(Arc::from(builder));
+        self
+    }
+}
+
 /// Ansatz types for VQE
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Ansatz {
@@ -55,14 +70,14 @@ pub enum Ansatz {
     EfficientSU2,
     /// Hardware-efficient ansatz
     HardwareEfficient,
-    /// UCCSD ansatz (chemistry)
+    /// UCCSD ansatz (chemistry), built as [`UccsdLite`] at half filling
     UCCSD,
-    /// Custom ansatz
+    /// Custom ansatz from `VQEConfig::ansatz_builder`
     Custom,
 }
 
 /// Classical optimizer
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Optimizer {
     /// Gradient descent
     GradientDescent,
@@ -74,6 +89,13 @@ pub enum Optimizer {
     NelderMead,
     /// Adam optimizer
     Adam,
+    /// Gradient descent on analytic ±π/2 parameter-shift gradients
+    ParameterShift {
+        /// Learning rate
+        lr: f64,
+        /// Number of descent steps
+        steps: usize,
+    },
 }
 
 /// VQE result
@@ -85,12 +107,18 @@ pub struct VQEResult {
     pub optimal_params: Vec<f64>,
     /// Energy history during optimization
     pub energy_history: Vec<f64>,
+    /// Gradient norm at each iteration (gradient-based optimizers only)
+    #[serde(default)]
+    pub gradient_norm_history: Vec<f64>,
     /// Number of function evaluations
     pub num_evaluations: usize,
     /// Whether optimization converged
     pub converged: bool,
     /// Final variance of energy
     pub variance: f64,
+    /// Measurement settings per energy evaluation after commuting-term grouping
+    #[serde(default)]
+    pub measurement_groups: usize,
 }
 
 /// Variational Quantum Eigensolver
@@ -122,18 +150,36 @@ impl VQE {
         let n = self.config.num_qubits;
         let layers = self.config.layers;
 
+        if let Some(builder) = self.builder() {
+            return builder.num_parameters(n, layers);
+        }
         match self.config.ansatz {
             Ansatz::RealAmplitudes => n * (layers + 1),
             Ansatz::EfficientSU2 => 2 * n * (layers + 1),
-            Ansatz::HardwareEfficient => 3 * n * layers,
             _ => n * layers,
         }
     }
 
+    /// Builder for ansätze not constructed inline
+    fn builder(&self) -> Option<Arc<dyn AnsatzBuilder>> {
+        if let Some(builder) = &self.config.ansatz_builder {
+            return Some(Arc::clone(builder));
+        }
+        match self.config.ansatz {
+            Ansatz::HardwareEfficient => Some(Arc::new(HardwareEfficient)),
+            Ansatz::UCCSD => Some(Arc::new(UccsdLite::new(self.config.num_qubits / 2))),
+            _ => None,
+        }
+    }
+
     /// Build ansatz circuit with given parameters
     pub fn build_ansatz(&self, params: &[f64]) -> Circuit {
         let n = self.config.num_qubits;
         let layers = self.config.layers;
+        if let Some(builder) = self.builder() {
+            return builder.build(params, n);
+        }
+
         let mut circuit = Circuit::with_name(n, "VQE_Ansatz");
 
         let mut param_idx = 0;
@@ -189,25 +235,6 @@ impl VQE {
                 }
             }
 
-            Ansatz::HardwareEfficient => {
-                for _ in 0..layers {
-                    // Single-qubit rotations
-                    for i in 0..n {
-                        circuit = circuit.rx(params[param_idx], i);
-                        param_idx += 1;
-                        circuit = circuit.ry(params[param_idx], i);
-                        param_idx += 1;
-                        circuit = circuit.rz(params[param_idx], i);
-                        param_idx += 1;
-                    }
-
-                    // Entanglement
-                    for i in 0..n-1 {
-                        circuit = circuit.cz(i, i + 1);
-                    }
-                }
-            }
-
             _ => {
                 // Default simple ansatz
                 for i in 0..n {
@@ -229,31 +256,31 @@ impl VQE {
         self.hamiltonian.expectation_value(&register)
     }
 
-    /// Evaluate energy with sampling (noisy)
+    /// Evaluate energy and its shot variance, measuring one basis per group
+    ///
+    /// Terms are grouped with [`PauliSum::group_commuting`]; each group costs
+    /// one measurement setting of `shots` shots, and covariances between
+    /// terms read from the same shots are included in the variance.
     pub fn evaluate_energy_sampled(&self, params: &[f64]) -> (f64, f64) {
         let circuit = self.build_ansatz(params);
         let mut register = QuantumRegister::new(self.config.num_qubits);
         register.apply_circuit(&circuit).ok();
 
-        // Sample each Pauli term
         let mut total_energy = 0.0;
         let mut total_variance = 0.0;
 
-        for term in &self.hamiltonian.terms {
-            let (exp, var) = self.measure_pauli_term(&register, &term.pauli, self.config.shots);
-            total_energy += term.coefficient * exp;
-            total_variance += term.coefficient.powi(2) * var;
+        for group in self.hamiltonian.group_commuting() {
+            let (exp, var) = group.measure(&register);
+            total_energy += exp;
+            total_variance += var / self.config.shots.max(1) as f64;
         }
 
         (total_energy, total_variance)
     }
 
-    /// Measure a Pauli term
-    fn measure_pauli_term(&self, register: &QuantumRegister, pauli: &str, shots: usize) -> (f64, f64) {
-        // For exact simulation, use expectation value
-        let exp = Measurement::expectation_pauli(register, pauli).unwrap_or(0.0);
-        let var = Measurement::variance_pauli(register, pauli).unwrap_or(0.0);
-        (exp, var / shots as f64)
+    /// Number of measurement settings needed per energy evaluation
+    pub fn measurement_groups(&self) -> usize {
+        self.hamiltonian.group_commuting().len()
     }
 
     /// Run VQE optimization
@@ -264,6 +291,7 @@ impl VQE {
             .collect();
 
         let mut energy_history = Vec::new();
+        let mut gradient_norm_history = Vec::new();
         let mut best_energy = f64::INFINITY;
         let mut best_params = params.clone();
         let mut num_evaluations = 0;
@@ -293,6 +321,7 @@ impl VQE {
                     // Compute gradient via parameter shift
                     let gradient = self.compute_gradient(&params);
                     num_evaluations += 2 * num_params;
+                    gradient_norm_history.push(norm(&gradient));
 
                     // Update parameters
                     for (p, g) in params.iter_mut().zip(gradient.iter()) {
@@ -301,6 +330,34 @@ impl VQE {
                 }
             }
 
+            Optimizer::ParameterShift { lr, steps } => {
+                // Every step is recorded so flat landscapes show up in the
+                // gradient norm history; stop only once the gradient vanishes
+                for _step in 0..steps {
+                    let energy = self.evaluate_energy(&params);
+                    energy_history.push(energy);
+                    num_evaluations += 1;
+
+                    if energy < best_energy {
+                        best_energy = energy;
+                        best_params = params.clone();
+                    }
+
+                    let gradient = self.compute_gradient(&params);
+                    num_evaluations += 2 * num_params;
+                    let gradient_norm = norm(&gradient);
+                    gradient_norm_history.push(gradient_norm);
+
+                    if gradient_norm < self.config.convergence_threshold {
+                        break;
+                    }
+
+                    for (p, g) in params.iter_mut().zip(gradient.iter()) {
+                        *p -= lr * g;
+                    }
+                }
+            }
+
             Optimizer::SPSA => {
                 let a = 0.1;
                 let c = 0.1;
@@ -380,15 +437,18 @@ impl VQE {
             last_delta < self.config.convergence_threshold
         } else {
             false
-        };
+        } || gradient_norm_history.last()
+            .is_some_and(|&g| g < self.config.convergence_threshold);
 
         VQEResult {
             energy: final_energy,
             optimal_params: best_params,
             energy_history,
+            gradient_norm_history,
             num_evaluations,
             converged,
             variance,
+            measurement_groups: self.measurement_groups(),
         }
     }
 
@@ -414,6 +474,11 @@ impl VQE {
     }
 }
 
+/// Euclidean norm of a gradient
+fn norm(gradient: &[f64]) -> f64 {
+    gradient.iter().map(|g| g * g).sum::<f64>().sqrt()
+}
+
 /// Pauli term in a Hamiltonian
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PauliTerm {
@@ -423,6 +488,76 @@ pub struct PauliTerm {
     pub pauli: String,
 }
 
+/// Qubit-wise commuting Pauli terms measurable in a single basis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauliGroup {
+    /// Measurement basis per position, 'I' where no term acts
+    pub basis: String,
+    /// Terms in the group
+    pub terms: Vec<PauliTerm>,
+}
+
+impl PauliGroup {
+    /// Whether a Pauli string agrees with this basis on every position it acts on
+    fn accepts(&self, pauli: &str) -> bool {
+        self.basis.len() == pauli.len()
+            && self.basis.chars().zip(pauli.chars()).all(|(b, p)| {
+                let p = p.to_ascii_uppercase();
+                b == 'I' || p == 'I' || b == p
+            })
+    }
+
+    fn insert(&mut self, term: &PauliTerm) {
+        self.basis = self.basis.chars()
+            .zip(term.pauli.chars())
+            .map(|(b, p)| if b == 'I' { p.to_ascii_uppercase() } else { b })
+            .collect();
+        self.terms.push(term.clone());
+    }
+
+    /// Expectation and single-shot variance of the group's weighted sum
+    ///
+    /// Rotates a copy of the register into the group basis, so every term
+    /// becomes a Z-parity read from the same outcome distribution.
+    pub fn measure(&self, register: &QuantumRegister) -> (f64, f64) {
+        let n = self.basis.len();
+        let mut rotated = register.clone();
+        // The first character acts on the highest qubit
+        for (i, b) in self.basis.chars().enumerate() {
+            let qubit = n - 1 - i;
+            match b {
+                'X' => { rotated.apply_single_gate(&Gate::h(), qubit).ok(); }
+                'Y' => {
+                    rotated.apply_single_gate(&Gate::sdg(), qubit).ok();
+                    rotated.apply_single_gate(&Gate::h(), qubit).ok();
+                }
+                _ => {}
+            }
+        }
+
+        let masks: Vec<usize> = self.terms.iter()
+            .map(|t| t.pauli.chars()
+                .enumerate()
+                .filter(|(_, c)| !matches!(c, 'I' | 'i'))
+                .fold(0, |m, (i, _)| m | (1 << (n - 1 - i))))
+            .collect();
+
+        let (mut mean, mut square) = (0.0, 0.0);
+        for (outcome, p) in rotated.probabilities().into_iter().enumerate() {
+            let value: f64 = self.terms.iter()
+                .zip(&masks)
+                .map(|(t, &mask)| {
+                    let sign = if (outcome & mask).count_ones() % 2 == 0 { 1.0 } else { -1.0 };
+                    t.coefficient * sign
+                })
+                .sum();
+            mean += p * value;
+            square += p * value * value;
+        }
+        (mean, (square - mean * mean).max(0.0))
+    }
+}
+
 /// Sum of Pauli terms representing a Hamiltonian
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PauliSum {
@@ -460,11 +595,37 @@ impl PauliSum {
             .unwrap_or(0)
     }
 
+    /// Sum of absolute coefficients, an upper bound on the operator norm
+    pub fn one_norm(&self) -> f64 {
+        self.terms.iter().map(|t| t.coefficient.abs()).sum()
+    }
+
+    /// Partition terms into qubit-wise commuting groups
+    ///
+    /// Greedy first fit in term order: each term joins the first group whose
+    /// basis it agrees with on every qubit it acts on. Identity terms join
+    /// any group.
+    pub fn group_commuting(&self) -> Vec<PauliGroup> {
+        let n = self.num_qubits();
+        let mut groups: Vec<PauliGroup> = Vec::new();
+        for term in &self.terms {
+            match groups.iter_mut().find(|g| g.accepts(&term.pauli)) {
+                Some(group) => group.insert(term),
+                None => {
+                    let mut group = PauliGroup { basis: "I".repeat(n), terms: Vec::new() };
+                    group.insert(term);
+                    groups.push(group);
+                }
+            }
+        }
+        groups
+    }
+
     /// Compute expectation value
     pub fn expectation_value(&self, register: &QuantumRegister) -> f64 {
         self.terms.iter()
             .map(|term| {
-                term.coefficient * Measurement::expectation_pauli(register, &term.pauli)
+                term.coefficient * register.expectation_pauli(&term.pauli)
                     .unwrap_or(0.0)
             })
             .sum()
@@ -545,6 +706,90 @@ mod tests {
         assert!(result.num_evaluations > 0);
     }
 
+    #[test]
+    fn test_parameter_shift_descent() {
+        let hamiltonian = PauliSum::from_terms(vec![(1.0, "ZI"), (0.5, "XX")]);
+        let config = VQEConfig {
+            num_qubits: 2,
+            layers: 1,
+            optimizer: Optimizer::ParameterShift { lr: 0.2, steps: 150 },
+            convergence_threshold: 1e-8,
+            ..Default::default()
+        };
+        let vqe = VQE::new(config, hamiltonian);
+
+        // The shift rule is exact for Ry rotations, unlike finite differences
+        let params = [0.3, -1.2, 0.7, 2.1];
+        let h = 1e-5;
+        for (i, g) in vqe.compute_gradient(&params).iter().enumerate() {
+            let mut plus = params.to_vec();
+            let mut minus = params.to_vec();
+            plus[i] += h;
+            minus[i] -= h;
+            let numeric = (vqe.evaluate_energy(&plus) - vqe.evaluate_energy(&minus)) / (2.0 * h);
+            assert_relative_eq!(*g, numeric, epsilon = 1e-6);
+        }
+
+        let result = vqe.run();
+        assert_eq!(result.energy_history.len(), result.gradient_norm_history.len());
+        assert!(result.energy_history.len() <= 150);
+        // Ground energy of Z⊗I + 0.5 X⊗X is -√1.25
+        assert!(result.energy < -1.1, "energy {}", result.energy);
+        assert!(result.gradient_norm_history.last().unwrap() < &result.gradient_norm_history[0]);
+    }
+
+    #[test]
+    fn test_custom_ansatz_builder() {
+        /// Single Ry on qubit 0, one parameter per layer
+        #[derive(Debug)]
+        struct Rotation;
+
+        impl AnsatzBuilder for Rotation {
+            fn build(&self, params: &[f64], qubits: usize) -> Circuit {
+                let mut circuit = Circuit::new(qubits);
+                for &theta in params {
+                    circuit = circuit.ry(theta, 0);
+                }
+                circuit
+            }
+
+            fn num_parameters(&self, _qubits: usize, layers: usize) -> usize {
+                layers
+            }
+        }
+
+        let config = VQEConfig {
+            num_qubits: 1,
+            layers: 3,
+            ..Default::default()
+        }
+        .with_ansatz_builder(Box::new(Rotation));
+        let vqe = VQE::new(config, PauliSum::from_terms(vec![(1.0, "Z")]));
+
+        assert_eq!(vqe.config.ansatz, Ansatz::Custom);
+        assert_eq!(vqe.num_parameters(), 3);
+        assert_eq!(vqe.build_ansatz(&[0.1, 0.2, 0.3]).gate_count(), 3);
+        // Ry(π) takes |0⟩ to |1⟩
+        assert_relative_eq!(vqe.evaluate_energy(&[PI, 0.0, 0.0]), -1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_uccsd_keeps_electron_count() {
+        // H = Z on every qubit favours |0⟩, but UCCSD keeps two electrons
+        let hamiltonian = PauliSum::from_terms(vec![(1.0, "ZIII"), (1.0, "IZII"), (1.0, "IIZI"), (1.0, "IIIZ")]);
+        let config = VQEConfig {
+            num_qubits: 4,
+            ansatz: Ansatz::UCCSD,
+            layers: 1,
+            ..Default::default()
+        };
+        let vqe = VQE::new(config, hamiltonian);
+        assert_eq!(vqe.num_parameters(), 5);
+
+        let params: Vec<f64> = (0..5).map(|i| 0.3 * i as f64).collect();
+        assert_relative_eq!(vqe.evaluate_energy(&params), 0.0, epsilon = 1e-10);
+    }
+
     #[test]
     fn test_ansatz_building() {
         let hamiltonian = PauliSum::from_terms(vec![(1.0, "ZZ")]);
@@ -571,4 +816,29 @@ mod tests {
         assert!(!hamiltonian.terms.is_empty());
         assert_eq!(hamiltonian.num_qubits(), 2);
     }
+
+    #[test]
+    fn test_commuting_groups() {
+        // 3 ZZ couplings and 4 X fields share just a Z and an X basis
+        let hamiltonian = PauliSum::transverse_ising(4, 1.0, 0.5);
+        assert_eq!(hamiltonian.terms.len(), 7);
+        let groups = hamiltonian.group_commuting();
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].basis, "ZZZZ");
+        assert_eq!(groups[1].basis, "XXXX");
+
+        // XX, YY and ZZ pairwise anticommute qubit-wise
+        assert_eq!(PauliSum::heisenberg(2, 1.0).group_commuting().len(), 3);
+
+        // Grouped measurement reproduces the exact energy
+        let hamiltonian = PauliSum::from_terms(vec![(0.7, "ZI"), (-0.4, "XY"), (1.1, "ZZ"), (0.2, "IY")]);
+        let config = VQEConfig { num_qubits: 2, layers: 1, ansatz: Ansatz::EfficientSU2, ..Default::default() };
+        let vqe = VQE::new(config, hamiltonian);
+        assert_eq!(vqe.measurement_groups(), 2);
+
+        let params: Vec<f64> = (0..vqe.num_parameters()).map(|i| 0.37 * i as f64 - 0.9).collect();
+        let (energy, variance) = vqe.evaluate_energy_sampled(&params);
+        assert_relative_eq!(energy, vqe.evaluate_energy(&params), epsilon = 1e-10);
+        assert!(variance >= 0.0);
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! Provides a fluent API for building quantum circuits.
 
-use crate::{Gate, Result, CircuitError};
+use crate::qasm::qasm_gate;
+use crate::{Gate, GateType, PauliError, Result, CircuitError};
 use serde::{Deserialize, Serialize};
 
 /// A single instruction in a quantum circuit
@@ -34,6 +35,9 @@ pub struct Circuit {
     pub num_classical_bits: usize,
     /// Circuit instructions
     pub instructions: Vec<CircuitInstruction>,
+    /// Measurements as (qubit, classical bit), in program order
+    #[serde(default)]
+    pub measurements: Vec<(usize, usize)>,
     /// Circuit name
     pub name: String,
 }
@@ -45,6 +49,7 @@ impl Circuit {
             num_qubits,
             num_classical_bits: num_qubits,
             instructions: Vec::new(),
+            measurements: Vec::new(),
             name: String::from("circuit"),
         }
     }
@@ -55,6 +60,7 @@ impl Circuit {
             num_qubits,
             num_classical_bits: num_qubits,
             instructions: Vec::new(),
+            measurements: Vec::new(),
             name: name.to_string(),
         }
     }
@@ -142,6 +148,49 @@ impl Circuit {
         Ok(self)
     }
 
+    /// Measure `qubit` into classical bit `bit`
+    pub fn add_measurement(&mut self, qubit: usize, bit: usize) -> Result<&mut Self> {
+        if qubit >= self.num_qubits {
+            return Err(CircuitError::InvalidQubitIndex(qubit, self.num_qubits));
+        }
+        if bit >= self.num_classical_bits {
+            return Err(CircuitError::InvalidParameter(format!(
+                "Classical bit {} out of range, circuit has {} classical bits",
+                bit, self.num_classical_bits
+            )));
+        }
+        self.measurements.push((qubit, bit));
+        Ok(self)
+    }
+
+    /// Insert a Pauli error on `qubit` right after instruction `after_instruction`
+    ///
+    /// Unlike a [`NoiseModel`](crate::NoiseModel), the fault is deterministic:
+    /// every run of the circuit sees the same error at the same location.
+    pub fn inject_fault(
+        &mut self,
+        after_instruction: usize,
+        error: PauliError,
+        qubit: usize,
+    ) -> Result<&mut Self> {
+        if qubit >= self.num_qubits {
+            return Err(CircuitError::InvalidQubitIndex(qubit, self.num_qubits));
+        }
+        if after_instruction >= self.instructions.len() {
+            return Err(CircuitError::InvalidParameter(format!(
+                "Instruction index {} out of range, circuit has {} instructions",
+                after_instruction,
+                self.instructions.len()
+            )));
+        }
+        self.instructions.insert(after_instruction + 1, CircuitInstruction {
+            gate: error.gate(),
+            qubits: vec![qubit],
+            condition: None,
+        });
+        Ok(self)
+    }
+
     // ==================== Single-Qubit Gates ====================
 
     /// Apply Identity gate
@@ -301,6 +350,43 @@ impl Circuit {
         self.fredkin(control, target1, target2)
     }
 
+    // ==================== Measurement ====================
+
+    /// Measure a qubit into a classical bit
+    pub fn measure(mut self, qubit: usize, bit: usize) -> Self {
+        self.add_measurement(qubit, bit).ok();
+        self
+    }
+
+    /// Measure every qubit into the classical bit of the same index
+    pub fn measure_all(mut self) -> Self {
+        for q in 0..self.num_qubits.min(self.num_classical_bits) {
+            self = self.measure(q, q);
+        }
+        self
+    }
+
+    // ==================== Multi-Controlled Gates ====================
+
+    /// Add a multi-controlled X flipping `target` when all `controls` are |1⟩
+    pub fn add_mcx(&mut self, controls: Vec<usize>, target: usize) -> Result<&mut Self> {
+        for (i, &c) in controls.iter().enumerate() {
+            if c == target || controls[..i].contains(&c) {
+                return Err(CircuitError::SameQubitIndex(c, c));
+            }
+        }
+        let gate = Gate::mcx(controls.len());
+        let mut qubits = controls;
+        qubits.push(target);
+        self.add_gate(gate, qubits)
+    }
+
+    /// Apply a multi-controlled X (0 controls is X, 1 is CNOT, 2 is Toffoli)
+    pub fn mcx(mut self, controls: Vec<usize>, target: usize) -> Self {
+        self.add_mcx(controls, target).ok();
+        self
+    }
+
     // ==================== Multi-Qubit Operations ====================
 
     /// Apply Hadamard to all qubits
@@ -336,16 +422,36 @@ impl Circuit {
             ));
         }
         self.instructions.extend(other.instructions.clone());
+        self.measurements.extend(other.measurements.iter().copied());
         Ok(self)
     }
 
     /// Get the inverse circuit
-    pub fn inverse(&self) -> Self {
-        let mut inv = Circuit::new(self.num_qubits);
+    ///
+    /// Reverses the instruction order and replaces every gate with its
+    /// adjoint, so `c.then(&c.inverse()?)` acts as the identity. Measurements
+    /// and non-unitary custom gates cannot be undone and fail with
+    /// [`CircuitError::NotInvertible`].
+    pub fn inverse(&self) -> Result<Self> {
+        if !self.measurements.is_empty() {
+            return Err(CircuitError::NotInvertible(format!(
+                "'{}' contains {} measurements",
+                self.name,
+                self.measurements.len()
+            )));
+        }
+
+        let mut inv = Circuit::new(self.num_qubits).classical_bits(self.num_classical_bits);
         inv.name = format!("{}†", self.name);
 
         // Reverse the order and take adjoint of each gate
         for instruction in self.instructions.iter().rev() {
+            if instruction.gate.gate_type == GateType::Custom && !instruction.gate.is_unitary() {
+                return Err(CircuitError::NotInvertible(format!(
+                    "gate {} is not unitary",
+                    instruction.gate.name
+                )));
+            }
             inv.instructions.push(CircuitInstruction {
                 gate: instruction.gate.adjoint(),
                 qubits: instruction.qubits.clone(),
@@ -353,7 +459,17 @@ impl Circuit {
             });
         }
 
-        inv
+        Ok(inv)
+    }
+
+    /// This circuit followed by `other`, widened to fit both
+    pub fn then(&self, other: &Circuit) -> Self {
+        let mut combined = self.clone();
+        combined.num_qubits = self.num_qubits.max(other.num_qubits);
+        combined.num_classical_bits = self.num_classical_bits.max(other.num_classical_bits);
+        combined.instructions.extend(other.instructions.iter().cloned());
+        combined.measurements.extend(other.measurements.iter().copied());
+        combined
     }
 
     /// Repeat the circuit n times
@@ -369,7 +485,10 @@ impl Circuit {
     }
 
     /// Convert to OpenQASM 2.0 string
-    pub fn to_qasm(&self) -> String {
+    ///
+    /// Fails with [`CircuitError::UnsupportedGate`] for gates that have no
+    /// `qelib1.inc` counterpart (such as iSWAP, √X† or custom matrices).
+    pub fn to_qasm(&self) -> Result<String> {
         let mut qasm = String::new();
         qasm.push_str("OPENQASM 2.0;\n");
         qasm.push_str("include \"qelib1.inc\";\n\n");
@@ -377,18 +496,135 @@ impl Circuit {
         qasm.push_str(&format!("creg c[{}];\n\n", self.num_classical_bits));
 
         for instruction in &self.instructions {
-            let gate_name = instruction.gate.name.to_lowercase();
             let qubits: Vec<String> = instruction.qubits.iter()
                 .map(|q| format!("q[{}]", q))
                 .collect();
 
-            qasm.push_str(&format!("{} {};\n", gate_name, qubits.join(", ")));
+            qasm.push_str(&format!("{} {};\n", qasm_gate(&instruction.gate)?, qubits.join(", ")));
         }
 
-        qasm
+        for &(qubit, bit) in &self.measurements {
+            qasm.push_str(&format!("measure q[{}] -> c[{}];\n", qubit, bit));
+        }
+
+        Ok(qasm)
+    }
+
+    /// Render the circuit as an ASCII timeline diagram
+    ///
+    /// Each qubit is a horizontal wire; gates are drawn as `[G]` boxes,
+    /// controls as `*`, swap endpoints as `x`, and multi-qubit gates are
+    /// joined by a vertical `|` line (wires it crosses show `+`).
+    ///
+    /// ```text
+    /// q0: -[H]---*--
+    ///            |
+    /// q1: ------[X]-
+    /// ```
+    pub fn to_ascii_diagram(&self) -> String {
+        let n = self.num_qubits;
+        if n == 0 {
+            return String::new();
+        }
+
+        // Pack gates into columns; a multi-qubit gate blocks its whole span
+        let mut columns: Vec<Vec<&CircuitInstruction>> = Vec::new();
+        let mut next_free = vec![0usize; n];
+        for instruction in &self.instructions {
+            let (lo, hi) = match (instruction.qubits.iter().min(), instruction.qubits.iter().max()) {
+                (Some(&lo), Some(&hi)) if hi < n => (lo, hi),
+                _ => continue,
+            };
+            let col = next_free[lo..=hi].iter().copied().max().unwrap_or(0);
+            if col == columns.len() {
+                columns.push(Vec::new());
+            }
+            columns[col].push(instruction);
+            for slot in &mut next_free[lo..=hi] {
+                *slot = col + 1;
+            }
+        }
+
+        let labels: Vec<String> = (0..n).map(|q| format!("q{}: ", q)).collect();
+        let prefix_width = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        let mut rows: Vec<String> = (0..2 * n - 1)
+            .map(|r| {
+                if r % 2 == 0 {
+                    format!("{:<width$}", labels[r / 2], width = prefix_width)
+                } else {
+                    " ".repeat(prefix_width)
+                }
+            })
+            .collect();
+
+        for column in &columns {
+            let symbols: Vec<Vec<String>> = column.iter().map(|inst| ascii_symbols(inst)).collect();
+            let width = symbols.iter().flatten().map(|s| s.chars().count()).max().unwrap_or(1);
+            let center = width / 2;
+
+            let mut cells: Vec<Option<String>> = vec![None; 2 * n - 1];
+            for (inst, syms) in column.iter().zip(&symbols) {
+                let lo = *inst.qubits.iter().min().unwrap_or(&0);
+                let hi = *inst.qubits.iter().max().unwrap_or(&0);
+                for (r, cell) in cells.iter_mut().enumerate().take(2 * hi + 1).skip(2 * lo) {
+                    *cell = Some(if r % 2 == 1 { "|".to_string() } else { "+".to_string() });
+                }
+                for (&q, sym) in inst.qubits.iter().zip(syms) {
+                    cells[2 * q] = Some(sym.clone());
+                }
+            }
+
+            for (r, row) in rows.iter_mut().enumerate() {
+                let fill = if r % 2 == 0 { '-' } else { ' ' };
+                let mut cell: Vec<char> = vec![fill; width];
+                if let Some(sym) = &cells[r] {
+                    let sym: Vec<char> = sym.chars().collect();
+                    let left = center - sym.len() / 2;
+                    cell[left..left + sym.len()].copy_from_slice(&sym);
+                }
+                row.push(fill);
+                row.extend(cell);
+                row.push(fill);
+            }
+        }
+
+        let mut out = String::new();
+        for row in &rows {
+            out.push_str(row.trim_end());
+            out.push('\n');
+        }
+        out
     }
 }
 
+/// Per-qubit ASCII symbols for an instruction, in `qubits` order
+fn ascii_symbols(instruction: &CircuitInstruction) -> Vec<String> {
+    let boxed = |label: &str| format!("[{}]", label);
+    let gate = &instruction.gate;
+    let label = gate.name.strip_prefix('C').unwrap_or(&gate.name);
+
+    let mut symbols = match gate.gate_type {
+        GateType::CNOT => vec!["*".to_string(), boxed("X")],
+        GateType::CY => vec!["*".to_string(), boxed("Y")],
+        GateType::CZ => vec!["*".to_string(), "*".to_string()],
+        GateType::CRx | GateType::CRy | GateType::CRz | GateType::CPhase => {
+            vec!["*".to_string(), boxed(label)]
+        }
+        GateType::SWAP => vec!["x".to_string(), "x".to_string()],
+        GateType::Toffoli => vec!["*".to_string(), "*".to_string(), boxed("X")],
+        GateType::Fredkin => vec!["*".to_string(), "x".to_string(), "x".to_string()],
+        GateType::MCX => {
+            let mut symbols = vec!["*".to_string(); instruction.qubits.len().saturating_sub(1)];
+            symbols.push(boxed("X"));
+            symbols
+        }
+        _ => vec![boxed(&gate.name); instruction.qubits.len()],
+    };
+
+    symbols.resize(instruction.qubits.len(), boxed(&gate.name));
+    symbols
+}
+
 /// Builder pattern for more complex circuits
 pub struct CircuitBuilder {
     circuit: Circuit,
@@ -472,12 +708,16 @@ impl Circuit {
 
         let mut circuit = Circuit::with_name(n, "QFT");
 
+        // Same layout as `qops_algorithms::QuantumFourierTransform`: textbook
+        // position i is register qubit n-1-i (qubit 0 is the least significant bit)
+        let q = |i: usize| n - 1 - i;
+
         for i in 0..n {
-            circuit = circuit.h(i);
+            circuit = circuit.h(q(i));
             for j in (i+1)..n {
                 let k = j - i + 1;
                 let theta = PI / (1 << (k - 1)) as f64;
-                circuit = circuit.cphase(theta, j, i);
+                circuit = circuit.cphase(theta, q(j), q(i));
             }
         }
 
@@ -491,7 +731,7 @@ impl Circuit {
 
     /// Create inverse QFT
     pub fn iqft(n: usize) -> Self {
-        Circuit::qft(n).inverse()
+        Circuit::qft(n).inverse().expect("QFT contains no measurements")
     }
 }
 
@@ -520,6 +760,29 @@ mod tests {
         assert_eq!(circuit.depth(), 2);
     }
 
+    #[test]
+    fn test_ascii_diagram_bell() {
+        let diagram = Circuit::bell_state().to_ascii_diagram();
+        let expected = "q0: -[H]---*--\n           |\nq1: ------[X]-\n";
+        assert_eq!(diagram, expected);
+    }
+
+    #[test]
+    fn test_ascii_diagram_spans() {
+        // The Toffoli spans q0..q2 and crosses nothing; the CZ on (0, 2) crosses q1
+        let circuit = Circuit::new(3).toffoli(0, 1, 2).cz(0, 2).x(1);
+        let diagram = circuit.to_ascii_diagram();
+        let lines: Vec<&str> = diagram.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[0].starts_with("q0: "));
+        assert!(lines[2].contains('+'));
+        assert!(lines[2].contains("[X]"));
+        assert!(lines[4].contains("[X]"));
+        assert_eq!(lines[0].matches('*').count(), 2);
+        assert!(Circuit::new(0).to_ascii_diagram().is_empty());
+    }
+
     #[test]
     fn test_bell_state() {
         let circuit = Circuit::bell_state();
@@ -541,7 +804,70 @@ mod tests {
             .s(0)
             .cnot(0, 1);
 
-        let inverse = circuit.inverse();
+        let inverse = circuit.inverse().unwrap();
         assert_eq!(inverse.gate_count(), circuit.gate_count());
+        assert_eq!(inverse.instructions[0].gate.gate_type, GateType::CNOT);
+        assert_eq!(inverse.instructions[1].gate.gate_type, GateType::PhaseDag);
+    }
+
+    #[test]
+    fn test_inverse_restores_initial_state() {
+        use crate::{QuantumRegister, StateVector};
+
+        let c = Circuit::new(3)
+            .h(0)
+            .s(1)
+            .t(2)
+            .rx(0.3, 0)
+            .ry(1.2, 1)
+            .u3(0.4, -0.8, 2.1, 2)
+            .cnot(0, 1)
+            .crz(0.7, 1, 2)
+            .cphase(-1.3, 2, 0)
+            .iswap(0, 2)
+            .sdg(1);
+
+        let initial = StateVector::basis_state(3, 0b101).unwrap();
+        let mut reg = QuantumRegister::from_state(initial.clone());
+        reg.apply_circuit(&c.then(&c.inverse().unwrap())).unwrap();
+        assert!((reg.state.fidelity(&initial) - 1.0).abs() < 1e-9);
+        for (a, b) in reg.state.amplitudes().iter().zip(initial.amplitudes()) {
+            assert!((a - b).norm() < 1e-9);
+        }
+
+        assert!(matches!(
+            c.clone().measure_all().inverse(),
+            Err(CircuitError::NotInvertible(_))
+        ));
+    }
+
+    #[test]
+    fn test_inject_fault_repetition_code() {
+        use crate::QuantumRegister;
+
+        // Three-qubit bit-flip code: encode |1⟩, then move parities onto q1, q2
+        let code = Circuit::new(3)
+            .x(0)
+            .cnot(0, 1)
+            .cnot(0, 2)
+            .cnot(0, 1)
+            .cnot(0, 2);
+
+        // (faulty qubit, expected final basis state; q1, q2 hold the syndrome)
+        for (qubit, expected) in [(0, 0b110), (1, 0b011), (2, 0b101)] {
+            let mut faulty = code.clone();
+            faulty.inject_fault(2, PauliError::X, qubit).unwrap();
+            assert_eq!(faulty.gate_count(), code.gate_count() + 1);
+            assert_eq!(faulty.instructions[3].gate.name, "X");
+            assert_eq!(faulty.instructions[3].qubits, vec![qubit]);
+
+            let mut reg = QuantumRegister::new(3);
+            reg.apply_circuit(&faulty).unwrap();
+            assert!((reg.state.probability(expected) - 1.0).abs() < 1e-10);
+        }
+
+        let mut circuit = code.clone();
+        assert!(circuit.inject_fault(5, PauliError::Z, 0).is_err());
+        assert!(circuit.inject_fault(0, PauliError::Z, 3).is_err());
     }
 }
@@ -0,0 +1,270 @@
+//! Density Matrix - Mixed-state simulation
+//!
+//! Represents a register as ρ rather than |ψ⟩, so noise channels can be
+//! applied exactly as Kraus sums instead of being sampled shot by shot.
+
+use crate::{Complex, CircuitError, Result, StateVector, ONE, ZERO};
+use nalgebra::DMatrix;
+
+/// Density matrix representation of a quantum register
+#[derive(Debug, Clone)]
+pub struct DensityMatrix {
+    /// Number of qubits
+    pub num_qubits: usize,
+    /// ρ, a 2^n × 2^n Hermitian matrix with unit trace
+    rho: DMatrix<Complex>,
+}
+
+impl DensityMatrix {
+    /// Create a density matrix initialized to |0...0⟩⟨0...0|
+    pub fn new(num_qubits: usize) -> Self {
+        let dim = 1 << num_qubits;
+        let mut rho = DMatrix::from_element(dim, dim, ZERO);
+        rho[(0, 0)] = ONE;
+        Self { num_qubits, rho }
+    }
+
+    /// Pure state |ψ⟩⟨ψ|
+    pub fn from_state(state: &StateVector) -> Self {
+        let psi = state.to_vector();
+        Self {
+            num_qubits: state.num_qubits,
+            rho: &psi * psi.adjoint(),
+        }
+    }
+
+    /// Create from an existing matrix
+    pub fn from_matrix(rho: DMatrix<Complex>) -> Result<Self> {
+        let dim = rho.nrows();
+        if dim == 0 || (dim & (dim - 1)) != 0 || rho.ncols() != dim {
+            return Err(CircuitError::InvalidState(
+                "Density matrix must be square with a power-of-2 dimension".to_string()
+            ));
+        }
+        let num_qubits = (dim as f64).log2() as usize;
+        Ok(Self { num_qubits, rho })
+    }
+
+    /// Maximally mixed state I / 2^n
+    pub fn maximally_mixed(num_qubits: usize) -> Self {
+        let dim = 1 << num_qubits;
+        let rho = DMatrix::identity(dim, dim) * Complex::new(1.0 / dim as f64, 0.0);
+        Self { num_qubits, rho }
+    }
+
+    /// Get the dimension (2^n)
+    pub fn dimension(&self) -> usize {
+        self.rho.nrows()
+    }
+
+    /// The matrix ρ
+    pub fn matrix(&self) -> &DMatrix<Complex> {
+        &self.rho
+    }
+
+    /// Matrix element ⟨i|ρ|j⟩
+    pub fn element(&self, i: usize, j: usize) -> Complex {
+        self.rho.get((i, j)).copied().unwrap_or(ZERO)
+    }
+
+    /// Trace of ρ (1 for a valid state)
+    pub fn trace(&self) -> f64 {
+        self.rho.diagonal().iter().map(|c| c.re).sum()
+    }
+
+    /// Purity Tr(ρ²): 1 for pure states, 1/2^n for the maximally mixed state
+    pub fn purity(&self) -> f64 {
+        // Tr(ρ²) = Σ |ρ_ij|² for Hermitian ρ
+        self.rho.iter().map(|c| c.norm_sqr()).sum()
+    }
+
+    /// Probability distribution over basis states (the diagonal of ρ)
+    pub fn probabilities(&self) -> Vec<f64> {
+        self.rho.diagonal().iter().map(|c| c.re.max(0.0)).collect()
+    }
+
+    /// Probability of measuring a specific basis state
+    pub fn probability(&self, index: usize) -> f64 {
+        self.rho.get((index, index)).map(|c| c.re.max(0.0)).unwrap_or(0.0)
+    }
+
+    /// Fidelity ⟨ψ|ρ|ψ⟩ with a pure state
+    pub fn fidelity(&self, state: &StateVector) -> f64 {
+        let psi = state.to_vector();
+        (psi.adjoint() * &self.rho * psi)[(0, 0)].re
+    }
+
+    /// Check trace one and Hermiticity
+    pub fn is_valid(&self) -> bool {
+        let hermitian = (&self.rho - self.rho.adjoint()).iter().all(|c| c.norm() < 1e-10);
+        hermitian && (self.trace() - 1.0).abs() < 1e-10
+    }
+
+    /// Apply a full-space unitary: ρ → UρU†
+    pub fn apply_unitary(&mut self, unitary: &DMatrix<Complex>) {
+        self.rho = unitary * &self.rho * unitary.adjoint();
+    }
+
+    /// Apply a single-qubit channel given by Kraus operators: ρ → Σ K ρ K†
+    ///
+    /// Each operator is 2×2 and acts on `qubit`; completeness (Σ K†K = I) is
+    /// the caller's responsibility.
+    pub fn apply_kraus(&mut self, operators: &[DMatrix<Complex>], qubit: usize) -> Result<()> {
+        if qubit >= self.num_qubits {
+            return Err(CircuitError::InvalidQubitIndex(qubit, self.num_qubits));
+        }
+        if operators.iter().any(|k| k.nrows() != 2 || k.ncols() != 2) {
+            return Err(CircuitError::InvalidParameter(
+                "Kraus operators must be 2x2".to_string()
+            ));
+        }
+
+        let dim = self.dimension();
+        let bit = 1 << qubit;
+        let mut result = DMatrix::from_element(dim, dim, ZERO);
+
+        // (KρK†)_ij = Σ_ab K[i_q, a] ρ[i with q=a, j with q=b] conj(K[j_q, b])
+        for k in operators {
+            for i in 0..dim {
+                let qi = (i >> qubit) & 1;
+                for j in 0..dim {
+                    let qj = (j >> qubit) & 1;
+                    let mut sum = ZERO;
+                    for a in 0..2 {
+                        let row = (i & !bit) | (a << qubit);
+                        for b in 0..2 {
+                            let col = (j & !bit) | (b << qubit);
+                            sum += k[(qi, a)] * self.rho[(row, col)] * k[(qj, b)].conj();
+                        }
+                    }
+                    result[(i, j)] += sum;
+                }
+            }
+        }
+
+        self.rho = result;
+        Ok(())
+    }
+
+    /// Project onto `qubit = result` and renormalize, returning the outcome probability
+    pub fn collapse(&mut self, qubit: usize, result: bool) -> f64 {
+        let dim = self.dimension();
+        let keep = |i: usize| ((i >> qubit) & 1 == 1) == result;
+        let prob: f64 = (0..dim).filter(|&i| keep(i)).map(|i| self.rho[(i, i)].re).sum();
+
+        for i in 0..dim {
+            for j in 0..dim {
+                self.rho[(i, j)] = if keep(i) && keep(j) && prob > 1e-15 {
+                    self.rho[(i, j)] / prob
+                } else {
+                    ZERO
+                };
+            }
+        }
+        prob
+    }
+}
+
+// ==================== Kraus Operators ====================
+
+/// 2×2 complex matrix from row-major entries
+fn kraus(entries: [Complex; 4]) -> DMatrix<Complex> {
+    DMatrix::from_row_slice(2, 2, &entries)
+}
+
+fn real(x: f64) -> Complex {
+    Complex::new(x, 0.0)
+}
+
+/// Depolarizing channel: a uniformly random Pauli with probability `p`
+pub fn depolarizing_kraus(p: f64) -> Vec<DMatrix<Complex>> {
+    let p = p.clamp(0.0, 1.0);
+    let a = real((1.0 - p).sqrt());
+    let b = (p / 3.0).sqrt();
+    vec![
+        kraus([a, ZERO, ZERO, a]),
+        kraus([ZERO, real(b), real(b), ZERO]),
+        kraus([ZERO, Complex::new(0.0, -b), Complex::new(0.0, b), ZERO]),
+        kraus([real(b), ZERO, ZERO, real(-b)]),
+    ]
+}
+
+/// Bit flip channel: X with probability `p`
+pub fn bit_flip_kraus(p: f64) -> Vec<DMatrix<Complex>> {
+    let p = p.clamp(0.0, 1.0);
+    let a = real((1.0 - p).sqrt());
+    let b = real(p.sqrt());
+    vec![kraus([a, ZERO, ZERO, a]), kraus([ZERO, b, b, ZERO])]
+}
+
+/// Phase flip channel: Z with probability `p`
+pub fn phase_flip_kraus(p: f64) -> Vec<DMatrix<Complex>> {
+    let p = p.clamp(0.0, 1.0);
+    let a = real((1.0 - p).sqrt());
+    let b = p.sqrt();
+    vec![kraus([a, ZERO, ZERO, a]), kraus([real(b), ZERO, ZERO, real(-b)])]
+}
+
+/// Amplitude damping channel (T1 decay) with γ = 1 - exp(-t/T1)
+pub fn amplitude_damping_kraus(gamma: f64) -> Vec<DMatrix<Complex>> {
+    let gamma = gamma.clamp(0.0, 1.0);
+    vec![
+        kraus([ONE, ZERO, ZERO, real((1.0 - gamma).sqrt())]),
+        kraus([ZERO, real(gamma.sqrt()), ZERO, ZERO]),
+    ]
+}
+
+/// Phase damping channel (pure dephasing) with parameter γ
+pub fn phase_damping_kraus(gamma: f64) -> Vec<DMatrix<Complex>> {
+    let gamma = gamma.clamp(0.0, 1.0);
+    vec![
+        kraus([ONE, ZERO, ZERO, real((1.0 - gamma).sqrt())]),
+        kraus([ZERO, ZERO, ZERO, real(gamma.sqrt())]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_pure_state_matches_state_vector() {
+        let psi = StateVector::uniform_superposition(2);
+        let rho = DensityMatrix::from_state(&psi);
+
+        assert!(rho.is_valid());
+        assert_relative_eq!(rho.purity(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(rho.fidelity(&psi), 1.0, epsilon = 1e-10);
+        for (p, q) in rho.probabilities().iter().zip(psi.probabilities()) {
+            assert_relative_eq!(*p, q, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_full_depolarizing_mixes_qubit() {
+        // p = 3/4 maps every single-qubit state to I/2
+        let mut rho = DensityMatrix::from_state(&StateVector::uniform_superposition(1));
+        rho.apply_kraus(&depolarizing_kraus(0.75), 0).unwrap();
+
+        assert!(rho.is_valid());
+        assert_relative_eq!(rho.purity(), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(rho.element(0, 1).norm(), 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_damping_channels() {
+        // |1⟩ decays towards |0⟩ by exactly γ
+        let mut rho = DensityMatrix::from_state(&StateVector::basis_state(2, 0b10).unwrap());
+        rho.apply_kraus(&amplitude_damping_kraus(0.3), 1).unwrap();
+        assert_relative_eq!(rho.probability(0b00), 0.3, epsilon = 1e-10);
+        assert_relative_eq!(rho.probability(0b10), 0.7, epsilon = 1e-10);
+
+        // Dephasing keeps populations and shrinks coherences by sqrt(1 - γ)
+        let mut rho = DensityMatrix::from_state(&StateVector::uniform_superposition(1));
+        rho.apply_kraus(&phase_damping_kraus(0.36), 0).unwrap();
+        assert_relative_eq!(rho.probability(0), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(rho.element(0, 1).re, 0.5 * 0.8, epsilon = 1e-10);
+        assert!(rho.is_valid());
+    }
+}
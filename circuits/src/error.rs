@@ -23,6 +23,12 @@ pub enum CircuitError {
     #[error("Invalid parameter: {0}")]
     InvalidParameter(String),
 
+    #[error("Unsupported gate: {0}")]
+    UnsupportedGate(String),
+
+    #[error("Circuit is not invertible: {0}")]
+    NotInvertible(String),
+
     #[error("Circuit depth exceeded maximum: {0}")]
     MaxDepthExceeded(usize),
 
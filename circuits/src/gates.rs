@@ -17,6 +17,9 @@
 //! ## Three-Qubit Gates
 //! - Toffoli (CCX)
 //! - Fredkin (CSWAP)
+//!
+//! ## Multi-Controlled Gates
+//! - MCX (C^kX) with any number of controls
 
 use crate::{Complex, ZERO, ONE, I, FRAC_1_SQRT_2};
 use nalgebra::{DMatrix, Matrix2, Matrix4};
@@ -65,6 +68,9 @@ pub enum GateType {
     Toffoli,
     Fredkin,
 
+    // Multi-controlled gates
+    MCX,
+
     // Custom gate
     Custom,
 }
@@ -80,6 +86,9 @@ pub struct Gate {
     pub num_qubits: usize,
     /// Optional parameter (for Rx, Ry, Rz, etc.)
     pub parameter: Option<f64>,
+    /// All angles of a multi-parameter gate, in constructor order (U3: θ, φ, λ)
+    #[serde(default)]
+    pub params: Vec<f64>,
     /// Gate matrix (flattened, row-major)
     matrix_data: Vec<(f64, f64)>, // (re, im) pairs
     matrix_rows: usize,
@@ -100,6 +109,7 @@ impl Gate {
             name: name.to_string(),
             num_qubits,
             parameter: None,
+            params: Vec::new(),
             matrix_data,
             matrix_rows: n,
         }
@@ -107,6 +117,9 @@ impl Gate {
 
     /// Get the gate matrix
     pub fn matrix(&self) -> DMatrix<Complex> {
+        if self.gate_type == GateType::MCX {
+            return mcx_matrix(self.matrix_rows);
+        }
         let n = self.matrix_rows;
         let data: Vec<Complex> = self.matrix_data.iter()
             .map(|(re, im)| Complex::new(*re, *im))
@@ -133,18 +146,60 @@ impl Gate {
     }
 
     /// Get the adjoint (conjugate transpose) of the gate
+    ///
+    /// Standard gates map to their named daggers (S → S†, T → T†) and
+    /// parameterized rotations negate their angle; anything else gets the
+    /// conjugate-transposed matrix.
     pub fn adjoint(&self) -> Self {
-        let m = self.matrix();
-        let adj = m.adjoint();
-        let matrix_data: Vec<(f64, f64)> = adj.iter()
-            .map(|c| (c.re, c.im))
+        match (self.gate_type, self.parameter) {
+            (
+                GateType::Identity | GateType::PauliX | GateType::PauliY | GateType::PauliZ
+                | GateType::Hadamard | GateType::CNOT | GateType::CZ | GateType::CY
+                | GateType::SWAP | GateType::Toffoli | GateType::Fredkin | GateType::MCX,
+                _,
+            ) => self.clone(),
+            (GateType::Phase, _) => Self::sdg(),
+            (GateType::PhaseDag, _) => Self::s(),
+            (GateType::TGate, _) => Self::tdg(),
+            (GateType::TGateDag, _) => Self::t(),
+            (GateType::Rx, Some(theta)) => Self::rx(-theta),
+            (GateType::Ry, Some(theta)) => Self::ry(-theta),
+            (GateType::Rz, Some(theta)) => Self::rz(-theta),
+            (GateType::U1, Some(lambda)) => Self::u1(-lambda),
+            (GateType::CRz, Some(theta)) => Self::crz(-theta),
+            (GateType::CPhase, Some(theta)) => Self::cphase(-theta),
+            (GateType::U3, _) if self.params.len() == 3 => {
+                Self::u3(-self.params[0], -self.params[2], -self.params[1])
+            }
+            _ => self.matrix_adjoint(),
+        }
+    }
+
+    /// Adjoint computed from the matrix
+    fn matrix_adjoint(&self) -> Self {
+        let adj = self.matrix().adjoint();
+        let n = self.matrix_rows;
+        let matrix_data: Vec<(f64, f64)> = (0..n)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .map(|(i, j)| (adj[(i, j)].re, adj[(i, j)].im))
             .collect();
 
+        let gate_type = match self.gate_type {
+            GateType::SqrtX => GateType::SqrtXDag,
+            GateType::SqrtXDag => GateType::SqrtX,
+            other => other,
+        };
+        let name = match self.name.strip_suffix('†') {
+            Some(base) => base.to_string(),
+            None => format!("{}†", self.name),
+        };
+
         Self {
-            gate_type: self.gate_type,
-            name: format!("{}†", self.name),
+            gate_type,
+            name,
             num_qubits: self.num_qubits,
-            parameter: self.parameter,
+            parameter: self.parameter.map(|p| -p),
+            params: Vec::new(),
             matrix_data,
             matrix_rows: self.matrix_rows,
         }
@@ -320,11 +375,13 @@ impl Gate {
         let u10 = Complex::from_polar(sin, phi);
         let u11 = Complex::from_polar(cos, phi + lambda);
 
-        Self::from_matrix_2x2(
+        let mut gate = Self::from_matrix_2x2(
             GateType::U3,
             &format!("U3({:.4},{:.4},{:.4})", theta, phi, lambda),
             Matrix2::new(u00, u01, u10, u11),
-        )
+        );
+        gate.params = vec![theta, phi, lambda];
+        gate
     }
 
     // ==================== Two-Qubit Gates ====================
@@ -473,6 +530,7 @@ impl Gate {
             name: "Toffoli".to_string(),
             num_qubits: 3,
             parameter: None,
+            params: Vec::new(),
             matrix_data,
             matrix_rows: 8,
         }
@@ -496,11 +554,37 @@ impl Gate {
             name: "Fredkin".to_string(),
             num_qubits: 3,
             parameter: None,
+            params: Vec::new(),
             matrix_data,
             matrix_rows: 8,
         }
     }
 
+    // ==================== Multi-Controlled Gates ====================
+
+    /// Multi-controlled X with `num_controls` controls
+    ///
+    /// Acts on the controls followed by the target, flipping the target only
+    /// when every control is |1⟩. Zero controls is X, one is CNOT and two is
+    /// Toffoli; larger gates build their matrix on demand, and registers
+    /// apply them by swapping amplitudes instead of expanding the matrix.
+    pub fn mcx(num_controls: usize) -> Self {
+        match num_controls {
+            0 => Self::x(),
+            1 => Self::cnot(),
+            2 => Self::toffoli(),
+            k => Self {
+                gate_type: GateType::MCX,
+                name: format!("C{}X", k),
+                num_qubits: k + 1,
+                parameter: None,
+                params: Vec::new(),
+                matrix_data: Vec::new(),
+                matrix_rows: 1 << (k + 1),
+            },
+        }
+    }
+
     // ==================== Helper Methods ====================
 
     fn from_matrix_2x2(gate_type: GateType, name: &str, m: Matrix2<Complex>) -> Self {
@@ -515,6 +599,7 @@ impl Gate {
             name: name.to_string(),
             num_qubits: 1,
             parameter: None,
+            params: Vec::new(),
             matrix_data,
             matrix_rows: 2,
         }
@@ -532,12 +617,24 @@ impl Gate {
             name: name.to_string(),
             num_qubits: 2,
             parameter: None,
+            params: Vec::new(),
             matrix_data,
             matrix_rows: 4,
         }
     }
 }
 
+/// Identity with the last two basis states swapped: X on the least
+/// significant qubit when all others are |1⟩
+fn mcx_matrix(dim: usize) -> DMatrix<Complex> {
+    let mut matrix = DMatrix::identity(dim, dim);
+    matrix[(dim - 2, dim - 2)] = ZERO;
+    matrix[(dim - 2, dim - 1)] = ONE;
+    matrix[(dim - 1, dim - 2)] = ONE;
+    matrix[(dim - 1, dim - 1)] = ZERO;
+    matrix
+}
+
 /// A controlled gate wrapper
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ControlledGate {
@@ -665,6 +762,33 @@ mod tests {
         assert!(rz_pi.is_unitary());
     }
 
+    #[test]
+    fn test_adjoint() {
+        assert_eq!(Gate::s().adjoint().gate_type, GateType::PhaseDag);
+        assert_eq!(Gate::t().adjoint().adjoint().gate_type, GateType::TGate);
+        assert_eq!(Gate::rx(0.7).adjoint().parameter, Some(-0.7));
+        assert_eq!(Gate::sqrt_x().adjoint().gate_type, GateType::SqrtXDag);
+        assert_eq!(Gate::u3(0.3, 1.1, -0.6).adjoint().params, vec![-0.3, 0.6, -1.1]);
+
+        for gate in [Gate::ry(0.4), Gate::sqrt_x(), Gate::u3(0.3, 1.1, -0.6), Gate::iswap(), Gate::crz(0.9)] {
+            let expected = gate.matrix().adjoint();
+            assert!((gate.adjoint().matrix() - expected).norm() < 1e-12, "{}", gate.name);
+        }
+    }
+
+    #[test]
+    fn test_mcx() {
+        assert_eq!(Gate::mcx(0).gate_type, GateType::PauliX);
+        assert_eq!(Gate::mcx(1).gate_type, GateType::CNOT);
+        assert!((Gate::mcx(2).matrix() - Gate::toffoli().matrix()).norm() < 1e-12);
+
+        let c4x = Gate::mcx(4);
+        assert_eq!(c4x.num_qubits, 5);
+        assert!(c4x.is_unitary());
+        assert_eq!(c4x.adjoint().gate_type, GateType::MCX);
+        assert_eq!(c4x.matrix()[(31, 30)], ONE);
+    }
+
     #[test]
     fn test_cnot() {
         let cnot = Gate::cnot();
@@ -7,9 +7,12 @@
 //! - **Qubit**: Single qubit representation with Bloch sphere coordinates
 //! - **Gates**: Universal quantum gate set (Pauli, Hadamard, Phase, CNOT, etc.)
 //! - **Circuit**: Quantum circuit construction and manipulation
+//! - **Optimization**: Peephole cancellation of inverse gates and rotation merging
 //! - **Register**: Multi-qubit quantum registers with state vector simulation
+//! - **Density Matrix**: Mixed-state simulation with exact Kraus noise channels
 //! - **Measurement**: Projective measurements with probability distribution
 //! - **Noise**: Noise models for realistic simulation (optional)
+//! - **QASM**: OpenQASM 2.0 export and import
 //!
 //! ## Example
 //!
@@ -34,17 +37,24 @@
 pub mod qubit;
 pub mod gates;
 pub mod circuit;
+pub mod optimize;
 pub mod register;
+pub mod density;
 pub mod measurement;
 pub mod noise;
+pub mod qasm;
 pub mod error;
 
 pub use qubit::{Qubit, BlochCoordinates};
 pub use gates::{Gate, GateType, ControlledGate, ParameterizedGate};
 pub use circuit::{Circuit, CircuitBuilder, CircuitInstruction};
-pub use register::{QuantumRegister, StateVector};
-pub use measurement::{Measurement, MeasurementResult, MeasurementBasis};
-pub use noise::{NoiseModel, NoiseChannel, DepolarizingNoise, AmplitudeDamping};
+pub use optimize::OptimizationStats;
+pub use register::{
+    fidelity, total_variation_distance, trace_distance, QuantumRegister, RegisterState, StateVector,
+};
+pub use density::DensityMatrix;
+pub use measurement::{Measurement, MeasurementResult, MeasurementBasis, MeasurementStatistics};
+pub use noise::{NoiseModel, NoiseChannel, PauliError, DepolarizingNoise, AmplitudeDamping};
 pub use error::{CircuitError, Result};
 
 use num_complex::Complex64;
@@ -2,8 +2,9 @@
 //!
 //! This module provides measurement operations for quantum states.
 
-use crate::{Complex, QuantumRegister, Result, CircuitError, ZERO, ONE};
-use nalgebra::DMatrix;
+use crate::{QuantumRegister, Result, CircuitError};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -103,10 +104,24 @@ pub struct Measurement;
 
 impl Measurement {
     /// Measure specific qubits in computational basis
+    ///
+    /// Shots are drawn from the register's seeded random source if it has
+    /// one (see [`QuantumRegister::set_rng_seed`]), otherwise from the
+    /// thread RNG.
     pub fn measure_qubits(
         register: &QuantumRegister,
         qubits: &[usize],
         shots: usize,
+    ) -> Result<MeasurementStatistics> {
+        Self::measure_qubits_with_rng(register, qubits, shots, &mut register.sampling_rng())
+    }
+
+    /// Measure specific qubits, drawing shots from the given random source
+    pub fn measure_qubits_with_rng<R: Rng + ?Sized>(
+        register: &QuantumRegister,
+        qubits: &[usize],
+        shots: usize,
+        rng: &mut R,
     ) -> Result<MeasurementStatistics> {
         for &q in qubits {
             if q >= register.num_qubits() {
@@ -115,13 +130,11 @@ impl Measurement {
         }
 
         let mut counts: HashMap<String, usize> = HashMap::new();
-        let mut rng = rand::thread_rng();
-
-        let probs = register.state.probabilities();
+        let probs = register.probabilities();
 
         for _ in 0..shots {
             // Sample from full distribution
-            let r: f64 = rand::Rng::gen(&mut rng);
+            let r: f64 = rng.gen();
             let mut cumulative = 0.0;
             let mut full_outcome = 0;
 
@@ -166,6 +179,19 @@ impl Measurement {
         Self::measure_qubits(register, &qubits, shots).unwrap()
     }
 
+    /// Measure all qubits with a seeded random source
+    ///
+    /// The same state, shot count and seed always give the same counts.
+    pub fn measure_all_seeded(
+        register: &QuantumRegister,
+        shots: usize,
+        seed: u64,
+    ) -> MeasurementStatistics {
+        let qubits: Vec<usize> = (0..register.num_qubits()).collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        Self::measure_qubits_with_rng(register, &qubits, shots, &mut rng).unwrap()
+    }
+
     /// Measure in X basis
     pub fn measure_x_basis(
         register: &mut QuantumRegister,
@@ -192,56 +218,13 @@ impl Measurement {
     }
 
     /// Compute expectation value of a Pauli observable
+    ///
+    /// See [`QuantumRegister::expectation_pauli`].
     pub fn expectation_pauli(
         register: &QuantumRegister,
         pauli: &str,
     ) -> Result<f64> {
-        if pauli.len() != register.num_qubits() {
-            return Err(CircuitError::InvalidParameter(
-                format!("Pauli string length {} doesn't match qubit count {}",
-                    pauli.len(), register.num_qubits())
-            ));
-        }
-
-        // Build Pauli operator matrix
-        let pauli_matrix = Self::build_pauli_operator(pauli)?;
-
-        // Compute ⟨ψ|P|ψ⟩
-        let state_vec = register.state.to_vector();
-        let result = state_vec.adjoint() * &pauli_matrix * &state_vec;
-
-        Ok(result[(0, 0)].re)
-    }
-
-    /// Build Pauli operator from string (e.g., "XYZ" for X⊗Y⊗Z)
-    fn build_pauli_operator(pauli_string: &str) -> Result<DMatrix<Complex>> {
-        let i = Complex::new(0.0, 1.0);
-
-        let pauli_i = DMatrix::from_row_slice(2, 2, &[ONE, ZERO, ZERO, ONE]);
-        let pauli_x = DMatrix::from_row_slice(2, 2, &[ZERO, ONE, ONE, ZERO]);
-        let pauli_y = DMatrix::from_row_slice(2, 2, &[ZERO, -i, i, ZERO]);
-        let pauli_z = DMatrix::from_row_slice(2, 2, &[ONE, ZERO, ZERO, -ONE]);
-
-        let mut result: Option<DMatrix<Complex>> = None;
-
-        for c in pauli_string.chars() {
-            let p = match c {
-                'I' | 'i' => pauli_i.clone(),
-                'X' | 'x' => pauli_x.clone(),
-                'Y' | 'y' => pauli_y.clone(),
-                'Z' | 'z' => pauli_z.clone(),
-                _ => return Err(CircuitError::InvalidParameter(
-                    format!("Invalid Pauli character: {}", c)
-                )),
-            };
-
-            result = Some(match result {
-                Some(r) => r.kronecker(&p),
-                None => p,
-            });
-        }
-
-        result.ok_or_else(|| CircuitError::InvalidParameter("Empty Pauli string".to_string()))
+        register.expectation_pauli(pauli)
     }
 
     /// Compute variance of a Pauli observable
@@ -2,7 +2,11 @@
 //!
 //! This module provides noise models for simulating decoherence and errors.
 
-use crate::{Complex, QuantumRegister, Gate, ZERO};
+use crate::density::{
+    amplitude_damping_kraus, bit_flip_kraus, depolarizing_kraus, phase_damping_kraus,
+    phase_flip_kraus,
+};
+use crate::{Complex, DensityMatrix, QuantumRegister, Gate, Result, ZERO};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +31,28 @@ pub enum NoiseChannel {
     ReadoutError,
 }
 
+/// Single-qubit Pauli error for targeted fault injection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PauliError {
+    /// Bit flip
+    X,
+    /// Bit-phase flip
+    Y,
+    /// Phase flip
+    Z,
+}
+
+impl PauliError {
+    /// Gate implementing this error
+    pub fn gate(&self) -> Gate {
+        match self {
+            PauliError::X => Gate::x(),
+            PauliError::Y => Gate::y(),
+            PauliError::Z => Gate::z(),
+        }
+    }
+}
+
 /// A noise model configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NoiseModel {
@@ -114,10 +140,17 @@ impl NoiseModel {
     }
 
     /// Apply noise after a single-qubit gate
+    ///
+    /// Registers in density-matrix mode get the exact channel via
+    /// [`NoiseModel::apply_density`]; state vectors get a sampled trajectory.
     pub fn apply_single_gate_noise(&self, register: &mut QuantumRegister, qubit: usize) {
         if self.channels.is_empty() || self.single_gate_error == 0.0 {
             return;
         }
+        if let Some(rho) = &mut register.density {
+            self.apply_density(rho, qubit).ok();
+            return;
+        }
 
         let mut rng = rand::thread_rng();
 
@@ -151,6 +184,43 @@ impl NoiseModel {
         }
     }
 
+    /// Apply every active channel to `qubit` of a density matrix as a Kraus sum
+    pub fn apply_density(&self, rho: &mut DensityMatrix, qubit: usize) -> Result<()> {
+        if self.channels.is_empty() || self.single_gate_error == 0.0 {
+            return Ok(());
+        }
+
+        for channel in &self.channels {
+            match channel {
+                NoiseChannel::Depolarizing => {
+                    rho.apply_kraus(&depolarizing_kraus(self.single_gate_error), qubit)?;
+                }
+                NoiseChannel::BitFlip => {
+                    rho.apply_kraus(&bit_flip_kraus(self.single_gate_error), qubit)?;
+                }
+                NoiseChannel::PhaseFlip => {
+                    rho.apply_kraus(&phase_flip_kraus(self.single_gate_error), qubit)?;
+                }
+                NoiseChannel::AmplitudeDamping => {
+                    let gamma = 1.0 - (-self.single_gate_time / self.t1).exp();
+                    rho.apply_kraus(&amplitude_damping_kraus(gamma), qubit)?;
+                }
+                NoiseChannel::PhaseDamping => {
+                    let gamma = 1.0 - (-self.single_gate_time / self.t2).exp();
+                    rho.apply_kraus(&phase_damping_kraus(gamma), qubit)?;
+                }
+                NoiseChannel::ThermalRelaxation => {
+                    let gamma_1 = 1.0 - (-self.single_gate_time / self.t1).exp();
+                    let dephasing = pure_dephasing(self.t1, self.t2, self.single_gate_time);
+                    rho.apply_kraus(&amplitude_damping_kraus(gamma_1), qubit)?;
+                    rho.apply_kraus(&phase_damping_kraus(dephasing), qubit)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     /// Apply noise after a two-qubit gate
     pub fn apply_two_gate_noise(
         &self,
@@ -195,9 +265,18 @@ impl DepolarizingNoise {
     }
 
     pub fn apply(&self, register: &mut QuantumRegister, qubit: usize) {
+        if let Some(rho) = &mut register.density {
+            self.apply_density(rho, qubit).ok();
+            return;
+        }
         let mut rng = rand::thread_rng();
         apply_depolarizing(register, qubit, self.probability, &mut rng);
     }
+
+    /// Apply the exact channel to a density matrix
+    pub fn apply_density(&self, rho: &mut DensityMatrix, qubit: usize) -> Result<()> {
+        rho.apply_kraus(&depolarizing_kraus(self.probability), qubit)
+    }
 }
 
 /// Amplitude damping channel (T1 decay)
@@ -218,8 +297,17 @@ impl AmplitudeDamping {
     }
 
     pub fn apply(&self, register: &mut QuantumRegister, qubit: usize) {
+        if let Some(rho) = &mut register.density {
+            self.apply_density(rho, qubit).ok();
+            return;
+        }
         apply_amplitude_damping(register, qubit, self.gamma);
     }
+
+    /// Apply the exact channel to a density matrix
+    pub fn apply_density(&self, rho: &mut DensityMatrix, qubit: usize) -> Result<()> {
+        rho.apply_kraus(&amplitude_damping_kraus(self.gamma), qubit)
+    }
 }
 
 // ==================== Noise Implementation Functions ====================
@@ -340,13 +428,17 @@ fn apply_thermal_relaxation(
     apply_amplitude_damping(register, qubit, gamma_1);
 
     // Apply additional dephasing (T2)
+    apply_phase_damping(register, qubit, pure_dephasing(t1, t2, gate_time));
+}
+
+/// Pure dephasing parameter left after T1 decay
+fn pure_dephasing(t1: f64, t2: f64, gate_time: f64) -> f64 {
     // T2* dephasing rate = 1/T2 - 1/(2*T1)
-    let pure_dephasing = if t2 < 2.0 * t1 {
+    if t2 < 2.0 * t1 {
         1.0 - (-(gate_time / t2 - gate_time / (2.0 * t1))).exp()
     } else {
         0.0
-    };
-    apply_phase_damping(register, qubit, pure_dephasing);
+    }
 }
 
 #[cfg(test)]
@@ -395,6 +487,23 @@ mod tests {
         assert!(reg.state.is_normalized());
     }
 
+    #[test]
+    fn test_density_noise_is_exact() {
+        // Depolarizing |0⟩ with p leaves P(1) = 2p/3 exactly, with no sampling
+        let mut reg = QuantumRegister::new_density(2);
+        DepolarizingNoise::new(0.3).apply(&mut reg, 1);
+        assert!((reg.probability(0b10) - 0.2).abs() < 1e-10);
+        assert!(reg.density.as_ref().unwrap().purity() < 1.0);
+
+        let model = NoiseModel::noisy();
+        let mut rho = DensityMatrix::new(1);
+        for _ in 0..50 {
+            model.apply_density(&mut rho, 0).unwrap();
+        }
+        assert!(rho.is_valid());
+        assert!(rho.apply_kraus(&depolarizing_kraus(0.1), 3).is_err());
+    }
+
     #[test]
     fn test_noise_model_application() {
         let model = NoiseModel::default();
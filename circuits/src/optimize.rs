@@ -0,0 +1,227 @@
+//! Circuit Optimization - Peephole gate cancellation
+//!
+//! Removes gate pairs that multiply to the identity, merges consecutive
+//! rotations about the same axis and drops identity gates. Two gates count as
+//! adjacent when no gate between them touches any of their qubits.
+
+use crate::{Circuit, CircuitInstruction, Gate, GateType};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Angles within this distance of a full period are treated as identity
+const ANGLE_TOLERANCE: f64 = 1e-12;
+
+/// What an optimization pass removed
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OptimizationStats {
+    /// Gate count before optimization
+    pub original_gates: usize,
+    /// Gate count after optimization
+    pub optimized_gates: usize,
+    /// Depth before optimization
+    pub original_depth: usize,
+    /// Depth after optimization
+    pub optimized_depth: usize,
+    /// Gates removed by cancelling inverse pairs
+    pub cancelled: usize,
+    /// Rotations folded into a neighboring rotation
+    pub merged: usize,
+    /// Identity gates and full-period rotations dropped
+    pub identities_removed: usize,
+}
+
+impl OptimizationStats {
+    /// Total number of gates removed
+    pub fn gates_removed(&self) -> usize {
+        self.original_gates - self.optimized_gates
+    }
+}
+
+impl Circuit {
+    /// Cancel adjacent inverse gates, merge rotations and drop identities
+    ///
+    /// The result implements the same unitary; rotations are only dropped at
+    /// multiples of their exact period (4π for Rx, Ry, Rz and CRz, 2π for U1
+    /// and CPhase), so not even a global phase is lost. Conditional
+    /// instructions are kept as they are and block optimization across them.
+    pub fn optimize(&self) -> (Circuit, OptimizationStats) {
+        let mut stats = OptimizationStats {
+            original_gates: self.gate_count(),
+            original_depth: self.depth(),
+            ..Default::default()
+        };
+        let mut output: Vec<CircuitInstruction> = Vec::with_capacity(self.instructions.len());
+
+        for instruction in &self.instructions {
+            if instruction.condition.is_none() && is_identity(&instruction.gate) {
+                stats.identities_removed += 1;
+                continue;
+            }
+
+            // Latest kept instruction sharing a qubit with this one
+            let previous = output
+                .iter()
+                .rposition(|prev| prev.qubits.iter().any(|q| instruction.qubits.contains(q)));
+
+            let Some(index) = previous else {
+                output.push(instruction.clone());
+                continue;
+            };
+            let prev = &output[index];
+            if prev.condition.is_some() || instruction.condition.is_some() || !same_operands(prev, instruction) {
+                output.push(instruction.clone());
+                continue;
+            }
+
+            if cancels(&prev.gate, &instruction.gate) {
+                output.remove(index);
+                stats.cancelled += 2;
+            } else if let Some(gate) = merge(&prev.gate, &instruction.gate) {
+                stats.merged += 1;
+                if is_identity(&gate) {
+                    output.remove(index);
+                    stats.identities_removed += 1;
+                } else {
+                    output[index].gate = gate;
+                }
+            } else {
+                output.push(instruction.clone());
+            }
+        }
+
+        let mut optimized = self.clone();
+        optimized.instructions = output;
+        stats.optimized_gates = optimized.gate_count();
+        stats.optimized_depth = optimized.depth();
+        (optimized, stats)
+    }
+}
+
+/// Whether two instructions act on the same qubits in a compatible order
+fn same_operands(a: &CircuitInstruction, b: &CircuitInstruction) -> bool {
+    if a.qubits == b.qubits {
+        return true;
+    }
+    // Symmetric two-qubit gates act the same with their operands swapped
+    let symmetric = matches!(a.gate.gate_type, GateType::CZ | GateType::SWAP | GateType::CPhase);
+    symmetric && a.qubits.len() == 2 && a.qubits[0] == b.qubits[1] && a.qubits[1] == b.qubits[0]
+}
+
+/// Whether `b` immediately after `a` is the identity
+fn cancels(a: &Gate, b: &Gate) -> bool {
+    use GateType::*;
+    match (a.gate_type, b.gate_type) {
+        (PauliX, PauliX) | (PauliY, PauliY) | (PauliZ, PauliZ) | (Hadamard, Hadamard)
+        | (CNOT, CNOT) | (CZ, CZ) | (CY, CY) | (SWAP, SWAP)
+        | (Toffoli, Toffoli) | (Fredkin, Fredkin) => true,
+        (MCX, MCX) => a.num_qubits == b.num_qubits,
+        (Phase, PhaseDag) | (PhaseDag, Phase) | (TGate, TGateDag) | (TGateDag, TGate)
+        | (SqrtX, SqrtXDag) | (SqrtXDag, SqrtX) => true,
+        _ => false,
+    }
+}
+
+/// Single rotation equal to `a` followed by `b`, if both rotate about the same axis
+fn merge(a: &Gate, b: &Gate) -> Option<Gate> {
+    if a.gate_type != b.gate_type {
+        return None;
+    }
+    let angle = a.parameter? + b.parameter?;
+    match a.gate_type {
+        GateType::Rx => Some(Gate::rx(angle)),
+        GateType::Ry => Some(Gate::ry(angle)),
+        GateType::Rz => Some(Gate::rz(angle)),
+        GateType::U1 => Some(Gate::u1(angle)),
+        GateType::CRz => Some(Gate::crz(angle)),
+        GateType::CPhase => Some(Gate::cphase(angle)),
+        _ => None,
+    }
+}
+
+/// Whether a gate is exactly the identity
+fn is_identity(gate: &Gate) -> bool {
+    let period = match gate.gate_type {
+        GateType::Identity => return true,
+        GateType::Rx | GateType::Ry | GateType::Rz | GateType::CRz => 4.0 * PI,
+        GateType::U1 | GateType::CPhase => 2.0 * PI,
+        _ => return false,
+    };
+    match gate.parameter {
+        Some(angle) => {
+            let r = angle.rem_euclid(period);
+            r < ANGLE_TOLERANCE || period - r < ANGLE_TOLERANCE
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::QuantumRegister;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn final_state(circuit: &Circuit) -> QuantumRegister {
+        let mut reg = QuantumRegister::new(circuit.num_qubits);
+        reg.apply_circuit(circuit).unwrap();
+        reg
+    }
+
+    #[test]
+    fn test_cancellation_and_merging() {
+        // H·H cancels across a gate on another qubit; S·S† and X·CX·CX·X unwind
+        let circuit = Circuit::new(2).h(0).x(1).h(0).s(1).sdg(1).x(0).cnot(0, 1).cnot(0, 1).x(0);
+        let (optimized, stats) = circuit.optimize();
+        assert_eq!(optimized.gate_count(), 1);
+        assert_eq!(optimized.instructions[0].gate.gate_type, GateType::PauliX);
+        assert_eq!(optimized.instructions[0].qubits, vec![1]);
+        assert_eq!(stats.cancelled, 8);
+        assert_eq!(stats.gates_removed(), 8);
+
+        let (optimized, stats) = Circuit::new(1).rz(0.3, 0).rz(0.4, 0).id(0).optimize();
+        assert_eq!(optimized.gate_count(), 1);
+        assert_eq!(optimized.instructions[0].gate.parameter, Some(0.3 + 0.4));
+        assert_eq!((stats.merged, stats.identities_removed), (1, 1));
+
+        // Rz(2π) is -I, so only a full 4π turn disappears
+        let (optimized, _) = Circuit::new(1).rz(PI, 0).rz(PI, 0).optimize();
+        assert_eq!(optimized.gate_count(), 1);
+        let (optimized, _) = Circuit::new(1).rz(3.0 * PI, 0).rz(PI, 0).optimize();
+        assert_eq!(optimized.gate_count(), 0);
+
+        // Non-symmetric gates with swapped operands do not cancel
+        let (optimized, _) = Circuit::new(2).cnot(0, 1).cnot(1, 0).cz(0, 1).cz(1, 0).optimize();
+        assert_eq!(optimized.gate_count(), 2);
+    }
+
+    #[test]
+    fn test_preserves_state() {
+        let mut rng = StdRng::seed_from_u64(3);
+        for _ in 0..20 {
+            let mut circuit = Circuit::new(3);
+            for _ in 0..60 {
+                let q = rng.gen_range(0..3);
+                circuit = match rng.gen_range(0..7) {
+                    0 => circuit.h(q),
+                    1 => circuit.x(q),
+                    2 => circuit.s(q),
+                    3 => circuit.sdg(q),
+                    4 => circuit.rz(rng.gen_range(-PI..PI), q),
+                    5 => circuit.cnot(q, (q + 1) % 3),
+                    _ => circuit.t(q),
+                };
+            }
+
+            let (optimized, stats) = circuit.optimize();
+            assert!(stats.optimized_gates <= stats.original_gates);
+            assert!(stats.optimized_depth <= stats.original_depth);
+
+            let before = final_state(&circuit);
+            let after = final_state(&optimized);
+            for (a, b) in before.state.amplitudes().iter().zip(after.state.amplitudes()) {
+                assert!((a - b).norm() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,522 @@
+//! OpenQASM 2.0 import
+//!
+//! Parses the `qelib1.inc` subset this crate can represent back into a
+//! [`Circuit`], and names gates for [`Circuit::to_qasm`] so that exported
+//! programs parse again into the same circuit.
+
+use crate::{Circuit, CircuitError, Gate, GateType, Result};
+use std::collections::HashMap;
+
+/// A declared quantum or classical register
+#[derive(Debug, Clone, Copy)]
+struct Register {
+    offset: usize,
+    size: usize,
+}
+
+impl Circuit {
+    /// Parse an OpenQASM 2.0 program
+    ///
+    /// Supports `id`, `x`, `y`, `z`, `h`, `s`, `sdg`, `t`, `tdg`, `sx`, `rx`,
+    /// `ry`, `rz`, `u1`/`p`, `u3`/`u`, `cx`, `cy`, `cz`, `swap`, `crz`,
+    /// `cu1`/`cp`, `ccx`, `c<k>x` (such as `c3x`), `cswap` and `measure`. Registers are laid out in
+    /// declaration order, so a second `qreg` starts after the first, and an
+    /// argument naming a whole register applies the operation to every
+    /// element. `barrier` is accepted and ignored; anything else, including
+    /// `gate` definitions, fails with [`CircuitError::UnsupportedGate`].
+    /// Measurements are terminal in a [`Circuit`], so a gate on a qubit that
+    /// was already measured is rejected rather than reordered.
+    ///
+    /// ```
+    /// use qops_circuits::Circuit;
+    ///
+    /// let circuit = Circuit::from_qasm(
+    ///     "OPENQASM 2.0;\ninclude \"qelib1.inc\";\nqreg q[2];\ncreg c[2];\n\
+    ///      h q[0];\ncx q[0], q[1];\nmeasure q -> c;\n",
+    /// ).unwrap();
+    /// assert_eq!(circuit.gate_count(), 2);
+    /// assert_eq!(circuit.measurements, vec![(0, 0), (1, 1)]);
+    /// ```
+    pub fn from_qasm(source: &str) -> Result<Circuit> {
+        let mut circuit = Circuit::new(0).classical_bits(0);
+        let mut qregs: HashMap<String, Register> = HashMap::new();
+        let mut cregs: HashMap<String, Register> = HashMap::new();
+
+        let code: String = source
+            .lines()
+            .map(|line| line.split("//").next().unwrap_or(""))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        for statement in code.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let (keyword, rest) = split_keyword(statement);
+            match keyword {
+                "OPENQASM" => {
+                    if !rest.starts_with('2') {
+                        return Err(CircuitError::InvalidParameter(format!(
+                            "Unsupported OpenQASM version {}",
+                            rest
+                        )));
+                    }
+                }
+                "include" | "barrier" => {}
+                "qreg" => {
+                    let (name, size) = parse_indexed(rest)?;
+                    declare(&mut qregs, name, size, circuit.num_qubits)?;
+                    circuit.num_qubits += size;
+                }
+                "creg" => {
+                    let (name, size) = parse_indexed(rest)?;
+                    declare(&mut cregs, name, size, circuit.num_classical_bits)?;
+                    circuit.num_classical_bits += size;
+                }
+                "measure" => {
+                    let (source, target) = rest.split_once("->").ok_or_else(|| {
+                        CircuitError::InvalidParameter(format!("Malformed measure: {}", statement))
+                    })?;
+                    let qubits = resolve(&qregs, source.trim())?;
+                    let bits = resolve(&cregs, target.trim())?;
+                    if qubits.len() != bits.len() {
+                        return Err(CircuitError::InvalidParameter(format!(
+                            "Measure width mismatch: {}",
+                            statement
+                        )));
+                    }
+                    for (qubit, bit) in qubits.into_iter().zip(bits) {
+                        circuit.add_measurement(qubit, bit)?;
+                    }
+                }
+                _ => apply_gate(&mut circuit, &qregs, statement)?,
+            }
+        }
+
+        Ok(circuit)
+    }
+}
+
+/// OpenQASM name of a gate, with its parameters in parentheses
+///
+/// Parameters are written at full precision. Gates without a `qelib1.inc`
+/// counterpart fail with [`CircuitError::UnsupportedGate`].
+pub(crate) fn qasm_gate(gate: &Gate) -> Result<String> {
+    let param = |name: &str| format!("{}({})", name, gate.parameter.unwrap_or(0.0));
+    Ok(match gate.gate_type {
+        GateType::Identity => "id".to_string(),
+        GateType::PauliX => "x".to_string(),
+        GateType::PauliY => "y".to_string(),
+        GateType::PauliZ => "z".to_string(),
+        GateType::Hadamard => "h".to_string(),
+        GateType::Phase => "s".to_string(),
+        GateType::PhaseDag => "sdg".to_string(),
+        GateType::TGate => "t".to_string(),
+        GateType::TGateDag => "tdg".to_string(),
+        GateType::SqrtX => "sx".to_string(),
+        GateType::Rx => param("rx"),
+        GateType::Ry => param("ry"),
+        GateType::Rz => param("rz"),
+        GateType::U1 => param("u1"),
+        GateType::CNOT => "cx".to_string(),
+        GateType::CZ => "cz".to_string(),
+        GateType::CY => "cy".to_string(),
+        GateType::SWAP => "swap".to_string(),
+        GateType::CRz => param("crz"),
+        GateType::CPhase => param("cu1"),
+        GateType::Toffoli => "ccx".to_string(),
+        GateType::Fredkin => "cswap".to_string(),
+        GateType::MCX => format!("c{}x", gate.num_qubits - 1),
+        GateType::U3 if gate.params.len() == 3 => {
+            format!("u3({},{},{})", gate.params[0], gate.params[1], gate.params[2])
+        }
+        _ => return Err(CircuitError::UnsupportedGate(gate.name.clone())),
+    })
+}
+
+/// Split a statement into its leading keyword and the remainder
+fn split_keyword(statement: &str) -> (&str, &str) {
+    let end = statement
+        .find(|c: char| c.is_whitespace() || c == '(')
+        .unwrap_or(statement.len());
+    (&statement[..end], statement[end..].trim())
+}
+
+/// Parse `name[n]`
+fn parse_indexed(text: &str) -> Result<(&str, usize)> {
+    let malformed = || CircuitError::InvalidParameter(format!("Expected name[index], got {}", text));
+    let (name, index) = text.trim().split_once('[').ok_or_else(malformed)?;
+    let index = index.strip_suffix(']').ok_or_else(malformed)?;
+    let index = index.trim().parse().map_err(|_| malformed())?;
+    Ok((name.trim(), index))
+}
+
+/// Record a register declaration starting at `offset`
+fn declare(registers: &mut HashMap<String, Register>, name: &str, size: usize, offset: usize) -> Result<()> {
+    if registers.insert(name.to_string(), Register { offset, size }).is_some() {
+        return Err(CircuitError::InvalidParameter(format!("Register {} declared twice", name)));
+    }
+    Ok(())
+}
+
+/// Resolve `name[i]` to one flat index, or a bare register name to all of them
+fn resolve(registers: &HashMap<String, Register>, arg: &str) -> Result<Vec<usize>> {
+    let name = arg.split('[').next().unwrap_or(arg).trim();
+    let register = registers
+        .get(name)
+        .ok_or_else(|| CircuitError::InvalidParameter(format!("Undeclared register {}", name)))?;
+
+    if arg.contains('[') {
+        let (_, index) = parse_indexed(arg)?;
+        if index >= register.size {
+            return Err(CircuitError::InvalidQubitIndex(index, register.size));
+        }
+        Ok(vec![register.offset + index])
+    } else {
+        Ok((register.offset..register.offset + register.size).collect())
+    }
+}
+
+/// Parse one gate application and append it to the circuit
+fn apply_gate(circuit: &mut Circuit, qregs: &HashMap<String, Register>, statement: &str) -> Result<()> {
+    let (name, rest) = split_keyword(statement);
+
+    let (params, args) = if let Some(inner) = rest.strip_prefix('(') {
+        let close = inner.rfind(')').ok_or_else(|| {
+            CircuitError::InvalidParameter(format!("Unclosed parameter list: {}", statement))
+        })?;
+        let params = inner[..close]
+            .split(',')
+            .map(eval_param)
+            .collect::<Result<Vec<f64>>>()?;
+        (params, inner[close + 1..].trim())
+    } else {
+        (Vec::new(), rest)
+    };
+
+    let (arity, expected_params) = match name {
+        "id" | "x" | "y" | "z" | "h" | "s" | "sdg" | "t" | "tdg" | "sx" => (1, 0),
+        "rx" | "ry" | "rz" | "u1" | "p" => (1, 1),
+        "u3" | "u" => (1, 3),
+        "cx" | "cy" | "cz" | "swap" => (2, 0),
+        "crz" | "cu1" | "cp" => (2, 1),
+        "ccx" | "cswap" => (3, 0),
+        _ => match mcx_controls(name) {
+            Some(k) => (k + 1, 0),
+            None => return Err(CircuitError::UnsupportedGate(name.to_string())),
+        },
+    };
+    if params.len() != expected_params {
+        return Err(CircuitError::InvalidParameter(format!(
+            "{} takes {} parameters, got {}",
+            name,
+            expected_params,
+            params.len()
+        )));
+    }
+
+    let operands = args
+        .split(',')
+        .map(|arg| resolve(qregs, arg.trim()))
+        .collect::<Result<Vec<Vec<usize>>>>()?;
+    if operands.len() != arity {
+        return Err(CircuitError::InvalidParameter(format!(
+            "{} acts on {} qubits, got {}",
+            name,
+            arity,
+            operands.len()
+        )));
+    }
+
+    // Whole-register arguments broadcast; all of them must have equal width
+    let width = operands.iter().map(Vec::len).max().unwrap_or(0);
+    if operands.iter().any(|o| o.len() != 1 && o.len() != width) {
+        return Err(CircuitError::InvalidParameter(format!(
+            "Register width mismatch: {}",
+            statement
+        )));
+    }
+
+    for i in 0..width {
+        let qubits: Vec<usize> = operands
+            .iter()
+            .map(|o| if o.len() == 1 { o[0] } else { o[i] })
+            .collect();
+        for (a, &q) in qubits.iter().enumerate() {
+            if qubits[..a].contains(&q) {
+                return Err(CircuitError::SameQubitIndex(q, q));
+            }
+        }
+
+        if let Some(&q) = qubits.iter().find(|q| circuit.measurements.iter().any(|(m, _)| m == *q)) {
+            return Err(CircuitError::InvalidParameter(format!(
+                "Gate {} on qubit {} after it was measured",
+                name, q
+            )));
+        }
+
+        let gate = match name {
+            "id" => Gate::identity(),
+            "x" => Gate::x(),
+            "y" => Gate::y(),
+            "z" => Gate::z(),
+            "h" => Gate::h(),
+            "s" => Gate::s(),
+            "sdg" => Gate::sdg(),
+            "t" => Gate::t(),
+            "tdg" => Gate::tdg(),
+            "sx" => Gate::sqrt_x(),
+            "rx" => Gate::rx(params[0]),
+            "ry" => Gate::ry(params[0]),
+            "rz" => Gate::rz(params[0]),
+            "u1" | "p" => Gate::u1(params[0]),
+            "u3" | "u" => Gate::u3(params[0], params[1], params[2]),
+            "cx" => Gate::cnot(),
+            "cy" => Gate::cy(),
+            "cz" => Gate::cz(),
+            "swap" => Gate::swap(),
+            "crz" => Gate::crz(params[0]),
+            "cu1" | "cp" => Gate::cphase(params[0]),
+            "ccx" => Gate::toffoli(),
+            "cswap" => Gate::fredkin(),
+            _ => Gate::mcx(arity - 1),
+        };
+        circuit.add_gate(gate, qubits)?;
+    }
+    Ok(())
+}
+
+/// Control count of a `c<k>x` gate name
+fn mcx_controls(name: &str) -> Option<usize> {
+    name.strip_prefix('c')?.strip_suffix('x')?.parse().ok().filter(|&k| k >= 3)
+}
+
+// ==================== Parameter Expressions ====================
+
+/// Evaluate a parameter expression such as `-pi/4` or `2*pi/3`
+fn eval_param(text: &str) -> Result<f64> {
+    let tokens: Vec<char> = text.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut parser = ExprParser { tokens: &tokens, pos: 0 };
+    let value = parser.expr()?;
+    if parser.pos != tokens.len() {
+        return Err(CircuitError::InvalidParameter(format!("Invalid expression {}", text.trim())));
+    }
+    Ok(value)
+}
+
+/// Recursive-descent parser over `+ - * / ^`, parentheses, numbers and `pi`
+struct ExprParser<'a> {
+    tokens: &'a [char],
+    pos: usize,
+}
+
+impl ExprParser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn error(&self) -> CircuitError {
+        let text: String = self.tokens.iter().collect();
+        CircuitError::InvalidParameter(format!("Invalid expression {}", text))
+    }
+
+    fn expr(&mut self) -> Result<f64> {
+        let mut value = self.term()?;
+        while let Some(op @ ('+' | '-')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            value = if op == '+' { value + rhs } else { value - rhs };
+        }
+        Ok(value)
+    }
+
+    fn term(&mut self) -> Result<f64> {
+        let mut value = self.power()?;
+        while let Some(op @ ('*' | '/')) = self.peek() {
+            self.pos += 1;
+            let rhs = self.power()?;
+            value = if op == '*' { value * rhs } else { value / rhs };
+        }
+        Ok(value)
+    }
+
+    fn power(&mut self) -> Result<f64> {
+        let base = self.unary()?;
+        if self.peek() == Some('^') {
+            self.pos += 1;
+            return Ok(base.powf(self.power()?));
+        }
+        Ok(base)
+    }
+
+    fn unary(&mut self) -> Result<f64> {
+        match self.peek() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(-self.unary()?)
+            }
+            Some('+') => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.atom(),
+        }
+    }
+
+    fn atom(&mut self) -> Result<f64> {
+        match self.peek() {
+            Some('(') => {
+                self.pos += 1;
+                let value = self.expr()?;
+                if self.peek() != Some(')') {
+                    return Err(self.error());
+                }
+                self.pos += 1;
+                Ok(value)
+            }
+            Some('p') if self.tokens.get(self.pos + 1) == Some(&'i') => {
+                self.pos += 2;
+                Ok(std::f64::consts::PI)
+            }
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                let start = self.pos;
+                while let Some(c) = self.peek() {
+                    let exponent_sign = (c == '-' || c == '+')
+                        && matches!(self.tokens.get(self.pos.wrapping_sub(1)), Some('e' | 'E'));
+                    if c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E' || exponent_sign {
+                        self.pos += 1;
+                    } else {
+                        break;
+                    }
+                }
+                let literal: String = self.tokens[start..self.pos].iter().collect();
+                literal.parse().map_err(|_| self.error())
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    /// Same registers, gates, parameters, operands and measurements
+    fn assert_same_structure(a: &Circuit, b: &Circuit) {
+        assert_eq!(a.num_qubits, b.num_qubits);
+        assert_eq!(a.num_classical_bits, b.num_classical_bits);
+        assert_eq!(a.measurements, b.measurements);
+        assert_eq!(a.instructions.len(), b.instructions.len());
+        for (x, y) in a.instructions.iter().zip(&b.instructions) {
+            assert_eq!(x.gate.gate_type, y.gate.gate_type);
+            assert_eq!(x.gate.parameter, y.gate.parameter);
+            assert_eq!(x.qubits, y.qubits);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let circuit = Circuit::new(3)
+            .h(0)
+            .x(1)
+            .y(2)
+            .z(0)
+            .s(1)
+            .t(2)
+            .rx(0.3, 0)
+            .ry(-PI / 7.0, 1)
+            .rz(1e-5, 2)
+            .cnot(0, 1)
+            .toffoli(0, 1, 2)
+            .cphase(PI / 4.0, 2, 0)
+            .measure_all();
+        let circuit = Circuit::new(5).h(4).mcx(vec![4, 0, 2], 1).then(&circuit);
+
+        let parsed = Circuit::from_qasm(&circuit.to_qasm().unwrap()).unwrap();
+        assert_same_structure(&circuit, &parsed);
+
+        let qft = Circuit::qft(4);
+        assert_same_structure(&qft, &Circuit::from_qasm(&qft.to_qasm().unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_round_trip_u3_full_precision() {
+        let (theta, phi, lambda) = (PI / 3.0, 0.123_456_789_012_345, -2.0 / 7.0);
+        let mut circuit = Circuit::new(1);
+        circuit.add_gate(Gate::u3(theta, phi, lambda), vec![0]).unwrap();
+
+        let qasm = circuit.to_qasm().unwrap();
+        assert!(qasm.contains(&format!("u3({},{},{}) q[0];", theta, phi, lambda)));
+
+        let parsed = Circuit::from_qasm(&qasm).unwrap();
+        assert_eq!(parsed.instructions[0].gate.gate_type, GateType::U3);
+        assert_eq!(parsed.instructions[0].gate.params, vec![theta, phi, lambda]);
+        assert_eq!(parsed.instructions[0].gate.matrix(), circuit.instructions[0].gate.matrix());
+    }
+
+    #[test]
+    fn test_export_rejects_non_qelib_gates() {
+        for gate in [Gate::sqrt_x().adjoint(), Gate::custom("S†", Gate::s().matrix().adjoint()), Gate::iswap()] {
+            let mut circuit = Circuit::new(2);
+            let qubits = (0..gate.num_qubits).collect();
+            circuit.add_gate(gate, qubits).unwrap();
+            assert!(matches!(circuit.to_qasm(), Err(CircuitError::UnsupportedGate(_))));
+        }
+    }
+
+    #[test]
+    fn test_rejects_gate_after_measurement() {
+        let header = "OPENQASM 2.0;\nqreg q[2];\ncreg c[2];\n";
+        assert!(Circuit::from_qasm(&format!("{}measure q[0] -> c[0];\nh q[0];", header)).is_err());
+
+        // Gates on other qubits commute with the terminal measurement
+        let circuit = Circuit::from_qasm(&format!("{}measure q[0] -> c[0];\nh q[1];", header)).unwrap();
+        assert_eq!(circuit.gate_count(), 1);
+        assert_eq!(circuit.measurements, vec![(0, 0)]);
+    }
+
+    #[test]
+    fn test_registers_and_expressions() {
+        let source = r#"
+            OPENQASM 2.0;
+            include "qelib1.inc";
+            // two quantum registers laid out back to back
+            qreg a[2];
+            qreg b[1];
+            creg c[3];
+            h a;
+            rz(-pi/2) b[0];
+            rx(2*(pi - 1)/4) a[1];
+            cx a[1], b[0];
+            barrier a, b;
+            measure a -> c[0];
+        "#;
+        // `measure a -> c[0]` broadcasts two qubits onto one bit
+        assert!(Circuit::from_qasm(source).is_err());
+
+        let circuit = Circuit::from_qasm(&source.replace("measure a -> c[0]", "measure b[0] -> c[2]")).unwrap();
+        assert_eq!(circuit.num_qubits, 3);
+        assert_eq!(circuit.num_classical_bits, 3);
+        assert_eq!(circuit.gate_count(), 5);
+        assert_eq!(circuit.instructions[1].qubits, vec![1]);
+        assert_eq!(circuit.instructions[2].gate.parameter, Some(-PI / 2.0));
+        assert!((circuit.instructions[3].gate.parameter.unwrap() - (PI - 1.0) / 2.0).abs() < 1e-12);
+        assert_eq!(circuit.instructions[4].qubits, vec![1, 2]);
+        assert_eq!(circuit.measurements, vec![(2, 2)]);
+    }
+
+    #[test]
+    fn test_rejects_unsupported_and_malformed() {
+        let header = "OPENQASM 2.0;\nqreg q[2];\n";
+        assert!(matches!(
+            Circuit::from_qasm(&format!("{}iswap q[0], q[1];", header)),
+            Err(CircuitError::UnsupportedGate(name)) if name == "iswap"
+        ));
+        assert!(matches!(
+            Circuit::from_qasm(&format!("{}x q[2];", header)),
+            Err(CircuitError::InvalidQubitIndex(2, 2))
+        ));
+        assert!(Circuit::from_qasm(&format!("{}rx q[0];", header)).is_err());
+        assert!(Circuit::from_qasm(&format!("{}cx q[0];", header)).is_err());
+        assert!(Circuit::from_qasm(&format!("{}h r[0];", header)).is_err());
+        assert!(Circuit::from_qasm("OPENQASM 3.0;").is_err());
+    }
+}
@@ -2,9 +2,10 @@
 //!
 //! This module provides the core simulation engine for quantum circuits.
 
-use crate::{Complex, Gate, Circuit, CircuitError, Result, ZERO, ONE};
+use crate::{Complex, DensityMatrix, Gate, GateType, Circuit, CircuitInstruction, CircuitError, Result, ZERO, ONE};
 use nalgebra::DMatrix;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
 
 /// State vector representation of a quantum register
 #[derive(Debug, Clone)]
@@ -140,6 +141,106 @@ impl StateVector {
         let result = matrix * state_vec;
         self.amplitudes = result.iter().copied().collect();
     }
+
+    /// Apply a 2^k × 2^k gate matrix to `qubits` in place
+    ///
+    /// The first listed qubit is the most significant bit of the gate index,
+    /// as in the full-space expansion. Costs O(2^n · 2^k) instead of the
+    /// O(4^n) of building the expanded matrix. Qubits must be distinct and in
+    /// range.
+    pub(crate) fn apply_local(&mut self, gate: &DMatrix<Complex>, qubits: &[usize]) {
+        let gate_dim = 1 << qubits.len();
+        let gate_mask = qubits.iter().fold(0usize, |m, &q| m | (1 << q));
+
+        // Offset of each gate basis state within the full index
+        let offsets: Vec<usize> = (0..gate_dim)
+            .map(|g| {
+                qubits.iter().enumerate().fold(0, |offset, (p, &q)| {
+                    let bit = (g >> (qubits.len() - 1 - p)) & 1;
+                    offset | (bit << q)
+                })
+            })
+            .collect();
+
+        let mut block = vec![ZERO; gate_dim];
+        for base in (0..self.amplitudes.len()).filter(|b| b & gate_mask == 0) {
+            for (slot, &offset) in block.iter_mut().zip(&offsets) {
+                *slot = self.amplitudes[base | offset];
+            }
+            for (row, &offset) in offsets.iter().enumerate() {
+                self.amplitudes[base | offset] = (0..gate_dim)
+                    .map(|col| gate[(row, col)] * block[col])
+                    .sum();
+            }
+        }
+    }
+}
+
+// ==================== State Comparison ====================
+
+/// Fidelity |⟨ψ|φ⟩|² between two pure states
+///
+/// Checked form of [`StateVector::fidelity`]: dimensions must match, and
+/// both states are normalized first, and the modulus discards any global
+/// phase, so `ψ` and `e^{iα}ψ` have fidelity 1.
+pub fn fidelity(a: &StateVector, b: &StateVector) -> Result<f64> {
+    if a.dimension() != b.dimension() {
+        return Err(CircuitError::DimensionMismatch {
+            expected: a.dimension(),
+            actual: b.dimension(),
+        });
+    }
+    let norms = a.norm_squared() * b.norm_squared();
+    if norms < 1e-30 {
+        return Err(CircuitError::InvalidState("Cannot compare a zero state".to_string()));
+    }
+    Ok((a.fidelity(b) / norms).min(1.0))
+}
+
+/// Trace distance ½‖ρ − σ‖₁ between two pure states, `sqrt(1 - F)`
+pub fn trace_distance(a: &StateVector, b: &StateVector) -> Result<f64> {
+    Ok((1.0 - fidelity(a, b)?).max(0.0).sqrt())
+}
+
+/// Total variation distance ½ Σ |p_i − q_i| between two distributions
+///
+/// The classical counterpart of [`trace_distance`], for comparing
+/// measurement distributions.
+pub fn total_variation_distance(p: &[f64], q: &[f64]) -> Result<f64> {
+    if p.len() != q.len() {
+        return Err(CircuitError::DimensionMismatch {
+            expected: p.len(),
+            actual: q.len(),
+        });
+    }
+    Ok(p.iter().zip(q).map(|(a, b)| (a - b).abs()).sum::<f64>() / 2.0)
+}
+
+/// Snapshot of a register's simulated state
+#[derive(Debug, Clone)]
+pub enum RegisterState {
+    /// Pure state, in state-vector mode
+    Pure(StateVector),
+    /// Mixed state, in density-matrix mode
+    Mixed(DensityMatrix),
+}
+
+impl RegisterState {
+    /// Probability distribution over basis states
+    pub fn probabilities(&self) -> Vec<f64> {
+        match self {
+            Self::Pure(state) => state.probabilities(),
+            Self::Mixed(rho) => rho.probabilities(),
+        }
+    }
+
+    /// Probability of measuring a specific basis state
+    pub fn probability(&self, index: usize) -> f64 {
+        match self {
+            Self::Pure(state) => state.probability(index),
+            Self::Mixed(rho) => rho.probability(index),
+        }
+    }
 }
 
 /// Quantum Register with simulation capabilities
@@ -147,10 +248,16 @@ impl StateVector {
 pub struct QuantumRegister {
     /// The quantum state
     pub state: StateVector,
+    /// Mixed state, when simulating in density-matrix mode
+    ///
+    /// In this mode gates and measurements act on ρ and `state` is unused.
+    pub density: Option<DensityMatrix>,
     /// Classical bits for measurement results
     pub classical_bits: Vec<bool>,
     /// History of applied gates (for debugging/visualization)
     pub gate_history: Vec<String>,
+    /// Seeded random source for measurement; `None` uses the thread RNG
+    rng: Option<StdRng>,
 }
 
 impl QuantumRegister {
@@ -158,8 +265,10 @@ impl QuantumRegister {
     pub fn new(num_qubits: usize) -> Self {
         Self {
             state: StateVector::new(num_qubits),
+            density: None,
             classical_bits: vec![false; num_qubits],
             gate_history: Vec::new(),
+            rng: None,
         }
     }
 
@@ -168,8 +277,51 @@ impl QuantumRegister {
         let num_qubits = state.num_qubits;
         Self {
             state,
+            density: None,
             classical_bits: vec![false; num_qubits],
             gate_history: Vec::new(),
+            rng: None,
+        }
+    }
+
+    /// Create a register with n qubits simulated as a density matrix
+    pub fn new_density(num_qubits: usize) -> Self {
+        Self {
+            density: Some(DensityMatrix::new(num_qubits)),
+            ..Self::new(num_qubits)
+        }
+    }
+
+    /// Seed the random source used by measurement and sampling
+    ///
+    /// After seeding, the same circuit and sequence of measurements always
+    /// give the same outcomes. [`QuantumRegister::sample`] and
+    /// [`QuantumRegister::get_counts`] borrow the register immutably, so they
+    /// draw from a copy of the current generator without advancing it.
+    pub fn set_rng_seed(&mut self, seed: u64) {
+        self.rng = Some(StdRng::seed_from_u64(seed));
+    }
+
+    /// Whether the register simulates a density matrix
+    pub fn is_density(&self) -> bool {
+        self.density.is_some()
+    }
+
+    /// Probability distribution over basis states
+    ///
+    /// Read from the diagonal of ρ in density-matrix mode.
+    pub fn probabilities(&self) -> Vec<f64> {
+        match &self.density {
+            Some(rho) => rho.probabilities(),
+            None => self.state.probabilities(),
+        }
+    }
+
+    /// Probability of measuring a specific basis state
+    pub fn probability(&self, index: usize) -> f64 {
+        match &self.density {
+            Some(rho) => rho.probability(index),
+            None => self.state.probability(index),
         }
     }
 
@@ -181,6 +333,9 @@ impl QuantumRegister {
     /// Reset to |0...0⟩
     pub fn reset(&mut self) {
         self.state = StateVector::new(self.num_qubits());
+        if self.density.is_some() {
+            self.density = Some(DensityMatrix::new(self.num_qubits()));
+        }
         self.classical_bits = vec![false; self.num_qubits()];
         self.gate_history.clear();
     }
@@ -196,8 +351,12 @@ impl QuantumRegister {
             return Err(CircuitError::InvalidQubitIndex(qubit, self.num_qubits()));
         }
 
-        let full_matrix = self.expand_single_gate(gate, qubit);
-        self.state.apply_matrix(&full_matrix);
+        if self.density.is_some() {
+            let full_matrix = self.expand_single_gate(gate, qubit);
+            self.apply_full_matrix(&full_matrix);
+        } else {
+            self.state.apply_local(&gate.matrix(), &[qubit]);
+        }
         self.gate_history.push(format!("{}({})", gate.name, qubit));
         Ok(())
     }
@@ -219,8 +378,12 @@ impl QuantumRegister {
             return Err(CircuitError::InvalidQubitIndex(qubit2, self.num_qubits()));
         }
 
-        let full_matrix = self.expand_two_qubit_gate(gate, qubit1, qubit2);
-        self.state.apply_matrix(&full_matrix);
+        if self.density.is_some() {
+            let full_matrix = self.expand_two_qubit_gate(gate, qubit1, qubit2);
+            self.apply_full_matrix(&full_matrix);
+        } else {
+            self.state.apply_local(&gate.matrix(), &[qubit1, qubit2]);
+        }
         self.gate_history.push(format!("{}({},{})", gate.name, qubit1, qubit2));
         Ok(())
     }
@@ -228,19 +391,116 @@ impl QuantumRegister {
     /// Apply a circuit
     pub fn apply_circuit(&mut self, circuit: &Circuit) -> Result<()> {
         for instruction in &circuit.instructions {
-            match instruction.qubits.len() {
-                1 => self.apply_single_gate(&instruction.gate, instruction.qubits[0])?,
-                2 => self.apply_two_qubit_gate(
-                    &instruction.gate,
-                    instruction.qubits[0],
-                    instruction.qubits[1],
-                )?,
-                _ => {
-                    // For 3+ qubit gates, use general expansion
+            self.apply_instruction(instruction)?;
+        }
+        Ok(())
+    }
+
+    /// Apply a circuit one instruction at a time, yielding the state after each.
+    ///
+    /// The iterator is lazy: each call to `next` applies exactly one instruction
+    /// and clones the simulated state for it (ρ in density-matrix mode), so
+    /// nothing is computed or copied for steps the consumer never pulls. An
+    /// invalid instruction yields its error and ends the iteration.
+    pub fn apply_circuit_stepwise<'a>(
+        &'a mut self,
+        circuit: &'a Circuit,
+    ) -> impl Iterator<Item = Result<(CircuitInstruction, RegisterState)>> + 'a {
+        let mut instructions = circuit.instructions.iter();
+        let mut failed = false;
+        std::iter::from_fn(move || {
+            if failed {
+                return None;
+            }
+            let instruction = instructions.next()?;
+            match self.apply_instruction(instruction) {
+                Ok(()) => Some(Ok((instruction.clone(), self.snapshot()))),
+                Err(e) => {
+                    failed = true;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+
+    /// Copy of whichever state is simulated
+    pub fn snapshot(&self) -> RegisterState {
+        match &self.density {
+            Some(rho) => RegisterState::Mixed(rho.clone()),
+            None => RegisterState::Pure(self.state.clone()),
+        }
+    }
+
+    /// Apply a full-space unitary to whichever state is simulated
+    fn apply_full_matrix(&mut self, matrix: &DMatrix<Complex>) {
+        match &mut self.density {
+            Some(rho) => rho.apply_unitary(matrix),
+            None => self.state.apply_matrix(matrix),
+        }
+    }
+
+    /// Apply a multi-controlled X: flip `target` where every control is |1⟩
+    ///
+    /// Zero controls is a plain X. State vectors swap amplitude pairs in place
+    /// rather than building the 2^n × 2^n matrix.
+    pub fn apply_mcx(&mut self, controls: &[usize], target: usize) -> Result<()> {
+        let n = self.num_qubits();
+        for &q in controls.iter().chain(std::iter::once(&target)) {
+            if q >= n {
+                return Err(CircuitError::InvalidQubitIndex(q, n));
+            }
+        }
+        for (i, &c) in controls.iter().enumerate() {
+            if c == target || controls[..i].contains(&c) {
+                return Err(CircuitError::SameQubitIndex(c, c));
+            }
+        }
+
+        if self.density.is_some() {
+            let mut qubits = controls.to_vec();
+            qubits.push(target);
+            let full_matrix = self.expand_multi_qubit_gate(&Gate::mcx(controls.len()), &qubits)?;
+            self.apply_full_matrix(&full_matrix);
+        } else {
+            let control_mask = controls.iter().fold(0usize, |m, &c| m | (1 << c));
+            let target_bit = 1 << target;
+            for i in 0..self.state.dimension() {
+                // Visit each pair once, from its target = 0 member
+                if i & control_mask == control_mask && i & target_bit == 0 {
+                    self.state.amplitudes.swap(i, i | target_bit);
+                }
+            }
+        }
+
+        self.gate_history.push(format!("C{}X({:?},{})", controls.len(), controls, target));
+        Ok(())
+    }
+
+    /// Apply a single circuit instruction
+    fn apply_instruction(&mut self, instruction: &CircuitInstruction) -> Result<()> {
+        if instruction.gate.gate_type == GateType::MCX {
+            if let Some((&target, controls)) = instruction.qubits.split_last() {
+                return self.apply_mcx(controls, target);
+            }
+        }
+
+        match instruction.qubits.len() {
+            1 => self.apply_single_gate(&instruction.gate, instruction.qubits[0])?,
+            2 => self.apply_two_qubit_gate(
+                &instruction.gate,
+                instruction.qubits[0],
+                instruction.qubits[1],
+            )?,
+            _ => {
+                // For 3+ qubit gates, use general expansion
+                if self.density.is_some() {
                     let full_matrix = self.expand_multi_qubit_gate(&instruction.gate, &instruction.qubits)?;
-                    self.state.apply_matrix(&full_matrix);
-                    self.gate_history.push(format!("{}({:?})", instruction.gate.name, instruction.qubits));
+                    self.apply_full_matrix(&full_matrix);
+                } else {
+                    self.check_gate_qubits(&instruction.gate, &instruction.qubits)?;
+                    self.state.apply_local(&instruction.gate.matrix(), &instruction.qubits);
                 }
+                self.gate_history.push(format!("{}({:?})", instruction.gate.name, instruction.qubits));
             }
         }
         Ok(())
@@ -252,9 +512,8 @@ impl QuantumRegister {
             return Err(CircuitError::InvalidQubitIndex(qubit, self.num_qubits()));
         }
 
-        let mut rng = rand::thread_rng();
         let prob_one = self.probability_of_one(qubit);
-        let result = rng.gen::<f64>() < prob_one;
+        let result = self.uniform() < prob_one;
 
         // Collapse the state
         self.collapse(qubit, result);
@@ -265,11 +524,10 @@ impl QuantumRegister {
 
     /// Measure all qubits
     pub fn measure_all(&mut self) -> Vec<bool> {
-        let mut rng = rand::thread_rng();
-        let probs = self.state.probabilities();
+        let probs = self.probabilities();
 
         // Sample from the distribution
-        let r: f64 = rng.gen();
+        let r = self.uniform();
         let mut cumulative = 0.0;
         let mut outcome = 0;
 
@@ -288,6 +546,9 @@ impl QuantumRegister {
 
         // Collapse to the measured state
         self.state = StateVector::basis_state(self.num_qubits(), outcome).unwrap();
+        if self.density.is_some() {
+            self.density = Some(DensityMatrix::from_state(&self.state));
+        }
         self.classical_bits = results.clone();
 
         results
@@ -295,8 +556,8 @@ impl QuantumRegister {
 
     /// Sample measurements without collapsing (for statistics)
     pub fn sample(&self, shots: usize) -> Vec<Vec<bool>> {
-        let mut rng = rand::thread_rng();
-        let probs = self.state.probabilities();
+        let mut rng = self.sampling_rng();
+        let probs = self.probabilities();
 
         (0..shots).map(|_| {
             let r: f64 = rng.gen();
@@ -333,6 +594,80 @@ impl QuantumRegister {
         counts
     }
 
+    /// Exact expectation value ⟨ψ|P|ψ⟩ of a Pauli string, without collapsing
+    ///
+    /// The string is read like a tensor product, so its first character acts
+    /// on the highest qubit: `"XIZ"` is X on qubit 2 and Z on qubit 0. Each
+    /// Pauli factor only permutes and phases basis states, so the sum runs
+    /// over amplitudes directly and identity factors cost nothing. In
+    /// density-matrix mode this is Tr(ρP).
+    pub fn expectation_pauli(&self, pauli: &str) -> Result<f64> {
+        let n = self.num_qubits();
+        if pauli.chars().count() != n {
+            return Err(CircuitError::InvalidParameter(
+                format!("Pauli string length {} doesn't match qubit count {}",
+                    pauli.chars().count(), n)
+            ));
+        }
+
+        // P|i⟩ = i^y · (-1)^popcount(i & phase_mask) · |i ^ flip_mask⟩
+        let mut flip_mask = 0usize;
+        let mut phase_mask = 0usize;
+        let mut num_y = 0;
+        for (k, c) in pauli.chars().enumerate() {
+            let bit = 1 << (n - 1 - k);
+            match c {
+                'I' | 'i' => {}
+                'X' | 'x' => flip_mask |= bit,
+                'Y' | 'y' => {
+                    flip_mask |= bit;
+                    phase_mask |= bit;
+                    num_y += 1;
+                }
+                'Z' | 'z' => phase_mask |= bit,
+                _ => return Err(CircuitError::InvalidParameter(
+                    format!("Invalid Pauli character: {}", c)
+                )),
+            }
+        }
+        let global = Complex::new(0.0, 1.0).powu(num_y);
+        let sign = |i: usize| if (i & phase_mask).count_ones().is_multiple_of(2) { 1.0 } else { -1.0 };
+
+        let dim = 1usize << n;
+        let sum: Complex = match &self.density {
+            Some(rho) => (0..dim)
+                .map(|i| rho.element(i, i ^ flip_mask) * sign(i))
+                .sum(),
+            None => {
+                let amps = self.state.amplitudes();
+                (0..dim)
+                    .map(|i| amps[i ^ flip_mask].conj() * amps[i] * sign(i))
+                    .sum()
+            }
+        };
+
+        Ok((global * sum).re)
+    }
+
+    /// Random source for sampling without collapse
+    ///
+    /// A copy of the seeded generator if [`QuantumRegister::set_rng_seed`]
+    /// was called, otherwise the thread RNG.
+    pub(crate) fn sampling_rng(&self) -> Box<dyn RngCore> {
+        match &self.rng {
+            Some(rng) => Box::new(rng.clone()),
+            None => Box::new(rand::thread_rng()),
+        }
+    }
+
+    /// Uniform draw in [0, 1) from the seeded or thread random source
+    fn uniform(&mut self) -> f64 {
+        match &mut self.rng {
+            Some(rng) => rng.gen(),
+            None => rand::thread_rng().gen(),
+        }
+    }
+
     /// Probability of measuring |1⟩ on a specific qubit
     fn probability_of_one(&self, qubit: usize) -> f64 {
         let dim = self.state.dimension();
@@ -340,7 +675,7 @@ impl QuantumRegister {
 
         for i in 0..dim {
             if (i >> qubit) & 1 == 1 {
-                prob += self.state.probability(i);
+                prob += self.probability(i);
             }
         }
 
@@ -349,6 +684,11 @@ impl QuantumRegister {
 
     /// Collapse state after measurement
     fn collapse(&mut self, qubit: usize, result: bool) {
+        if let Some(rho) = &mut self.density {
+            rho.collapse(qubit, result);
+            return;
+        }
+
         let dim = self.state.dimension();
         let mut new_amplitudes = vec![ZERO; dim];
         let mut norm_sq = 0.0;
@@ -425,6 +765,26 @@ impl QuantumRegister {
         full_matrix
     }
 
+    /// Check that a multi-qubit gate gets one distinct, valid qubit per input
+    fn check_gate_qubits(&self, gate: &Gate, qubits: &[usize]) -> Result<()> {
+        if gate.num_qubits != qubits.len() {
+            return Err(CircuitError::InvalidParameter(format!(
+                "{}-qubit gate applied to {} qubits",
+                gate.num_qubits,
+                qubits.len()
+            )));
+        }
+        for (i, &q) in qubits.iter().enumerate() {
+            if q >= self.num_qubits() {
+                return Err(CircuitError::InvalidQubitIndex(q, self.num_qubits()));
+            }
+            if qubits[..i].contains(&q) {
+                return Err(CircuitError::SameQubitIndex(q, q));
+            }
+        }
+        Ok(())
+    }
+
     /// Expand multi-qubit gate
     fn expand_multi_qubit_gate(&self, gate: &Gate, qubits: &[usize]) -> Result<DMatrix<Complex>> {
         let n = self.num_qubits();
@@ -447,12 +807,13 @@ impl QuantumRegister {
                     continue;
                 }
 
-                // Extract gate qubit indices
+                // Extract gate qubit indices; the first qubit is the most
+                // significant, matching the two-qubit expansion
                 let mut gi = 0;
                 let mut gj = 0;
-                for (k, &q) in qubits.iter().enumerate() {
-                    gi |= ((i >> q) & 1) << k;
-                    gj |= ((j >> q) & 1) << k;
+                for &q in qubits {
+                    gi = (gi << 1) | ((i >> q) & 1);
+                    gj = (gj << 1) | ((j >> q) & 1);
                 }
 
                 full_matrix[(i, j)] = gate_matrix[(gi, gj)];
@@ -466,16 +827,24 @@ impl QuantumRegister {
     pub fn state_string(&self) -> String {
         let dim = self.state.dimension();
         let n = self.num_qubits();
+        let basis = |i: usize| -> String {
+            (0..n).rev().map(|b| if (i >> b) & 1 == 1 { '1' } else { '0' }).collect()
+        };
+
+        if let Some(rho) = &self.density {
+            // Mixed states have no amplitudes; show the populations instead
+            let terms: Vec<String> = (0..dim)
+                .filter(|&i| rho.probability(i) > 1e-10)
+                .map(|i| format!("{:.4}|{}⟩⟨{}|", rho.probability(i), basis(i), basis(i)))
+                .collect();
+            return terms.join(" + ");
+        }
 
         let terms: Vec<String> = (0..dim)
             .filter_map(|i| {
                 let amp = self.state.amplitude(i);
                 if amp.norm_sqr() > 1e-10 {
-                    let basis: String = (0..n)
-                        .rev()
-                        .map(|b| if (i >> b) & 1 == 1 { '1' } else { '0' })
-                        .collect();
-                    Some(format!("({:.4})|{}⟩", amp, basis))
+                    Some(format!("({:.4})|{}⟩", amp, basis(i)))
                 } else {
                     None
                 }
@@ -527,6 +896,218 @@ mod tests {
         assert_relative_eq!(reg.state.probability(0b11), 0.5, epsilon = 1e-10);
     }
 
+    #[test]
+    fn test_apply_circuit_stepwise() {
+        let circuit = Circuit::bell_state();
+        let mut reg = QuantumRegister::new(2);
+        let steps: Vec<_> = reg
+            .apply_circuit_stepwise(&circuit)
+            .collect::<Result<_>>()
+            .unwrap();
+
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].0.gate.name, "H");
+        assert_relative_eq!(steps[0].1.probability(0b00), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(steps[0].1.probability(0b01), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(steps[1].1.probability(0b11), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(reg.state.probability(0b11), 0.5, epsilon = 1e-10);
+
+        // Density mode yields the evolving ρ, not the unused state vector
+        let mut mixed = QuantumRegister::new_density(2);
+        let steps: Vec<_> = mixed
+            .apply_circuit_stepwise(&circuit)
+            .collect::<Result<_>>()
+            .unwrap();
+        assert!(matches!(steps[0].1, RegisterState::Mixed(_)));
+        assert_relative_eq!(steps[0].1.probability(0b01), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(steps[1].1.probability(0b11), 0.5, epsilon = 1e-10);
+        assert_relative_eq!(steps[1].1.probability(0b01), 0.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_density_mode_matches_state_vector() {
+        let circuit = Circuit::ghz_state(3).rz(0.4, 1).h(2);
+        let mut pure = QuantumRegister::new(3);
+        let mut mixed = QuantumRegister::new_density(3);
+        pure.apply_circuit(&circuit).unwrap();
+        mixed.apply_circuit(&circuit).unwrap();
+
+        let rho = mixed.density.as_ref().unwrap();
+        assert!(rho.is_valid());
+        assert_relative_eq!(rho.fidelity(&pure.state), 1.0, epsilon = 1e-10);
+        for (p, q) in mixed.probabilities().iter().zip(pure.probabilities()) {
+            assert_relative_eq!(*p, q, epsilon = 1e-10);
+        }
+
+        // Measuring q0 collapses ρ; the GHZ correlation fixes q1 to match
+        let bit = mixed.measure(0).unwrap() as usize;
+        let consistent: f64 = (0..8)
+            .filter(|i| i & 1 == bit && (i >> 1) & 1 == bit)
+            .map(|i| mixed.probability(i))
+            .sum();
+        assert_relative_eq!(consistent, 1.0, epsilon = 1e-10);
+        assert!(mixed.density.as_ref().unwrap().is_valid());
+    }
+
+    #[test]
+    fn test_expectation_pauli() {
+        // |ψ⟩ = Ry(θ) on qubit 1 ⊗ |+⟩ on qubit 0
+        let theta: f64 = 0.8;
+        let mut reg = QuantumRegister::new(2);
+        reg.apply_circuit(&Circuit::new(2).ry(theta, 1).h(0)).unwrap();
+
+        assert_relative_eq!(reg.expectation_pauli("ZI").unwrap(), theta.cos(), epsilon = 1e-10);
+        assert_relative_eq!(reg.expectation_pauli("XI").unwrap(), theta.sin(), epsilon = 1e-10);
+        assert_relative_eq!(reg.expectation_pauli("IX").unwrap(), 1.0, epsilon = 1e-10);
+        assert_relative_eq!(reg.expectation_pauli("ZX").unwrap(), theta.cos(), epsilon = 1e-10);
+        assert_relative_eq!(reg.expectation_pauli("YI").unwrap(), 0.0, epsilon = 1e-10);
+        assert_relative_eq!(reg.expectation_pauli("II").unwrap(), 1.0, epsilon = 1e-10);
+
+        // |+i⟩ has ⟨Y⟩ = 1; the density path agrees with the state vector
+        let mut pure = QuantumRegister::new(1);
+        let mut mixed = QuantumRegister::new_density(1);
+        for reg in [&mut pure, &mut mixed] {
+            reg.apply_circuit(&Circuit::new(1).h(0).s(0)).unwrap();
+            assert_relative_eq!(reg.expectation_pauli("Y").unwrap(), 1.0, epsilon = 1e-10);
+        }
+        assert_eq!(pure.probabilities().len(), 2);
+
+        assert!(reg.expectation_pauli("Z").is_err());
+        assert!(reg.expectation_pauli("ZQ").is_err());
+    }
+
+    #[test]
+    fn test_toffoli_and_mcx() {
+        // Toffoli flips its last qubit only when both controls are set
+        for (input, expected) in [(0b011, 0b111), (0b001, 0b001), (0b110, 0b110)] {
+            let mut reg = QuantumRegister::from_state(StateVector::basis_state(3, input).unwrap());
+            reg.apply_circuit(&Circuit::new(3).toffoli(0, 1, 2)).unwrap();
+            assert_relative_eq!(reg.state.probability(expected), 1.0, epsilon = 1e-10);
+        }
+
+        // C4X on a 6-qubit register, controls out of order
+        let controls = [4, 0, 2, 5];
+        for input in 0..64usize {
+            let mut reg = QuantumRegister::from_state(StateVector::basis_state(6, input).unwrap());
+            reg.apply_circuit(&Circuit::new(6).mcx(controls.to_vec(), 1)).unwrap();
+            let all_set = controls.iter().all(|&c| (input >> c) & 1 == 1);
+            let expected = if all_set { input ^ 0b10 } else { input };
+            assert_relative_eq!(reg.state.probability(expected), 1.0, epsilon = 1e-10);
+        }
+
+        // The density path agrees with amplitude swapping
+        let circuit = Circuit::new(4).h_all().mcx(vec![0, 1, 2], 3).rz(0.3, 3);
+        let mut pure = QuantumRegister::new(4);
+        let mut mixed = QuantumRegister::new_density(4);
+        pure.apply_circuit(&circuit).unwrap();
+        mixed.apply_circuit(&circuit).unwrap();
+        assert_relative_eq!(mixed.density.as_ref().unwrap().fidelity(&pure.state), 1.0, epsilon = 1e-10);
+
+        let mut reg = QuantumRegister::new(3);
+        assert!(reg.apply_mcx(&[0, 1], 1).is_err());
+        assert!(reg.apply_mcx(&[0, 0], 2).is_err());
+        assert!(reg.apply_mcx(&[0, 3], 2).is_err());
+        reg.apply_mcx(&[], 2).unwrap();
+        assert_relative_eq!(reg.state.probability(0b100), 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_apply_local_matches_full_expansion() {
+        // Entangled, unevenly weighted 4-qubit state so every amplitude matters
+        let n = 4;
+        let raw: Vec<Complex> = (0..1usize << n)
+            .map(|k| Complex::new(1.0 + k as f64, (k * k % 5) as f64 - 2.0))
+            .collect();
+        let norm = raw.iter().map(|a| a.norm_sqr()).sum::<f64>().sqrt();
+        let state = StateVector::from_amplitudes(raw.iter().map(|a| a / norm).collect()).unwrap();
+        let reg = QuantumRegister::from_state(state.clone());
+
+        let cases: Vec<(Gate, Vec<usize>)> = vec![
+            (Gate::h(), vec![0]),
+            (Gate::y(), vec![3]),
+            (Gate::t(), vec![2]),
+            (Gate::u3(0.3, 1.2, -0.7), vec![1]),
+            (Gate::cnot(), vec![0, 1]),
+            (Gate::cnot(), vec![3, 0]),
+            (Gate::cy(), vec![2, 1]),
+            (Gate::crz(0.8), vec![1, 3]),
+            (Gate::iswap(), vec![3, 1]),
+            (Gate::sqrt_swap(), vec![0, 2]),
+            (Gate::toffoli(), vec![0, 1, 2]),
+            (Gate::toffoli(), vec![3, 1, 0]),
+            (Gate::fredkin(), vec![2, 0, 3]),
+        ];
+
+        for (gate, qubits) in cases {
+            let full = match qubits.len() {
+                1 => reg.expand_single_gate(&gate, qubits[0]),
+                2 => reg.expand_two_qubit_gate(&gate, qubits[0], qubits[1]),
+                _ => reg.expand_multi_qubit_gate(&gate, &qubits).unwrap(),
+            };
+            let mut expected = state.clone();
+            expected.apply_matrix(&full);
+
+            let mut local = state.clone();
+            local.apply_local(&gate.matrix(), &qubits);
+
+            for k in 0..1usize << n {
+                let diff = (local.amplitude(k) - expected.amplitude(k)).norm();
+                assert!(diff < 1e-12, "{} on {:?}: amplitude {} differs by {}", gate.name, qubits, k, diff);
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeded_measurement_is_reproducible() {
+        use crate::Measurement;
+
+        let circuit = Circuit::ghz_state(3).h(1).t(2).h(2);
+        let run = |seed: u64| {
+            let mut reg = QuantumRegister::new(3);
+            reg.apply_circuit(&circuit).unwrap();
+            reg.set_rng_seed(seed);
+            let counts = reg.get_counts(500);
+            let stats = Measurement::measure_all_seeded(&reg, 500, seed);
+            let first = reg.measure(0).unwrap();
+            (counts, stats.counts, first, reg.measure_all())
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7).1, run(8).1);
+
+        // Unseeded helpers follow the register's seed too
+        let measured = |seed: u64| {
+            let mut reg = QuantumRegister::new(3);
+            reg.apply_circuit(&circuit).unwrap();
+            reg.set_rng_seed(seed);
+            Measurement::measure_all(&reg, 500).counts
+        };
+        assert_eq!(measured(7), measured(7));
+        assert_ne!(measured(7), measured(8));
+    }
+
+    #[test]
+    fn test_fidelity_and_distances() {
+        let plus = StateVector::uniform_superposition(1);
+        let zero = StateVector::new(1);
+        let mut phased = plus.clone();
+        let phase = Complex::from_polar(1.0, 0.9);
+        phased.set_amplitudes(plus.amplitudes().iter().map(|a| a * phase).collect()).unwrap();
+
+        assert_relative_eq!(fidelity(&plus, &phased).unwrap(), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(trace_distance(&plus, &phased).unwrap(), 0.0, epsilon = 1e-6);
+        assert_relative_eq!(fidelity(&plus, &zero).unwrap(), 0.5, epsilon = 1e-12);
+        assert_relative_eq!(trace_distance(&plus, &zero).unwrap(), 0.5f64.sqrt(), epsilon = 1e-12);
+
+        let one = StateVector::basis_state(1, 1).unwrap();
+        assert_relative_eq!(trace_distance(&zero, &one).unwrap(), 1.0, epsilon = 1e-12);
+        assert!(fidelity(&zero, &StateVector::new(2)).is_err());
+
+        let tvd = total_variation_distance(&[0.5, 0.5, 0.0], &[0.25, 0.25, 0.5]).unwrap();
+        assert_relative_eq!(tvd, 0.5, epsilon = 1e-12);
+        assert!(total_variation_distance(&[1.0], &[0.5, 0.5]).is_err());
+    }
+
     #[test]
     fn test_measurement_statistics() {
         let mut reg = QuantumRegister::new(1);
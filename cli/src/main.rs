@@ -15,6 +15,9 @@ use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 use std::time::Instant;
 
+#[cfg(feature = "http")]
+mod serve;
+
 /// QOPS - Unified Quantum Operator Processing System
 ///
 /// A comprehensive framework for quantum algorithm research, experimentation, and education.
@@ -80,6 +83,39 @@ enum Commands {
 
     /// Hypercube Kernel for Generative Theomimesis
     Kernel(KernelArgs),
+
+    /// Serve the core pipelines as JSON HTTP endpoints
+    #[cfg(feature = "http")]
+    Serve(ServeArgs),
+}
+
+#[cfg(feature = "http")]
+#[derive(Args)]
+struct ServeArgs {
+    /// Port to listen on
+    #[arg(short, long, default_value_t = 8080)]
+    port: u16,
+
+    /// Address to bind
+    #[arg(long, default_value = "127.0.0.1")]
+    host: String,
+}
+
+#[cfg(feature = "http")]
+fn run_serve(args: ServeArgs) {
+    println!("\n{}", "QOPS HTTP Server".cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+    println!("{}: http://{}:{}", "Listening".yellow(), args.host, args.port);
+    println!("  GET  /health");
+    println!("  POST /mining/kernel");
+    println!("  POST /circuit/simulate");
+    println!("  POST /algorithm/grover");
+    println!("  POST /hypercube/compile");
+
+    if let Err(e) = serve::serve(&args.host, args.port) {
+        eprintln!("{}: {}", "Server error".red(), e);
+        std::process::exit(1);
+    }
 }
 
 // ============================================================================
@@ -102,6 +138,22 @@ struct GenesisArgs {
     /// Traversal strategy
     #[arg(long, default_value = "balanced")]
     strategy: TraversalStrategyArg,
+
+    /// Stop mining once this resonance is reached
+    #[arg(long)]
+    stop_at: Option<f64>,
+
+    /// Stop mining after this many agent steps without improvement
+    #[arg(long)]
+    patience: Option<usize>,
+
+    /// Write a checkpoint to this file after every agent's worth of steps
+    #[arg(long)]
+    checkpoint: Option<String>,
+
+    /// Continue mining from a checkpoint file
+    #[arg(long)]
+    resume: Option<String>,
 }
 
 #[derive(Subcommand)]
@@ -148,6 +200,9 @@ enum GenesisMode {
         /// Export Monolith report
         #[arg(long)]
         export: bool,
+        /// Seed for reproducible candidate mining
+        #[arg(long)]
+        seed: Option<u64>,
     },
 }
 
@@ -164,6 +219,9 @@ enum GenesisStageCommand {
         /// Export filter results
         #[arg(long)]
         export: bool,
+        /// Seed for reproducible candidate mining
+        #[arg(long)]
+        seed: Option<u64>,
     },
     /// Chronokrator: Resonance expansion
     Chronokrator {
@@ -176,6 +234,9 @@ enum GenesisStageCommand {
         /// Visualize dynamics
         #[arg(long)]
         visualize: bool,
+        /// Seed for reproducible candidate mining
+        #[arg(long)]
+        seed: Option<u64>,
     },
     /// Pfauenthron: Mandorla finalization
     Pfauenthron {
@@ -185,6 +246,9 @@ enum GenesisStageCommand {
         /// Number of Ophanim nodes
         #[arg(long, default_value_t = 4)]
         ophanim: usize,
+        /// Seed for reproducible candidate mining
+        #[arg(long)]
+        seed: Option<u64>,
     },
 }
 
@@ -216,8 +280,8 @@ fn run_genesis(args: GenesisArgs) {
             GenesisMode::Spiral { adaptive, iterations, export } => {
                 run_spiral_search(adaptive, iterations, export);
             }
-            GenesisMode::Finalize { export } => {
-                run_finalize_monolith(export);
+            GenesisMode::Finalize { export, seed } => {
+                run_finalize_monolith(export, seed);
             }
         }
         return;
@@ -227,7 +291,20 @@ fn run_genesis(args: GenesisArgs) {
     println!("\n{}", "Genesis Pipeline - S7 Operator Mining".cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
-    use qops_genesis::{MiningSession, MiningConfig, MiningStrategy};
+    use qops_genesis::{ConvergenceCriterion, MiningCheckpoint, MiningSession, MiningConfig, MiningStrategy};
+
+    let mut criteria = Vec::new();
+    if let Some(target) = args.stop_at {
+        criteria.push(ConvergenceCriterion::TargetResonance(target));
+    }
+    if let Some(steps) = args.patience {
+        criteria.push(ConvergenceCriterion::NoImprovement { steps });
+    }
+    let convergence = match criteria.len() {
+        0 => ConvergenceCriterion::Never,
+        1 => criteria.remove(0),
+        _ => ConvergenceCriterion::Composite(criteria),
+    };
 
     let strategy = match args.strategy {
         TraversalStrategyArg::Balanced => MiningStrategy::Balanced,
@@ -244,6 +321,7 @@ fn run_genesis(args: GenesisArgs) {
         num_agents: args.agents,
         steps_per_agent: args.steps,
         extract_families: true,
+        convergence,
         ..Default::default()
     };
 
@@ -258,10 +336,38 @@ fn run_genesis(args: GenesisArgs) {
         .unwrap());
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
-    let mut session = MiningSession::new(config);
+    let mut session = match &args.resume {
+        Some(path) => {
+            let resumed = MiningCheckpoint::load(path)
+                .map_err(|e| e.to_string())
+                .and_then(|checkpoint| MiningSession::resume(checkpoint, config).map_err(|e| e.to_string()));
+            match resumed {
+                Ok(session) => {
+                    pb.println(format!("{}: {}", "Resuming from".yellow(), path));
+                    session
+                }
+                Err(e) => {
+                    pb.finish_and_clear();
+                    eprintln!("{}: {}", "Cannot resume".red(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => MiningSession::new(config),
+    };
     let start = Instant::now();
 
-    let result = session.mine();
+    let result = match &args.checkpoint {
+        Some(path) => loop {
+            if let Some(result) = session.mine_for(args.steps.max(1)) {
+                break result;
+            }
+            if let Err(e) = session.checkpoint().save(path) {
+                pb.println(format!("{}: {}", "Checkpoint failed".red(), e));
+            }
+        },
+        None => session.mine(),
+    };
 
     pb.finish_and_clear();
     let elapsed = start.elapsed();
@@ -275,6 +381,11 @@ fn run_genesis(args: GenesisArgs) {
     println!("  Avg resonance:   {:.4}", result.stats.avg_resonance);
     println!("  Std resonance:   {:.4}", result.stats.std_resonance);
     println!("  Unique nodes:    {}", result.stats.unique_nodes);
+    println!("  Nodes visited:   {}", result.stats.nodes_visited);
+    if let Some(criterion) = &result.stats.converged_by {
+        println!("  Stopped early:   {:?}", criterion);
+        println!("  Steps taken:     {} of {}", result.steps_taken, result.total_steps);
+    }
     println!();
 
     // Show top artefacts
@@ -283,12 +394,12 @@ fn run_genesis(args: GenesisArgs) {
     sorted_artefacts.sort_by(|a, b| b.resonance.partial_cmp(&a.resonance).unwrap());
 
     for (i, artefact) in sorted_artefacts.iter().take(10).enumerate() {
-        let status = if artefact.is_mandorla() {
+        let status = if artefact.is_mandorla {
             "M".green()
         } else {
             "o".dimmed()
         };
-        println!("  {} {:2}. resonance = {:.4}", status, i + 1, artefact.resonance);
+        println!("  {} {:2}. resonance = {}", status, i + 1, resonance_str(artefact.resonance));
     }
 
     // Show families if any
@@ -309,8 +420,8 @@ fn run_genesis(args: GenesisArgs) {
     }
 
     if let Some(best) = &result.best_artefact {
-        println!("\n{}: resonance = {:.4}",
-            "Best artefact".green().bold(), best.resonance);
+        println!("\n{}: resonance = {}",
+            "Best artefact".green().bold(), resonance_str(best.resonance));
     }
 
     println!("\n{}: {:?} ({} ms)", "Elapsed time".dimmed(), elapsed, result.duration_ms);
@@ -391,7 +502,7 @@ fn run_holistic_mining(agents: usize, steps: usize, triton: bool, preset: &str,
 
     println!("\n{}", "Final Results:".green());
     println!("  Candidates discovered: {}", session.candidates().len());
-    println!("  Best resonance:        {:.4}", result.best_resonance);
+    println!("  Best resonance:        {}", resonance_str(result.best_resonance));
     println!("  Total steps:           {}", result.total_steps);
     println!("  Duration:              {:?}", elapsed);
     println!("  Stage:                 {:?}", session.stage());
@@ -400,8 +511,8 @@ fn run_holistic_mining(agents: usize, steps: usize, triton: bool, preset: &str,
     if !result.finalized_families.is_empty() {
         println!("\n{}", "Finalized Operator Families:".yellow());
         for (i, family) in result.finalized_families.iter().take(5).enumerate() {
-            println!("  {}. {} (members: {}, resonance: {:.4})",
-                i + 1, family.name.cyan(), family.member_count, family.avg_resonance);
+            println!("  {}. {} (members: {}, resonance: {})",
+                i + 1, family.name.cyan(), family.member_count, resonance_str(family.avg_resonance));
         }
     }
 
@@ -409,30 +520,40 @@ fn run_holistic_mining(agents: usize, steps: usize, triton: bool, preset: &str,
     if export {
         let export_path = format!("genesis_holistic_{}.json", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
         println!("\n{}: {}", "Exporting to".yellow(), export_path);
-        // Export logic would go here
+
+        let mut chain = qops_genesis::Cubechain::new();
+        for artefact in session.artefacts() {
+            if let Err(e) = chain.add_artefact(artefact.clone(), vec![]) {
+                eprintln!("{}: {}", "Failed to record artefact".red(), e);
+            }
+        }
+        match chain.save(&export_path) {
+            Ok(()) => println!("  {} blocks written", chain.len()),
+            Err(e) => eprintln!("{}: {}", "Export failed".red(), e),
+        }
     }
 }
 
 fn run_genesis_stage(stage: GenesisStageCommand) {
     match stage {
-        GenesisStageCommand::Kosmokrator { kappa, epsilon, export } => {
-            run_stage_kosmokrator(kappa, epsilon, export);
+        GenesisStageCommand::Kosmokrator { kappa, epsilon, export, seed } => {
+            run_stage_kosmokrator(kappa, epsilon, export, seed);
         }
-        GenesisStageCommand::Chronokrator { channels, threshold, visualize } => {
-            run_stage_chronokrator(channels, threshold, visualize);
+        GenesisStageCommand::Chronokrator { channels, threshold, visualize, seed } => {
+            run_stage_chronokrator(channels, threshold, visualize, seed);
         }
-        GenesisStageCommand::Pfauenthron { mandorla_threshold, ophanim } => {
-            run_stage_pfauenthron(mandorla_threshold, ophanim);
+        GenesisStageCommand::Pfauenthron { mandorla_threshold, ophanim, seed } => {
+            run_stage_pfauenthron(mandorla_threshold, ophanim, seed);
         }
     }
 }
 
-fn run_stage_kosmokrator(kappa: f64, epsilon: f64, export: bool) {
+fn run_stage_kosmokrator(kappa: f64, epsilon: f64, export: bool, seed: Option<u64>) {
     println!("\n{}", "Kosmokrator Filter Stage".magenta().bold());
     println!("{}", "Proof-of-Resonance Exclusion Axis".magenta());
     println!("{}\n", "=".repeat(50).dimmed());
 
-    use qops_core::{KosmokratorConfig, KosmokratorState, OperatorCandidate, Signature5D};
+    use qops_core::{KosmokratorConfig, KosmokratorState};
 
     let config = KosmokratorConfig {
         kappa_threshold: kappa,
@@ -452,19 +573,8 @@ fn run_stage_kosmokrator(kappa: f64, epsilon: f64, export: bool) {
 
     let pb = create_stage_spinner("Running Proof-of-Resonance...");
 
-    // Generate sample candidates
-    let candidates: Vec<OperatorCandidate> = (0..20).map(|i| {
-        OperatorCandidate {
-            id: format!("cand{}", i),
-            signature: Signature5D::default(),
-            phase: (i as f64 * 0.2).sin(),
-            resonance: 0.5 + 0.4 * ((i as f64 * 0.3).cos()),
-            stability: 0.7 + 0.2 * ((i as f64 * 0.1).sin()),
-            is_mandorla: i % 3 == 0,
-            node_index: i,
-            discovered_at: i as f64 * 0.05,
-        }
-    }).collect();
+    // Mine candidates from the Metatron topology
+    let candidates = mined_candidates(20, seed);
 
     // Get phases for PoR computation
     let phases: Vec<f64> = candidates.iter().map(|c| c.phase).collect();
@@ -484,15 +594,27 @@ fn run_stage_kosmokrator(kappa: f64, epsilon: f64, export: bool) {
 
     if export {
         println!("\n{}: kosmokrator_result.json", "Exporting".yellow());
+        let report = serde_json::json!({
+            "kappa_threshold": kappa,
+            "epsilon": epsilon,
+            "seed": seed,
+            "por": result,
+            "stats": state.stats(),
+            "survivors": survivors,
+        });
+        match write_json_report("kosmokrator_result.json", &report) {
+            Ok(()) => println!("  {} survivors written", survivors.len()),
+            Err(e) => eprintln!("{}: {}", "Export failed".red(), e),
+        }
     }
 }
 
-fn run_stage_chronokrator(channels: usize, threshold: f64, visualize: bool) {
+fn run_stage_chronokrator(channels: usize, threshold: f64, visualize: bool, seed: Option<u64>) {
     println!("\n{}", "Chronokrator Expansion Stage".cyan().bold());
     println!("{}", "Resonance Dynamics Expansion Axis".cyan());
     println!("{}\n", "=".repeat(50).dimmed());
 
-    use qops_core::{ChronokratorConfig, ChronokratorState, OperatorCandidate, Signature5D};
+    use qops_core::{ChronokratorConfig, ChronokratorState};
 
     let config = ChronokratorConfig {
         num_channels: channels,
@@ -511,19 +633,8 @@ fn run_stage_chronokrator(channels: usize, threshold: f64, visualize: bool) {
 
     let pb = create_stage_spinner("Running resonance expansion...");
 
-    // Create sample candidates for expansion
-    let candidates: Vec<OperatorCandidate> = (0..channels).map(|i| {
-        OperatorCandidate {
-            id: format!("op{}", i),
-            signature: Signature5D::default(),
-            phase: 0.5 + 0.1 * i as f64,
-            resonance: 0.7 + 0.05 * i as f64,
-            stability: 0.8,
-            is_mandorla: i % 2 == 0,
-            node_index: i,
-            discovered_at: i as f64 * 0.1,
-        }
-    }).collect();
+    // Mine candidates for expansion
+    let candidates = mined_candidates(channels, seed);
 
     // Run expansion
     let exkal = state.expand(&candidates, 1.0);
@@ -558,12 +669,12 @@ fn run_stage_chronokrator(channels: usize, threshold: f64, visualize: bool) {
     }
 }
 
-fn run_stage_pfauenthron(mandorla_threshold: f64, ophanim_count: usize) {
+fn run_stage_pfauenthron(mandorla_threshold: f64, ophanim_count: usize, seed: Option<u64>) {
     println!("\n{}", "Pfauenthron/Monolith Collapse Stage".yellow().bold());
     println!("{}", "O.P.H.A.N. Geometry / Mandorla Convergence".yellow());
     println!("{}\n", "=".repeat(50).dimmed());
 
-    use qops_core::{PfauenthronConfig, PfauenthronState, OperatorCandidate, Signature5D, ExkalibrationVector};
+    use qops_core::{PfauenthronConfig, PfauenthronState, ExkalibrationVector};
 
     let config = PfauenthronConfig {
         mandorla_threshold,
@@ -582,19 +693,8 @@ fn run_stage_pfauenthron(mandorla_threshold: f64, ophanim_count: usize) {
 
     let pb = create_stage_spinner("Computing Mandorla convergence...");
 
-    // Create sample candidates
-    let candidates = vec![
-        OperatorCandidate {
-            id: "op1".to_string(),
-            signature: Signature5D::default(),
-            phase: 0.5,
-            resonance: 0.85,
-            stability: 0.9,
-            is_mandorla: true,
-            node_index: 1,
-            discovered_at: 0.0,
-        },
-    ];
+    // Mine candidates
+    let candidates = mined_candidates(ophanim_count, seed);
 
     // Initialize Ophanim nodes
     state.init_ophanim(&candidates);
@@ -665,7 +765,7 @@ fn run_spiral_search(adaptive: bool, iterations: usize, export: bool) {
     println!("  Adaptive: {}", if adaptive { "Yes".green() } else { "No".dimmed() });
     println!();
 
-    if adaptive {
+    let trajectory = if adaptive {
         use qops_triton::{AdaptiveTritonConfig, AdaptiveTritonOptimizer};
 
         let adaptive_config = AdaptiveTritonConfig {
@@ -691,6 +791,7 @@ fn run_spiral_search(adaptive: bool, iterations: usize, export: bool) {
             println!("  Valid outputs: {}", holistic_out.valid_outputs);
             println!("  Stage: {:?}", holistic_out.current_stage);
         }
+        optimizer.trajectory().clone()
     } else {
         use qops_triton::TritonOptimizer;
 
@@ -706,47 +807,31 @@ fn run_spiral_search(adaptive: bool, iterations: usize, export: bool) {
         println!("  Best score:  {:.6}", result.best_score);
         println!("  Iterations:  {}", result.iterations);
         println!("  Converged:   {}", if result.converged { "Yes".green() } else { "No".red() });
-    }
+        optimizer.trajectory().clone()
+    };
 
     if export {
         println!("\n{}: triton_trajectory.json", "Exporting".yellow());
+        match trajectory.save("triton_trajectory.json") {
+            Ok(()) => println!("  {} points written", trajectory.points.len()),
+            Err(e) => eprintln!("{}: {}", "Export failed".red(), e),
+        }
     }
 }
 
-fn run_finalize_monolith(export: bool) {
+fn run_finalize_monolith(export: bool, seed: Option<u64>) {
     println!("\n{}", "Monolith Finalization".yellow().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
-    use qops_core::{HolisticConfig, HolisticMatrix, OperatorCandidate, Signature5D};
+    use qops_core::{HolisticConfig, HolisticMatrix};
 
     let config = HolisticConfig::default();
     let mut matrix = HolisticMatrix::new(config);
 
     let pb = create_stage_spinner("Finalizing Monolith structure...");
 
-    // Create sample candidates
-    let candidates = vec![
-        OperatorCandidate {
-            id: "op1".to_string(),
-            signature: Signature5D::default(),
-            phase: 0.5,
-            resonance: 0.85,
-            stability: 0.9,
-            is_mandorla: true,
-            node_index: 1,
-            discovered_at: 0.0,
-        },
-        OperatorCandidate {
-            id: "op2".to_string(),
-            signature: Signature5D::default(),
-            phase: 0.7,
-            resonance: 0.78,
-            stability: 0.85,
-            is_mandorla: false,
-            node_index: 2,
-            discovered_at: 0.1,
-        },
-    ];
+    // Mine candidates
+    let candidates = mined_candidates(8, seed);
     let output = matrix.process(candidates, 1.0);
 
     pb.finish_and_clear();
@@ -772,6 +857,15 @@ fn run_finalize_monolith(export: bool) {
         if export {
             let export_path = format!("monolith_{}.json", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
             println!("\n{}: {}", "Exporting Monolith report".yellow(), export_path);
+            let report = serde_json::json!({
+                "seed": seed,
+                "output": output,
+                "stats": stats,
+            });
+            match write_json_report(&export_path, &report) {
+                Ok(()) => println!("  {} valid outputs written", stats.valid_outputs),
+                Err(e) => eprintln!("{}: {}", "Export failed".red(), e),
+            }
         }
     } else {
         println!("{}", "No Monolith available. Run holistic mining first.".yellow());
@@ -780,6 +874,18 @@ fn run_finalize_monolith(export: bool) {
 
 // Helper functions for stage output
 
+/// Write a pretty-printed JSON report
+fn write_json_report(path: &str, report: &serde_json::Value) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(report)?;
+    std::fs::write(path, json)
+}
+
+/// Format a resonance value colored by the shared resonance color map
+fn resonance_str(value: f64) -> colored::ColoredString {
+    let [r, g, b] = qops_core::ResonanceColorMap::default().color_for(value);
+    format!("{:.4}", value).truecolor(r, g, b)
+}
+
 fn print_stage_header(name: &str, color: &str) {
     let header = match color {
         "blue" => format!(">>> {} <<<", name).blue().bold(),
@@ -792,6 +898,24 @@ fn print_stage_header(name: &str, color: &str) {
     println!("{}", "-".repeat(40).dimmed());
 }
 
+/// Draw candidates from agent traversal of the Metatron topology
+///
+/// With a seed, the cube and traversals repeat across runs.
+fn mined_candidates(n: usize, seed: Option<u64>) -> Vec<qops_core::OperatorCandidate> {
+    use qops_core::CandidateSource;
+    use qops_genesis::{AgentConfig, MetatronCandidateSource};
+
+    let config = AgentConfig {
+        max_steps: 20,
+        ..Default::default()
+    };
+    let mut source = match seed {
+        Some(seed) => MetatronCandidateSource::with_seed(config, seed),
+        None => MetatronCandidateSource::new(config),
+    };
+    source.next_batch(n)
+}
+
 fn create_stage_spinner(msg: &str) -> ProgressBar {
     let pb = ProgressBar::new_spinner();
     pb.set_style(ProgressStyle::default_spinner()
@@ -839,13 +963,63 @@ enum QuantumMode {
         #[arg(short, long, default_value_t = 3)]
         layers: usize,
     },
+    /// Eigenvalue spectrum of the Metatron Hamiltonian
+    Spectrum {
+        /// Also print the ground-state node distribution
+        #[arg(long)]
+        ground_state: bool,
+    },
 }
 
-fn run_quantum(args: QuantumArgs) {
+fn run_quantum(args: QuantumArgs, format: &OutputFormat) {
     match args.mode {
         QuantumMode::Walk { times } => run_quantum_walk(&times),
         QuantumMode::Vqe { layers } => run_legacy_vqe(layers),
         QuantumMode::Qaoa { layers } => run_legacy_qaoa(layers),
+        QuantumMode::Spectrum { ground_state } => run_quantum_spectrum(ground_state, format),
+    }
+}
+
+fn run_quantum_spectrum(ground_state: bool, format: &OutputFormat) {
+    use qops_quantum::{MetatronGraph, MetatronHamiltonian};
+
+    let graph = MetatronGraph::new();
+    let hamiltonian = MetatronHamiltonian::from_graph(&graph);
+    let eigenvalues = hamiltonian.eigenvalues();
+    let gap = hamiltonian.spectral_gap();
+    let distribution = ground_state.then(|| hamiltonian.ground_state_distribution());
+
+    if let OutputFormat::Json = format {
+        let mut data = serde_json::json!({
+            "dimension": hamiltonian.dimension(),
+            "eigenvalues": eigenvalues,
+            "spectral_gap": gap,
+        });
+        if let Some(dist) = &distribution {
+            data["ground_state_distribution"] = serde_json::json!(dist);
+        }
+        println!("{}", serde_json::to_string_pretty(&data).unwrap_or_default());
+        return;
+    }
+
+    println!("\n{}", "Metatron Hamiltonian Spectrum".cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+
+    println!("{}: {}", "Dimension".yellow(), hamiltonian.dimension());
+    println!("{}", "Eigenvalues (ascending):".green());
+    for (k, e) in eigenvalues.iter().enumerate() {
+        println!("  E{:<2} = {:>10.6}", k, e);
+    }
+    println!();
+    println!("{}: {:.6}", "Spectral gap (E1 - E0)".yellow(), gap);
+
+    if let Some(dist) = distribution {
+        println!();
+        println!("{}", "Ground-state node distribution:".green());
+        for (node, p) in dist.iter().enumerate() {
+            let bar = "#".repeat((p * 50.0).round() as usize);
+            println!("  node {:2}: {:.4} {}", node, p, bar.cyan());
+        }
     }
 }
 
@@ -942,6 +1116,10 @@ fn run_legacy_qaoa(layers: usize) {
 struct CircuitArgs {
     #[command(subcommand)]
     circuit_type: CircuitType,
+
+    /// Print an ASCII diagram of the circuit
+    #[arg(long, global = true)]
+    draw: bool,
 }
 
 #[derive(Subcommand)]
@@ -965,18 +1143,32 @@ enum CircuitType {
         #[arg(short, long, default_value_t = 5)]
         depth: usize,
     },
+    /// Load and simulate an OpenQASM 2.0 file
+    FromQasm {
+        /// Path to the .qasm file
+        file: String,
+    },
 }
 
 fn run_circuit(args: CircuitArgs) {
     match args.circuit_type {
-        CircuitType::Bell => run_bell_circuit(),
-        CircuitType::Ghz { qubits } => run_ghz_circuit(qubits),
-        CircuitType::Qft { qubits } => run_qft_circuit(qubits),
-        CircuitType::Random { qubits, depth } => run_random_circuit(qubits, depth),
+        CircuitType::Bell => run_bell_circuit(args.draw),
+        CircuitType::Ghz { qubits } => run_ghz_circuit(qubits, args.draw),
+        CircuitType::Qft { qubits } => run_qft_circuit(qubits, args.draw),
+        CircuitType::Random { qubits, depth } => run_random_circuit(qubits, depth, args.draw),
+        CircuitType::FromQasm { file } => run_qasm_circuit(&file, args.draw),
+    }
+}
+
+fn print_circuit_diagram(circuit: &qops_circuits::Circuit) {
+    println!("{}:", "Diagram".yellow());
+    for line in circuit.to_ascii_diagram().lines() {
+        println!("  {}", line);
     }
+    println!();
 }
 
-fn run_bell_circuit() {
+fn run_bell_circuit(draw: bool) {
     println!("\n{}", "Bell State Circuit".cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
@@ -990,6 +1182,10 @@ fn run_bell_circuit() {
     println!("{}: {}", "State".yellow(), reg);
     println!();
 
+    if draw {
+        print_circuit_diagram(&circuit);
+    }
+
     let stats = Measurement::measure_all(&reg, 1000);
     println!("{} (1000 shots):", "Measurement statistics".green());
     for (outcome, count) in &stats.counts {
@@ -1000,7 +1196,7 @@ fn run_bell_circuit() {
     }
 }
 
-fn run_ghz_circuit(qubits: usize) {
+fn run_ghz_circuit(qubits: usize, draw: bool) {
     println!("\n{}", format!("GHZ State ({} qubits)", qubits).cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
@@ -1014,6 +1210,10 @@ fn run_ghz_circuit(qubits: usize) {
     println!("{}: {}", "Gate count".yellow(), circuit.gate_count());
     println!();
 
+    if draw {
+        print_circuit_diagram(&circuit);
+    }
+
     let stats = Measurement::measure_all(&reg, 1000);
     println!("{} (1000 shots):", "Measurement statistics".green());
     let mut sorted: Vec<_> = stats.counts.iter().collect();
@@ -1023,7 +1223,7 @@ fn run_ghz_circuit(qubits: usize) {
     }
 }
 
-fn run_qft_circuit(qubits: usize) {
+fn run_qft_circuit(qubits: usize, draw: bool) {
     println!("\n{}", format!("Quantum Fourier Transform ({} qubits)", qubits).cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
@@ -1036,6 +1236,11 @@ fn run_qft_circuit(qubits: usize) {
     println!("{}: {}", "Circuit depth".yellow(), circuit.depth());
     println!("{}: {}", "Gate count".yellow(), circuit.gate_count());
 
+    if draw {
+        println!();
+        print_circuit_diagram(&circuit);
+    }
+
     let mut reg = QuantumRegister::new(qubits);
     reg.apply_single_gate(&Gate::x(), 0).unwrap();
     println!("\n{}: |1>", "Initial state".yellow());
@@ -1051,7 +1256,45 @@ fn run_qft_circuit(qubits: usize) {
     }
 }
 
-fn run_random_circuit(qubits: usize, depth: usize) {
+fn run_qasm_circuit(file: &str, draw: bool) {
+    use qops_circuits::{Circuit, QuantumRegister, Measurement};
+
+    let source = std::fs::read_to_string(file).unwrap_or_else(|e| {
+        eprintln!("{}: failed to read {}: {}", "Error".red(), file, e);
+        std::process::exit(2);
+    });
+    let circuit = Circuit::from_qasm(&source).unwrap_or_else(|e| {
+        eprintln!("{}: failed to parse {}: {}", "Error".red(), file, e);
+        std::process::exit(2);
+    });
+
+    println!("\n{}", format!("QASM Circuit ({})", file).cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+
+    println!("{}: {}", "Qubits".yellow(), circuit.num_qubits);
+    println!("{}: {}", "Classical bits".yellow(), circuit.num_classical_bits);
+    println!("{}: {}", "Circuit depth".yellow(), circuit.depth());
+    println!("{}: {}", "Total gates".yellow(), circuit.gate_count());
+    println!("{}: {}", "Measurements".yellow(), circuit.measurements.len());
+    println!();
+
+    if draw {
+        print_circuit_diagram(&circuit);
+    }
+
+    let mut reg = QuantumRegister::new(circuit.num_qubits);
+    reg.apply_circuit(&circuit).unwrap();
+
+    let stats = Measurement::measure_all(&reg, 1000);
+    println!("{} (1000 shots):", "Measurement statistics".green());
+    let mut sorted: Vec<_> = stats.counts.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1));
+    for (outcome, count) in sorted.iter().take(8) {
+        println!("  |{}>: {} ({:.1}%)", outcome, count, **count as f64 / 10.0);
+    }
+}
+
+fn run_random_circuit(qubits: usize, depth: usize, draw: bool) {
     println!("\n{}", format!("Random Circuit ({} qubits, depth {})", qubits, depth).cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
@@ -1085,6 +1328,10 @@ fn run_random_circuit(qubits: usize, depth: usize) {
     println!("{}: {}", "Total gates".yellow(), circuit.gate_count());
     println!();
 
+    if draw {
+        print_circuit_diagram(&circuit);
+    }
+
     let stats = Measurement::measure_all(&reg, 1000);
     println!("{}", "Measurement statistics:".green());
     let mut sorted: Vec<_> = stats.counts.iter().collect();
@@ -1119,6 +1366,9 @@ enum AlgorithmType {
     Shor {
         #[arg(short, long, default_value_t = 15)]
         number: u64,
+        /// Seed for reproducible base choices
+        #[arg(long)]
+        seed: Option<u64>,
     },
     /// Quantum Phase Estimation
     Qpe {
@@ -1146,7 +1396,7 @@ enum AlgorithmType {
 fn run_algorithm(args: AlgorithmArgs) {
     match args.algorithm {
         AlgorithmType::Grover { qubits, target, shots } => run_grover(qubits, target, shots),
-        AlgorithmType::Shor { number } => run_shor(number),
+        AlgorithmType::Shor { number, seed } => run_shor(number, seed),
         AlgorithmType::Qpe { qubits, phase } => run_qpe(qubits, phase),
         AlgorithmType::Vqe { qubits, layers } => run_vqe(qubits, layers),
         AlgorithmType::Qaoa { qubits, layers } => run_qaoa(qubits, layers),
@@ -1199,7 +1449,7 @@ fn run_grover(qubits: usize, target: usize, shots: usize) {
     }
 }
 
-fn run_shor(number: u64) {
+fn run_shor(number: u64, seed: Option<u64>) {
     println!("\n{}", "Shor's Factorization Algorithm".cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
 
@@ -1214,9 +1464,12 @@ fn run_shor(number: u64) {
         .unwrap());
     pb.set_message("Running factorization...");
 
-    let shor = Shor::new(number)
+    let mut shor = Shor::new(number)
         .with_method(FactorizationMethod::Simulated)
         .with_max_attempts(10);
+    if let Some(seed) = seed {
+        shor = shor.with_seed(seed);
+    }
 
     let result = shor.run();
     pb.finish_and_clear();
@@ -1236,6 +1489,16 @@ fn run_shor(number: u64) {
     } else {
         println!("  {} Factorization failed after {} attempts", "FAIL".red(), result.attempts);
     }
+
+    if !result.attempt_log.is_empty() {
+        println!("\n{}", "Attempts:".yellow());
+        for (i, attempt) in result.attempt_log.iter().enumerate() {
+            let period = attempt.period
+                .map(|r| r.to_string())
+                .unwrap_or_else(|| "-".to_string());
+            println!("  {:2}. a = {:<6} r = {:<6} {:?}", i + 1, attempt.base, period, attempt.outcome);
+        }
+    }
 }
 
 fn run_qpe(precision: usize, phase: f64) {
@@ -1478,10 +1741,36 @@ enum BenchmarkType {
     /// GUI latency benchmarks
     #[command(name = "gui-latency")]
     GuiLatency,
+    /// Simulation backend comparison benchmarks (time/memory per backend)
+    Backends,
     /// Run all benchmarks
     All,
     /// Run quick benchmark suite (for CI)
     Quick,
+    /// Run the comprehensive benchmark suites and write JSON results
+    Suite {
+        /// Use the quick (CI) suite with small configurations
+        #[arg(long)]
+        quick: bool,
+        /// Output directory for JSON results
+        #[arg(long, default_value = "bench_results")]
+        output_dir: String,
+        /// Only run these suites (comma-separated, e.g. vqe,qaoa)
+        #[arg(long, value_delimiter = ',')]
+        only: Option<Vec<String>>,
+    },
+    /// Compare two benchmark result files and fail on regressions
+    Diff {
+        /// Baseline benchmark JSON file
+        #[arg(long)]
+        baseline: String,
+        /// Candidate benchmark JSON file
+        #[arg(long)]
+        candidate: String,
+        /// Allowed slowdown before a benchmark counts as regressed (e.g. 10% or 0.1)
+        #[arg(long, default_value = "10%")]
+        threshold: String,
+    },
     /// Legacy Grover benchmark (deprecated)
     #[command(name = "grover-legacy")]
     GroverLegacy {
@@ -1522,8 +1811,11 @@ fn run_benchmark(args: BenchmarkArgs) {
         BenchmarkType::Mining => run_bench_mining(&runner),
         BenchmarkType::Topology => run_bench_topology(&runner),
         BenchmarkType::GuiLatency => run_bench_gui_latency(&runner),
+        BenchmarkType::Backends => run_bench_backends(&runner),
         BenchmarkType::All => run_bench_all(&runner),
         BenchmarkType::Quick => run_bench_quick(&runner),
+        BenchmarkType::Suite { quick, output_dir, only } => run_bench_suite(quick, &output_dir, only),
+        BenchmarkType::Diff { baseline, candidate, threshold } => run_bench_diff(&baseline, &candidate, &threshold),
         BenchmarkType::GroverLegacy { qubits } => benchmark_grover(&qubits),
         BenchmarkType::QftLegacy { qubits } => benchmark_qft(&qubits),
         BenchmarkType::SimulationLegacy { qubits } => benchmark_simulation(&qubits),
@@ -1762,6 +2054,35 @@ fn run_bench_gui_latency(runner: &qops_research::BenchmarkRunner) {
     }
 }
 
+fn run_bench_backends(runner: &qops_research::BenchmarkRunner) {
+    println!("\n{}", "Backend Comparison Benchmark Suite".cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+
+    let pb = create_stage_spinner("Running backend comparison benchmarks...");
+    match runner.run_backends() {
+        Ok(output) => {
+            pb.finish_and_clear();
+            println!("{}", "Results:".green().bold());
+            println!("  Benchmarks run: {}", output.summary.total_benchmarks);
+            println!("  Total duration: {:.2} ms", output.summary.total_duration_ms);
+            println!("\n{}", output.suite.comparison_table());
+
+            let mut skipped: Vec<_> = output.suite.metadata.iter()
+                .filter(|(k, _)| k.starts_with("skipped."))
+                .collect();
+            skipped.sort();
+            for (key, reason) in skipped {
+                println!("  {} {}: {}", "Skipped".yellow(), key.trim_start_matches("skipped."), reason);
+            }
+            println!("\n{}: {}/backend_comparison_bench.json", "Saved to".yellow(), runner.output_dir);
+        }
+        Err(e) => {
+            pb.finish_and_clear();
+            println!("{}: {}", "Error".red(), e);
+        }
+    }
+}
+
 fn run_bench_all(runner: &qops_research::BenchmarkRunner) {
     println!("\n{}", "Full Benchmark Suite".cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
@@ -1822,6 +2143,117 @@ fn run_bench_quick(runner: &qops_research::BenchmarkRunner) {
     }
 }
 
+fn run_bench_suite(quick: bool, output_dir: &str, only: Option<Vec<String>>) {
+    use qops_research::BenchmarkRunner;
+
+    println!("\n{}", "QOPS Benchmark Suite".cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+
+    let runner = BenchmarkRunner::new(output_dir, quick);
+    let pb = create_stage_spinner("Running benchmark suites...");
+    let result = match &only {
+        Some(names) => runner.run_selected(names),
+        None if quick => runner.run_quick(),
+        None => runner.run_all(),
+    };
+    pb.finish_and_clear();
+
+    let outputs = match result {
+        Ok(outputs) => outputs,
+        Err(e) => {
+            println!("{}: {}", "Error".red(), e);
+            return;
+        }
+    };
+
+    println!("{:<24} {:>10} {:>10} {:>14}", "Suite".bold(), "Benchmarks".bold(), "Success".bold(), "Duration (ms)".bold());
+    println!("{}", "-".repeat(61).dimmed());
+
+    let mut total_benchmarks = 0;
+    let mut total_duration = 0.0;
+    for output in &outputs {
+        let rate = format!("{:.1}%", output.summary.success_rate * 100.0);
+        let rate = if output.summary.success_rate >= 1.0 { rate.green() } else { rate.yellow() };
+        println!("{:<24} {:>10} {:>10} {:>14.2}",
+            output.benchmark_type.cyan(),
+            output.summary.total_benchmarks,
+            rate,
+            output.summary.total_duration_ms);
+        total_benchmarks += output.summary.total_benchmarks;
+        total_duration += output.summary.total_duration_ms;
+    }
+
+    println!("{}", "-".repeat(61).dimmed());
+    println!("{:<24} {:>10} {:>10} {:>14.2}", "Total".bold(), total_benchmarks, "", total_duration);
+    println!("\n{}: {}/", "Results saved to".yellow(), output_dir);
+}
+
+fn run_bench_diff(baseline: &str, candidate: &str, threshold: &str) {
+    use qops_research::{BenchmarkOutput, ChangeStatus};
+    use std::path::Path;
+
+    let threshold = match parse_threshold(threshold) {
+        Some(t) => t,
+        None => {
+            eprintln!("{}: invalid threshold '{}'", "Error".red(), threshold);
+            std::process::exit(2);
+        }
+    };
+    let load = |path: &str| {
+        BenchmarkOutput::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("{}: failed to load {}: {}", "Error".red(), path, e);
+            std::process::exit(2);
+        })
+    };
+    let baseline_output = load(baseline);
+    let candidate_output = load(candidate);
+    let diff = candidate_output.compare_to(&baseline_output, threshold);
+
+    println!("\n{}", "Benchmark Diff".cyan().bold());
+    println!("{}\n", "=".repeat(50).dimmed());
+    println!("  Baseline:  {}", baseline);
+    println!("  Candidate: {}", candidate);
+    println!("  Threshold: {:.1}%\n", threshold * 100.0);
+
+    let ms = |v: Option<f64>| v.map(|v| format!("{:.3}", v)).unwrap_or_else(|| "-".to_string());
+    println!("{:<30} {:>14} {:>14} {:>10} {:>10}",
+        "Benchmark".bold(), "Baseline (ms)".bold(), "Candidate (ms)".bold(), "Change".bold(), "Status".bold());
+    println!("{}", "-".repeat(82).dimmed());
+    for change in &diff.changes {
+        let pct = change.change
+            .map(|c| format!("{:+.1}%", c * 100.0))
+            .unwrap_or_else(|| "-".to_string());
+        let status = match change.status {
+            ChangeStatus::Improved => "improved".green(),
+            ChangeStatus::Unchanged => "ok".normal(),
+            ChangeStatus::Regressed => "REGRESSED".red().bold(),
+            ChangeStatus::Added => "added".cyan(),
+            ChangeStatus::Removed => "removed".yellow(),
+            ChangeStatus::Failed => "FAILED".red().bold(),
+        };
+        println!("{:<30} {:>14} {:>14} {:>10} {:>10}",
+            change.name, ms(change.baseline_ms), ms(change.candidate_ms), pct, status);
+    }
+
+    let regressions = diff.regressions().count();
+    if regressions > 0 {
+        println!("\n{}: {} benchmark(s) failed or regressed beyond {:.1}%",
+            "FAIL".red().bold(), regressions, threshold * 100.0);
+        std::process::exit(1);
+    }
+    println!("\n{}: no regressions", "OK".green().bold());
+}
+
+/// Parse a threshold given as a percentage (`10%`) or a fraction (`0.1`)
+fn parse_threshold(s: &str) -> Option<f64> {
+    let s = s.trim();
+    let value = match s.strip_suffix('%') {
+        Some(pct) => pct.trim().parse::<f64>().ok()? / 100.0,
+        None => s.parse::<f64>().ok()?,
+    };
+    (value.is_finite() && value >= 0.0).then_some(value)
+}
+
 fn benchmark_grover(qubits_str: &str) {
     println!("\n{}", "Grover Algorithm Benchmark".cyan().bold());
     println!("{}\n", "=".repeat(50).dimmed());
@@ -1965,10 +2397,10 @@ enum HypercubeMode {
     /// Execute HDAG pipeline
     #[command(name = "exec-hdag")]
     ExecHdag {
-        /// HDAG file (optional)
+        /// HDAG JSON graph file (optional, overrides --parallel)
         #[arg(long)]
         graph: Option<String>,
-        /// Use parallel branches
+        /// Use parallel branches and run independent nodes concurrently
         #[arg(long)]
         parallel: bool,
     },
@@ -2073,13 +2505,24 @@ fn run_hypercube(args: HypercubeArgs) {
             println!("  Max depth: {}", cube.stats.max_depth_reached);
         }
 
-        HypercubeMode::ExecHdag { graph: _, parallel } => {
+        HypercubeMode::ExecHdag { graph, parallel } => {
             println!("\n{}", "HDAG Execution".blue().bold());
             println!("{}\n", "=".repeat(50).dimmed());
 
             let seed = Coord5D::center();
 
-            let hdag = if parallel {
+            let mut hdag = if let Some(path) = graph {
+                match HDAG::from_json(&path) {
+                    Ok(hdag) => {
+                        println!("{}: {} ({})", "Graph".yellow(), hdag.name, path);
+                        hdag
+                    }
+                    Err(e) => {
+                        eprintln!("{}: {}", "Failed to load HDAG".red(), e);
+                        return;
+                    }
+                }
+            } else if parallel {
                 println!("{}: Parallel branches", "Mode".yellow());
                 HDAG::parallel_branches(seed)
             } else {
@@ -2091,9 +2534,25 @@ fn run_hypercube(args: HypercubeArgs) {
             println!("  Edges: {}", hdag.edge_count());
             println!();
 
+            // Resolve `operator_name` nodes against the same registry the compiler uses
+            let operators = HypercubeCompiler::new(CompilationConfig::default()).operators();
+            if let Err(e) = hdag.bind_operators(&operators) {
+                eprintln!("{}: {}", "Failed to bind HDAG operators".red(), e);
+                return;
+            }
+
             let pb = create_stage_spinner("Executing HDAG...");
-            let mut executor = HDAGExecutor::new(hdag);
-            let result = executor.execute().unwrap();
+            let run = |mut executor: HDAGExecutor| {
+                if parallel { executor.execute_parallel() } else { executor.execute() }
+            };
+            let result = match HDAGExecutor::new(hdag).and_then(run) {
+                Ok(result) => result,
+                Err(e) => {
+                    pb.finish_and_clear();
+                    eprintln!("{}: {}", "HDAG execution failed".red(), e);
+                    return;
+                }
+            };
             pb.finish_and_clear();
 
             println!("{}", "Execution Results:".green().bold());
@@ -2275,7 +2734,7 @@ fn run_slots(args: SlotsArgs) {
             println!("\n{}", "Top Sequences:".cyan());
             for (i, seq) in result.top_sequences.iter().take(5).enumerate() {
                 let symbols: String = seq.symbols.iter().take(5).map(|s| format!("{}", s)).collect();
-                println!("  {}. {} (R={:.4})", i + 1, symbols, seq.resonance);
+                println!("  {}. {} (R={})", i + 1, symbols, resonance_str(seq.resonance));
             }
         }
 
@@ -2464,7 +2923,7 @@ fn run_kernel(args: KernelArgs) {
                         println!("\n{}", "Top Candidates:".cyan());
                         for (i, candidate) in result.candidates.iter().take(5).enumerate() {
                             let sig = candidate.blueprint.signature();
-                            println!("  {}. {} (R={:.4})", i + 1, candidate.blueprint.name, candidate.resonance_score);
+                            println!("  {}. {} (R={})", i + 1, candidate.blueprint.name, resonance_str(candidate.resonance_score));
                             println!("     State: ({:.2}, {:.2}, {:.2}, {:.2}, {:.2})",
                                 sig.psi, sig.rho, sig.omega, sig.chi, sig.eta);
                         }
@@ -2729,7 +3188,7 @@ fn main() {
     match cli.command {
         Commands::Info => print_info(),
         Commands::Genesis(args) => run_genesis(args),
-        Commands::Quantum(args) => run_quantum(args),
+        Commands::Quantum(args) => run_quantum(args, &cli.format),
         Commands::Circuit(args) => run_circuit(args),
         Commands::Algorithm(args) => run_algorithm(args),
         Commands::Research(args) => run_research(args),
@@ -2738,5 +3197,7 @@ fn main() {
         Commands::Hypercube(args) => run_hypercube(args),
         Commands::Slots(args) => run_slots(args),
         Commands::Kernel(args) => run_kernel(args),
+        #[cfg(feature = "http")]
+        Commands::Serve(args) => run_serve(args),
     }
 }
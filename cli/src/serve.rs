@@ -0,0 +1,438 @@
+//! HTTP server mode (`qops serve`)
+//!
+//! Exposes the main pipelines as JSON endpoints so non-Rust tooling can drive
+//! QOPS without FFI or spawning the CLI per call. Bodies are the serde forms of
+//! the library types (`CoreSignature`, `MiningConfig`, `MiningResult`,
+//! `GroverResult`, `CompilationResult`, ...), so clients see the same shapes as
+//! any other serialized QOPS output.
+//!
+//! | Method | Path                  | Body                    | Response              |
+//! |--------|-----------------------|-------------------------|-----------------------|
+//! | GET    | `/health`             | –                       | `{"status","version"}`|
+//! | POST   | `/mining/kernel`      | [`KernelMiningRequest`] | `MiningResult`        |
+//! | POST   | `/circuit/simulate`   | [`SimulateRequest`]     | [`SimulateResponse`]  |
+//! | POST   | `/algorithm/grover`   | [`GroverRequest`]       | [`GroverResponse`]    |
+//! | POST   | `/hypercube/compile`  | [`CompileRequest`]      | `CompilationResult`   |
+//!
+//! Requests above the `MAX_*` limits below are rejected with `400 Bad Request`
+//! before any work is scheduled.
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use qops_algorithms::GroverResult;
+use qops_circuits::MeasurementStatistics;
+use qops_hypercube::CompilationResult;
+use qops_kernel::{CoreSignature, MiningConfig, MiningResult, SearchStrategy};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Limits
+// ============================================================================
+
+/// Largest register accepted by `/circuit/simulate`
+pub const MAX_SIMULATION_QUBITS: usize = 20;
+/// Largest register accepted by `/algorithm/grover`
+pub const MAX_GROVER_QUBITS: usize = 15;
+/// Largest gate list accepted by `/circuit/simulate`
+pub const MAX_GATES: usize = 10_000;
+/// Largest shot count for sampling endpoints
+pub const MAX_SHOTS: usize = 100_000;
+/// Largest `max_iterations` accepted by `/mining/kernel`
+pub const MAX_MINING_ITERATIONS: usize = 10_000;
+/// Largest candidate pool, beam width or population accepted by `/mining/kernel`
+pub const MAX_MINING_CANDIDATES: usize = 1_000;
+/// Largest expansion depth accepted by `/hypercube/compile`
+pub const MAX_COMPILE_ITERATIONS: usize = 20;
+
+// ============================================================================
+// Requests and responses
+// ============================================================================
+
+/// `POST /mining/kernel`
+#[derive(Debug, Clone, Deserialize)]
+pub struct KernelMiningRequest {
+    pub seed: CoreSignature,
+    #[serde(default)]
+    pub config: MiningConfig,
+}
+
+/// A gate in a `/circuit/simulate` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GateSpec {
+    pub gate_type: String,
+    pub qubits: Vec<usize>,
+    pub parameter: Option<f64>,
+}
+
+/// `POST /circuit/simulate`
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimulateRequest {
+    pub qubits: usize,
+    pub gates: Vec<GateSpec>,
+    #[serde(default = "default_shots")]
+    pub shots: usize,
+}
+
+/// Response of `/circuit/simulate`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulateResponse {
+    pub probabilities: Vec<f64>,
+    pub statistics: MeasurementStatistics,
+}
+
+/// `POST /algorithm/grover`
+#[derive(Debug, Clone, Deserialize)]
+pub struct GroverRequest {
+    pub qubits: usize,
+    pub target: usize,
+    #[serde(default = "default_shots")]
+    pub shots: usize,
+}
+
+/// Response of `/algorithm/grover`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroverResponse {
+    #[serde(flatten)]
+    pub result: GroverResult,
+    pub theoretical_probability: f64,
+}
+
+/// `POST /hypercube/compile`
+#[derive(Debug, Clone, Deserialize)]
+pub struct CompileRequest {
+    pub iterations: usize,
+    #[serde(default)]
+    pub use_triton: bool,
+}
+
+fn default_shots() -> usize {
+    1000
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Error returned to HTTP clients as `{"error": "..."}`
+pub struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: impl Into<String>) -> Self {
+        Self { status: StatusCode::BAD_REQUEST, message: message.into() }
+    }
+
+    fn internal(message: impl Into<String>) -> Self {
+        Self { status: StatusCode::INTERNAL_SERVER_ERROR, message: message.into() }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(serde_json::json!({ "error": self.message }))).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+/// Run CPU-bound pipeline work off the async executor
+async fn blocking<T, F>(f: F) -> ApiResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T, ApiError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| ApiError::internal(e.to_string()))?
+        .map(Json)
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+async fn health() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+/// Reject `value` above `max` with a 400
+fn check_limit(what: &str, value: usize, max: usize) -> Result<(), ApiError> {
+    if value > max {
+        return Err(ApiError::bad_request(format!("{} must be at most {} (got {})", what, max, value)));
+    }
+    Ok(())
+}
+
+fn check_mining_config(config: &MiningConfig) -> Result<(), ApiError> {
+    check_limit("max_iterations", config.max_iterations, MAX_MINING_ITERATIONS)?;
+    check_limit("max_candidates", config.max_candidates, MAX_MINING_CANDIDATES)?;
+    match config.strategy {
+        SearchStrategy::Beam { width } => check_limit("beam width", width, MAX_MINING_CANDIDATES),
+        SearchStrategy::Evolutionary { population_size, .. } =>
+            check_limit("population_size", population_size, MAX_MINING_CANDIDATES),
+        _ => Ok(()),
+    }
+}
+
+async fn kernel_mining(Json(req): Json<KernelMiningRequest>) -> ApiResult<MiningResult> {
+    use qops_kernel::{MiningKernel, State};
+
+    check_mining_config(&req.config)?;
+
+    blocking(move || {
+        let mut miner = MiningKernel::new(req.config);
+        miner.mine(&[State::Core(req.seed)]).map_err(|e| ApiError::internal(e.to_string()))
+    }).await
+}
+
+fn parse_gate(gate: &GateSpec) -> Result<qops_circuits::Gate, ApiError> {
+    use qops_circuits::Gate;
+    use std::f64::consts::FRAC_PI_2;
+
+    let angle = gate.parameter.unwrap_or(FRAC_PI_2);
+    Ok(match gate.gate_type.to_lowercase().as_str() {
+        "h" | "hadamard" => Gate::h(),
+        "x" | "paulix" => Gate::x(),
+        "y" | "pauliy" => Gate::y(),
+        "z" | "pauliz" => Gate::z(),
+        "s" | "phase" => Gate::s(),
+        "sdg" => Gate::sdg(),
+        "t" => Gate::t(),
+        "tdg" => Gate::tdg(),
+        "rx" => Gate::rx(angle),
+        "ry" => Gate::ry(angle),
+        "rz" => Gate::rz(angle),
+        "cnot" | "cx" => Gate::cnot(),
+        "cz" => Gate::cz(),
+        "cy" => Gate::cy(),
+        "swap" => Gate::swap(),
+        "iswap" => Gate::iswap(),
+        "toffoli" | "ccx" => Gate::toffoli(),
+        "fredkin" | "cswap" => Gate::fredkin(),
+        other => return Err(ApiError::bad_request(format!("Unknown gate type: {}", other))),
+    })
+}
+
+async fn simulate_circuit(Json(req): Json<SimulateRequest>) -> ApiResult<SimulateResponse> {
+    use qops_circuits::{Circuit, Measurement, QuantumRegister};
+
+    if req.qubits == 0 || req.qubits > MAX_SIMULATION_QUBITS {
+        return Err(ApiError::bad_request(format!(
+            "Qubit count must be between 1 and {}", MAX_SIMULATION_QUBITS
+        )));
+    }
+    check_limit("gate count", req.gates.len(), MAX_GATES)?;
+    check_limit("shots", req.shots, MAX_SHOTS)?;
+
+    blocking(move || {
+        let mut circuit = Circuit::new(req.qubits);
+        for gate in &req.gates {
+            circuit.add_gate(parse_gate(gate)?, gate.qubits.clone())
+                .map_err(|e| ApiError::bad_request(e.to_string()))?;
+        }
+
+        let mut register = QuantumRegister::new(req.qubits);
+        register.apply_circuit(&circuit).map_err(|e| ApiError::internal(e.to_string()))?;
+
+        Ok(SimulateResponse {
+            probabilities: register.state.probabilities(),
+            statistics: Measurement::measure_all(&register, req.shots),
+        })
+    }).await
+}
+
+async fn run_grover(Json(req): Json<GroverRequest>) -> ApiResult<GroverResponse> {
+    use qops_algorithms::{Grover, Oracle};
+
+    if req.qubits == 0 || req.qubits > MAX_GROVER_QUBITS {
+        return Err(ApiError::bad_request(format!(
+            "Qubit count must be between 1 and {}", MAX_GROVER_QUBITS
+        )));
+    }
+    if req.target >= (1 << req.qubits) {
+        return Err(ApiError::bad_request(format!(
+            "Target {} out of range for {} qubits", req.target, req.qubits
+        )));
+    }
+    check_limit("shots", req.shots, MAX_SHOTS)?;
+
+    blocking(move || {
+        let grover = Grover::new(req.qubits, Oracle::marked_state(req.qubits, req.target));
+        Ok(GroverResponse {
+            theoretical_probability: grover.theoretical_success_probability(),
+            result: grover.run_with_shots(req.shots),
+        })
+    }).await
+}
+
+async fn compile_hypercube(Json(req): Json<CompileRequest>) -> ApiResult<CompilationResult> {
+    use qops_hypercube::{CompilationConfig, CubeExpansionRule, Hypercube, HypercubeCompiler, HypercubeConfig};
+
+    check_limit("iterations", req.iterations, MAX_COMPILE_ITERATIONS)?;
+
+    blocking(move || {
+        let config = HypercubeConfig {
+            max_depth: req.iterations,
+            expansion_rule: if req.use_triton {
+                CubeExpansionRule::Triton
+            } else {
+                CubeExpansionRule::ResonanceGuided
+            },
+            ..Default::default()
+        };
+
+        let mut cube = Hypercube::new("http_cube", config);
+        for _ in 0..req.iterations {
+            cube.expand_step().map_err(|e| ApiError::internal(e.to_string()))?;
+        }
+
+        let mut compiler = HypercubeCompiler::new(CompilationConfig::default());
+        compiler.compile(&mut cube).map_err(|e| ApiError::internal(e.to_string()))
+    }).await
+}
+
+// ============================================================================
+// Server
+// ============================================================================
+
+/// Build the router with all endpoints
+pub fn router() -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/mining/kernel", post(kernel_mining))
+        .route("/circuit/simulate", post(simulate_circuit))
+        .route("/algorithm/grover", post(run_grover))
+        .route("/hypercube/compile", post(compile_hypercube))
+}
+
+/// Serve the API on `host:port` until the process is stopped
+pub fn serve(host: &str, port: u16) -> std::io::Result<()> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let listener = tokio::net::TcpListener::bind((host, port)).await?;
+        tracing::info!("QOPS HTTP server listening on {}", listener.local_addr()?);
+        axum::serve(listener, router()).await
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{to_bytes, Body};
+    use axum::http::Request;
+    use tower::ServiceExt;
+
+    async fn call(method: &str, uri: &str, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(if method == "GET" { Body::empty() } else { Body::from(body.to_string()) })
+            .unwrap();
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_health() {
+        let (status, body) = call("GET", "/health", serde_json::Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["status"], "ok");
+    }
+
+    #[tokio::test]
+    async fn test_kernel_mining() {
+        let (status, body) = call("POST", "/mining/kernel", serde_json::json!({
+            "seed": { "psi": 0.5, "rho": 0.5, "omega": 0.5, "chi": 0.5, "eta": 0.5 },
+            "config": { "max_iterations": 5, "strategy": "Greedy" },
+        })).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: MiningResult = serde_json::from_value(body).unwrap();
+        assert!(result.iterations <= 5);
+
+        let (status, _) = call("POST", "/mining/kernel", serde_json::json!({
+            "seed": { "psi": 0.5, "rho": 0.5, "omega": 0.5, "chi": 0.5, "eta": 0.5 },
+            "config": { "max_iterations": MAX_MINING_ITERATIONS + 1 },
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_simulate_circuit() {
+        let (status, body) = call("POST", "/circuit/simulate", serde_json::json!({
+            "qubits": 2,
+            "gates": [
+                { "gate_type": "h", "qubits": [0], "parameter": null },
+                { "gate_type": "cnot", "qubits": [0, 1], "parameter": null },
+            ],
+            "shots": 100,
+        })).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: SimulateResponse = serde_json::from_value(body).unwrap();
+        assert!((result.probabilities[0] - 0.5).abs() < 1e-10);
+        assert!((result.probabilities[3] - 0.5).abs() < 1e-10);
+        assert_eq!(result.statistics.counts.values().sum::<usize>(), 100);
+
+        let (status, _) = call("POST", "/circuit/simulate", serde_json::json!({
+            "qubits": MAX_SIMULATION_QUBITS + 1, "gates": [],
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = call("POST", "/circuit/simulate", serde_json::json!({
+            "qubits": 1, "gates": [], "shots": MAX_SHOTS + 1,
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = call("POST", "/circuit/simulate", serde_json::json!({
+            "qubits": 1, "gates": [{ "gate_type": "bogus", "qubits": [0], "parameter": null }],
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_grover() {
+        let (status, body) = call("POST", "/algorithm/grover", serde_json::json!({
+            "qubits": 3, "target": 5, "shots": 200,
+        })).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: GroverResponse = serde_json::from_value(body).unwrap();
+        assert_eq!(result.result.shots, 200);
+        assert!(result.theoretical_probability > 0.9);
+
+        let (status, _) = call("POST", "/algorithm/grover", serde_json::json!({
+            "qubits": 3, "target": 8,
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+
+        let (status, _) = call("POST", "/algorithm/grover", serde_json::json!({
+            "qubits": MAX_GROVER_QUBITS + 1, "target": 0,
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_compile_hypercube() {
+        let (status, body) = call("POST", "/hypercube/compile", serde_json::json!({
+            "iterations": 2,
+        })).await;
+        assert_eq!(status, StatusCode::OK);
+        let result: CompilationResult = serde_json::from_value(body).unwrap();
+        assert!(result.resonance.is_finite());
+
+        let (status, _) = call("POST", "/hypercube/compile", serde_json::json!({
+            "iterations": MAX_COMPILE_ITERATIONS + 1,
+        })).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -11,7 +11,8 @@
 //!
 //! Reference: Sebastian Klemm, "Holistische Resonanzarchitektur"
 
-use crate::Signature5D;
+use crate::{reservoir_sample, DistanceMetric, Signature5D};
+use num_complex::Complex64;
 use serde::{Deserialize, Serialize};
 use std::f64::consts::PI;
 
@@ -52,6 +53,12 @@ impl GenesisStage {
         }
     }
 
+    /// Stage color as RGB, parsed from [`GenesisStage::color`]
+    pub fn rgb(&self) -> [u8; 3] {
+        let hex = &self.color()[1..];
+        [0, 2, 4].map(|i| u8::from_str_radix(&hex[i..i + 2], 16).expect("stage colors are #rrggbb"))
+    }
+
     /// Get human-readable name
     pub fn name(&self) -> &'static str {
         match self {
@@ -110,6 +117,21 @@ pub struct ProofOfResonanceResult {
     pub timestamp: f64,
 }
 
+/// Phase coherence κ = |1/N Σ exp(iθⱼ)|
+///
+/// 1.0 when all phases coincide, near 0.0 when they are spread evenly.
+pub fn phase_coherence(phases: &[f64]) -> f64 {
+    if phases.is_empty() {
+        return 0.0;
+    }
+
+    let n = phases.len() as f64;
+    let sum_real: f64 = phases.iter().map(|&theta| theta.cos()).sum();
+    let sum_imag: f64 = phases.iter().map(|&theta| theta.sin()).sum();
+
+    ((sum_real / n).powi(2) + (sum_imag / n).powi(2)).sqrt()
+}
+
 /// Kosmokrator filter state
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KosmokratorState {
@@ -167,15 +189,7 @@ impl KosmokratorState {
     /// Compute coherence measure κ(t) using exp(iθ) averaging
     /// κ(t) = |1/N Σ exp(iθⱼ(t))|
     pub fn compute_kappa(&self, phases: &[f64]) -> f64 {
-        if phases.is_empty() {
-            return 0.0;
-        }
-
-        let n = phases.len() as f64;
-        let sum_real: f64 = phases.iter().map(|&theta| theta.cos()).sum();
-        let sum_imag: f64 = phases.iter().map(|&theta| theta.sin()).sum();
-
-        ((sum_real / n).powi(2) + (sum_imag / n).powi(2)).sqrt()
+        phase_coherence(phases)
     }
 
     /// Compute Proof-of-Resonance
@@ -313,6 +327,16 @@ pub struct ChronokratorConfig {
     pub omega_central: f64,
     /// Enable Exkalibration vector computation
     pub compute_exkalibration: bool,
+    /// Enable FFT-based spike detection over the D_total history
+    #[serde(default)]
+    pub spectral_detection: bool,
+    /// Minimum share of the non-DC spectral power a bin needs to count as a spike
+    #[serde(default = "default_spectral_power_threshold")]
+    pub spectral_power_threshold: f64,
+}
+
+fn default_spectral_power_threshold() -> f64 {
+    0.2
 }
 
 impl Default for ChronokratorConfig {
@@ -323,6 +347,8 @@ impl Default for ChronokratorConfig {
             threshold_adaptation: 0.1,
             omega_central: 1.0,
             compute_exkalibration: true,
+            spectral_detection: false,
+            spectral_power_threshold: default_spectral_power_threshold(),
         }
     }
 }
@@ -410,6 +436,19 @@ pub struct SpikeEvent {
     pub exkalibration: ExkalibrationVector,
 }
 
+/// A dominant frequency in the D_total history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpectralSpike {
+    /// Frequency in cycles per expansion step, in (0, 0.5]
+    pub frequency: f64,
+    /// Period in expansion steps (1 / frequency)
+    pub period: f64,
+    /// Amplitude of the oscillation at this frequency
+    pub magnitude: f64,
+    /// Share of the non-DC spectral power in this bin
+    pub power_fraction: f64,
+}
+
 impl ChronokratorState {
     /// Create new Chronokrator state
     pub fn new(config: ChronokratorConfig) -> Self {
@@ -556,6 +595,53 @@ impl ChronokratorState {
         None
     }
 
+    /// Detect periodic resonance in the D_total history
+    ///
+    /// Removes the mean, zero-pads to a power of two and takes the FFT. Every
+    /// local maximum of the power spectrum holding at least
+    /// `spectral_power_threshold` of the non-DC power is reported, strongest
+    /// first. Returns nothing unless `spectral_detection` is enabled and at
+    /// least 8 steps have been recorded.
+    pub fn detect_spikes_spectral(&self) -> Vec<SpectralSpike> {
+        let len = self.d_total_history.len();
+        if !self.config.spectral_detection || len < 8 {
+            return Vec::new();
+        }
+
+        let mean = self.d_total_history.iter().sum::<f64>() / len as f64;
+        let n = len.next_power_of_two();
+        let mut spectrum: Vec<Complex64> = self.d_total_history
+            .iter()
+            .map(|&d| Complex64::new(d - mean, 0.0))
+            .chain(std::iter::repeat(Complex64::new(0.0, 0.0)))
+            .take(n)
+            .collect();
+        fft(&mut spectrum);
+
+        let power: Vec<f64> = spectrum[..=n / 2].iter().map(|c| c.norm_sqr()).collect();
+        let total: f64 = power[1..].iter().sum();
+        if total <= f64::EPSILON {
+            return Vec::new();
+        }
+
+        let mut spikes: Vec<SpectralSpike> = (1..=n / 2)
+            .filter(|&k| power[k] >= power[k - 1] && power.get(k + 1).is_none_or(|&p| power[k] >= p))
+            .filter(|&k| power[k] / total >= self.config.spectral_power_threshold)
+            .map(|k| {
+                let frequency = k as f64 / n as f64;
+                let scale = if k == n / 2 { 1.0 } else { 2.0 };
+                SpectralSpike {
+                    frequency,
+                    period: 1.0 / frequency,
+                    magnitude: scale * spectrum[k].norm() / len as f64,
+                    power_fraction: power[k] / total,
+                }
+            })
+            .collect();
+        spikes.sort_by(|a, b| b.power_fraction.total_cmp(&a.power_fraction));
+        spikes
+    }
+
     /// Get expansion statistics
     pub fn stats(&self) -> ChronokratorStats {
         let avg_d_total = if !self.d_total_history.is_empty() {
@@ -575,6 +661,37 @@ impl ChronokratorState {
     }
 }
 
+/// In-place iterative radix-2 FFT; `data.len()` must be a power of two
+fn fft(data: &mut [Complex64]) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    if n <= 1 {
+        return;
+    }
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut size = 2;
+    while size <= n {
+        let step = Complex64::from_polar(1.0, -2.0 * PI / size as f64);
+        for start in (0..n).step_by(size) {
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..size / 2 {
+                let even = data[start + k];
+                let odd = w * data[start + k + size / 2];
+                data[start + k] = even + odd;
+                data[start + k + size / 2] = even - odd;
+                w *= step;
+            }
+        }
+        size *= 2;
+    }
+}
+
 /// Chronokrator statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChronokratorStats {
@@ -601,6 +718,19 @@ pub struct PfauenthronConfig {
     pub num_ophanim: usize,
     /// Enable Monolith emission
     pub emit_monolith: bool,
+    /// Metric used to group candidates into finalized families
+    #[serde(default)]
+    pub family_metric: DistanceMetric,
+    /// Dimension of the Mandorla perception and intention vectors
+    #[serde(default = "default_mandorla_dimension")]
+    pub mandorla_dimension: usize,
+    /// Distance below which a candidate joins a finalized family
+    #[serde(default = "default_family_threshold")]
+    pub family_threshold: f64,
+}
+
+fn default_family_threshold() -> f64 {
+    0.2
 }
 
 impl Default for PfauenthronConfig {
@@ -610,6 +740,9 @@ impl Default for PfauenthronConfig {
             convergence_epsilon: 0.01,
             num_ophanim: 4,
             emit_monolith: true,
+            family_metric: DistanceMetric::default(),
+            mandorla_dimension: MANDORLA_DIMENSION,
+            family_threshold: default_family_threshold(),
         }
     }
 }
@@ -645,13 +778,29 @@ impl Ophanim {
     }
 }
 
-/// Mandorla convergence field
+/// Default Mandorla field dimension, one component per signature axis
+pub const MANDORLA_DIMENSION: usize = 5;
+
+/// Resonance threshold above which a candidate counts as Mandorla
+pub const DEFAULT_MANDORLA_THRESHOLD: f64 = 0.85;
+
+fn default_mandorla_dimension() -> usize {
+    MANDORLA_DIMENSION
+}
+
+/// Mandorla convergence field over perception/intention storage `V`
+///
+/// The leading components of perception and intention are the signature axes
+/// ψ, ρ, ω, χ, η. Wider fields (such as the 16D M(t) of the Seraphic shell)
+/// zero-pad both vectors symmetrically, so the convergence score equals the
+/// 5D score for any dimension of at least five; narrower fields keep the
+/// leading axes.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MandorlaField {
+pub struct MandorlaFieldOf<V> {
     /// Perception vector P_Gabriel
-    pub perception: [f64; 5],
+    pub perception: V,
     /// Intention vector I_Oriphiel
-    pub intention: [f64; 5],
+    pub intention: V,
     /// Convergence score S_Mandorla(t) = P · I
     pub convergence_score: f64,
     /// Score derivative
@@ -660,6 +809,94 @@ pub struct MandorlaField {
     pub is_converged: bool,
 }
 
+/// Default 5D Mandorla field, one component per signature axis
+pub type MandorlaField = MandorlaFieldOf<[f64; MANDORLA_DIMENSION]>;
+
+/// Mandorla field of configurable dimension
+pub type DynMandorlaField = MandorlaFieldOf<Vec<f64>>;
+
+impl<V: AsRef<[f64]>> MandorlaFieldOf<V> {
+    /// Field dimension
+    pub fn dimension(&self) -> usize {
+        self.perception.as_ref().len()
+    }
+
+    /// Dot product P · I over all dimensions
+    pub fn dot(&self) -> f64 {
+        self.perception.as_ref().iter().zip(self.intention.as_ref()).map(|(p, i)| p * i).sum()
+    }
+}
+
+impl MandorlaField {
+    /// Unconverged field with `n`-dimensional zero vectors
+    pub fn with_dimension(n: usize) -> DynMandorlaField {
+        DynMandorlaField {
+            perception: vec![0.0; n],
+            intention: vec![0.0; n],
+            convergence_score: 0.0,
+            score_derivative: 0.0,
+            is_converged: false,
+        }
+    }
+}
+
+impl Default for MandorlaField {
+    fn default() -> Self {
+        Self {
+            perception: [0.0; MANDORLA_DIMENSION],
+            intention: [0.0; MANDORLA_DIMENSION],
+            convergence_score: 0.0,
+            score_derivative: 0.0,
+            is_converged: false,
+        }
+    }
+}
+
+impl Default for DynMandorlaField {
+    fn default() -> Self {
+        MandorlaField::with_dimension(MANDORLA_DIMENSION)
+    }
+}
+
+impl From<MandorlaField> for DynMandorlaField {
+    fn from(field: MandorlaField) -> Self {
+        Self {
+            perception: field.perception.to_vec(),
+            intention: field.intention.to_vec(),
+            convergence_score: field.convergence_score,
+            score_derivative: field.score_derivative,
+            is_converged: field.is_converged,
+        }
+    }
+}
+
+impl TryFrom<DynMandorlaField> for MandorlaField {
+    type Error = DynMandorlaField;
+
+    /// Succeeds for 5D fields; any other dimension is handed back unchanged
+    fn try_from(field: DynMandorlaField) -> std::result::Result<Self, Self::Error> {
+        match (<[f64; MANDORLA_DIMENSION]>::try_from(field.perception.as_slice()),
+               <[f64; MANDORLA_DIMENSION]>::try_from(field.intention.as_slice())) {
+            (Ok(perception), Ok(intention)) => Ok(Self {
+                perception,
+                intention,
+                convergence_score: field.convergence_score,
+                score_derivative: field.score_derivative,
+                is_converged: field.is_converged,
+            }),
+            _ => Err(field),
+        }
+    }
+}
+
+/// `axes` truncated or zero-padded to `n` components
+fn mandorla_components(axes: &[f64; 5], n: usize) -> Vec<f64> {
+    let mut components = vec![0.0; n];
+    let shared = n.min(axes.len());
+    components[..shared].copy_from_slice(&axes[..shared]);
+    components
+}
+
 /// Monolith action output
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Monolith {
@@ -687,7 +924,7 @@ pub struct PfauenthronState {
     /// Konus envelope Ω(t)
     pub konus_omega: f64,
     /// Mandorla field history
-    pub mandorla_history: Vec<MandorlaField>,
+    pub mandorla_history: Vec<DynMandorlaField>,
     /// Emitted Monoliths
     pub monoliths: Vec<Monolith>,
     /// Finalized operator families
@@ -705,6 +942,9 @@ pub struct FinalizedFamily {
     pub centroid: Signature5D,
     /// Member count
     pub member_count: usize,
+    /// IDs of the member candidates
+    #[serde(default)]
+    pub member_ids: Vec<String>,
     /// Average resonance
     pub avg_resonance: f64,
     /// Mandorla convergence score
@@ -749,32 +989,33 @@ impl PfauenthronState {
     }
 
     /// Compute Mandorla convergence S_Mandorla(t) = P_Gabriel · I_Oriphiel
-    pub fn compute_mandorla(&mut self, candidates: &[OperatorCandidate], exkal: &ExkalibrationVector, _t: f64) -> MandorlaField {
-        // Perception vector: average of candidate signatures
-        let mut perception = [0.0; 5];
+    ///
+    /// The field has `mandorla_dimension` components (see [`MandorlaFieldOf`]);
+    /// convert with `MandorlaField::try_from` where a 5D array is needed.
+    pub fn compute_mandorla(&mut self, candidates: &[OperatorCandidate], exkal: &ExkalibrationVector, _t: f64) -> DynMandorlaField {
+        let dimension = self.config.mandorla_dimension;
+        let mut field = MandorlaField::with_dimension(dimension);
+
+        // Perception vector: average of candidate signature components
         if !candidates.is_empty() {
             for c in candidates {
                 let sig = &c.signature;
-                perception[0] += sig.psi;
-                perception[1] += sig.rho;
-                perception[2] += sig.omega;
-                perception[3] += sig.chi;
-                perception[4] += sig.eta;
+                let axes = [sig.psi, sig.rho, sig.omega, sig.chi, sig.eta];
+                for (p, v) in field.perception.iter_mut().zip(mandorla_components(&axes, dimension)) {
+                    *p += v;
+                }
             }
             let n = candidates.len() as f64;
-            for p in &mut perception {
+            for p in &mut field.perception {
                 *p /= n;
             }
         }
 
-        // Intention vector: Exkalibration direction
-        let intention = exkal.direction;
+        // Intention vector: Exkalibration direction components
+        field.intention = mandorla_components(&exkal.direction, dimension);
 
         // Convergence score: dot product
-        let convergence_score: f64 = perception.iter()
-            .zip(intention.iter())
-            .map(|(p, i)| p * i)
-            .sum();
+        let convergence_score = field.dot();
 
         // Compute derivative
         let score_derivative = if let Some(last) = self.mandorla_history.last() {
@@ -787,13 +1028,9 @@ impl PfauenthronState {
         let is_converged = convergence_score.abs() >= self.config.mandorla_threshold
             && score_derivative <= self.config.convergence_epsilon;
 
-        let field = MandorlaField {
-            perception,
-            intention,
-            convergence_score,
-            score_derivative,
-            is_converged,
-        };
+        field.convergence_score = convergence_score;
+        field.score_derivative = score_derivative;
+        field.is_converged = is_converged;
 
         self.mandorla_history.push(field.clone());
         field
@@ -831,16 +1068,32 @@ impl PfauenthronState {
     }
 
     /// Finalize operator families
+    ///
+    /// Candidates are grouped in a canonical order (signature components,
+    /// then ID), so the same candidates yield the same families regardless
+    /// of the order they are passed in.
     pub fn finalize_families(&mut self, candidates: &[OperatorCandidate]) {
+        let mut sorted: Vec<&OperatorCandidate> = candidates.iter().collect();
+        sorted.sort_by(|a, b| {
+            a.signature
+                .to_vec()
+                .iter()
+                .zip(b.signature.to_vec().iter())
+                .map(|(x, y)| x.total_cmp(y))
+                .find(|o| o.is_ne())
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
         // Group candidates into families based on signature similarity
         let mut families: Vec<Vec<&OperatorCandidate>> = Vec::new();
-        let threshold = 0.2;
+        let threshold = self.config.family_threshold;
 
-        for c in candidates {
+        for c in sorted {
             let mut found = false;
             for family in &mut families {
                 if let Some(first) = family.first() {
-                    let dist = c.signature.distance(&first.signature);
+                    let dist = c.signature.distance_with(&first.signature, self.config.family_metric);
                     if dist < threshold {
                         family.push(c);
                         found = true;
@@ -860,16 +1113,9 @@ impl PfauenthronState {
                 continue;
             }
 
-            // Compute centroid
-            let mut centroid = Signature5D::zero();
+            let centroid = Signature5D::centroid_of(family.iter().map(|c| &c.signature))
+                .unwrap_or_default();
             let n = family.len() as f64;
-            for c in family {
-                centroid.psi += c.signature.psi / n;
-                centroid.rho += c.signature.rho / n;
-                centroid.omega += c.signature.omega / n;
-                centroid.chi += c.signature.chi / n;
-                centroid.eta += c.signature.eta / n;
-            }
 
             let avg_resonance: f64 = family.iter().map(|c| c.resonance).sum::<f64>() / n;
             let mandorla_score = self.mandorla_history.last()
@@ -881,6 +1127,7 @@ impl PfauenthronState {
                 name: format!("Family {}", i + 1),
                 centroid,
                 member_count: family.len(),
+                member_ids: family.iter().map(|c| c.id.clone()).collect(),
                 avg_resonance,
                 mandorla_score,
                 is_high_quality: centroid.psi >= 0.7,
@@ -929,6 +1176,20 @@ pub struct HolisticConfig {
     pub kosmokrator: KosmokratorConfig,
     pub chronokrator: ChronokratorConfig,
     pub pfauenthron: PfauenthronConfig,
+    /// Candidates buffered by [`HolisticMatrix::process_one`] before a flush
+    #[serde(default = "default_stream_window")]
+    pub stream_window: usize,
+    /// Largest batch passed to the stages; bigger batches are reservoir-sampled
+    /// by resonance down to this size (`None` keeps every candidate)
+    #[serde(default)]
+    pub max_candidates: Option<usize>,
+    /// Seed for reproducible candidate sampling
+    #[serde(default)]
+    pub sample_seed: Option<u64>,
+}
+
+fn default_stream_window() -> usize {
+    16
 }
 
 impl Default for HolisticConfig {
@@ -937,6 +1198,9 @@ impl Default for HolisticConfig {
             kosmokrator: KosmokratorConfig::default(),
             chronokrator: ChronokratorConfig::default(),
             pfauenthron: PfauenthronConfig::default(),
+            stream_window: default_stream_window(),
+            max_candidates: None,
+            sample_seed: None,
         }
     }
 }
@@ -968,6 +1232,9 @@ pub struct HolisticMatrix {
     pub pfauenthron: PfauenthronState,
     pub current_stage: GenesisStage,
     pub output_history: Vec<MatrixOutput>,
+    /// Streamed candidates waiting for the window to fill
+    #[serde(default)]
+    pending: Vec<OperatorCandidate>,
 }
 
 impl HolisticMatrix {
@@ -980,15 +1247,68 @@ impl HolisticMatrix {
             config,
             current_stage: GenesisStage::Discovery,
             output_history: Vec::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// Buffer a single candidate, running the pipeline once the window fills
+    ///
+    /// Returns the output of the flush this candidate triggered, if any.
+    pub fn process_one(&mut self, candidate: OperatorCandidate, t: f64) -> Option<MatrixOutput> {
+        self.pending.push(candidate);
+        if self.pending.len() >= self.config.stream_window.max(1) {
+            self.flush(t)
+        } else {
+            None
+        }
+    }
+
+    /// Run the buffered candidates through the pipeline now
+    ///
+    /// Returns `None` without touching the stages if nothing is buffered.
+    pub fn flush(&mut self, t: f64) -> Option<MatrixOutput> {
+        if self.pending.is_empty() {
+            return None;
         }
+        let batch = std::mem::take(&mut self.pending);
+        Some(self.process(batch, t))
+    }
+
+    /// Number of streamed candidates waiting for the next flush
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
     }
 
     /// Process candidates through the full pipeline
     /// M(t) = { E(t) if PoR(t) = true ∧ D_total(t) > Θ(t), ∅ otherwise }
+    ///
+    /// Batches larger than `max_candidates` are first cut down to a
+    /// resonance-weighted sample, which keeps lower-resonance candidates a
+    /// proportional chance instead of only the top ones.
     pub fn process(&mut self, candidates: Vec<OperatorCandidate>, t: f64) -> MatrixOutput {
+        let _span = tracing::debug_span!("holistic_matrix", t, candidates = candidates.len()).entered();
+
+        let candidates = match self.config.max_candidates {
+            Some(max) if candidates.len() > max => {
+                // A different seed per batch, so batches are sampled independently
+                let seed = self.config.sample_seed.map(|s| s.wrapping_add(self.output_history.len() as u64));
+                let sampled = reservoir_sample(candidates, max, seed);
+                tracing::debug!(retained = sampled.len(), "candidates sampled");
+                sampled
+            }
+            _ => candidates,
+        };
+
         // Stage 1: Kosmokrator Filter (Exclusion)
         self.current_stage = GenesisStage::KosmokratorFilter;
+        let stage_start = std::time::Instant::now();
         let survivors = self.kosmokrator.filter(candidates, t);
+        tracing::debug!(
+            stage = "kosmokrator",
+            survivors = survivors.len(),
+            elapsed_us = stage_start.elapsed().as_micros() as u64,
+            "stage complete"
+        );
 
         if survivors.is_empty() {
             return self.emit_empty(t);
@@ -996,7 +1316,14 @@ impl HolisticMatrix {
 
         // Stage 2: Chronokrator Expansion
         self.current_stage = GenesisStage::ChronokratorExpansion;
+        let stage_start = std::time::Instant::now();
         let exkal = self.chronokrator.expand(&survivors, t);
+        tracing::debug!(
+            stage = "chronokrator",
+            valid = exkal.as_ref().is_some_and(|e| e.valid),
+            elapsed_us = stage_start.elapsed().as_micros() as u64,
+            "stage complete"
+        );
 
         let exkal = match exkal {
             Some(e) if e.valid => e,
@@ -1005,11 +1332,19 @@ impl HolisticMatrix {
 
         // Stage 3: Pfauenthron Collapse
         self.current_stage = GenesisStage::PfauenthronCollapse;
+        let stage_start = std::time::Instant::now();
         let monolith = self.pfauenthron.collapse(&survivors, &exkal, t);
 
         // Finalize families
         self.pfauenthron.finalize_families(&survivors);
         self.current_stage = GenesisStage::Finalized;
+        tracing::debug!(
+            stage = "pfauenthron",
+            monolith = monolith.is_some(),
+            families = self.pfauenthron.finalized_families.len(),
+            elapsed_us = stage_start.elapsed().as_micros() as u64,
+            "stage complete"
+        );
 
         let output = MatrixOutput {
             valid: true,
@@ -1024,6 +1359,7 @@ impl HolisticMatrix {
     }
 
     fn emit_empty(&mut self, t: f64) -> MatrixOutput {
+        tracing::debug!(stage = ?self.current_stage, "no output emitted");
         let output = MatrixOutput {
             valid: false,
             exkalibration: None,
@@ -1059,6 +1395,7 @@ impl HolisticMatrix {
         self.pfauenthron = PfauenthronState::new(self.config.pfauenthron.clone());
         self.current_stage = GenesisStage::Discovery;
         self.output_history.clear();
+        self.pending.clear();
     }
 }
 
@@ -1073,6 +1410,33 @@ pub struct HolisticStats {
     pub total_outputs: usize,
 }
 
+// ============================================================================
+// CANDIDATE SOURCES
+// ============================================================================
+
+/// Source of operator candidates feeding the Discovery stage
+///
+/// Implementations wrap a real exploration engine (topology traversal,
+/// slot mining, spiral search) so the holistic pipeline consumes mined
+/// candidates instead of synthetic ones.
+pub trait CandidateSource {
+    /// Human-readable source name
+    fn name(&self) -> &str;
+
+    /// Produce the next batch of up to `n` candidates
+    fn next_batch(&mut self, n: usize) -> Vec<OperatorCandidate>;
+}
+
+impl<S: CandidateSource + ?Sized> CandidateSource for Box<S> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+
+    fn next_batch(&mut self, n: usize) -> Vec<OperatorCandidate> {
+        (**self).next_batch(n)
+    }
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -1135,6 +1499,43 @@ mod tests {
         assert!(d_total > 0.0);
     }
 
+    #[test]
+    fn test_spectral_spike_detection() {
+        let mut state = ChronokratorState::new(ChronokratorConfig::default());
+        // Period-8 oscillation of amplitude 0.2 on a constant level
+        state.d_total_history = (0..64)
+            .map(|i| 0.5 + 0.2 * (2.0 * PI * i as f64 / 8.0).sin())
+            .collect();
+        assert!(state.detect_spikes_spectral().is_empty());
+
+        state.config.spectral_detection = true;
+        let spikes = state.detect_spikes_spectral();
+        assert_eq!(spikes.len(), 1);
+        assert!((spikes[0].frequency - 0.125).abs() < 1e-12);
+        assert!((spikes[0].period - 8.0).abs() < 1e-9);
+        assert!((spikes[0].magnitude - 0.2).abs() < 1e-9);
+        assert!(spikes[0].power_fraction > 0.99);
+
+        // A flat history has no spectral content
+        state.d_total_history = vec![0.7; 32];
+        assert!(state.detect_spikes_spectral().is_empty());
+    }
+
+    #[test]
+    fn test_fft_matches_dft() {
+        let signal: Vec<Complex64> = (0..16).map(|i| Complex64::new((i as f64 * 0.7).sin(), 0.0)).collect();
+        let mut fast = signal.clone();
+        fft(&mut fast);
+        for (k, value) in fast.iter().enumerate() {
+            let direct: Complex64 = signal
+                .iter()
+                .enumerate()
+                .map(|(j, x)| x * Complex64::from_polar(1.0, -2.0 * PI * (j * k) as f64 / 16.0))
+                .sum();
+            assert!((value - direct).norm() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_pfauenthron_mandorla() {
         let mut state = PfauenthronState::new(PfauenthronConfig::default());
@@ -1153,6 +1554,94 @@ mod tests {
 
         let mandorla = state.compute_mandorla(&candidates, &exkal, 1.0);
         assert!(mandorla.convergence_score > 0.0);
+        assert_eq!(mandorla.dimension(), MANDORLA_DIMENSION);
+
+        let five: MandorlaField = mandorla.clone().try_into().unwrap();
+        assert_eq!(five.perception.to_vec(), mandorla.perception);
+        assert!((five.dot() - mandorla.convergence_score).abs() < 1e-12);
+
+        // A 16D field zero-pads both vectors and keeps the 5D score
+        let mut wide = PfauenthronState::new(PfauenthronConfig {
+            mandorla_dimension: 16,
+            ..Default::default()
+        });
+        let field = wide.compute_mandorla(&candidates, &exkal, 1.0);
+        assert_eq!(field.dimension(), 16);
+        assert_eq!(field.perception[..5], mandorla.perception[..]);
+        assert_eq!(field.intention[..5], exkal.direction[..]);
+        assert!(field.perception[5..].iter().chain(&field.intention[5..]).all(|&v| v == 0.0));
+        assert!((field.convergence_score - mandorla.convergence_score).abs() < 1e-12);
+        assert!(MandorlaField::try_from(field).is_err());
+
+        // A 3D field only sees ψ, ρ, ω
+        let mut narrow = PfauenthronState::new(PfauenthronConfig {
+            mandorla_dimension: 3,
+            ..Default::default()
+        });
+        let field = narrow.compute_mandorla(&candidates, &exkal, 1.0);
+        let expected: f64 = (0..3).map(|k| mandorla.perception[k] * exkal.direction[k]).sum();
+        assert!((field.convergence_score - expected).abs() < 1e-12);
+        assert_eq!(MandorlaField::with_dimension(16).dot(), 0.0);
+        assert_eq!(mandorla_components(&[1.0, 2.0, 3.0, 4.0, 5.0], 7), [1.0, 2.0, 3.0, 4.0, 5.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_pfauenthron_family_metric() {
+        // Two directions at two scales each
+        let mut candidates = Vec::new();
+        for (i, scale) in [0.3, 0.9].into_iter().enumerate() {
+            let mut a = create_test_candidate(&format!("a_{}", i), scale);
+            a.signature = Signature5D::new(scale, scale, 0.0, 0.0, 0.0);
+            let mut b = create_test_candidate(&format!("b_{}", i), scale);
+            b.signature = Signature5D::new(0.0, 0.0, scale, scale, 0.0);
+            candidates.extend([a, b]);
+        }
+
+        let mut euclidean = PfauenthronState::new(PfauenthronConfig::default());
+        euclidean.finalize_families(&candidates);
+        assert_eq!(euclidean.finalized_families.len(), 4);
+
+        let mut cosine = PfauenthronState::new(PfauenthronConfig {
+            family_metric: DistanceMetric::Cosine,
+            ..Default::default()
+        });
+        cosine.finalize_families(&candidates);
+        assert_eq!(cosine.finalized_families.len(), 2);
+        assert!(cosine.finalized_families.iter().all(|f| f.member_count == 2));
+    }
+
+    #[test]
+    fn test_pfauenthron_families_ignore_input_order() {
+        // A chain of points 0.15 apart: greedy grouping depends on where it starts
+        let candidates: Vec<_> = (0..8)
+            .map(|i| {
+                let mut c = create_test_candidate(&format!("op_{}", i), 0.8);
+                c.signature = Signature5D::new(0.15 * i as f64, 0.5, 0.5, 0.5, 0.5);
+                c
+            })
+            .collect();
+        let families = |input: &[OperatorCandidate]| {
+            let mut state = PfauenthronState::new(PfauenthronConfig::default());
+            state.finalize_families(input);
+            state
+                .finalized_families
+                .iter()
+                .map(|f| (f.member_count, f.centroid.to_vec()))
+                .collect::<Vec<_>>()
+        };
+
+        let expected = families(&candidates);
+        let mut reversed = candidates.clone();
+        reversed.reverse();
+        assert_eq!(families(&reversed), expected);
+        let mut rotated = candidates.clone();
+        rotated.rotate_left(3);
+        assert_eq!(families(&rotated), expected);
+
+        // The threshold comes from the config
+        let mut loose = PfauenthronState::new(PfauenthronConfig { family_threshold: 2.0, ..Default::default() });
+        loose.finalize_families(&candidates);
+        assert_eq!(loose.finalized_families.len(), 1);
     }
 
     #[test]
@@ -1179,4 +1668,50 @@ mod tests {
         // Output validity depends on convergence criteria
         assert!(matrix.stats().total_outputs == 1);
     }
+
+    #[test]
+    fn test_holistic_streaming() {
+        let mut matrix = HolisticMatrix::new(HolisticConfig {
+            stream_window: 4,
+            ..Default::default()
+        });
+
+        for i in 0..3 {
+            let output = matrix.process_one(create_test_candidate(&format!("op_{}", i), 0.85), i as f64);
+            assert!(output.is_none());
+        }
+        assert_eq!(matrix.pending_count(), 3);
+        assert_eq!(matrix.stats().total_outputs, 0);
+
+        // The fourth candidate fills the window
+        let output = matrix.process_one(create_test_candidate("op_3", 0.85), 3.0);
+        assert!(output.is_some());
+        assert_eq!(matrix.pending_count(), 0);
+        assert_eq!(matrix.kosmokrator.stats().total_processed, 4);
+
+        // Partial windows flush on demand; empty buffers do nothing
+        matrix.process_one(create_test_candidate("op_4", 0.85), 4.0);
+        assert!(matrix.flush(5.0).is_some());
+        assert!(matrix.flush(6.0).is_none());
+        assert_eq!(matrix.stats().total_outputs, 2);
+    }
+
+    #[test]
+    fn test_holistic_samples_large_batches() {
+        let run = || {
+            let mut matrix = HolisticMatrix::new(HolisticConfig {
+                max_candidates: Some(8),
+                sample_seed: Some(3),
+                ..Default::default()
+            });
+            let candidates: Vec<_> = (0..50)
+                .map(|i| create_test_candidate(&format!("op_{}", i), 0.5 + i as f64 / 100.0))
+                .collect();
+            matrix.process(candidates, 1.0);
+            matrix.kosmokrator.stats().total_processed
+        };
+
+        assert_eq!(run(), 8);
+        assert_eq!(run(), run());
+    }
 }
@@ -0,0 +1,229 @@
+//! Resonance landscape sampling.
+//!
+//! Characterizes a region of signature space before committing to a search:
+//! score range, number of local maxima and how rugged the landscape is.
+
+use crate::signature::Signature5D;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Number of nearest samples compared when detecting local maxima
+pub const LANDSCAPE_NEIGHBORS: usize = 6;
+
+/// Summary of a sampled resonance landscape
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LandscapeSummary {
+    /// Number of samples scored, including any with non-finite scores
+    pub samples: usize,
+    /// Lowest score
+    pub min: f64,
+    /// Highest score
+    pub max: f64,
+    /// Mean score
+    pub mean: f64,
+    /// Standard deviation of scores
+    pub std: f64,
+    /// Samples scoring strictly above all their nearest neighbors
+    pub local_maxima: usize,
+    /// Correlation between each sample's score and its nearest neighbor's
+    /// (1.0 = smooth, near 0 or below = rugged)
+    pub autocorrelation: f64,
+    /// Best sample found
+    pub best: Option<Signature5D>,
+}
+
+impl LandscapeSummary {
+    /// Ruggedness in [0, 2], `1 - autocorrelation`
+    pub fn ruggedness(&self) -> f64 {
+        1.0 - self.autocorrelation
+    }
+}
+
+/// Sample the landscape around `center` within `radius`
+///
+/// Points are drawn uniformly from the 5D ball and clamped to the unit cube.
+pub fn sample_landscape<F>(
+    center: &Signature5D,
+    radius: f64,
+    samples: usize,
+    scorer: F,
+) -> LandscapeSummary
+where
+    F: Fn(&Signature5D) -> f64,
+{
+    sample_landscape_with_rng(center, radius, samples, scorer, &mut rand::thread_rng())
+}
+
+/// [`sample_landscape`] with an explicit random source, for reproducible runs
+pub fn sample_landscape_with_rng<F, R>(
+    center: &Signature5D,
+    radius: f64,
+    samples: usize,
+    scorer: F,
+    rng: &mut R,
+) -> LandscapeSummary
+where
+    F: Fn(&Signature5D) -> f64,
+    R: Rng + ?Sized,
+{
+    // Non-finite scores are dropped up front so they cannot poison the moments,
+    // local maxima or autocorrelation
+    let (points, scores): (Vec<Signature5D>, Vec<f64>) = (0..samples)
+        .map(|_| random_in_ball(center, radius, rng))
+        .map(|p| (p, scorer(&p)))
+        .filter(|(_, s)| s.is_finite())
+        .unzip();
+
+    if scores.is_empty() {
+        return LandscapeSummary {
+            samples,
+            min: 0.0,
+            max: 0.0,
+            mean: 0.0,
+            std: 0.0,
+            local_maxima: 0,
+            autocorrelation: 1.0,
+            best: None,
+        };
+    }
+
+    let n = scores.len() as f64;
+    let min = scores.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let mean = scores.iter().sum::<f64>() / n;
+    let std = (scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+    let best = scores
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(i, _)| points[i]);
+
+    // Nearest neighbors of every sample, closest first
+    let k = LANDSCAPE_NEIGHBORS.min(points.len() - 1);
+    let neighbors: Vec<Vec<usize>> = (0..points.len())
+        .map(|i| {
+            let mut others: Vec<(usize, f64)> = (0..points.len())
+                .filter(|&j| j != i)
+                .map(|j| (j, points[i].distance(&points[j])))
+                .collect();
+            others.sort_by(|a, b| a.1.total_cmp(&b.1));
+            others.into_iter().take(k).map(|(j, _)| j).collect()
+        })
+        .collect();
+
+    let local_maxima = if k == 0 {
+        0
+    } else {
+        (0..points.len())
+            .filter(|&i| neighbors[i].iter().all(|&j| scores[i] > scores[j]))
+            .count()
+    };
+
+    let pairs: Vec<(f64, f64)> = neighbors
+        .iter()
+        .enumerate()
+        .filter_map(|(i, nn)| nn.first().map(|&j| (scores[i], scores[j])))
+        .collect();
+
+    LandscapeSummary {
+        samples,
+        min,
+        max,
+        mean,
+        std,
+        local_maxima,
+        autocorrelation: correlation(&pairs),
+        best,
+    }
+}
+
+/// Uniform point in the 5D ball via rejection sampling
+fn random_in_ball<R: Rng + ?Sized>(center: &Signature5D, radius: f64, rng: &mut R) -> Signature5D {
+    let offset = loop {
+        let v: [f64; 5] = std::array::from_fn(|_| rng.gen_range(-1.0..=1.0));
+        if v.iter().map(|x| x * x).sum::<f64>() <= 1.0 {
+            break v;
+        }
+    };
+    let c = center.to_vec();
+    Signature5D::new(
+        c[0] + radius * offset[0],
+        c[1] + radius * offset[1],
+        c[2] + radius * offset[2],
+        c[3] + radius * offset[3],
+        c[4] + radius * offset[4],
+    )
+}
+
+/// Pearson correlation; a flat landscape counts as perfectly smooth
+fn correlation(pairs: &[(f64, f64)]) -> f64 {
+    if pairs.len() < 2 {
+        return 1.0;
+    }
+    let n = pairs.len() as f64;
+    let mean_a = pairs.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_b = pairs.iter().map(|p| p.1).sum::<f64>() / n;
+    let cov: f64 = pairs.iter().map(|p| (p.0 - mean_a) * (p.1 - mean_b)).sum();
+    let var_a: f64 = pairs.iter().map(|p| (p.0 - mean_a).powi(2)).sum();
+    let var_b: f64 = pairs.iter().map(|p| (p.1 - mean_b).powi(2)).sum();
+
+    if var_a < 1e-12 || var_b < 1e-12 {
+        return 1.0;
+    }
+    cov / (var_a * var_b).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resonance::resonance_5d;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_smooth_landscape() {
+        let center = Signature5D::default();
+        let mut rng = StdRng::seed_from_u64(7);
+        let summary = sample_landscape_with_rng(&center, 0.2, 200, resonance_5d, &mut rng);
+
+        assert_eq!(summary.samples, 200);
+        assert!(summary.min <= summary.mean && summary.mean <= summary.max);
+        assert!(summary.autocorrelation > 0.5);
+        assert!(summary.best.is_some());
+    }
+
+    #[test]
+    fn test_rugged_landscape_scores_higher() {
+        let center = Signature5D::default();
+        let rugged = |s: &Signature5D| (s.psi * 400.0).sin() * (s.rho * 370.0).cos();
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let smooth = sample_landscape_with_rng(&center, 0.3, 200, resonance_5d, &mut rng);
+        let mut rng = StdRng::seed_from_u64(1);
+        let noisy = sample_landscape_with_rng(&center, 0.3, 200, rugged, &mut rng);
+
+        assert!(noisy.ruggedness() > smooth.ruggedness());
+        assert!(noisy.local_maxima > smooth.local_maxima);
+    }
+
+    #[test]
+    fn test_nan_scores() {
+        let holes = |s: &Signature5D| if s.psi > 0.5 { f64::NAN } else { s.psi };
+        let mut rng = StdRng::seed_from_u64(3);
+        let summary = sample_landscape_with_rng(&Signature5D::default(), 0.3, 100, holes, &mut rng);
+
+        assert_eq!(summary.samples, 100);
+        assert!(summary.mean.is_finite() && summary.std.is_finite());
+        assert!(summary.autocorrelation.is_finite());
+        assert!(summary.max <= 0.5);
+        assert!(summary.best.unwrap().psi <= 0.5);
+    }
+
+    #[test]
+    fn test_empty_sample() {
+        let summary = sample_landscape(&Signature5D::default(), 0.1, 0, resonance_5d);
+        assert_eq!(summary.samples, 0);
+        assert_eq!(summary.local_maxima, 0);
+        assert!(summary.best.is_none());
+    }
+}
@@ -5,22 +5,41 @@
 //! - Proof-of-Resonance verification
 //! - Query by signature properties
 //! - Replay capability
+//! - Optional SQLite persistence (`sqlite` feature)
 
 use crate::signature::Signature;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::borrow::Cow;
 use uuid::Uuid;
 
+/// Hash scheme of entries written before [`LEDGER_HASH_VERSION`]
+///
+/// SHA-256 over the concatenated id, previous hash, timestamp (seconds),
+/// resonance, type and payload. The signature is not covered.
+pub const LEGACY_HASH_VERSION: u32 = 1;
+
+/// Hash scheme used for new entries, see [`LedgerEntry::compute_hash`]
+pub const LEDGER_HASH_VERSION: u32 = 2;
+
+fn legacy_hash_version() -> u32 {
+    LEGACY_HASH_VERSION
+}
+
 /// Entry in the resonance ledger
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LedgerEntry {
     /// Unique identifier
     pub id: Uuid,
     /// Hash of previous entry (for chaining)
+    #[serde(alias = "hash_prev")]
     pub prev_hash: String,
     /// Entry's own hash
     pub hash: String,
+    /// Scheme `hash` was computed with; entries without one predate versioning
+    #[serde(default = "legacy_hash_version")]
+    pub hash_version: u32,
     /// Timestamp
     pub timestamp: DateTime<Utc>,
     /// Signature at this entry
@@ -51,6 +70,7 @@ impl LedgerEntry {
             id,
             prev_hash: prev_hash.to_string(),
             hash: String::new(),
+            hash_version: LEDGER_HASH_VERSION,
             timestamp,
             signature,
             resonance,
@@ -63,8 +83,35 @@ impl LedgerEntry {
         entry
     }
 
-    /// Compute the hash of this entry
+    /// Compute the hash of this entry with its `hash_version` scheme
+    ///
+    /// The current scheme is SHA-256 over a JSON encoding of [`HashedFields`],
+    /// so the signature, resonance and previous hash (plus identity, time,
+    /// type and payload) are all covered. Entries with
+    /// [`LEGACY_HASH_VERSION`] are hashed the way they were written. The
+    /// proof is attached later and is not hashed.
     pub fn compute_hash(&self) -> String {
+        if self.hash_version == LEGACY_HASH_VERSION {
+            return self.compute_legacy_hash();
+        }
+
+        let fields = HashedFields {
+            prev_hash: &self.prev_hash,
+            signature: &self.signature,
+            resonance: self.resonance,
+            id: &self.id,
+            timestamp: self.timestamp.timestamp_micros(),
+            entry_type: &self.entry_type,
+            payload: &self.payload,
+        };
+        let data = serde_json::to_vec(&fields).expect("hashed fields serialize");
+
+        let mut hasher = Sha256::new();
+        hasher.update(&data);
+        format!("{:x}", hasher.finalize())
+    }
+
+    fn compute_legacy_hash(&self) -> String {
         let data = format!(
             "{}{}{}{}{}{}",
             self.id,
@@ -74,15 +121,15 @@ impl LedgerEntry {
             self.entry_type,
             self.payload
         );
-
-        let mut hasher = Sha256::new();
-        hasher.update(data.as_bytes());
-        format!("{:x}", hasher.finalize())
+        format!("{:x}", Sha256::digest(data.as_bytes()))
     }
 
     /// Verify the entry's hash
+    ///
+    /// Entries claiming a hash version newer than this build knows fail.
     pub fn verify_hash(&self) -> bool {
-        self.hash == self.compute_hash()
+        (LEGACY_HASH_VERSION..=LEDGER_HASH_VERSION).contains(&self.hash_version)
+            && self.hash == self.compute_hash()
     }
 
     /// Add proof-of-resonance
@@ -91,6 +138,20 @@ impl LedgerEntry {
     }
 }
 
+/// Entry fields covered by [`LedgerEntry::compute_hash`]
+///
+/// Field order is part of the hash; do not reorder.
+#[derive(Serialize)]
+struct HashedFields<'a> {
+    prev_hash: &'a str,
+    signature: &'a Signature,
+    resonance: f64,
+    id: &'a Uuid,
+    timestamp: i64,
+    entry_type: &'a str,
+    payload: &'a str,
+}
+
 /// Proof-of-Resonance data
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProofOfResonance {
@@ -151,18 +212,21 @@ impl ProofOfResonance {
 }
 
 /// Trait for resonance ledger implementations
+///
+/// Lookups return [`Cow`] so in-memory ledgers can hand out borrowed entries
+/// while persistent ledgers return entries loaded on demand.
 pub trait ResonanceLedger: Send + Sync {
     /// Append an entry to the ledger
     fn append(&mut self, entry: LedgerEntry) -> crate::error::Result<String>;
 
     /// Get an entry by hash
-    fn get(&self, hash: &str) -> Option<&LedgerEntry>;
+    fn get(&self, hash: &str) -> Option<Cow<'_, LedgerEntry>>;
 
     /// Get the latest entry
-    fn latest(&self) -> Option<&LedgerEntry>;
+    fn latest(&self) -> Option<Cow<'_, LedgerEntry>>;
 
     /// Get the genesis (first) entry
-    fn genesis(&self) -> Option<&LedgerEntry>;
+    fn genesis(&self) -> Option<Cow<'_, LedgerEntry>>;
 
     /// Get total number of entries
     fn len(&self) -> usize;
@@ -173,13 +237,17 @@ pub trait ResonanceLedger: Send + Sync {
     }
 
     /// Verify chain integrity
-    fn verify_chain(&self) -> bool;
+    ///
+    /// Recomputes every entry hash and checks each entry links to its
+    /// predecessor. Returns the index of the first entry that fails either
+    /// check.
+    fn verify_chain(&self) -> std::result::Result<(), usize>;
 
     /// Query entries by minimum resonance
-    fn query_by_resonance(&self, min_resonance: f64) -> Vec<&LedgerEntry>;
+    fn query_by_resonance(&self, min_resonance: f64) -> Vec<Cow<'_, LedgerEntry>>;
 
     /// Query entries by type
-    fn query_by_type(&self, entry_type: &str) -> Vec<&LedgerEntry>;
+    fn query_by_type(&self, entry_type: &str) -> Vec<Cow<'_, LedgerEntry>>;
 }
 
 /// In-memory implementation of ResonanceLedger
@@ -260,60 +328,273 @@ impl ResonanceLedger for MemoryLedger {
         Ok(hash)
     }
 
-    fn get(&self, hash: &str) -> Option<&LedgerEntry> {
-        self.index.get(hash).map(|&idx| &self.entries[idx])
+    fn get(&self, hash: &str) -> Option<Cow<'_, LedgerEntry>> {
+        self.index.get(hash).map(|&idx| Cow::Borrowed(&self.entries[idx]))
     }
 
-    fn latest(&self) -> Option<&LedgerEntry> {
-        self.entries.last()
+    fn latest(&self) -> Option<Cow<'_, LedgerEntry>> {
+        self.entries.last().map(Cow::Borrowed)
     }
 
-    fn genesis(&self) -> Option<&LedgerEntry> {
-        self.entries.first()
+    fn genesis(&self) -> Option<Cow<'_, LedgerEntry>> {
+        self.entries.first().map(Cow::Borrowed)
     }
 
     fn len(&self) -> usize {
         self.entries.len()
     }
 
-    fn verify_chain(&self) -> bool {
-        if self.entries.is_empty() {
-            return true;
-        }
-
-        for i in 1..self.entries.len() {
-            let current = &self.entries[i];
-            let previous = &self.entries[i - 1];
-
-            // Verify hash
-            if !current.verify_hash() {
-                return false;
-            }
-
-            // Verify linkage
-            if current.prev_hash != previous.hash {
-                return false;
+    fn verify_chain(&self) -> std::result::Result<(), usize> {
+        for (i, entry) in self.entries.iter().enumerate() {
+            let linked = i == 0 || entry.prev_hash == self.entries[i - 1].hash;
+            if !entry.verify_hash() || !linked {
+                return Err(i);
             }
         }
-
-        true
+        Ok(())
     }
 
-    fn query_by_resonance(&self, min_resonance: f64) -> Vec<&LedgerEntry> {
+    fn query_by_resonance(&self, min_resonance: f64) -> Vec<Cow<'_, LedgerEntry>> {
         self.entries
             .iter()
             .filter(|e| e.resonance >= min_resonance)
+            .map(Cow::Borrowed)
             .collect()
     }
 
-    fn query_by_type(&self, entry_type: &str) -> Vec<&LedgerEntry> {
+    fn query_by_type(&self, entry_type: &str) -> Vec<Cow<'_, LedgerEntry>> {
         self.entries
             .iter()
             .filter(|e| e.entry_type == entry_type)
+            .map(Cow::Borrowed)
             .collect()
     }
 }
 
+// ============================================================================
+// SQLITE LEDGER
+// ============================================================================
+
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteLedger;
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::{LedgerEntry, ResonanceLedger};
+    use crate::error::{QopsError, Result};
+    use chrono::{DateTime, Utc};
+    use rusqlite::{params, Connection, OptionalExtension, Params};
+    use std::borrow::Cow;
+    use std::path::Path;
+    use std::sync::{Mutex, MutexGuard};
+
+    const SCHEMA: &str = "
+        CREATE TABLE IF NOT EXISTS ledger_entries (
+            seq        INTEGER PRIMARY KEY AUTOINCREMENT,
+            hash       TEXT NOT NULL UNIQUE,
+            prev_hash  TEXT NOT NULL,
+            timestamp  INTEGER NOT NULL,
+            resonance  REAL NOT NULL,
+            entry_type TEXT NOT NULL,
+            entry      TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS idx_ledger_resonance ON ledger_entries (resonance);
+        CREATE INDEX IF NOT EXISTS idx_ledger_timestamp ON ledger_entries (timestamp);
+        CREATE INDEX IF NOT EXISTS idx_ledger_type ON ledger_entries (entry_type);
+    ";
+
+    /// SQLite-backed implementation of ResonanceLedger
+    ///
+    /// Each entry is stored as JSON next to indexed resonance, timestamp
+    /// (microseconds since the epoch) and type columns, so queries only load
+    /// the matching rows. Lookups through [`ResonanceLedger`] return owned
+    /// entries; storage errors make them return `None` or an empty list.
+    pub struct SqliteLedger {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteLedger {
+        /// Open or create a ledger database at `path`
+        pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+            Self::from_connection(Connection::open(path).map_err(storage)?)
+        }
+
+        /// Create a ledger in a private in-memory database
+        pub fn open_in_memory() -> Result<Self> {
+            Self::from_connection(Connection::open_in_memory().map_err(storage)?)
+        }
+
+        fn from_connection(conn: Connection) -> Result<Self> {
+            conn.execute_batch(SCHEMA).map_err(storage)?;
+            Ok(Self { conn: Mutex::new(conn) })
+        }
+
+        fn conn(&self) -> Result<MutexGuard<'_, Connection>> {
+            self.conn.lock().map_err(|_| QopsError::ledger("SQLite connection lock poisoned"))
+        }
+
+        /// Entries with resonance at or above `threshold`, in append order
+        pub fn query_above(&self, threshold: f64) -> Result<Vec<LedgerEntry>> {
+            self.load("WHERE resonance >= ?1", params![threshold])
+        }
+
+        /// Entries with `from <= timestamp < to`, in append order
+        pub fn range(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<LedgerEntry>> {
+            self.load(
+                "WHERE timestamp >= ?1 AND timestamp < ?2",
+                params![from.timestamp_micros(), to.timestamp_micros()],
+            )
+        }
+
+        /// Entries of the given type, in append order
+        pub fn query_type(&self, entry_type: &str) -> Result<Vec<LedgerEntry>> {
+            self.load("WHERE entry_type = ?1", params![entry_type])
+        }
+
+        /// Number of stored entries
+        pub fn count(&self) -> Result<usize> {
+            let conn = self.conn()?;
+            let count: i64 = conn
+                .query_row("SELECT COUNT(*) FROM ledger_entries", [], |row| row.get(0))
+                .map_err(storage)?;
+            Ok(count as usize)
+        }
+
+        fn load(&self, clause: &str, params: impl Params) -> Result<Vec<LedgerEntry>> {
+            let conn = self.conn()?;
+            let sql = format!("SELECT entry FROM ledger_entries {} ORDER BY seq", clause);
+            let mut stmt = conn.prepare(&sql).map_err(storage)?;
+            let rows = stmt.query_map(params, |row| row.get::<_, String>(0)).map_err(storage)?;
+            rows.map(|json| decode(&json.map_err(storage)?)).collect()
+        }
+
+        fn load_one(&self, sql: &str, params: impl Params) -> Result<Option<LedgerEntry>> {
+            let conn = self.conn()?;
+            let json: Option<String> = conn
+                .query_row(sql, params, |row| row.get(0))
+                .optional()
+                .map_err(storage)?;
+            json.map(|j| decode(&j)).transpose()
+        }
+
+        fn latest_hash(conn: &Connection) -> Result<Option<String>> {
+            conn.query_row(
+                "SELECT hash FROM ledger_entries ORDER BY seq DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(storage)
+        }
+    }
+
+    impl ResonanceLedger for SqliteLedger {
+        fn append(&mut self, entry: LedgerEntry) -> Result<String> {
+            if !entry.verify_hash() {
+                return Err(QopsError::Validation("Invalid entry hash".to_string()));
+            }
+
+            let conn = self.conn()?;
+            if let Some(latest) = Self::latest_hash(&conn)? {
+                if entry.prev_hash != latest {
+                    return Err(QopsError::Validation("Invalid chain linkage".to_string()));
+                }
+            }
+
+            let json = serde_json::to_string(&entry)
+                .map_err(|e| QopsError::Serialization(e.to_string()))?;
+            conn.execute(
+                "INSERT INTO ledger_entries (hash, prev_hash, timestamp, resonance, entry_type, entry)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    entry.hash,
+                    entry.prev_hash,
+                    entry.timestamp.timestamp_micros(),
+                    entry.resonance,
+                    entry.entry_type,
+                    json
+                ],
+            )
+            .map_err(storage)?;
+
+            Ok(entry.hash)
+        }
+
+        fn get(&self, hash: &str) -> Option<Cow<'_, LedgerEntry>> {
+            self.load_one("SELECT entry FROM ledger_entries WHERE hash = ?1", params![hash])
+                .ok()
+                .flatten()
+                .map(Cow::Owned)
+        }
+
+        fn latest(&self) -> Option<Cow<'_, LedgerEntry>> {
+            self.load_one("SELECT entry FROM ledger_entries ORDER BY seq DESC LIMIT 1", [])
+                .ok()
+                .flatten()
+                .map(Cow::Owned)
+        }
+
+        fn genesis(&self) -> Option<Cow<'_, LedgerEntry>> {
+            self.load_one("SELECT entry FROM ledger_entries ORDER BY seq LIMIT 1", [])
+                .ok()
+                .flatten()
+                .map(Cow::Owned)
+        }
+
+        fn len(&self) -> usize {
+            self.count().unwrap_or(0)
+        }
+
+        fn verify_chain(&self) -> std::result::Result<(), usize> {
+            // Stream rows so the chain is checked without loading it whole;
+            // unreadable rows count as tampered
+            let conn = self.conn().map_err(|_| 0usize)?;
+            let mut stmt = conn
+                .prepare("SELECT entry FROM ledger_entries ORDER BY seq")
+                .map_err(|_| 0usize)?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(|_| 0usize)?;
+
+            let mut prev_hash: Option<String> = None;
+            for (i, json) in rows.enumerate() {
+                let entry = json.ok().and_then(|j| decode(&j).ok()).ok_or(i)?;
+                let linked = prev_hash.is_none_or(|prev| entry.prev_hash == prev);
+                if !entry.verify_hash() || !linked {
+                    return Err(i);
+                }
+                prev_hash = Some(entry.hash);
+            }
+
+            Ok(())
+        }
+
+        fn query_by_resonance(&self, min_resonance: f64) -> Vec<Cow<'_, LedgerEntry>> {
+            self.query_above(min_resonance)
+                .unwrap_or_default()
+                .into_iter()
+                .map(Cow::Owned)
+                .collect()
+        }
+
+        fn query_by_type(&self, entry_type: &str) -> Vec<Cow<'_, LedgerEntry>> {
+            self.query_type(entry_type)
+                .unwrap_or_default()
+                .into_iter()
+                .map(Cow::Owned)
+                .collect()
+        }
+    }
+
+    fn storage(e: rusqlite::Error) -> QopsError {
+        QopsError::ledger(e.to_string())
+    }
+
+    fn decode(json: &str) -> Result<LedgerEntry> {
+        serde_json::from_str(json).map_err(|e| QopsError::Serialization(e.to_string()))
+    }
+}
+
 /// Ledger statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LedgerStats {
@@ -399,7 +680,7 @@ mod tests {
         let mut ledger = MemoryLedger::with_genesis(sig);
 
         assert_eq!(ledger.len(), 1);
-        assert!(ledger.verify_chain());
+        assert_eq!(ledger.verify_chain(), Ok(()));
 
         // Add another entry
         let prev_hash = ledger.latest().unwrap().hash.clone();
@@ -408,7 +689,84 @@ mod tests {
         ledger.append(entry2).unwrap();
 
         assert_eq!(ledger.len(), 2);
-        assert!(ledger.verify_chain());
+        assert_eq!(ledger.verify_chain(), Ok(()));
+    }
+
+    #[test]
+    fn test_legacy_entries_verify() {
+        // Written before hash versioning: no `hash_version`, old hash scheme
+        let json = r#"[
+            {
+                "id": "3f2b8c1e-9a4d-4e6f-8b2a-1c5d7e9f0a12",
+                "prev_hash": "0000000000000000000000000000000000000000000000000000000000000000",
+                "hash": "4c57cf903e27b66da9d64979c93f48381f2e0c1587c71f576f5ce9dfb2393a1b",
+                "timestamp": "2024-05-01T12:00:00Z",
+                "signature": { "D3": { "psi": 0.8, "rho": 0.7, "omega": 0.6 } },
+                "resonance": 0.7,
+                "entry_type": "genesis",
+                "payload": "{}",
+                "proof": null
+            },
+            {
+                "id": "3f2b8c1e-9a4d-4e6f-8b2a-1c5d7e9f0a13",
+                "prev_hash": "4c57cf903e27b66da9d64979c93f48381f2e0c1587c71f576f5ce9dfb2393a1b",
+                "hash": "b673918d4eae0e8be19c7e7e8da96ccb4f26a1fac52e47566cde9bda421603f7",
+                "timestamp": "2024-05-01T12:01:00Z",
+                "signature": { "D3": { "psi": 0.9, "rho": 0.8, "omega": 0.7 } },
+                "resonance": 0.8,
+                "entry_type": "operator",
+                "payload": "{\"step\":1}",
+                "proof": null
+            }
+        ]"#;
+        let mut ledger = MemoryLedger::from_json(json).unwrap();
+        assert!(ledger.entries().iter().all(|e| e.hash_version == LEGACY_HASH_VERSION));
+        assert_eq!(ledger.verify_chain(), Ok(()));
+
+        // New entries chain onto legacy ones with the current scheme
+        let prev = ledger.latest().unwrap().hash.clone();
+        let entry = LedgerEntry::new(&prev, Signature::D3(Signature3D::new(0.9, 0.9, 0.9)), "operator", "{}");
+        assert_eq!(entry.hash_version, LEDGER_HASH_VERSION);
+        ledger.append(entry).unwrap();
+        assert_eq!(ledger.verify_chain(), Ok(()));
+
+        // A legacy entry with edited payload still fails
+        let mut tampered = MemoryLedger::from_json(json).unwrap();
+        tampered.entries[1].payload = "{}".to_string();
+        assert_eq!(tampered.verify_chain(), Err(1));
+    }
+
+    #[test]
+    fn test_verify_chain_finds_tampering() {
+        let mut ledger = MemoryLedger::with_genesis(Signature::D3(Signature3D::new(0.5, 0.5, 0.5)));
+        for i in 0..4 {
+            let prev = ledger.latest().unwrap().hash.clone();
+            let level = 0.6 + 0.05 * i as f64;
+            let sig = Signature::D3(Signature3D::new(level, level, level));
+            ledger.append(LedgerEntry::new(&prev, sig, "operator", "{}")).unwrap();
+        }
+        assert_eq!(ledger.verify_chain(), Ok(()));
+
+        // Editing a signature without rehashing breaks that entry
+        let mut tampered = ledger.clone();
+        tampered.entries[2].signature = Signature::D3(Signature3D::new(1.0, 1.0, 1.0));
+        assert_eq!(tampered.verify_chain(), Err(2));
+
+        // Rehashing the edit breaks the link from the next entry instead
+        let mut tampered = ledger.clone();
+        tampered.entries[3].resonance = 0.99;
+        tampered.entries[3].hash = tampered.entries[3].compute_hash();
+        assert_eq!(tampered.verify_chain(), Err(4));
+
+        // The link also deserializes under the name `hash_prev`
+        let json = ledger.to_json().replace("\"prev_hash\"", "\"hash_prev\"");
+        assert_eq!(MemoryLedger::from_json(&json).unwrap().verify_chain(), Ok(()));
+
+        // Hash versions this build does not know never verify
+        let mut tampered = ledger.clone();
+        tampered.entries[1].hash_version = LEDGER_HASH_VERSION + 1;
+        tampered.entries[1].hash = tampered.entries[1].compute_hash();
+        assert_eq!(tampered.verify_chain(), Err(1));
     }
 
     #[test]
@@ -428,4 +786,52 @@ mod tests {
         let high_res = ledger.query_by_resonance(0.8);
         assert!(!high_res.is_empty());
     }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_ledger() {
+        use chrono::TimeZone;
+
+        let path = std::env::temp_dir().join(format!("qops_ledger_{}.db", Uuid::new_v4()));
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        {
+            let mut ledger = SqliteLedger::open(&path).unwrap();
+            let mut prev = "0".to_string();
+            for i in 0..10 {
+                let level = 0.1 * i as f64;
+                let sig = Signature::D3(Signature3D::new(level, level, level));
+                let mut entry = LedgerEntry::new(&prev, sig, "operator", "{}");
+                entry.timestamp = start + chrono::Duration::minutes(i);
+                entry.hash = entry.compute_hash();
+                prev = ledger.append(entry).unwrap();
+            }
+
+            // Wrong linkage is rejected
+            let sig = Signature::D3(Signature3D::new(0.5, 0.5, 0.5));
+            assert!(ledger.append(LedgerEntry::new("0", sig, "operator", "{}")).is_err());
+        }
+
+        // Reopening keeps the chain
+        let ledger = SqliteLedger::open(&path).unwrap();
+        assert_eq!(ledger.len(), 10);
+        assert_eq!(ledger.verify_chain(), Ok(()));
+        assert_eq!(ledger.genesis().unwrap().prev_hash, "0");
+        let latest = ledger.latest().unwrap();
+        assert_eq!(ledger.get(&latest.hash).unwrap().id, latest.id);
+
+        let above = ledger.query_above(0.75).unwrap();
+        assert_eq!(above.len(), 2);
+        assert!(above.iter().all(|e| e.resonance >= 0.75));
+        assert_eq!(ledger.query_by_resonance(0.75).len(), 2);
+
+        let window = ledger
+            .range(start + chrono::Duration::minutes(3), start + chrono::Duration::minutes(6))
+            .unwrap();
+        assert_eq!(window.len(), 3);
+        assert_eq!(window[0].timestamp, start + chrono::Duration::minutes(3));
+        assert_eq!(ledger.query_by_type("operator").len(), 10);
+
+        drop(ledger);
+        std::fs::remove_file(&path).ok();
+    }
 }
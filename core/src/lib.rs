@@ -37,30 +37,46 @@ pub mod ledger;
 pub mod field;
 pub mod error;
 pub mod holistic;
+pub mod viz;
+pub mod landscape;
+pub mod reservoir;
+pub mod stats;
+pub mod persist;
 
 // Re-exports
-pub use signature::{Signature, Signature3D, Signature5D};
-pub use resonance::{resonance, resonance_5d, resonance_3d, resonance_gradient, validate_invariant, ResonanceConfig};
+pub use signature::{DistanceMetric, Signature, Signature3D, Signature5D};
+pub use resonance::{resonance, resonance_5d, resonance_5d_weighted, resonance_3d, resonance_gradient, validate_invariant, ResonanceConfig, ResonanceWeights};
 pub use topology::{ResonanceTopology, NodeSignature};
 pub use operators::{CalibrationOperator, Configuration, DoubleKickOperator, UpdateKick, StabilizationKick};
 pub use pipeline::{GenerativePipeline, PipelineConfig, PipelineState, SimplePipeline};
-pub use ledger::{ResonanceLedger, LedgerEntry, ProofOfResonance, MemoryLedger};
+pub use ledger::{
+    ResonanceLedger, LedgerEntry, ProofOfResonance, MemoryLedger, LEDGER_HASH_VERSION, LEGACY_HASH_VERSION,
+};
+#[cfg(feature = "sqlite")]
+pub use ledger::SqliteLedger;
 pub use field::{MandorlaField, FieldVector, ResonanceAttractor};
 pub use error::{QopsError, Result};
+pub use viz::ResonanceColorMap;
+pub use landscape::{sample_landscape, sample_landscape_with_rng, LandscapeSummary};
+pub use reservoir::{reservoir_sample, ReservoirSampler, Weighted};
 pub use holistic::{
     // Stages
     GenesisStage,
     // Kosmokrator
     KosmokratorConfig, KosmokratorState, KosmokratorStats,
-    ProofOfResonanceResult, OperatorCandidate,
+    ProofOfResonanceResult, OperatorCandidate, phase_coherence,
     // Chronokrator
     ChronokratorConfig, ChronokratorState, ChronokratorStats,
-    ResonanceChannel, ExkalibrationVector, SpikeEvent,
+    ResonanceChannel, ExkalibrationVector, SpikeEvent, SpectralSpike,
     // Pfauenthron
     PfauenthronConfig, PfauenthronState, PfauenthronStats,
-    Ophanim, MandorlaField as HolisticMandorlaField, Monolith, FinalizedFamily,
+    Ophanim, MandorlaField as HolisticMandorlaField, MandorlaFieldOf, DynMandorlaField, MANDORLA_DIMENSION,
+    DEFAULT_MANDORLA_THRESHOLD,
+    Monolith, FinalizedFamily,
     // Matrix
     HolisticConfig, HolisticMatrix, HolisticStats, MatrixOutput,
+    // Candidate sources
+    CandidateSource,
 };
 
 /// QOPS version
@@ -0,0 +1,48 @@
+//! JSON file persistence helpers.
+//!
+//! Snapshots and checkpoints across crates save themselves as pretty-printed
+//! JSON; these helpers hold the shared read/write path.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::io;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+/// Write `value` to `path` as pretty-printed JSON
+pub fn save_json<T: Serialize + ?Sized>(value: &T, path: impl AsRef<Path>) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(value)?;
+    std::fs::write(path, json)
+}
+
+/// Read a value written by [`save_json`]
+///
+/// Malformed JSON fails with [`io::ErrorKind::InvalidData`].
+pub fn load_json<T: DeserializeOwned>(path: impl AsRef<Path>) -> io::Result<T> {
+    let json = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// Fresh path for a scratch JSON file in the system temp directory
+pub fn temp_json_path(prefix: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{}_{}.json", prefix, Uuid::new_v4()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let path = temp_json_path("qops_persist");
+        save_json(&vec![1.5, -2.0], &path).unwrap();
+        let loaded: Vec<f64> = load_json(&path).unwrap();
+        assert_eq!(loaded, vec![1.5, -2.0]);
+
+        std::fs::write(&path, "{").unwrap();
+        let err = load_json::<Vec<f64>>(&path).unwrap_err();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(load_json::<Vec<f64>>(&path).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+//! Weighted reservoir sampling.
+//!
+//! Keeps a bounded, resonance-weighted sample of an unbounded candidate
+//! stream using the A-Res algorithm (Efraimidis & Spirakis): every item gets
+//! the key `u^(1/w)` for uniform `u`, and the `capacity` largest keys are kept.
+//! Unlike top-K truncation, lower-weight items keep a proportional chance of
+//! being retained, so the sample stays diverse.
+//!
+//! [`HolisticMatrix`](crate::HolisticMatrix) samples oversized batches with
+//! it (`HolisticConfig::max_candidates`).
+
+use crate::holistic::OperatorCandidate;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+/// An item with a sampling weight
+pub trait Weighted {
+    /// Sampling weight; items with a non-positive or non-finite weight are
+    /// never retained
+    fn weight(&self) -> f64;
+}
+
+impl Weighted for OperatorCandidate {
+    fn weight(&self) -> f64 {
+        self.resonance
+    }
+}
+
+/// Reservoir entry ordered by its A-Res key
+#[derive(Debug, Clone)]
+struct Keyed<T> {
+    /// `ln(u) / w`, a monotone transform of `u^(1/w)` that avoids underflow
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key.total_cmp(&other.key) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.total_cmp(&other.key)
+    }
+}
+
+/// Bounded weighted sample of a stream
+///
+/// ```
+/// use qops_core::{ReservoirSampler, Weighted};
+///
+/// struct Score(f64);
+/// impl Weighted for Score {
+///     fn weight(&self) -> f64 { self.0 }
+/// }
+///
+/// let mut sampler = ReservoirSampler::new(10).with_seed(42);
+/// for i in 0..1000 {
+///     sampler.offer(Score(i as f64 / 1000.0));
+/// }
+/// assert_eq!(sampler.len(), 10);
+/// assert_eq!(sampler.seen(), 1000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReservoirSampler<T> {
+    capacity: usize,
+    /// Min-heap on key, so the weakest retained item is evicted first
+    heap: BinaryHeap<Reverse<Keyed<T>>>,
+    seen: usize,
+    rng: StdRng,
+}
+
+impl<T: Weighted> ReservoirSampler<T> {
+    /// Create a sampler retaining at most `capacity` items
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            heap: BinaryHeap::with_capacity(capacity),
+            seen: 0,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Use a seeded random source, for reproducible samples
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    /// Offer an item; returns whether it is currently retained
+    pub fn offer(&mut self, item: T) -> bool {
+        self.seen += 1;
+
+        let w = item.weight();
+        if self.capacity == 0 || !w.is_finite() || w <= 0.0 {
+            return false;
+        }
+
+        let u: f64 = self.rng.gen_range(f64::EPSILON..1.0);
+        let entry = Keyed { key: u.ln() / w, item };
+
+        if self.heap.len() < self.capacity {
+            self.heap.push(Reverse(entry));
+            return true;
+        }
+
+        let weakest = &self.heap.peek().expect("reservoir is full").0;
+        if entry.key > weakest.key {
+            self.heap.pop();
+            self.heap.push(Reverse(entry));
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Offer every item of an iterator
+    pub fn extend<I: IntoIterator<Item = T>>(&mut self, items: I) {
+        for item in items {
+            self.offer(item);
+        }
+    }
+
+    /// Maximum number of retained items
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of retained items
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Whether no item is retained
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Number of items offered so far
+    pub fn seen(&self) -> usize {
+        self.seen
+    }
+
+    /// Retained items, in no particular order
+    pub fn items(&self) -> impl Iterator<Item = &T> {
+        self.heap.iter().map(|e| &e.0.item)
+    }
+
+    /// Consume the sampler, returning the retained items
+    pub fn into_items(self) -> Vec<T> {
+        self.heap.into_iter().map(|e| e.0.item).collect()
+    }
+}
+
+/// Draw a weighted sample of `capacity` items from an iterator
+pub fn reservoir_sample<T, I>(items: I, capacity: usize, seed: Option<u64>) -> Vec<T>
+where
+    T: Weighted,
+    I: IntoIterator<Item = T>,
+{
+    let mut sampler = ReservoirSampler::new(capacity);
+    if let Some(seed) = seed {
+        sampler = sampler.with_seed(seed);
+    }
+    sampler.extend(items);
+    sampler.into_items()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Item(usize, f64);
+
+    impl Weighted for Item {
+        fn weight(&self) -> f64 {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_bounded_and_seeded() {
+        let items = (0..500).map(|i| Item(i, 1.0 + (i % 7) as f64));
+        let a = reservoir_sample(items.clone(), 20, Some(5));
+        let b = reservoir_sample(items, 20, Some(5));
+
+        assert_eq!(a.len(), 20);
+        let mut ids_a: Vec<usize> = a.iter().map(|i| i.0).collect();
+        let mut ids_b: Vec<usize> = b.iter().map(|i| i.0).collect();
+        ids_a.sort();
+        ids_b.sort();
+        assert_eq!(ids_a, ids_b);
+    }
+
+    #[test]
+    fn test_heavier_items_retained_more_often() {
+        // Half the stream has weight 9, half weight 1
+        let mut heavy = 0;
+        for seed in 0..50 {
+            let items = (0..200).map(|i| Item(i, if i % 2 == 0 { 9.0 } else { 1.0 }));
+            heavy += reservoir_sample(items, 10, Some(seed))
+                .iter()
+                .filter(|i| i.1 > 1.0)
+                .count();
+        }
+        // Expect roughly 90% heavy; top-K would be 100%, uniform 50%
+        let share = heavy as f64 / 500.0;
+        assert!(share > 0.75 && share < 0.98, "heavy share {share}");
+    }
+
+    #[test]
+    fn test_non_positive_weights_skipped() {
+        let mut sampler = ReservoirSampler::new(4).with_seed(1);
+        assert!(!sampler.offer(Item(0, 0.0)));
+        assert!(!sampler.offer(Item(1, -1.0)));
+        assert!(!sampler.offer(Item(2, f64::NAN)));
+        assert!(sampler.offer(Item(3, 0.5)));
+        assert_eq!(sampler.len(), 1);
+        assert_eq!(sampler.seen(), 4);
+    }
+}
@@ -61,6 +61,34 @@ impl Default for ResonanceWeights {
     }
 }
 
+impl ResonanceWeights {
+    /// Create from weights in (ψ, ρ, ω, χ, η) order
+    pub fn from_array(weights: [f64; 5]) -> Self {
+        let [psi, rho, omega, chi, eta] = weights;
+        Self { psi, rho, omega, chi, eta }
+    }
+
+    /// Weights in (ψ, ρ, ω, χ, η) order
+    pub fn to_array(&self) -> [f64; 5] {
+        [self.psi, self.rho, self.omega, self.chi, self.eta]
+    }
+}
+
+impl ResonanceConfig {
+    /// Default configuration with custom resonance weights
+    pub fn with_weights(weights: ResonanceWeights) -> Self {
+        Self {
+            weights,
+            ..Default::default()
+        }
+    }
+
+    /// Resonance of a 5D signature under the configured weights
+    pub fn resonance_5d(&self, sig: &Signature5D) -> f64 {
+        resonance_5d_weighted(sig, &self.weights.to_array())
+    }
+}
+
 /// Compute resonance score for a 3D signature
 ///
 /// Formula: R(v) = 0.4·ψ + 0.3·ρ + 0.3·ω
@@ -72,10 +100,20 @@ pub fn resonance_3d(sig: &Signature3D) -> f64 {
 ///
 /// Formula: R(v) = 0.4·ψ + 0.3·ρ + 0.3·ω + 0.05·χ - 0.05·η
 pub fn resonance_5d(sig: &Signature5D) -> f64 {
-    let base = 0.4 * sig.psi + 0.3 * sig.rho + 0.3 * sig.omega;
-    let topo_correction = 0.05 * sig.chi;
-    let fluct_correction = -0.05 * sig.eta;
-    (base + topo_correction + fluct_correction).clamp(0.0, 1.0)
+    resonance_5d_weighted(sig, &ResonanceWeights::default().to_array())
+}
+
+/// Compute resonance for a 5D signature with weights in (ψ, ρ, ω, χ, η) order
+///
+/// Formula: R(v) = w₀·ψ + w₁·ρ + w₂·ω + w₃·χ + w₄·η, clamped to [0, 1]
+pub fn resonance_5d_weighted(sig: &Signature5D, weights: &[f64; 5]) -> f64 {
+    let [w_psi, w_rho, w_omega, w_chi, w_eta] = *weights;
+    let result = w_psi * sig.psi
+        + w_rho * sig.rho
+        + w_omega * sig.omega
+        + w_chi * sig.chi
+        + w_eta * sig.eta;
+    result.clamp(0.0, 1.0)
 }
 
 /// Compute resonance score for any signature type
@@ -88,12 +126,7 @@ pub fn resonance(sig: &Signature) -> f64 {
 
 /// Compute resonance with custom weights
 pub fn resonance_weighted(sig: &Signature5D, weights: &ResonanceWeights) -> f64 {
-    let result = weights.psi * sig.psi
-        + weights.rho * sig.rho
-        + weights.omega * sig.omega
-        + weights.chi * sig.chi
-        + weights.eta * sig.eta;
-    result.clamp(0.0, 1.0)
+    resonance_5d_weighted(sig, &weights.to_array())
 }
 
 /// Validate resonance invariant condition
@@ -277,6 +310,26 @@ mod tests {
         assert!(res > 0.7 && res <= 1.0);
     }
 
+    #[test]
+    fn test_resonance_5d_weighted() {
+        // Default weights reproduce the fixed formula exactly
+        let sig = Signature5D::new(0.9, 0.8, 0.7, 0.6, 0.5);
+        let fixed = (0.4 * 0.9 + 0.3 * 0.8 + 0.3 * 0.7 + 0.05 * 0.6 - 0.05 * 0.5_f64).clamp(0.0, 1.0);
+        assert_eq!(resonance_5d(&sig), fixed);
+        assert_eq!(ResonanceConfig::default().resonance_5d(&sig), fixed);
+
+        // Stability weighting flips the ranking of a high-ψ and a high-ρ signature
+        let quality = Signature5D::new(0.9, 0.3, 0.5, 0.0, 0.0);
+        let stable = Signature5D::new(0.4, 0.9, 0.5, 0.0, 0.0);
+        let weights = [0.2, 0.6, 0.2, 0.0, 0.0];
+        assert!(resonance_5d(&quality) > resonance_5d(&stable));
+        assert!(resonance_5d_weighted(&stable, &weights) > resonance_5d_weighted(&quality, &weights));
+
+        let config = ResonanceConfig::with_weights(ResonanceWeights::from_array(weights));
+        assert_eq!(config.weights.to_array(), weights);
+        assert_eq!(config.resonance_5d(&stable), resonance_5d_weighted(&stable, &weights));
+    }
+
     #[test]
     fn test_invariant_validation() {
         let v1 = Signature5D::new(0.5, 0.5, 0.5, 0.1, 0.1);
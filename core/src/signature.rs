@@ -72,6 +72,30 @@ impl Signature3D {
     pub fn to_5d(&self) -> Signature5D {
         Signature5D::from_3d(self)
     }
+
+    /// Linear interpolation a + t·(b - a), clamped to [0, 1]
+    ///
+    /// Computed per component, so unlike `a + (b - a) * t` it does not lose
+    /// the negative part of `b - a` to clamping.
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let lerp = |x: f64, y: f64| x + t * (y - x);
+        Self::new(lerp(a.psi, b.psi), lerp(a.rho, b.rho), lerp(a.omega, b.omega))
+    }
+
+    /// Component-wise mean of the signatures, or `None` if there are none
+    pub fn centroid(signatures: &[Self]) -> Option<Self> {
+        if signatures.is_empty() {
+            return None;
+        }
+        let n = signatures.len() as f64;
+        let mut sum = [0.0; 3];
+        for sig in signatures {
+            for (s, v) in sum.iter_mut().zip(sig.to_vec()) {
+                *s += v;
+            }
+        }
+        Some(Self::new(sum[0] / n, sum[1] / n, sum[2] / n))
+    }
 }
 
 impl Default for Signature3D {
@@ -80,6 +104,10 @@ impl Default for Signature3D {
     }
 }
 
+// Arithmetic goes through `new`, so every component is clamped to [0, 1]
+// after each operation: `a - b` saturates at zero and `a + b` at one. Use
+// `lerp` or `centroid` for interpolation and averaging.
+
 impl Add for Signature3D {
     type Output = Self;
 
@@ -181,11 +209,83 @@ impl Signature5D {
         .sqrt()
     }
 
+    /// Cosine distance 1 - cos∠(self, other), in [0, 1] for signatures in [0, 1]⁵
+    ///
+    /// Depends only on direction, so collinear signatures are at distance 0.
+    /// The zero signature has no direction: it is at distance 0 from itself
+    /// and 1 from everything else.
+    pub fn cosine_distance(&self, other: &Self) -> f64 {
+        let (a, b) = (self.to_vec(), other.to_vec());
+        let dot: f64 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+        let norm_a = a.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+        match (norm_a > 0.0, norm_b > 0.0) {
+            (true, true) => (1.0 - dot / (norm_a * norm_b)).max(0.0),
+            (false, false) => 0.0,
+            _ => 1.0,
+        }
+    }
+
+    /// Chebyshev (L∞) distance: the largest per-component difference
+    pub fn chebyshev_distance(&self, other: &Self) -> f64 {
+        self.to_vec()
+            .iter()
+            .zip(other.to_vec().iter())
+            .map(|(a, b)| (a - b).abs())
+            .fold(0.0, f64::max)
+    }
+
+    /// Distance to another signature under the given metric
+    pub fn distance_with(&self, other: &Self, metric: DistanceMetric) -> f64 {
+        match metric {
+            DistanceMetric::Euclidean => self.distance(other),
+            DistanceMetric::Cosine => self.cosine_distance(other),
+            DistanceMetric::Chebyshev => self.chebyshev_distance(other),
+        }
+    }
+
     /// Convert to vector representation
     pub fn to_vec(&self) -> [f64; 5] {
         [self.psi, self.rho, self.omega, self.chi, self.eta]
     }
 
+    /// Linear interpolation a + t·(b - a), clamped to [0, 1]
+    ///
+    /// Computed per component, so unlike `a + (b - a) * t` it does not lose
+    /// the negative part of `b - a` to clamping.
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let lerp = |x: f64, y: f64| x + t * (y - x);
+        Self::new(
+            lerp(a.psi, b.psi),
+            lerp(a.rho, b.rho),
+            lerp(a.omega, b.omega),
+            lerp(a.chi, b.chi),
+            lerp(a.eta, b.eta),
+        )
+    }
+
+    /// Component-wise mean of the signatures, or `None` if there are none
+    pub fn centroid(signatures: &[Self]) -> Option<Self> {
+        Self::centroid_of(signatures.iter())
+    }
+
+    /// Component-wise mean of borrowed signatures, or `None` if there are none
+    pub fn centroid_of<'a>(signatures: impl IntoIterator<Item = &'a Self>) -> Option<Self> {
+        let mut sum = [0.0; 5];
+        let mut count = 0usize;
+        for sig in signatures {
+            for (s, v) in sum.iter_mut().zip(sig.to_vec()) {
+                *s += v;
+            }
+            count += 1;
+        }
+        if count == 0 {
+            return None;
+        }
+        let n = count as f64;
+        Some(Self::new(sum[0] / n, sum[1] / n, sum[2] / n, sum[3] / n, sum[4] / n))
+    }
+
     /// Clamp all values to valid range [0, 1]
     pub fn clamp(&mut self) {
         self.psi = self.psi.clamp(0.0, 1.0);
@@ -202,6 +302,8 @@ impl Default for Signature5D {
     }
 }
 
+// Clamped like the 3D operators above
+
 impl Add for Signature5D {
     type Output = Self;
 
@@ -244,6 +346,18 @@ impl Mul<f64> for Signature5D {
     }
 }
 
+/// Distance metric between 5D signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// Straight-line distance, see [`Signature5D::distance`]
+    #[default]
+    Euclidean,
+    /// Angular distance, see [`Signature5D::cosine_distance`]
+    Cosine,
+    /// Largest component difference, see [`Signature5D::chebyshev_distance`]
+    Chebyshev,
+}
+
 /// Unified signature type that can be either 3D or 5D
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Signature {
@@ -385,6 +499,54 @@ mod tests {
         assert_relative_eq!(a.distance(&c), 0.5);
     }
 
+    #[test]
+    fn test_signature5d_metrics() {
+        // Collinear signatures: cosine distance ignores scale, the others do not
+        let a = Signature5D::new(0.1, 0.2, 0.05, 0.15, 0.1);
+        for scale in [2.0, 3.0, 5.0] {
+            let b = a * scale;
+            assert_relative_eq!(a.cosine_distance(&b), 0.0, epsilon = 1e-12);
+            assert!(a.distance(&b) > 0.1);
+        }
+
+        let x = Signature5D::new(1.0, 0.0, 0.0, 0.0, 0.0);
+        let y = Signature5D::new(0.0, 1.0, 0.0, 0.0, 0.0);
+        assert_relative_eq!(x.cosine_distance(&y), 1.0);
+        assert_relative_eq!(x.cosine_distance(&Signature5D::zero()), 1.0);
+        assert_relative_eq!(Signature5D::zero().cosine_distance(&Signature5D::zero()), 0.0);
+
+        let c = Signature5D::new(0.5, 0.9, 0.4, 0.5, 0.5);
+        let d = Signature5D::default();
+        assert_relative_eq!(c.chebyshev_distance(&d), 0.4, epsilon = 1e-12);
+        assert_eq!(c.distance_with(&d, DistanceMetric::default()), c.distance(&d));
+        assert_eq!(c.distance_with(&d, DistanceMetric::Chebyshev), c.chebyshev_distance(&d));
+    }
+
+    #[test]
+    fn test_interpolation_and_centroid() {
+        let a = Signature5D::new(0.2, 0.8, 0.4, 0.0, 1.0);
+        let b = Signature5D::new(0.6, 0.4, 0.4, 1.0, 0.0);
+
+        let mid = Signature5D::lerp(&a, &b, 0.5);
+        assert_relative_eq!(mid.psi, 0.4);
+        assert_relative_eq!(mid.rho, 0.6);
+        assert_relative_eq!(mid.chi, 0.5);
+        assert_eq!(Signature5D::lerp(&a, &b, 0.0), a);
+        assert_eq!(Signature5D::lerp(&a, &b, 1.0), b);
+        // Extrapolation stays in range
+        assert_relative_eq!(Signature5D::lerp(&a, &b, 3.0).rho, 0.0);
+
+        // Plain arithmetic clamps the intermediate difference
+        assert_relative_eq!((b - a).rho, 0.0);
+        assert_relative_eq!((a + b).chi, 1.0);
+
+        assert_eq!(Signature5D::centroid(&[a, b]), Some(mid));
+        assert_eq!(Signature5D::centroid(&[]), None);
+
+        let c = Signature3D::centroid(&[Signature3D::zero(), Signature3D::unit()]).unwrap();
+        assert_eq!(c, Signature3D::lerp(&Signature3D::zero(), &Signature3D::unit(), 0.5));
+    }
+
     #[test]
     fn test_unified_signature() {
         let sig3 = Signature::D3(Signature3D::new(0.8, 0.7, 0.6));
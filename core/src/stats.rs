@@ -0,0 +1,62 @@
+//! Shared numerical statistics helpers.
+//!
+//! Small closed-form approximations used by the significance tests and
+//! acquisition functions across crates, and a seed mixer for deriving
+//! independent per-run seeds.
+
+/// Standard normal CDF
+pub fn normal_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Abramowitz–Stegun error function approximation (max error 1.5e-7)
+pub fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+    let t = 1.0 / (1.0 + 0.3275911 * x);
+    let y = 1.0
+        - (((((1.061405429 * t - 1.453152027) * t) + 1.421413741) * t - 0.284496736) * t
+            + 0.254829592)
+            * t
+            * (-x * x).exp();
+    sign * y
+}
+
+/// SplitMix64 finalizer
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Derive the seed of run `stream` from a base seed
+///
+/// SplitMix64 of the pre-mixed base seed XOR the stream index, so runs of
+/// nearby base seeds (e.g. 1 and 2) do not share streams the way
+/// `seed.wrapping_add(k)` would.
+pub fn mix_seed(seed: u64, stream: u64) -> u64 {
+    splitmix64(splitmix64(seed) ^ stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-9);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+        assert!((normal_cdf(-1.0) + normal_cdf(1.0) - 1.0).abs() < 1e-9);
+        assert!((erf(0.5) - 0.520_499_877_8).abs() < 2e-7);
+    }
+
+    #[test]
+    fn test_mix_seed_streams_are_distinct() {
+        let seeds: std::collections::HashSet<u64> = (0..4)
+            .flat_map(|seed| (0..4).map(move |k| mix_seed(seed, k)))
+            .collect();
+        assert_eq!(seeds.len(), 16);
+        assert_eq!(mix_seed(7, 3), mix_seed(7, 3));
+    }
+}
@@ -0,0 +1,154 @@
+//! Shared resonance color mapping.
+//!
+//! A single place mapping resonance values to colors, so the CLI, GUI and
+//! reports render the same resonance with the same color.
+
+use crate::holistic::GenesisStage;
+use serde::{Deserialize, Serialize};
+
+/// Viridis anchor colors at evenly spaced positions in [0, 1]
+const VIRIDIS: [[u8; 3]; 9] = [
+    [68, 1, 84],
+    [71, 45, 123],
+    [59, 82, 139],
+    [44, 114, 142],
+    [33, 145, 140],
+    [40, 174, 128],
+    [94, 201, 98],
+    [173, 220, 48],
+    [253, 231, 37],
+];
+
+/// Plasma anchor colors at evenly spaced positions in [0, 1]
+const PLASMA: [[u8; 3]; 9] = [
+    [13, 8, 135],
+    [76, 2, 161],
+    [126, 3, 168],
+    [169, 35, 149],
+    [204, 71, 120],
+    [229, 107, 93],
+    [248, 148, 65],
+    [253, 195, 40],
+    [240, 249, 33],
+];
+
+/// Pipeline stages in order, used by the stage-based scheme
+const STAGES: [GenesisStage; 5] = [
+    GenesisStage::Discovery,
+    GenesisStage::KosmokratorFilter,
+    GenesisStage::ChronokratorExpansion,
+    GenesisStage::PfauenthronCollapse,
+    GenesisStage::Finalized,
+];
+
+/// Named color scheme for resonance values in [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ResonanceColorMap {
+    /// Perceptually uniform purple → yellow
+    #[default]
+    Viridis,
+    /// Perceptually uniform blue → yellow via magenta
+    Plasma,
+    /// Five equal resonance bands in Genesis stage colors
+    StageBased,
+}
+
+impl ResonanceColorMap {
+    /// All schemes
+    pub const ALL: [ResonanceColorMap; 3] = [Self::Viridis, Self::Plasma, Self::StageBased];
+
+    /// Scheme name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Viridis => "viridis",
+            Self::Plasma => "plasma",
+            Self::StageBased => "stage",
+        }
+    }
+
+    /// Parse a scheme name (case-insensitive)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.name().eq_ignore_ascii_case(name))
+    }
+
+    /// RGB color for a resonance value
+    ///
+    /// Values are clamped to [0, 1]; NaN maps like 0.
+    pub fn color_for(&self, resonance: f64) -> [u8; 3] {
+        let r = if resonance.is_nan() { 0.0 } else { resonance.clamp(0.0, 1.0) };
+        match self {
+            Self::Viridis => interpolate(&VIRIDIS, r),
+            Self::Plasma => interpolate(&PLASMA, r),
+            Self::StageBased => {
+                let band = ((r * STAGES.len() as f64) as usize).min(STAGES.len() - 1);
+                STAGES[band].rgb()
+            }
+        }
+    }
+
+    /// Hex color (`#rrggbb`) for a resonance value
+    pub fn hex_for(&self, resonance: f64) -> String {
+        to_hex(self.color_for(resonance))
+    }
+
+    /// Sample `steps` evenly spaced colors from 0 to 1
+    pub fn samples(&self, steps: usize) -> Vec<[u8; 3]> {
+        match steps {
+            0 => Vec::new(),
+            1 => vec![self.color_for(0.0)],
+            _ => (0..steps)
+                .map(|i| self.color_for(i as f64 / (steps - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+/// Format an RGB color as `#rrggbb`
+pub fn to_hex(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+/// Linear interpolation between evenly spaced anchors
+fn interpolate(anchors: &[[u8; 3]], t: f64) -> [u8; 3] {
+    let scaled = t * (anchors.len() - 1) as f64;
+    let i = (scaled.floor() as usize).min(anchors.len() - 2);
+    let frac = scaled - i as f64;
+    let (a, b) = (anchors[i], anchors[i + 1]);
+    let mix = |x: u8, y: u8| (x as f64 + (y as f64 - x as f64) * frac).round() as u8;
+    [mix(a[0], b[0]), mix(a[1], b[1]), mix(a[2], b[2])]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoints_and_clamping() {
+        let map = ResonanceColorMap::Viridis;
+        assert_eq!(map.color_for(0.0), VIRIDIS[0]);
+        assert_eq!(map.color_for(1.0), VIRIDIS[8]);
+        assert_eq!(map.color_for(-3.0), VIRIDIS[0]);
+        assert_eq!(map.color_for(7.0), VIRIDIS[8]);
+        assert_eq!(map.color_for(f64::NAN), VIRIDIS[0]);
+        assert_eq!(map.hex_for(1.0), "#fde725");
+    }
+
+    #[test]
+    fn test_stage_based_matches_stage_colors() {
+        let map = ResonanceColorMap::StageBased;
+        assert_eq!(map.hex_for(0.1), GenesisStage::Discovery.color());
+        assert_eq!(map.hex_for(0.95), GenesisStage::Finalized.color());
+        assert_eq!(map.hex_for(1.0), GenesisStage::Finalized.color());
+        assert_eq!(GenesisStage::ChronokratorExpansion.rgb(), [0x99, 0x32, 0xcc]);
+    }
+
+    #[test]
+    fn test_names_and_samples() {
+        for map in ResonanceColorMap::ALL {
+            assert_eq!(ResonanceColorMap::from_name(map.name()), Some(map));
+        }
+        let samples = ResonanceColorMap::Plasma.samples(5);
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[4], PLASMA[8]);
+    }
+}
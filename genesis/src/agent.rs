@@ -1,10 +1,15 @@
 //! Traversal agents for operator mining.
+//!
+//! An agent walks the [`MetatronCube`], asking its [`AgentPolicy`] which
+//! neighbor to move to at every step. The built-in [`TraversalStrategy`]
+//! variants construct the provided policies; custom heuristics implement
+//! the trait and are set with [`AgentConfig::with_policy`].
 
 use crate::metatron_cube::MetatronCube;
-use qops_core::{ResonanceTopology, Signature5D, resonance_5d};
+use qops_core::{ResonanceTopology, Signature, Signature5D, resonance_5d};
 use petgraph::graph::NodeIndex;
 use rand::seq::SliceRandom;
-use rand::Rng;
+use rand::{Rng, RngCore};
 use serde::{Deserialize, Serialize};
 
 /// Traversal strategy for agents
@@ -28,6 +33,161 @@ impl Default for TraversalStrategy {
     }
 }
 
+impl TraversalStrategy {
+    /// The policy implementing this strategy
+    pub fn policy(&self) -> Box<dyn AgentPolicy> {
+        match self {
+            Self::GradientAscent => Box::new(GradientAscentPolicy),
+            Self::StabilityMaximization => Box::new(StabilityPolicy),
+            Self::CycleRecognition => Box::new(CycleRecognitionPolicy),
+            Self::Balanced => Box::new(BalancedPolicy),
+            Self::Random => Box::new(RandomPolicy),
+        }
+    }
+}
+
+// ==================== Agent Policies ====================
+
+/// What a policy sees of the agent besides its candidate moves
+pub struct StepContext<'a> {
+    /// Nodes visited so far, starting node first and current node last
+    pub path: &'a [NodeIndex],
+    /// Configured exploration rate (for epsilon-greedy policies)
+    pub exploration_rate: f64,
+    /// Randomness source; drawing from it keeps seeded sessions reproducible
+    pub rng: &'a mut dyn RngCore,
+}
+
+/// Chooses where an agent moves next
+///
+/// Nodes are given by their `NodeIndex::index()`. `neighbors` is never
+/// empty and already excludes nodes the agent's [`AgentMemory`] forbids
+/// (unless that would leave none); the returned node must be one of them,
+/// otherwise the agent falls back to the first neighbor.
+pub trait AgentPolicy: PolicyClone + std::fmt::Debug + Send + Sync {
+    /// Pick the next node from `neighbors` of `current`
+    fn choose_next(&mut self, current: usize, neighbors: &[usize], cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize;
+}
+
+/// Boxed cloning for [`AgentPolicy`], implemented for every `Clone` policy
+pub trait PolicyClone {
+    /// Clone into a new box
+    fn clone_box(&self) -> Box<dyn AgentPolicy>;
+}
+
+impl<T: AgentPolicy + Clone + 'static> PolicyClone for T {
+    fn clone_box(&self) -> Box<dyn AgentPolicy> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn AgentPolicy> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
+}
+
+/// Uniformly random neighbor
+fn random_neighbor(neighbors: &[usize], ctx: &mut StepContext<'_>) -> usize {
+    *neighbors.choose(&mut ctx.rng).unwrap()
+}
+
+/// Epsilon-greedy choice of the neighbor maximizing `score`
+fn greedy_neighbor(
+    neighbors: &[usize],
+    cube: &MetatronCube,
+    ctx: &mut StepContext<'_>,
+    score: impl Fn(&Signature) -> f64,
+) -> usize {
+    if ctx.rng.gen::<f64>() < ctx.exploration_rate {
+        return random_neighbor(neighbors, ctx);
+    }
+
+    let value = |n: usize| cube.signature_at(&NodeIndex::new(n)).map(|s| score(&s)).unwrap_or(0.0);
+    neighbors
+        .iter()
+        .max_by(|&&a, &&b| value(a).partial_cmp(&value(b)).unwrap())
+        .copied()
+        .unwrap_or_else(|| random_neighbor(neighbors, ctx))
+}
+
+/// Uniform random walk
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomPolicy;
+
+impl AgentPolicy for RandomPolicy {
+    fn choose_next(&mut self, _current: usize, neighbors: &[usize], _cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+        random_neighbor(neighbors, ctx)
+    }
+}
+
+/// Epsilon-greedy ascent in spectral quality (ψ)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GradientAscentPolicy;
+
+impl AgentPolicy for GradientAscentPolicy {
+    fn choose_next(&mut self, _current: usize, neighbors: &[usize], cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+        greedy_neighbor(neighbors, cube, ctx, |s| s.psi())
+    }
+}
+
+/// Epsilon-greedy ascent in structural coherence (ω)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StabilityPolicy;
+
+impl AgentPolicy for StabilityPolicy {
+    fn choose_next(&mut self, _current: usize, neighbors: &[usize], cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+        greedy_neighbor(neighbors, cube, ctx, |s| s.omega())
+    }
+}
+
+/// Prefer neighbors already on the path, seeking cycles
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CycleRecognitionPolicy;
+
+impl AgentPolicy for CycleRecognitionPolicy {
+    fn choose_next(&mut self, _current: usize, neighbors: &[usize], _cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+        neighbors
+            .iter()
+            .find(|&&n| ctx.path.contains(&NodeIndex::new(n)))
+            .copied()
+            .unwrap_or_else(|| random_neighbor(neighbors, ctx))
+    }
+}
+
+/// Epsilon-greedy ascent in overall resonance
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BalancedPolicy;
+
+impl AgentPolicy for BalancedPolicy {
+    fn choose_next(&mut self, _current: usize, neighbors: &[usize], cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+        greedy_neighbor(neighbors, cube, ctx, |s| s.resonance())
+    }
+}
+
+/// Memory of visited nodes used to avoid revisits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AgentMemory {
+    /// No memory, any neighbor may be chosen
+    #[default]
+    None,
+    /// Avoid the last `n` visited nodes (tabu search)
+    TabuList(usize),
+    /// Avoid every node visited so far
+    FullHistory,
+}
+
+impl AgentMemory {
+    /// Whether `node` is currently forbidden given the path so far
+    pub fn is_tabu(&self, path: &[NodeIndex], node: &NodeIndex) -> bool {
+        match *self {
+            AgentMemory::None => false,
+            AgentMemory::TabuList(size) => path[path.len().saturating_sub(size)..].contains(node),
+            AgentMemory::FullHistory => path.contains(node),
+        }
+    }
+}
+
 /// Agent configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AgentConfig {
@@ -39,6 +199,25 @@ pub struct AgentConfig {
     pub exploration_rate: f64,
     /// Target resonance threshold
     pub target_resonance: f64,
+    /// Memory used to avoid revisiting nodes
+    #[serde(default)]
+    pub memory: AgentMemory,
+    /// Custom policy used instead of `strategy`; not serialized
+    #[serde(skip)]
+    pub policy: Option<Box<dyn AgentPolicy>>,
+}
+
+impl AgentConfig {
+    /// Use a custom traversal policy instead of the built-in strategy
+    pub fn with_policy(mut self, policy: impl AgentPolicy + 'static) -> Self {
+        self.policy = Some(Box::new(policy));
+        self
+    }
+
+    /// Fresh policy for an agent: the custom one if set, otherwise the strategy's
+    pub fn build_policy(&self) -> Box<dyn AgentPolicy> {
+        self.policy.clone().unwrap_or_else(|| self.strategy.policy())
+    }
 }
 
 impl Default for AgentConfig {
@@ -48,12 +227,14 @@ impl Default for AgentConfig {
             strategy: TraversalStrategy::Balanced,
             exploration_rate: 0.1,
             target_resonance: 0.85,
+            memory: AgentMemory::default(),
+            policy: None,
         }
     }
 }
 
 /// Traversal agent for operator mining
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Agent {
     /// Agent ID
     pub id: uuid::Uuid,
@@ -69,11 +250,15 @@ pub struct Agent {
     pub steps: usize,
     /// Best resonance encountered
     pub best_resonance: f64,
+    /// Policy choosing each step, rebuilt from `config` after deserializing
+    #[serde(skip)]
+    policy: Option<Box<dyn AgentPolicy>>,
 }
 
 impl Agent {
     /// Create a new agent at a starting position
     pub fn new(start: NodeIndex, config: AgentConfig) -> Self {
+        let config_policy = config.build_policy();
         Self {
             id: uuid::Uuid::new_v4(),
             position: start,
@@ -82,28 +267,42 @@ impl Agent {
             signature: Signature5D::default(),
             steps: 0,
             best_resonance: 0.0,
+            policy: Some(config_policy),
         }
     }
 
     /// Take a single step in the graph
     pub fn step(&mut self, cube: &MetatronCube) -> Option<NodeIndex> {
+        self.step_with_rng(cube, &mut rand::thread_rng())
+    }
+
+    /// Take a single step drawing randomness from `rng`
+    pub fn step_with_rng<R: Rng + ?Sized>(&mut self, cube: &MetatronCube, rng: &mut R) -> Option<NodeIndex> {
         if self.steps >= self.config.max_steps {
             return None;
         }
 
-        let neighbors = cube.neighbors(&self.position);
+        let mut neighbors: Vec<NodeIndex> = cube.adjacent(self.position).collect();
         if neighbors.is_empty() {
             return None;
         }
 
-        // Choose next node based on strategy
-        let next = match self.config.strategy {
-            TraversalStrategy::Random => self.random_step(&neighbors),
-            TraversalStrategy::GradientAscent => self.gradient_step(cube, &neighbors),
-            TraversalStrategy::StabilityMaximization => self.stability_step(cube, &neighbors),
-            TraversalStrategy::CycleRecognition => self.cycle_step(&neighbors),
-            TraversalStrategy::Balanced => self.balanced_step(cube, &neighbors),
+        // Skip remembered nodes unless that would leave nowhere to go
+        if neighbors.iter().any(|n| !self.config.memory.is_tabu(&self.path, n)) {
+            neighbors.retain(|n| !self.config.memory.is_tabu(&self.path, n));
+        }
+
+        // Choose next node with the policy
+        let candidates: Vec<usize> = neighbors.iter().map(|n| n.index()).collect();
+        let policy = self.policy.get_or_insert_with(|| self.config.build_policy());
+        let mut rng = rng;
+        let mut ctx = StepContext {
+            path: &self.path,
+            exploration_rate: self.config.exploration_rate,
+            rng: &mut rng,
         };
+        let chosen = policy.choose_next(self.position.index(), &candidates, cube, &mut ctx);
+        let next = if candidates.contains(&chosen) { NodeIndex::new(chosen) } else { neighbors[0] };
 
         // Update state
         self.position = next;
@@ -122,82 +321,14 @@ impl Agent {
         Some(next)
     }
 
-    fn random_step(&self, neighbors: &[NodeIndex]) -> NodeIndex {
-        let mut rng = rand::thread_rng();
-        *neighbors.choose(&mut rng).unwrap()
-    }
-
-    fn gradient_step(&self, cube: &MetatronCube, neighbors: &[NodeIndex]) -> NodeIndex {
-        let mut rng = rand::thread_rng();
-
-        // Epsilon-greedy: sometimes explore randomly
-        if rng.gen::<f64>() < self.config.exploration_rate {
-            return self.random_step(neighbors);
-        }
-
-        // Find neighbor with highest psi
-        neighbors
-            .iter()
-            .max_by(|a, b| {
-                let sig_a = cube.signature_at(a).map(|s| s.psi()).unwrap_or(0.0);
-                let sig_b = cube.signature_at(b).map(|s| s.psi()).unwrap_or(0.0);
-                sig_a.partial_cmp(&sig_b).unwrap()
-            })
-            .copied()
-            .unwrap_or_else(|| self.random_step(neighbors))
-    }
-
-    fn stability_step(&self, cube: &MetatronCube, neighbors: &[NodeIndex]) -> NodeIndex {
-        let mut rng = rand::thread_rng();
-
-        if rng.gen::<f64>() < self.config.exploration_rate {
-            return self.random_step(neighbors);
-        }
-
-        // Find neighbor with highest omega (stability)
-        neighbors
-            .iter()
-            .max_by(|a, b| {
-                let sig_a = cube.signature_at(a).map(|s| s.omega()).unwrap_or(0.0);
-                let sig_b = cube.signature_at(b).map(|s| s.omega()).unwrap_or(0.0);
-                sig_a.partial_cmp(&sig_b).unwrap()
-            })
-            .copied()
-            .unwrap_or_else(|| self.random_step(neighbors))
-    }
-
-    fn cycle_step(&self, neighbors: &[NodeIndex]) -> NodeIndex {
-        // Prefer nodes already in path (seeking cycles)
-        for node in neighbors {
-            if self.path.contains(node) {
-                return *node;
-            }
-        }
-        self.random_step(neighbors)
-    }
-
-    fn balanced_step(&self, cube: &MetatronCube, neighbors: &[NodeIndex]) -> NodeIndex {
-        let mut rng = rand::thread_rng();
-
-        if rng.gen::<f64>() < self.config.exploration_rate {
-            return self.random_step(neighbors);
-        }
-
-        // Find neighbor with highest overall resonance
-        neighbors
-            .iter()
-            .max_by(|a, b| {
-                let sig_a = cube.signature_at(a).map(|s| s.resonance()).unwrap_or(0.0);
-                let sig_b = cube.signature_at(b).map(|s| s.resonance()).unwrap_or(0.0);
-                sig_a.partial_cmp(&sig_b).unwrap()
-            })
-            .copied()
-            .unwrap_or_else(|| self.random_step(neighbors))
-    }
-
     /// Run full traversal
     pub fn traverse(&mut self, cube: &MetatronCube) {
-        while self.step(cube).is_some() {}
+        self.traverse_with_rng(cube, &mut rand::thread_rng());
+    }
+
+    /// Run full traversal drawing randomness from `rng`
+    pub fn traverse_with_rng<R: Rng + ?Sized>(&mut self, cube: &MetatronCube, rng: &mut R) {
+        while self.step_with_rng(cube, rng).is_some() {}
     }
 
     /// Check if target resonance was reached
@@ -255,4 +386,93 @@ mod tests {
         agent.traverse(&cube);
         assert_eq!(agent.steps, 5);
     }
+
+    #[test]
+    fn test_agent_memory_avoids_revisits() {
+        use rand::SeedableRng;
+
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures_with(&mut rand::rngs::StdRng::seed_from_u64(5));
+
+        let distinct = |memory| {
+            let config = AgentConfig {
+                max_steps: 40,
+                exploration_rate: 0.0,
+                memory,
+                ..Default::default()
+            };
+            let mut agent = Agent::new(cube.identity_node(), config);
+            agent.traverse_with_rng(&cube, &mut rand::rngs::StdRng::seed_from_u64(9));
+            agent.path.iter().collect::<std::collections::HashSet<_>>().len()
+        };
+        assert!(distinct(AgentMemory::FullHistory) > distinct(AgentMemory::None));
+
+        let tabu = AgentMemory::TabuList(2);
+        let path = [NodeIndex::new(1), NodeIndex::new(2), NodeIndex::new(3)];
+        assert!(!tabu.is_tabu(&path, &NodeIndex::new(1)));
+        assert!(tabu.is_tabu(&path, &NodeIndex::new(2)));
+        assert!(AgentMemory::FullHistory.is_tabu(&path, &NodeIndex::new(1)));
+        assert!(!AgentMemory::None.is_tabu(&path, &NodeIndex::new(3)));
+    }
+
+    /// Walks to the lowest-numbered neighbor not yet on the path
+    #[derive(Debug, Clone, Copy)]
+    struct LowestUnvisited;
+
+    impl AgentPolicy for LowestUnvisited {
+        fn choose_next(&mut self, _current: usize, neighbors: &[usize], _cube: &MetatronCube, ctx: &mut StepContext<'_>) -> usize {
+            neighbors
+                .iter()
+                .copied()
+                .filter(|&n| !ctx.path.contains(&NodeIndex::new(n)))
+                .min()
+                .unwrap_or(neighbors[0])
+        }
+    }
+
+    #[test]
+    fn test_custom_policy() {
+        let cube = MetatronCube::new();
+        let config = AgentConfig {
+            max_steps: 6,
+            ..Default::default()
+        }
+        .with_policy(LowestUnvisited);
+
+        let mut agent = Agent::new(cube.identity_node(), config);
+        agent.traverse(&cube);
+        assert_eq!(agent.steps, 6);
+
+        for i in 1..agent.path.len() {
+            let visited = &agent.path[..i];
+            let neighbors = &cube.adjacency()[visited[i - 1].index()];
+            let expected = neighbors
+                .iter()
+                .copied()
+                .filter(|&n| !visited.contains(&NodeIndex::new(n)))
+                .min()
+                .unwrap_or(neighbors[0]);
+            assert_eq!(agent.path[i].index(), expected);
+        }
+    }
+
+    #[test]
+    fn test_strategies_match_policies() {
+        use rand::SeedableRng;
+
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures_with(&mut rand::rngs::StdRng::seed_from_u64(2));
+
+        let walk = |config: AgentConfig| {
+            let mut agent = Agent::new(cube.identity_node(), config);
+            agent.traverse_with_rng(&cube, &mut rand::rngs::StdRng::seed_from_u64(4));
+            agent.path
+        };
+        let base = AgentConfig { max_steps: 20, ..Default::default() };
+        assert_eq!(
+            walk(AgentConfig { strategy: TraversalStrategy::GradientAscent, ..base.clone() }),
+            walk(base.clone().with_policy(GradientAscentPolicy)),
+        );
+        assert_eq!(walk(base.clone()), walk(base.with_policy(BalancedPolicy)));
+    }
 }
@@ -1,6 +1,7 @@
 //! Mining artefacts with blueprint history.
 
-use qops_core::Signature5D;
+use crate::mining::DEFAULT_MANDORLA_THRESHOLD;
+use qops_core::{Signature5D, Weighted};
 use petgraph::graph::NodeIndex;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -57,19 +58,18 @@ impl Artefact {
     /// Create a new artefact
     pub fn new(node: NodeIndex, signature: Signature5D) -> Self {
         let resonance = qops_core::resonance_5d(&signature);
-        let is_mandorla = resonance >= 0.85 &&
-            signature.psi * signature.rho * signature.omega >= 0.5;
-
-        Self {
+        let mut artefact = Self {
             id: Uuid::new_v4(),
             node,
             signature,
             blueprint: Vec::new(),
             resonance,
-            is_mandorla,
+            is_mandorla: false,
             created_at: Utc::now(),
             stability: 0.5,
-        }
+        };
+        artefact.is_mandorla = artefact.is_mandorla();
+        artefact
     }
 
     /// Create artefact from just a signature (uses dummy node)
@@ -77,9 +77,17 @@ impl Artefact {
         Self::new(NodeIndex::new(0), signature)
     }
 
-    /// Check if artefact is in Mandorla zone
+    /// Check if artefact is in Mandorla zone at the default threshold
     pub fn is_mandorla(&self) -> bool {
-        self.resonance >= 0.85 &&
+        self.is_mandorla_at(DEFAULT_MANDORLA_THRESHOLD)
+    }
+
+    /// Check if artefact is in Mandorla zone at a given resonance threshold
+    ///
+    /// Use [`MandorlaPolicy::threshold`](crate::MandorlaPolicy::threshold) to
+    /// derive a session-relative threshold.
+    pub fn is_mandorla_at(&self, threshold: f64) -> bool {
+        self.resonance >= threshold &&
             self.signature.psi * self.signature.rho * self.signature.omega >= 0.5
     }
 
@@ -117,6 +125,12 @@ impl Artefact {
     }
 }
 
+impl Weighted for Artefact {
+    fn weight(&self) -> f64 {
+        self.resonance
+    }
+}
+
 impl Transformation {
     /// Create a new transformation
     pub fn new(kind: TransformationType, from: NodeIndex, to: NodeIndex) -> Self {
@@ -0,0 +1,266 @@
+//! Candidate sources for the holistic pipeline.
+//!
+//! Implementations of [`CandidateSource`] backed by real exploration:
+//! - [`MetatronCandidateSource`]: agent traversal of the S7 MetatronCube
+//! - [`TritonCandidateSource`]: TRITON spiral search in signature space
+
+use crate::agent::{Agent, AgentConfig};
+use crate::metatron_cube::{MetatronCube, Permutation};
+use crate::mining::DEFAULT_MANDORLA_THRESHOLD;
+use qops_core::{resonance_5d, CandidateSource, OperatorCandidate, Signature5D};
+use qops_triton::{SpiralEngine, SpiralParams};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::f64::consts::PI;
+
+/// Maximum number of inversions of an S7 permutation (7 choose 2)
+const MAX_INVERSIONS: f64 = 21.0;
+
+/// Phase of a permutation derived from its inversion count
+fn permutation_phase(perm: &Permutation) -> f64 {
+    let mut inversions = 0usize;
+    for i in 0..perm.len() {
+        for j in (i + 1)..perm.len() {
+            if perm[i] > perm[j] {
+                inversions += 1;
+            }
+        }
+    }
+    2.0 * PI * inversions as f64 / MAX_INVERSIONS
+}
+
+/// Mandorla criterion shared with [`crate::artefact::Artefact`]
+fn is_mandorla(sig: &Signature5D, resonance: f64) -> bool {
+    resonance >= DEFAULT_MANDORLA_THRESHOLD && sig.psi * sig.rho * sig.omega >= 0.5
+}
+
+// ============================================================================
+// METATRON CUBE TRAVERSAL
+// ============================================================================
+
+/// Candidate source driven by agent traversal of the MetatronCube
+pub struct MetatronCandidateSource {
+    cube: MetatronCube,
+    agent_config: AgentConfig,
+    produced: usize,
+    rng: StdRng,
+}
+
+impl MetatronCandidateSource {
+    /// Create a source over a freshly randomized MetatronCube
+    pub fn new(agent_config: AgentConfig) -> Self {
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures();
+        Self::with_cube(cube, agent_config)
+    }
+
+    /// Create a reproducible source whose cube and traversals derive from `seed`
+    pub fn with_seed(agent_config: AgentConfig, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures_with(&mut rng);
+        Self {
+            rng,
+            ..Self::with_cube(cube, agent_config)
+        }
+    }
+
+    /// Create a source over an existing cube
+    pub fn with_cube(cube: MetatronCube, agent_config: AgentConfig) -> Self {
+        Self {
+            cube,
+            agent_config,
+            produced: 0,
+            rng: StdRng::from_entropy(),
+        }
+    }
+
+    /// Get the underlying cube
+    pub fn cube(&self) -> &MetatronCube {
+        &self.cube
+    }
+}
+
+impl Default for MetatronCandidateSource {
+    fn default() -> Self {
+        Self::new(AgentConfig::default())
+    }
+}
+
+impl CandidateSource for MetatronCandidateSource {
+    fn name(&self) -> &str {
+        "metatron"
+    }
+
+    fn next_batch(&mut self, n: usize) -> Vec<OperatorCandidate> {
+        (0..n)
+            .map(|_| {
+                let mut agent = Agent::new(self.cube.identity_node(), self.agent_config.clone());
+                agent.traverse_with_rng(&self.cube, &mut self.rng);
+
+                let signature = agent.signature;
+                let resonance = resonance_5d(&signature);
+                let phase = self.cube
+                    .permutation(agent.position)
+                    .map(permutation_phase)
+                    .unwrap_or(0.0);
+                let stability = if agent.steps > 0 {
+                    agent.best_resonance.min(1.0)
+                } else {
+                    0.5
+                };
+
+                let candidate = OperatorCandidate {
+                    id: format!("metatron_{}", self.produced),
+                    signature,
+                    phase,
+                    resonance,
+                    stability,
+                    is_mandorla: is_mandorla(&signature, resonance),
+                    node_index: agent.position.index(),
+                    discovered_at: self.produced as f64,
+                };
+                self.produced += 1;
+                candidate
+            })
+            .collect()
+    }
+}
+
+// ============================================================================
+// TRITON SPIRAL SEARCH
+// ============================================================================
+
+/// Candidate source driven by the TRITON spiral engine
+///
+/// When a spiral completes, it is re-centered on the best point found so far
+/// and restarted, so the source never runs dry.
+pub struct TritonCandidateSource {
+    engine: SpiralEngine,
+    best: Option<(Signature5D, f64)>,
+    produced: usize,
+}
+
+impl TritonCandidateSource {
+    /// Create a source with the given spiral parameters
+    pub fn new(params: SpiralParams) -> Self {
+        Self::from_engine(SpiralEngine::new(params))
+    }
+
+    /// Create a reproducible source with a fixed seed
+    pub fn with_seed(params: SpiralParams, seed: u64) -> Self {
+        Self::from_engine(SpiralEngine::with_seed(params, seed))
+    }
+
+    fn from_engine(engine: SpiralEngine) -> Self {
+        Self {
+            engine,
+            best: None,
+            produced: 0,
+        }
+    }
+
+    /// Best signature and resonance produced so far
+    pub fn best(&self) -> Option<(Signature5D, f64)> {
+        self.best
+    }
+
+    fn next_point(&mut self) -> Option<Signature5D> {
+        if let Some(point) = self.engine.next_point() {
+            return Some(point);
+        }
+
+        // Restart the spiral around the best point found
+        if let Some((sig, _)) = self.best {
+            self.engine.set_center_from_signature(&sig);
+        }
+        self.engine.reset();
+        self.engine.next_point()
+    }
+}
+
+impl Default for TritonCandidateSource {
+    fn default() -> Self {
+        Self::new(SpiralParams::default())
+    }
+}
+
+impl CandidateSource for TritonCandidateSource {
+    fn name(&self) -> &str {
+        "triton"
+    }
+
+    fn next_batch(&mut self, n: usize) -> Vec<OperatorCandidate> {
+        let mut batch = Vec::with_capacity(n);
+
+        for _ in 0..n {
+            let phase = self.engine.state().angle.rem_euclid(2.0 * PI);
+            let layer = self.engine.state().layer;
+            let Some(signature) = self.next_point() else {
+                break;
+            };
+
+            let resonance = resonance_5d(&signature);
+            if self.best.is_none_or(|(_, r)| resonance > r) {
+                self.best = Some((signature, resonance));
+            }
+
+            batch.push(OperatorCandidate {
+                id: format!("triton_{}", self.produced),
+                signature,
+                phase,
+                resonance,
+                stability: 1.0 / (1.0 + layer as f64),
+                is_mandorla: is_mandorla(&signature, resonance),
+                node_index: self.produced,
+                discovered_at: self.produced as f64,
+            });
+            self.produced += 1;
+        }
+
+        batch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qops_core::ResonanceTopology;
+
+    #[test]
+    fn test_metatron_source_batch() {
+        let mut source = MetatronCandidateSource::new(AgentConfig {
+            max_steps: 5,
+            ..Default::default()
+        });
+
+        let batch = source.next_batch(4);
+        assert_eq!(batch.len(), 4);
+        for c in &batch {
+            assert!(source.cube().has_node(&petgraph::graph::NodeIndex::new(c.node_index)));
+            assert!((0.0..=2.0 * PI).contains(&c.phase));
+        }
+
+        let config = AgentConfig { max_steps: 5, ..Default::default() };
+        let draw = |seed| MetatronCandidateSource::with_seed(config.clone(), seed).next_batch(4);
+        let trace = |batch: Vec<OperatorCandidate>| -> Vec<(usize, u64)> {
+            batch.iter().map(|c| (c.node_index, c.resonance.to_bits())).collect()
+        };
+        assert_eq!(trace(draw(3)), trace(draw(3)));
+    }
+
+    #[test]
+    fn test_triton_source_restarts() {
+        let params = SpiralParams {
+            layers: 1,
+            points_per_layer: 3,
+            ..Default::default()
+        };
+        let mut source = TritonCandidateSource::with_seed(params, 7);
+
+        // More than one spiral's worth of points
+        let batch = source.next_batch(10);
+        assert_eq!(batch.len(), 10);
+        assert!(source.best().is_some());
+    }
+}
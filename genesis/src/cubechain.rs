@@ -1,8 +1,15 @@
 //! Cubechain - Hypercube-DAG Ledger with Proof-of-Resonance.
 
 use crate::artefact::Artefact;
-use qops_core::{MemoryLedger, LedgerEntry, ResonanceLedger, Signature, ProofOfResonance};
+use crate::metatron_cube::MetatronCube;
+use qops_core::{
+    resonance_5d, MemoryLedger, LedgerEntry, ResonanceLedger, ResonanceTopology,
+    Signature, ProofOfResonance,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
 use uuid::Uuid;
 
 /// Cubechain ledger for operator storage
@@ -11,9 +18,9 @@ pub struct Cubechain {
     /// Underlying ledger
     ledger: MemoryLedger,
     /// Artefact index
-    artefacts: std::collections::HashMap<Uuid, Artefact>,
+    artefacts: HashMap<Uuid, Artefact>,
     /// DAG parents (for hypercube structure)
-    parents: std::collections::HashMap<Uuid, Vec<Uuid>>,
+    parents: HashMap<Uuid, Vec<Uuid>>,
     /// Current epoch
     epoch: usize,
 }
@@ -25,8 +32,8 @@ impl Cubechain {
 
         Self {
             ledger,
-            artefacts: std::collections::HashMap::new(),
-            parents: std::collections::HashMap::new(),
+            artefacts: HashMap::new(),
+            parents: HashMap::new(),
             epoch: 0,
         }
     }
@@ -107,9 +114,140 @@ impl Cubechain {
 
     /// Verify chain integrity
     pub fn verify(&self) -> bool {
+        self.verify_chain().is_ok()
+    }
+
+    /// Verify chain integrity, returning the index of the first tampered block
+    pub fn verify_chain(&self) -> Result<(), usize> {
         self.ledger.verify_chain()
     }
 
+    /// Replay every recorded block against a topology
+    ///
+    /// For each artefact block, the node signature is re-derived from
+    /// `topology` and its resonance recomputed, then compared with the stored
+    /// entry and Proof-of-Resonance. Mismatches indicate tampering or drift
+    /// between the code/topology that produced the ledger and the current one.
+    pub fn replay_verify(&self, topology: &MetatronCube) -> VerificationReport {
+        let mut report = VerificationReport::default();
+        let entries = self.ledger.entries();
+
+        for (index, entry) in entries.iter().enumerate() {
+            report.total_blocks += 1;
+            let before = report.mismatches.len();
+
+            if !entry.verify_hash() {
+                report.push(index, MismatchKind::Hash, entry.hash.clone(), entry.compute_hash());
+            }
+            if index > 0 && entry.prev_hash != entries[index - 1].hash {
+                report.push(index, MismatchKind::ChainLinkage, entries[index - 1].hash.clone(), entry.prev_hash.clone());
+            }
+
+            if entry.entry_type == "artefact" {
+                self.replay_artefact_block(index, entry, topology, &mut report);
+            }
+
+            if report.mismatches.len() == before {
+                report.verified_blocks += 1;
+            }
+        }
+
+        report
+    }
+
+    fn replay_artefact_block(
+        &self,
+        index: usize,
+        entry: &LedgerEntry,
+        topology: &MetatronCube,
+        report: &mut VerificationReport,
+    ) {
+        let artefact: Artefact = match serde_json::from_str(&entry.payload) {
+            Ok(a) => a,
+            Err(e) => {
+                report.push(index, MismatchKind::Payload, "valid artefact".to_string(), e.to_string());
+                return;
+            }
+        };
+
+        let derived = match topology.signature_at(&artefact.node) {
+            Some(sig) => sig.to_5d(),
+            None => {
+                report.push(index, MismatchKind::MissingNode, format!("node {}", artefact.node.index()), "absent".to_string());
+                return;
+            }
+        };
+
+        let stored = entry.signature.to_5d();
+        let distance = stored.distance(&derived);
+        if distance > REPLAY_TOLERANCE {
+            report.push(index, MismatchKind::Signature, format!("{:?}", derived), format!("{:?} (distance {:.6})", stored, distance));
+        }
+
+        let resonance = resonance_5d(&derived);
+        if (entry.resonance - resonance).abs() > REPLAY_TOLERANCE {
+            report.push(index, MismatchKind::Resonance, format!("{:.6}", resonance), format!("{:.6}", entry.resonance));
+        }
+
+        if let Some(proof) = &entry.proof {
+            if !proof.verify() {
+                report.push(index, MismatchKind::Proof, "valid proof".to_string(), "proof fails verification".to_string());
+            } else if (proof.achieved - resonance).abs() > REPLAY_TOLERANCE {
+                report.push(index, MismatchKind::Proof, format!("{:.6}", resonance), format!("{:.6}", proof.achieved));
+            }
+        }
+    }
+
+    /// Save the full chain as JSON
+    ///
+    /// Blocks are written with their hashes and Proof-of-Resonance; artefacts
+    /// are recovered from the block payloads on [`Cubechain::load`].
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let snapshot = CubechainSnapshot {
+            epoch: self.epoch,
+            blocks: self.ledger.entries().to_vec(),
+            parents: self.parents.clone(),
+        };
+        let json = serde_json::to_string_pretty(&snapshot)?;
+        std::fs::write(path, json)
+    }
+
+    /// Load a chain written by [`Cubechain::save`]
+    ///
+    /// Every block's hash and link to its predecessor is re-validated, so a
+    /// corrupted or tampered file yields an `InvalidData` error.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        let snapshot: CubechainSnapshot = serde_json::from_str(&json)?;
+        let invalid = |msg: String| io::Error::new(io::ErrorKind::InvalidData, msg);
+
+        let mut ledger = MemoryLedger::new();
+        let mut artefacts = HashMap::new();
+        for (index, entry) in snapshot.blocks.into_iter().enumerate() {
+            if entry.entry_type == "artefact" {
+                let artefact: Artefact = serde_json::from_str(&entry.payload)
+                    .map_err(|e| invalid(format!("block {}: invalid artefact payload: {}", index, e)))?;
+                artefacts.insert(artefact.id, artefact);
+            }
+            ledger
+                .append(entry)
+                .map_err(|e| invalid(format!("block {}: {}", index, e)))?;
+        }
+        if ledger.is_empty() {
+            return Err(invalid("cubechain has no genesis block".to_string()));
+        }
+        if let Some(id) = snapshot.parents.keys().find(|id| !artefacts.contains_key(id)) {
+            return Err(invalid(format!("parent links for unknown artefact {}", id)));
+        }
+
+        Ok(Self {
+            ledger,
+            artefacts,
+            parents: snapshot.parents,
+            epoch: snapshot.epoch,
+        })
+    }
+
     /// Advance epoch
     pub fn advance_epoch(&mut self) {
         self.epoch += 1;
@@ -156,6 +294,76 @@ pub struct CubechainStats {
     pub current_epoch: usize,
 }
 
+/// On-disk form of a [`Cubechain`]
+#[derive(Serialize, Deserialize)]
+struct CubechainSnapshot {
+    epoch: usize,
+    blocks: Vec<LedgerEntry>,
+    parents: HashMap<Uuid, Vec<Uuid>>,
+}
+
+/// Numerical tolerance when replaying signatures and resonances
+const REPLAY_TOLERANCE: f64 = 1e-9;
+
+/// Kind of discrepancy found during replay verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MismatchKind {
+    /// Stored hash does not match entry contents
+    Hash,
+    /// Entry does not link to its predecessor
+    ChainLinkage,
+    /// Payload could not be decoded as an artefact
+    Payload,
+    /// Artefact node does not exist in the topology
+    MissingNode,
+    /// Stored signature differs from the topology's node signature
+    Signature,
+    /// Stored resonance differs from the recomputed resonance
+    Resonance,
+    /// Proof-of-Resonance is invalid or does not reproduce
+    Proof,
+}
+
+/// A single replay discrepancy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockMismatch {
+    /// Index of the block in the ledger
+    pub block_index: usize,
+    /// What went wrong
+    pub kind: MismatchKind,
+    /// Value re-derived from the topology
+    pub expected: String,
+    /// Value stored in the ledger
+    pub found: String,
+}
+
+/// Result of replaying a Cubechain against a topology
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerificationReport {
+    /// Number of blocks replayed
+    pub total_blocks: usize,
+    /// Number of blocks without any discrepancy
+    pub verified_blocks: usize,
+    /// All discrepancies found
+    pub mismatches: Vec<BlockMismatch>,
+}
+
+impl VerificationReport {
+    fn push(&mut self, block_index: usize, kind: MismatchKind, expected: String, found: String) {
+        self.mismatches.push(BlockMismatch {
+            block_index,
+            kind,
+            expected,
+            found,
+        });
+    }
+
+    /// Whether every block reproduced
+    pub fn is_valid(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,4 +392,79 @@ mod tests {
         assert_eq!(stats.total_artefacts, 1);
         assert_eq!(stats.mandorla_count, 1);
     }
+
+    fn mined_chain(cube: &MetatronCube) -> Cubechain {
+        let mut chain = Cubechain::new();
+        for node in cube.nodes().into_iter().take(5) {
+            let sig = cube.signature_at(&node).unwrap().to_5d();
+            chain.add_artefact(Artefact::new(node, sig), vec![]).unwrap();
+        }
+        chain
+    }
+
+    #[test]
+    fn test_replay_verify_consistent() {
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures();
+        let chain = mined_chain(&cube);
+
+        let report = chain.replay_verify(&cube);
+        assert!(report.is_valid());
+        assert_eq!(report.total_blocks, 6);
+        assert_eq!(report.verified_blocks, 6);
+    }
+
+    #[test]
+    fn test_save_load_roundtrip() {
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures();
+        let mut chain = mined_chain(&cube);
+
+        let mut certified = Artefact::new(NodeIndex::new(1), Signature5D::new(0.9, 0.85, 0.8, 0.7, 0.3));
+        certified.stability = 0.9;
+        certified.check_mandorla(0.85);
+        let parent = chain.artefacts.keys().next().copied().unwrap();
+        let id = certified.id;
+        chain.add_artefact(certified, vec![parent]).unwrap();
+        chain.advance_epoch();
+
+        let path = std::env::temp_dir().join(format!("cubechain_{}.json", Uuid::new_v4()));
+        chain.save(&path).unwrap();
+        let loaded = Cubechain::load(&path).unwrap();
+
+        assert!(loaded.verify());
+        assert_eq!(loaded.len(), chain.len());
+        assert_eq!(loaded.current_epoch(), 1);
+        assert_eq!(loaded.get_parents(&id)[0].id, parent);
+        assert!(loaded.ledger.latest().unwrap().proof.is_some());
+        assert_eq!(loaded.stats().mandorla_count, chain.stats().mandorla_count);
+
+        // Tampering with a stored resonance breaks the block hash
+        let json = std::fs::read_to_string(&path).unwrap();
+        let mut value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        value["blocks"][2]["resonance"] = serde_json::json!(0.99);
+        std::fs::write(&path, value.to_string()).unwrap();
+        let err = Cubechain::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("block 2"));
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_replay_verify_detects_drift() {
+        let mut cube = MetatronCube::new();
+        cube.randomize_signatures();
+        let chain = mined_chain(&cube);
+
+        // Change the topology after mining
+        let drifted = cube.nodes()[2];
+        cube.set_signature(&drifted, Signature::D5(Signature5D::new(0.1, 0.1, 0.1, 0.1, 0.1)));
+
+        let report = chain.replay_verify(&cube);
+        assert!(!report.is_valid());
+        // Block 0 is genesis, so node i lives at block i + 1
+        assert!(report.mismatches.iter().all(|m| m.block_index == 3));
+        assert!(report.mismatches.iter().any(|m| m.kind == MismatchKind::Signature));
+    }
 }
@@ -0,0 +1,227 @@
+//! Repeated-trial evaluation of mining strategies.
+//!
+//! Runs every strategy over the same seeded trials so runs are paired, then
+//! reports per-strategy best-resonance statistics, win rates and a pairwise
+//! paired t-test significance matrix.
+
+use crate::mining::{MiningConfig, MiningSession, MiningStrategy};
+use qops_core::stats::{mix_seed, normal_cdf};
+use serde::{Deserialize, Serialize};
+
+/// Significance level used for the pairwise comparisons
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// Per-strategy results over all trials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyStats {
+    /// Strategy evaluated
+    pub strategy: MiningStrategy,
+    /// Best resonance of each trial, in trial order
+    pub best_resonances: Vec<f64>,
+    /// Mean best resonance
+    pub mean: f64,
+    /// Sample standard deviation of best resonance
+    pub std: f64,
+    /// Fraction of trials this strategy won (ties are shared)
+    pub win_rate: f64,
+}
+
+/// Outcome of evaluating several strategies on the same trials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyEvaluation {
+    /// Statistics per strategy, in input order
+    pub strategies: Vec<StrategyStats>,
+    /// Two-sided p-values, `p_values[i][j]` compares strategy `i` with `j`
+    pub p_values: Vec<Vec<f64>>,
+    /// Seed of each trial (`None` for unseeded trials)
+    pub trial_seeds: Vec<Option<u64>>,
+    /// Significance level applied by [`StrategyEvaluation::is_significant`]
+    pub alpha: f64,
+}
+
+impl StrategyEvaluation {
+    /// Number of trials each strategy was run for
+    pub fn num_trials(&self) -> usize {
+        self.trial_seeds.len()
+    }
+
+    /// Strategy with the highest mean best resonance
+    pub fn best(&self) -> Option<&StrategyStats> {
+        self.strategies
+            .iter()
+            .max_by(|a, b| a.mean.partial_cmp(&b.mean).unwrap())
+    }
+
+    /// Whether strategies `i` and `j` differ significantly
+    pub fn is_significant(&self, i: usize, j: usize) -> bool {
+        self.p_values[i][j] < self.alpha
+    }
+}
+
+/// Evaluate mining strategies over repeated, paired trials
+///
+/// Each seed contributes `trials` runs seeded [`mix_seed`]`(seed, k)`; with no
+/// seeds, `trials` unseeded runs are made. Every strategy sees the same trial
+/// seeds, so trial `k` of each strategy mines the same randomized cube and the
+/// strategies are compared on their per-trial differences.
+pub fn evaluate_strategies(
+    strategies: &[MiningStrategy],
+    config: &MiningConfig,
+    trials: usize,
+    seeds: &[u64],
+) -> StrategyEvaluation {
+    let trial_seeds: Vec<Option<u64>> = if seeds.is_empty() {
+        vec![None; trials]
+    } else {
+        seeds
+            .iter()
+            .flat_map(|&seed| (0..trials as u64).map(move |k| Some(mix_seed(seed, k))))
+            .collect()
+    };
+
+    let results: Vec<Vec<f64>> = strategies
+        .iter()
+        .map(|&strategy| {
+            trial_seeds
+                .iter()
+                .map(|&seed| {
+                    let mut session = MiningSession::new(MiningConfig {
+                        strategy,
+                        seed,
+                        ..config.clone()
+                    });
+                    session.mine().best_resonance
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut wins = vec![0.0; strategies.len()];
+    for trial in 0..trial_seeds.len() {
+        let best = results
+            .iter()
+            .map(|r| r[trial])
+            .fold(f64::MIN, f64::max);
+        let winners: Vec<usize> = (0..strategies.len())
+            .filter(|&i| results[i][trial] == best)
+            .collect();
+        for &i in &winners {
+            wins[i] += 1.0 / winners.len() as f64;
+        }
+    }
+
+    let p_values = (0..strategies.len())
+        .map(|i| {
+            (0..strategies.len())
+                .map(|j| if i == j { 1.0 } else { paired_p_value(&results[i], &results[j]) })
+                .collect()
+        })
+        .collect();
+
+    let num_trials = trial_seeds.len().max(1) as f64;
+    let stats = strategies
+        .iter()
+        .zip(results)
+        .zip(wins)
+        .map(|((&strategy, best_resonances), wins)| {
+            let (mean, std) = mean_std(&best_resonances);
+            StrategyStats {
+                strategy,
+                best_resonances,
+                mean,
+                std,
+                win_rate: wins / num_trials,
+            }
+        })
+        .collect();
+
+    StrategyEvaluation {
+        strategies: stats,
+        p_values,
+        trial_seeds,
+        alpha: SIGNIFICANCE_LEVEL,
+    }
+}
+
+/// Mean and sample standard deviation
+fn mean_std(data: &[f64]) -> (f64, f64) {
+    if data.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let var = if data.len() > 1 {
+        data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    } else {
+        0.0
+    };
+    (mean, var.sqrt())
+}
+
+/// Two-sided paired t-test p-value on per-trial differences (normal approximation)
+fn paired_p_value(a: &[f64], b: &[f64]) -> f64 {
+    let diffs: Vec<f64> = a.iter().zip(b).map(|(x, y)| x - y).collect();
+    if diffs.len() < 2 {
+        return 1.0;
+    }
+
+    let (mean, std) = mean_std(&diffs);
+    let se = std / (diffs.len() as f64).sqrt();
+
+    if se == 0.0 {
+        return if mean == 0.0 { 1.0 } else { 0.0 };
+    }
+
+    let t = mean / se;
+    2.0 * (1.0 - normal_cdf(t.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_session_is_reproducible() {
+        let config = MiningConfig {
+            seed: Some(11),
+            ..MiningConfig::quick()
+        };
+
+        let a = MiningSession::new(config.clone()).mine();
+        let b = MiningSession::new(config).mine();
+        assert_eq!(a.best_resonance, b.best_resonance);
+        assert_eq!(a.stats.avg_resonance, b.stats.avg_resonance);
+    }
+
+    #[test]
+    fn test_evaluate_strategies() {
+        let strategies = [MiningStrategy::Balanced, MiningStrategy::Random];
+        let eval = evaluate_strategies(&strategies, &MiningConfig::quick(), 2, &[1, 100]);
+
+        assert_eq!(eval.num_trials(), 4);
+        let distinct: std::collections::HashSet<_> = eval.trial_seeds.iter().collect();
+        assert_eq!(distinct.len(), 4);
+        assert_eq!(eval.strategies.len(), 2);
+        assert!(eval.strategies.iter().all(|s| s.best_resonances.len() == 4));
+
+        let total_wins: f64 = eval.strategies.iter().map(|s| s.win_rate).sum();
+        assert!((total_wins - 1.0).abs() < 1e-10);
+
+        assert_eq!(eval.p_values[0][0], 1.0);
+        assert_eq!(eval.p_values[0][1], eval.p_values[1][0]);
+        assert!(eval.best().is_some());
+    }
+
+    #[test]
+    fn test_paired_p_value() {
+        let a = [0.50, 0.51, 0.49, 0.50, 0.52];
+        let b = [0.80, 0.81, 0.79, 0.82, 0.80];
+        assert!(paired_p_value(&a, &b) < SIGNIFICANCE_LEVEL);
+        assert!(paired_p_value(&a, &a) > 0.99);
+
+        // A consistent per-trial gain is significant despite wide trial spread
+        let a = [0.1, 0.5, 0.9, 0.3, 0.7];
+        let b = [0.12, 0.51, 0.92, 0.31, 0.72];
+        assert!(paired_p_value(&a, &b) < SIGNIFICANCE_LEVEL);
+    }
+}
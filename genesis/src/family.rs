@@ -3,8 +3,9 @@
 //! Families group similar operators based on signature proximity.
 
 use crate::artefact::Artefact;
-use qops_core::Signature5D;
+use qops_core::{DistanceMetric, Signature5D};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
 /// An operator family - a group of similar artefacts
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -61,6 +62,16 @@ impl OperatorFamily {
         family
     }
 
+    /// Create family from a complete member list, computing the centroid once
+    fn from_members(members: Vec<Artefact>) -> Self {
+        let mut family = Self::new();
+        family.members = members;
+        family.update_centroid();
+        family.update_characteristics();
+        family.update_name();
+        family
+    }
+
     /// Add member to family
     pub fn add_member(&mut self, artefact: Artefact) {
         self.members.push(artefact);
@@ -71,47 +82,21 @@ impl OperatorFamily {
 
     /// Check if signature is similar to family
     pub fn is_similar(&self, sig: &Signature5D, threshold: f64) -> bool {
+        self.is_similar_with(sig, threshold, DistanceMetric::Euclidean)
+    }
+
+    /// Check if a signature is within `threshold` of the centroid under `metric`
+    pub fn is_similar_with(&self, sig: &Signature5D, threshold: f64, metric: DistanceMetric) -> bool {
         if let Some(centroid) = &self.centroid {
-            Self::signature_distance(sig, centroid) < threshold
+            sig.distance_with(centroid, metric) < threshold
         } else {
             true // Empty family accepts anything
         }
     }
 
-    /// Compute distance between two signatures
-    fn signature_distance(a: &Signature5D, b: &Signature5D) -> f64 {
-        ((a.psi - b.psi).powi(2) +
-         (a.rho - b.rho).powi(2) +
-         (a.omega - b.omega).powi(2) +
-         (a.chi - b.chi).powi(2) +
-         (a.eta - b.eta).powi(2)).sqrt()
-    }
-
     /// Update centroid from members
     fn update_centroid(&mut self) {
-        if self.members.is_empty() {
-            self.centroid = None;
-            return;
-        }
-
-        let n = self.members.len() as f64;
-        let mut sum = Signature5D::new(0.0, 0.0, 0.0, 0.0, 0.0);
-
-        for member in &self.members {
-            sum.psi += member.signature.psi;
-            sum.rho += member.signature.rho;
-            sum.omega += member.signature.omega;
-            sum.chi += member.signature.chi;
-            sum.eta += member.signature.eta;
-        }
-
-        self.centroid = Some(Signature5D::new(
-            sum.psi / n,
-            sum.rho / n,
-            sum.omega / n,
-            sum.chi / n,
-            sum.eta / n,
-        ));
+        self.centroid = Signature5D::centroid_of(self.members.iter().map(|m| &m.signature));
     }
 
     /// Update family characteristics
@@ -144,7 +129,7 @@ impl OperatorFamily {
         // Compute spread (average distance from centroid)
         let spread = if let Some(centroid) = &self.centroid {
             self.members.iter()
-                .map(|m| Self::signature_distance(&m.signature, centroid))
+                .map(|m| m.signature.distance(centroid))
                 .sum::<f64>() / n
         } else {
             0.0
@@ -243,12 +228,76 @@ impl Default for OperatorFamily {
     }
 }
 
+/// Canonical order artefacts are visited in before clustering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ClusterOrder {
+    /// Highest resonance first, so the strongest artefacts seed families
+    #[default]
+    Resonance,
+    /// Lexicographic by signature (ψ, ρ, ω, χ, η)
+    Signature,
+}
+
+impl ClusterOrder {
+    /// Compare two artefacts; remaining ties are broken by signature, then ID
+    fn compare(&self, a: &Artefact, b: &Artefact) -> Ordering {
+        let by_signature = || {
+            a.signature
+                .to_vec()
+                .iter()
+                .zip(b.signature.to_vec().iter())
+                .map(|(x, y)| x.total_cmp(y))
+                .find(|o| o.is_ne())
+                .unwrap_or(Ordering::Equal)
+        };
+        let primary = match self {
+            Self::Resonance => b.resonance.total_cmp(&a.resonance),
+            Self::Signature => Ordering::Equal,
+        };
+        primary.then_with(by_signature).then_with(|| a.id.cmp(&b.id))
+    }
+}
+
+/// Algorithm [`FamilyClusterer`] groups artefacts with
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ClusterAlgorithm {
+    /// Greedy: join the first family whose centroid is within `threshold`
+    #[default]
+    Threshold,
+    /// Lloyd's k-means with farthest-point seeding
+    KMeans {
+        /// Number of families
+        k: usize,
+    },
+    /// Density-based clustering; artefacts in sparse regions are left out
+    DBSCAN {
+        /// Neighborhood radius
+        eps: f64,
+        /// Neighbors (including itself) an artefact needs to be a core point
+        min_pts: usize,
+    },
+}
+
+/// Lloyd iterations k-means runs before giving up on convergence
+const KMEANS_MAX_ITERATIONS: usize = 100;
+
 /// Family clustering algorithm
+///
+/// Inputs are first sorted into a canonical [`ClusterOrder`], which fixes
+/// greedy assignment, k-means seeding and DBSCAN expansion, so the same
+/// artefacts yield the same families regardless of the order they are
+/// passed in.
 pub struct FamilyClusterer {
     /// Distance threshold for grouping
     pub threshold: f64,
-    /// Maximum families to create
+    /// Maximum families to create (threshold clustering only)
     pub max_families: usize,
+    /// Order artefacts are assigned in
+    pub order: ClusterOrder,
+    /// Metric distances are measured in
+    pub metric: DistanceMetric,
+    /// Clustering algorithm
+    pub algorithm: ClusterAlgorithm,
 }
 
 impl FamilyClusterer {
@@ -257,23 +306,66 @@ impl FamilyClusterer {
         Self {
             threshold,
             max_families: 20,
+            order: ClusterOrder::default(),
+            metric: DistanceMetric::default(),
+            algorithm: ClusterAlgorithm::default(),
         }
     }
 
+    /// Set the canonical assignment order
+    pub fn with_order(mut self, order: ClusterOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Set the distance metric
+    pub fn with_metric(mut self, metric: DistanceMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    /// Set the clustering algorithm
+    pub fn with_algorithm(mut self, algorithm: ClusterAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Cluster artefacts into families
     pub fn cluster(&self, artefacts: &[Artefact]) -> Vec<OperatorFamily> {
         if artefacts.is_empty() {
             return Vec::new();
         }
 
+        let mut sorted: Vec<&Artefact> = artefacts.iter().collect();
+        sorted.sort_by(|a, b| self.order.compare(a, b));
+
+        let mut families = match self.algorithm {
+            ClusterAlgorithm::Threshold => self.cluster_threshold(&sorted),
+            ClusterAlgorithm::KMeans { k } => self.cluster_kmeans(&sorted, k),
+            ClusterAlgorithm::DBSCAN { eps, min_pts } => self.cluster_dbscan(&sorted, eps, min_pts),
+        };
+
+        // Sort by average resonance (descending)
+        families.sort_by(|a, b| {
+            b.avg_resonance().partial_cmp(&a.avg_resonance()).unwrap()
+        });
+
+        families
+    }
+
+    fn distance(&self, a: &Signature5D, b: &Signature5D) -> f64 {
+        a.distance_with(b, self.metric)
+    }
+
+    fn cluster_threshold(&self, sorted: &[&Artefact]) -> Vec<OperatorFamily> {
         let mut families: Vec<OperatorFamily> = Vec::new();
 
-        for artefact in artefacts {
+        for &artefact in sorted {
             let mut assigned = false;
 
             // Try to find a matching family
             for family in &mut families {
-                if family.is_similar(&artefact.signature, self.threshold) {
+                if family.is_similar_with(&artefact.signature, self.threshold, self.metric) {
                     family.add_member(artefact.clone());
                     assigned = true;
                     break;
@@ -288,10 +380,10 @@ impl FamilyClusterer {
                 if let Some(closest) = families.iter_mut()
                     .min_by(|a, b| {
                         let dist_a = a.centroid()
-                            .map(|c| Self::distance(&artefact.signature, c))
+                            .map(|c| self.distance(&artefact.signature, c))
                             .unwrap_or(f64::MAX);
                         let dist_b = b.centroid()
-                            .map(|c| Self::distance(&artefact.signature, c))
+                            .map(|c| self.distance(&artefact.signature, c))
                             .unwrap_or(f64::MAX);
                         dist_a.partial_cmp(&dist_b).unwrap()
                     })
@@ -301,20 +393,97 @@ impl FamilyClusterer {
             }
         }
 
-        // Sort by average resonance (descending)
-        families.sort_by(|a, b| {
-            b.avg_resonance().partial_cmp(&a.avg_resonance()).unwrap()
-        });
-
         families
     }
 
-    fn distance(a: &Signature5D, b: &Signature5D) -> f64 {
-        ((a.psi - b.psi).powi(2) +
-         (a.rho - b.rho).powi(2) +
-         (a.omega - b.omega).powi(2) +
-         (a.chi - b.chi).powi(2) +
-         (a.eta - b.eta).powi(2)).sqrt()
+    fn cluster_kmeans(&self, sorted: &[&Artefact], k: usize) -> Vec<OperatorFamily> {
+        let k = k.clamp(1, sorted.len());
+        let nearest = |sig: &Signature5D, centers: &[Signature5D]| -> (usize, f64) {
+            centers
+                .iter()
+                .map(|c| self.distance(sig, c))
+                .enumerate()
+                .fold((0, f64::MAX), |best, (i, d)| if d < best.1 { (i, d) } else { best })
+        };
+
+        // Seed with the first artefact, then repeatedly the one farthest from every center
+        let mut centers = vec![sorted[0].signature];
+        while centers.len() < k {
+            let (farthest, _) = sorted
+                .iter()
+                .map(|a| nearest(&a.signature, &centers).1)
+                .enumerate()
+                .fold((0, f64::MIN), |best, (i, d)| if d > best.1 { (i, d) } else { best });
+            centers.push(sorted[farthest].signature);
+        }
+
+        let mut assignment = vec![usize::MAX; sorted.len()];
+        for _ in 0..KMEANS_MAX_ITERATIONS {
+            let next: Vec<usize> = sorted.iter().map(|a| nearest(&a.signature, &centers).0).collect();
+            if next == assignment {
+                break;
+            }
+            assignment = next;
+
+            for (c, center) in centers.iter_mut().enumerate() {
+                let members = sorted.iter().zip(&assignment).filter(|(_, &a)| a == c);
+                // An empty cluster keeps its previous center
+                if let Some(centroid) = Signature5D::centroid_of(members.map(|(m, _)| &m.signature)) {
+                    *center = centroid;
+                }
+            }
+        }
+
+        let mut groups: Vec<Vec<Artefact>> = vec![Vec::new(); k];
+        for (&artefact, &c) in sorted.iter().zip(&assignment) {
+            groups[c].push(artefact.clone());
+        }
+        groups.into_iter().filter(|g| !g.is_empty()).map(OperatorFamily::from_members).collect()
+    }
+
+    fn cluster_dbscan(&self, sorted: &[&Artefact], eps: f64, min_pts: usize) -> Vec<OperatorFamily> {
+        let region = |i: usize| -> Vec<usize> {
+            (0..sorted.len())
+                .filter(|&j| self.distance(&sorted[i].signature, &sorted[j].signature) <= eps)
+                .collect()
+        };
+
+        let mut visited = vec![false; sorted.len()];
+        let mut in_cluster = vec![false; sorted.len()];
+        let mut groups: Vec<Vec<Artefact>> = Vec::new();
+
+        for start in 0..sorted.len() {
+            if visited[start] {
+                continue;
+            }
+            visited[start] = true;
+            let neighbors = region(start);
+            if neighbors.len() < min_pts {
+                continue; // Noise, unless a later core point reaches it
+            }
+
+            let mut members = vec![start];
+            in_cluster[start] = true;
+            let mut frontier = neighbors;
+            while let Some(i) = frontier.pop() {
+                if !in_cluster[i] {
+                    in_cluster[i] = true;
+                    members.push(i);
+                }
+                if !visited[i] {
+                    visited[i] = true;
+                    let reach = region(i);
+                    if reach.len() >= min_pts {
+                        frontier.extend(reach.into_iter().filter(|&j| !in_cluster[j]));
+                    }
+                }
+            }
+
+            members.sort_unstable();
+            groups.push(members.into_iter().map(|i| sorted[i].clone()).collect());
+        }
+
+        groups.into_iter().map(OperatorFamily::from_members).collect()
     }
 }
 
@@ -324,6 +493,80 @@ impl Default for FamilyClusterer {
     }
 }
 
+/// Symmetric matrix of pairwise signature distances between artefacts
+///
+/// Uses [`Signature5D::distance`], the default metric [`FamilyClusterer`] groups by.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimilarityMatrix {
+    /// Artefact IDs, in row/column order
+    pub ids: Vec<String>,
+    /// Artefact resonances, in row/column order
+    pub resonances: Vec<f64>,
+    /// `distances[i][j]` is the distance between artefacts `i` and `j`
+    pub distances: Vec<Vec<f64>>,
+}
+
+impl SimilarityMatrix {
+    /// Build the matrix for the given artefacts
+    pub fn from_artefacts(artefacts: &[Artefact]) -> Self {
+        let n = artefacts.len();
+        let mut distances = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = artefacts[i].signature.distance(&artefacts[j].signature);
+                distances[i][j] = d;
+                distances[j][i] = d;
+            }
+        }
+
+        Self {
+            ids: artefacts.iter().map(|a| a.id.to_string()).collect(),
+            resonances: artefacts.iter().map(|a| a.resonance).collect(),
+            distances,
+        }
+    }
+
+    /// Number of artefacts
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Whether the matrix is empty
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Distance between artefacts `i` and `j`
+    pub fn distance(&self, i: usize, j: usize) -> f64 {
+        self.distances[i][j]
+    }
+
+    /// Export as JSON
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Export as CSV with artefact IDs as row and column headers
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("id");
+        for id in &self.ids {
+            csv.push(',');
+            csv.push_str(id);
+        }
+        csv.push('\n');
+
+        for (id, row) in self.ids.iter().zip(&self.distances) {
+            csv.push_str(id);
+            for d in row {
+                csv.push_str(&format!(",{:.6}", d));
+            }
+            csv.push('\n');
+        }
+
+        csv
+    }
+}
+
 /// Family evaluation metrics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FamilyMetrics {
@@ -343,6 +586,9 @@ pub struct FamilyMetrics {
     pub mandorla_rate: f64,
     /// Quality score (composite)
     pub quality_score: f64,
+    /// Mean silhouette of the members against the other families, in [-1, 1]
+    #[serde(default)]
+    pub silhouette: Option<f64>,
 }
 
 impl FamilyMetrics {
@@ -370,8 +616,78 @@ impl FamilyMetrics {
             coherence,
             mandorla_rate,
             quality_score,
+            silhouette: None,
         }
     }
+
+    /// Compute metrics for every family of a clustering, including silhouettes
+    pub fn for_families(families: &[OperatorFamily], metric: DistanceMetric) -> Vec<Self> {
+        let silhouettes = member_silhouettes(families, metric);
+        families
+            .iter()
+            .zip(silhouettes)
+            .map(|(family, scores)| {
+                let mut metrics = Self::from_family(family);
+                if families.len() > 1 && !scores.is_empty() {
+                    metrics.silhouette = Some(scores.iter().sum::<f64>() / scores.len() as f64);
+                }
+                metrics
+            })
+            .collect()
+    }
+}
+
+/// Mean silhouette over all members of a clustering
+///
+/// Near 1 when families are compact and well separated, near 0 when they
+/// overlap. `None` for fewer than two families, where it is undefined.
+pub fn silhouette_score(families: &[OperatorFamily], metric: DistanceMetric) -> Option<f64> {
+    if families.len() < 2 {
+        return None;
+    }
+    let scores: Vec<f64> = member_silhouettes(families, metric).into_iter().flatten().collect();
+    if scores.is_empty() {
+        return None;
+    }
+    Some(scores.iter().sum::<f64>() / scores.len() as f64)
+}
+
+/// Silhouette of every member, grouped by family
+///
+/// Members of single-member families score 0 by convention.
+fn member_silhouettes(families: &[OperatorFamily], metric: DistanceMetric) -> Vec<Vec<f64>> {
+    let mean_distance = |sig: &Signature5D, family: &OperatorFamily, skip_self: bool| -> f64 {
+        let others = family.members().len() - usize::from(skip_self);
+        if others == 0 {
+            return 0.0;
+        }
+        family.members().iter().map(|m| sig.distance_with(&m.signature, metric)).sum::<f64>() / others as f64
+    };
+
+    families
+        .iter()
+        .enumerate()
+        .map(|(f, family)| {
+            family
+                .members()
+                .iter()
+                .map(|member| {
+                    if family.size() < 2 {
+                        return 0.0;
+                    }
+                    let a = mean_distance(&member.signature, family, true);
+                    let b = families
+                        .iter()
+                        .enumerate()
+                        .filter(|&(g, other)| g != f && other.size() > 0)
+                        .map(|(_, other)| mean_distance(&member.signature, other, false))
+                        .fold(f64::MAX, f64::min);
+                    let denom = a.max(b);
+                    if denom > 0.0 && b < f64::MAX { (b - a) / denom } else { 0.0 }
+                })
+                .collect()
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -419,4 +735,134 @@ mod tests {
         let families = clusterer.cluster(&artefacts);
         assert!(!families.is_empty());
     }
+
+    #[test]
+    fn test_cosine_clustering_groups_by_direction() {
+        // Three scales along each of two directions
+        let artefacts: Vec<Artefact> = [0.2, 0.5, 0.8]
+            .into_iter()
+            .flat_map(|s| {
+                [
+                    Artefact::from_signature(Signature5D::new(s, s, 0.0, 0.0, 0.0)),
+                    Artefact::from_signature(Signature5D::new(0.0, 0.0, s, s, s)),
+                ]
+            })
+            .collect();
+
+        let euclidean = FamilyClusterer::new(0.2).cluster(&artefacts);
+        assert_eq!(euclidean.len(), 6);
+
+        let cosine = FamilyClusterer::new(0.2).with_metric(DistanceMetric::Cosine).cluster(&artefacts);
+        assert_eq!(cosine.len(), 2);
+        assert!(cosine.iter().all(|f| f.members().len() == 3));
+    }
+
+    #[test]
+    fn test_clustering_is_order_independent() {
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let artefacts: Vec<Artefact> = (0..30)
+            .map(|i| {
+                let x = (i as f64 * 0.37).fract();
+                let y = (i as f64 * 0.61).fract();
+                Artefact::from_signature(Signature5D::new(x, y, 0.5, 0.5, 0.2))
+            })
+            .collect();
+
+        let member_ids = |families: &[OperatorFamily]| -> Vec<Vec<uuid::Uuid>> {
+            families
+                .iter()
+                .map(|f| f.members().iter().map(|m| m.id).collect())
+                .collect()
+        };
+
+        for order in [ClusterOrder::Resonance, ClusterOrder::Signature] {
+            let clusterer = FamilyClusterer::new(0.2).with_order(order);
+            let expected = member_ids(&clusterer.cluster(&artefacts));
+
+            let mut rng = rand::rngs::StdRng::seed_from_u64(9);
+            for _ in 0..5 {
+                let mut shuffled = artefacts.clone();
+                shuffled.shuffle(&mut rng);
+                assert_eq!(member_ids(&clusterer.cluster(&shuffled)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cluster_algorithms_on_dense_blobs() {
+        // Two dense blobs, each wider than the threshold along ψ
+        let artefacts: Vec<Artefact> = (0..20)
+            .map(|i| {
+                let offset = if i < 10 { 0.1 } else { 0.6 };
+                let psi = offset + (i % 10) as f64 * 0.015;
+                Artefact::from_signature(Signature5D::new(psi, 0.5, 0.5, 0.5, 0.2))
+            })
+            .collect();
+
+        let threshold = FamilyClusterer::new(0.05).cluster(&artefacts);
+        assert!(threshold.len() > 2);
+
+        let kmeans = FamilyClusterer::default()
+            .with_algorithm(ClusterAlgorithm::KMeans { k: 2 })
+            .cluster(&artefacts);
+        let dbscan = FamilyClusterer::default()
+            .with_algorithm(ClusterAlgorithm::DBSCAN { eps: 0.02, min_pts: 3 })
+            .cluster(&artefacts);
+
+        for families in [&kmeans, &dbscan] {
+            assert_eq!(families.len(), 2);
+            assert!(families.iter().all(|f| f.size() == 10 && f.centroid().is_some()));
+        }
+
+        let metric = DistanceMetric::Euclidean;
+        let kmeans_score = silhouette_score(&kmeans, metric).unwrap();
+        assert!(kmeans_score > 0.8);
+        assert!(kmeans_score > silhouette_score(&threshold, metric).unwrap());
+        assert!(silhouette_score(&kmeans[..1], metric).is_none());
+
+        let metrics = FamilyMetrics::for_families(&kmeans, metric);
+        assert!(metrics.iter().all(|m| m.silhouette.is_some_and(|s| s > 0.8)));
+        assert!(FamilyMetrics::from_family(&kmeans[0]).silhouette.is_none());
+    }
+
+    #[test]
+    fn test_dbscan_leaves_out_noise() {
+        let mut artefacts: Vec<Artefact> = (0..5)
+            .map(|i| Artefact::from_signature(Signature5D::new(0.3 + i as f64 * 0.01, 0.5, 0.5, 0.5, 0.2)))
+            .collect();
+        artefacts.push(Artefact::from_signature(Signature5D::new(0.9, 0.1, 0.9, 0.1, 0.9)));
+
+        let families = FamilyClusterer::default()
+            .with_algorithm(ClusterAlgorithm::DBSCAN { eps: 0.05, min_pts: 3 })
+            .cluster(&artefacts);
+        assert_eq!(families.len(), 1);
+        assert_eq!(families[0].size(), 5);
+    }
+
+    #[test]
+    fn test_similarity_matrix() {
+        let artefacts = vec![
+            Artefact::from_signature(Signature5D::new(0.8, 0.7, 0.6, 0.5, 0.2)),
+            Artefact::from_signature(Signature5D::new(0.81, 0.71, 0.61, 0.51, 0.21)),
+            Artefact::from_signature(Signature5D::new(0.3, 0.3, 0.3, 0.3, 0.3)),
+        ];
+        let matrix = SimilarityMatrix::from_artefacts(&artefacts);
+
+        assert_eq!(matrix.len(), 3);
+        for i in 0..3 {
+            assert_eq!(matrix.distance(i, i), 0.0);
+            for j in 0..3 {
+                assert_eq!(matrix.distance(i, j), matrix.distance(j, i));
+            }
+        }
+        assert!(matrix.distance(0, 1) < matrix.distance(0, 2));
+
+        let csv = matrix.to_csv();
+        assert_eq!(csv.lines().count(), 4);
+        assert!(csv.starts_with("id,"));
+        let parsed: SimilarityMatrix = serde_json::from_str(&matrix.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.distances, matrix.distances);
+    }
 }
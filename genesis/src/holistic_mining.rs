@@ -12,10 +12,10 @@ use crate::agent::{Agent, AgentConfig};
 use crate::artefact::Artefact;
 use crate::metatron_cube::MetatronCube;
 use crate::family::OperatorFamily;
-use crate::mining::{MiningConfig, MiningStrategy, MiningStats};
+use crate::mining::{ConvergenceCriterion, MiningConfig, MiningStrategy, MiningStats};
 
 use qops_core::{
-    Signature5D,
+    Signature5D, CandidateSource,
     GenesisStage, HolisticMatrix, HolisticConfig, HolisticStats,
     KosmokratorConfig, ChronokratorConfig, PfauenthronConfig,
     OperatorCandidate, ExkalibrationVector, Monolith, FinalizedFamily,
@@ -25,6 +25,8 @@ use qops_triton::{
     TritonConfig, SpiralParams,
 };
 
+use petgraph::graph::NodeIndex;
+use std::collections::HashSet;
 use serde::{Deserialize, Serialize};
 use rayon::prelude::*;
 
@@ -260,13 +262,28 @@ pub struct HolisticMiningResult {
 // HOLISTIC MINING SESSION
 // ============================================================================
 
+/// Callback invoked after each pipeline stage with the live matrix statistics
+pub type StageObserver = Box<dyn FnMut(GenesisStage, &HolisticStats) + Send>;
+
 /// Holistic multi-stage mining session
 pub struct HolisticMiningSession {
     config: HolisticMiningConfig,
     cube: MetatronCube,
+    /// Optional candidate source replacing agent discovery
+    source: Option<Box<dyn CandidateSource>>,
+    /// Optional per-stage progress callback
+    observer: Option<StageObserver>,
     matrix: HolisticMatrix,
     triton: Option<AdaptiveTritonOptimizer>,
     artefacts: Vec<Artefact>,
+    /// Nodes visited by discovery agents along their paths
+    visited: HashSet<NodeIndex>,
+    /// Best discovery resonance seen so far
+    best_so_far: f64,
+    /// Agent steps since `best_so_far` last improved
+    steps_since_improvement: usize,
+    /// Criterion that stopped agent discovery, once met
+    converged_by: Option<ConvergenceCriterion>,
     candidates: Vec<OperatorCandidate>,
     stage_log: Vec<StageLogEntry>,
     session_id: String,
@@ -292,6 +309,7 @@ impl HolisticMiningSession {
             kosmokrator: config.kosmokrator.clone(),
             chronokrator: config.chronokrator.clone(),
             pfauenthron: config.pfauenthron.clone(),
+            ..Default::default()
         };
 
         let matrix = HolisticMatrix::new(holistic_config);
@@ -305,9 +323,15 @@ impl HolisticMiningSession {
         Self {
             config,
             cube,
+            source: None,
+            observer: None,
             matrix,
             triton,
             artefacts: Vec::new(),
+            visited: HashSet::new(),
+            best_so_far: 0.0,
+            steps_since_improvement: 0,
+            converged_by: None,
             candidates: Vec::new(),
             stage_log: Vec::new(),
             session_id: uuid::Uuid::new_v4().to_string(),
@@ -321,6 +345,35 @@ impl HolisticMiningSession {
         }
     }
 
+    /// Use a candidate source for the Discovery stage
+    ///
+    /// Each run draws `mining.num_agents` candidates from the source instead
+    /// of running the built-in agent exploration.
+    pub fn with_candidate_source<S: CandidateSource + 'static>(mut self, source: S) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+
+    /// Name of the configured candidate source, if any
+    pub fn candidate_source_name(&self) -> Option<&str> {
+        self.source.as_ref().map(|s| s.name())
+    }
+
+    /// Observe the pipeline: `observer` is called after every stage with
+    /// the holistic matrix statistics at that point
+    pub fn with_stage_observer<F>(mut self, observer: F) -> Self
+    where
+        F: FnMut(GenesisStage, &HolisticStats) + Send + 'static,
+    {
+        self.observer = Some(Box::new(observer));
+        self
+    }
+
+    /// Current holistic matrix statistics
+    pub fn holistic_stats(&self) -> HolisticStats {
+        self.matrix.stats()
+    }
+
     /// Run full holistic mining pipeline
     pub fn mine(&mut self) -> HolisticMiningResult {
         self.start_time = Some(std::time::Instant::now());
@@ -351,14 +404,21 @@ impl HolisticMiningSession {
         let mut unique_nodes = std::collections::HashSet::new();
         let mut max_resonance = 0.0f64;
 
-        // Run agent-based exploration
-        if self.config.mining.parallel && self.config.mining.num_agents > 1 {
-            self.parallel_agent_discovery();
-        } else {
-            self.sequential_agent_discovery();
-        }
+        // Draw from the configured source, or run agent-based exploration
+        let sourced = match &mut self.source {
+            Some(source) => source.next_batch(self.config.mining.num_agents),
+            None => {
+                if self.config.mining.parallel && self.config.mining.num_agents > 1 {
+                    self.parallel_agent_discovery();
+                } else {
+                    self.sequential_agent_discovery();
+                }
+                Vec::new()
+            }
+        };
 
         // Run adaptive TRITON if enabled
+        let mut triton_best = None;
         if let Some(triton) = &mut self.triton {
             let result = triton.optimize();
 
@@ -373,14 +433,19 @@ impl HolisticMiningSession {
 
             let artefact = Artefact::from_signature(sig);
             self.artefacts.push(artefact);
+            triton_best = Some((sig, result.best_score, result.converged));
+        }
+
+        let threshold = self.mandorla_threshold();
 
+        if let Some((sig, best_score, converged)) = triton_best {
             self.candidates.push(OperatorCandidate {
                 id: format!("triton_best"),
                 signature: sig,
                 phase: 0.0,
-                resonance: result.best_score,
-                stability: if result.converged { 1.0 } else { 0.5 },
-                is_mandorla: result.best_score >= 0.85,
+                resonance: best_score,
+                stability: if converged { 1.0 } else { 0.5 },
+                is_mandorla: best_score >= threshold,
                 node_index: 0,
                 discovered_at: self.current_step as f64,
             });
@@ -398,7 +463,7 @@ impl HolisticMiningSession {
                 phase: artefact.resonance * std::f64::consts::PI,
                 resonance: artefact.resonance,
                 stability: 0.5,
-                is_mandorla: artefact.is_mandorla(),
+                is_mandorla: artefact.is_mandorla_at(threshold),
                 node_index: artefact.node.index(),
                 discovered_at: self.current_step as f64,
             });
@@ -406,6 +471,20 @@ impl HolisticMiningSession {
             self.resonance_timeline.push(artefact.resonance);
         }
 
+        // Sourced candidates keep their own phase and stability
+        for candidate in sourced {
+            nodes_visited += 1;
+            unique_nodes.insert(NodeIndex::new(candidate.node_index));
+            max_resonance = max_resonance.max(candidate.resonance);
+
+            let mut artefact = Artefact::new(NodeIndex::new(candidate.node_index), candidate.signature);
+            artefact.stability = candidate.stability;
+            self.artefacts.push(artefact);
+
+            self.resonance_timeline.push(candidate.resonance);
+            self.candidates.push(candidate);
+        }
+
         self.current_step += self.artefacts.len();
 
         // Track candidates discovered
@@ -419,44 +498,66 @@ impl HolisticMiningSession {
     }
 
     fn sequential_agent_discovery(&mut self) {
-        let agent_config = AgentConfig {
-            max_steps: self.config.mining.steps_per_agent,
-            strategy: self.config.mining.strategy.to_traversal_strategy(),
-            exploration_rate: self.config.mining.exploration_rate,
-            target_resonance: self.config.mining.target_resonance,
-        };
+        let agent_config = self.config.mining.agent_config();
 
         for _ in 0..self.config.mining.num_agents {
+            if self.converged_by.is_some() {
+                break;
+            }
+
             let mut agent = Agent::new(self.cube.identity_node(), agent_config.clone());
             agent.traverse(&self.cube);
 
             let artefact = Artefact::new(agent.position, agent.signature);
+            self.record_progress(artefact.resonance, agent.steps);
             self.artefacts.push(artefact);
+            self.visited.extend(agent.path);
         }
     }
 
     fn parallel_agent_discovery(&mut self) {
         let configs: Vec<AgentConfig> = (0..self.config.mining.num_agents)
-            .map(|_| AgentConfig {
-                max_steps: self.config.mining.steps_per_agent,
-                strategy: self.config.mining.strategy.to_traversal_strategy(),
-                exploration_rate: self.config.mining.exploration_rate,
-                target_resonance: self.config.mining.target_resonance,
-            })
+            .map(|_| self.config.mining.agent_config())
             .collect();
 
-        let artefacts: Vec<Artefact> = configs
+        let artefacts: Vec<(Artefact, Vec<NodeIndex>)> = configs
             .par_iter()
             .map(|config| {
                 let mut cube = MetatronCube::new();
                 cube.randomize_signatures();
                 let mut agent = Agent::new(cube.identity_node(), config.clone());
                 agent.traverse(&cube);
-                Artefact::new(agent.position, agent.signature)
+                (Artefact::new(agent.position, agent.signature), agent.path)
             })
             .collect();
 
-        self.artefacts.extend(artefacts);
+        // Results past convergence are dropped so the output matches a sequential run
+        for (artefact, path) in artefacts {
+            if self.converged_by.is_some() {
+                break;
+            }
+            self.record_progress(artefact.resonance, path.len() - 1);
+            self.visited.extend(path);
+            self.artefacts.push(artefact);
+        }
+    }
+
+    /// Track the best discovery resonance, checking `mining.convergence`
+    fn record_progress(&mut self, resonance: f64, steps: usize) {
+        if resonance > self.best_so_far {
+            self.best_so_far = resonance;
+            self.steps_since_improvement = 0;
+        } else {
+            self.steps_since_improvement += steps;
+        }
+
+        if self.converged_by.is_none() {
+            self.converged_by = self
+                .config
+                .mining
+                .convergence
+                .triggered(self.best_so_far, self.steps_since_improvement);
+        }
     }
 
     /// Stage 2: Kosmokrator Filter - Apply PoR-based exclusion
@@ -615,6 +716,11 @@ impl HolisticMiningSession {
                 metrics,
             });
         }
+
+        if let Some(observer) = &mut self.observer {
+            let stats = self.matrix.stats();
+            observer(stage, &stats);
+        }
     }
 
     /// Build final result
@@ -623,7 +729,16 @@ impl HolisticMiningSession {
             .map(|t| t.elapsed().as_millis() as u64)
             .unwrap_or(0);
 
-        let best_artefact = self.artefacts.iter()
+        let threshold = self.mandorla_threshold();
+        let artefacts: Vec<Artefact> = self.artefacts.iter()
+            .cloned()
+            .map(|mut a| {
+                a.is_mandorla = a.is_mandorla_at(threshold);
+                a
+            })
+            .collect();
+
+        let best_artefact = artefacts.iter()
             .max_by(|a, b| a.resonance.partial_cmp(&b.resonance).unwrap())
             .cloned();
 
@@ -631,8 +746,8 @@ impl HolisticMiningSession {
             .map(|a| a.resonance)
             .unwrap_or(0.0);
 
-        let mandorla_count = self.artefacts.iter()
-            .filter(|a| a.is_mandorla())
+        let mandorla_count = artefacts.iter()
+            .filter(|a| a.is_mandorla)
             .count();
 
         // Extract families using standard method
@@ -670,6 +785,7 @@ impl HolisticMiningSession {
                 },
                 convergence_point: None,
                 holistic_output: None,
+                bias_history: Vec::new(),
             }
         });
 
@@ -678,7 +794,7 @@ impl HolisticMiningSession {
         HolisticMiningResult {
             session_id: self.session_id.clone(),
             final_stage: self.current_stage,
-            artefacts: self.artefacts.clone(),
+            artefacts,
             best_artefact,
             best_resonance,
             total_steps: self.current_step,
@@ -741,7 +857,11 @@ impl HolisticMiningSession {
                 max_resonance: 0.0,
                 num_agents: 0,
                 unique_nodes: 0,
+                nodes_visited: 0,
                 efficiency: 0.0,
+                mandorla_threshold: self.mandorla_threshold(),
+                converged_by: self.converged_by.clone(),
+                parallel_speedup: None,
             };
         }
 
@@ -760,12 +880,13 @@ impl HolisticMiningSession {
         let min = resonances.iter().copied().fold(f64::MAX, f64::min);
         let max = resonances.iter().copied().fold(f64::MIN, f64::max);
 
-        let unique_nodes = self.artefacts.iter()
+        // Distinct artefact nodes measure efficiency; agent paths add to coverage
+        let artefact_nodes: HashSet<NodeIndex> = self.artefacts.iter()
             .map(|a| a.node)
-            .collect::<std::collections::HashSet<_>>()
-            .len();
-
+            .collect();
+        let unique_nodes = artefact_nodes.len();
         let efficiency = unique_nodes as f64 / self.artefacts.len() as f64;
+        let nodes_visited = self.visited.union(&artefact_nodes).count();
 
         MiningStats {
             avg_resonance: avg,
@@ -774,10 +895,20 @@ impl HolisticMiningSession {
             max_resonance: max,
             num_agents: self.config.mining.num_agents,
             unique_nodes,
+            nodes_visited,
             efficiency,
+            mandorla_threshold: self.config.mining.mandorla_policy.threshold(&resonances),
+            converged_by: self.converged_by.clone(),
+            parallel_speedup: None,
         }
     }
 
+    /// Effective Mandorla threshold for the current artefacts
+    pub fn mandorla_threshold(&self) -> f64 {
+        let resonances: Vec<f64> = self.artefacts.iter().map(|a| a.resonance).collect();
+        self.config.mining.mandorla_policy.threshold(&resonances)
+    }
+
     /// Get current stage
     pub fn stage(&self) -> GenesisStage {
         self.current_stage
@@ -822,6 +953,29 @@ mod tests {
         assert!(!result.stage_log.is_empty());
     }
 
+    #[test]
+    fn test_holistic_convergence_stops_discovery() {
+        let config = |convergence| HolisticMiningConfig {
+            mining: MiningConfig {
+                num_agents: 4,
+                steps_per_agent: 10,
+                convergence,
+                ..Default::default()
+            },
+            adaptive_triton: false,
+            ..Default::default()
+        };
+
+        let target = ConvergenceCriterion::TargetResonance(0.0);
+        let result = HolisticMiningSession::new(config(target.clone())).mine();
+        assert_eq!(result.artefacts.len(), 1);
+        assert_eq!(result.mining_stats.converged_by, Some(target));
+
+        let result = HolisticMiningSession::new(config(ConvergenceCriterion::Never)).mine();
+        assert_eq!(result.artefacts.len(), 4);
+        assert_eq!(result.mining_stats.converged_by, None);
+    }
+
     #[test]
     fn test_holistic_mining_stages() {
         let config = HolisticMiningConfig::quick();
@@ -869,4 +1023,56 @@ mod tests {
 
         assert!(result.best_resonance > 0.0);
     }
+
+    #[test]
+    fn test_holistic_with_candidate_source() {
+        use crate::candidate_source::TritonCandidateSource;
+
+        let config = HolisticMiningConfig {
+            mining: MiningConfig {
+                num_agents: 6,
+                ..Default::default()
+            },
+            adaptive_triton: false,
+            ..Default::default()
+        };
+
+        let mut session = HolisticMiningSession::new(config)
+            .with_candidate_source(TritonCandidateSource::with_seed(SpiralParams::default(), 1));
+        assert_eq!(session.candidate_source_name(), Some("triton"));
+
+        let result = session.mine();
+        assert_eq!(result.candidates_discovered, 6);
+        assert_eq!(result.artefacts.len(), 6);
+        assert_eq!(result.final_stage, GenesisStage::Finalized);
+    }
+
+    #[test]
+    fn test_holistic_stage_observer() {
+        use std::sync::{Arc, Mutex};
+
+        let config = HolisticMiningConfig {
+            mining: MiningConfig {
+                num_agents: 3,
+                steps_per_agent: 10,
+                ..Default::default()
+            },
+            adaptive_triton: false,
+            ..Default::default()
+        };
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&seen);
+        let mut session = HolisticMiningSession::new(config)
+            .with_stage_observer(move |stage, stats| {
+                sink.lock().unwrap().push((stage, stats.kosmokrator.total_processed));
+            });
+        session.mine();
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 4);
+        assert_eq!(seen[0].0, GenesisStage::Discovery);
+        assert_eq!(seen[3].0, GenesisStage::PfauenthronCollapse);
+        assert_eq!(seen.last().unwrap().1, session.holistic_stats().kosmokrator.total_processed);
+    }
 }
@@ -12,6 +12,8 @@
 //! - **KNO Framework**: Cyclic Conversion Operator system
 //! - **Mining**: TRITON-integrated mining session management
 //! - **Family**: Operator family extraction and clustering
+//! - **Candidate Sources**: Real mined candidates for the holistic pipeline
+//! - **Evaluation**: Repeated-trial strategy comparison with significance tests
 //!
 //! ## Architecture
 //!
@@ -40,21 +42,34 @@ pub mod evolution;
 pub mod mining;
 pub mod family;
 pub mod holistic_mining;
+pub mod candidate_source;
+pub mod evaluation;
 pub mod reporting;
 
-pub use metatron_cube::MetatronCube;
-pub use agent::{Agent, AgentConfig, TraversalStrategy};
+pub use metatron_cube::{MetatronCube, Permutation, S7_ORDER};
+pub use agent::{
+    Agent, AgentConfig, AgentMemory, TraversalStrategy, AgentPolicy, PolicyClone, StepContext,
+    RandomPolicy, GradientAscentPolicy, StabilityPolicy, CycleRecognitionPolicy, BalancedPolicy,
+};
 pub use artefact::Artefact;
-pub use cubechain::Cubechain;
+pub use cubechain::{Cubechain, VerificationReport, BlockMismatch, MismatchKind};
 pub use meta_cognition::MetaCognitionLayer;
 pub use traversal::TraversalEngine;
 pub use evolution::{EvolutionEngine, EvolutionConfig, GenerationStats};
-pub use mining::{MiningSession, MiningConfig, MiningStrategy, MiningResult, MiningStats};
-pub use family::{OperatorFamily, FamilyClusterer, FamilyMetrics, FamilyCharacteristics};
+pub use mining::{
+    MiningSession, MiningCheckpoint, MiningConfig, MiningStrategy, MiningResult, MiningStats,
+    MandorlaPolicy, ConvergenceCriterion, DEFAULT_MANDORLA_THRESHOLD,
+};
+pub use family::{
+    OperatorFamily, FamilyClusterer, ClusterOrder, ClusterAlgorithm, FamilyMetrics, FamilyCharacteristics,
+    SimilarityMatrix, silhouette_score,
+};
 pub use holistic_mining::{
     HolisticMiningConfig, HolisticMiningSession, HolisticMiningResult,
-    StageLogEntry, StageMetrics,
+    StageLogEntry, StageMetrics, StageObserver,
 };
+pub use candidate_source::{MetatronCandidateSource, TritonCandidateSource};
+pub use evaluation::{evaluate_strategies, StrategyEvaluation, StrategyStats};
 // Re-export GenesisStage from core
 pub use qops_core::GenesisStage;
 pub use reporting::{
@@ -1,13 +1,17 @@
 //! MetatronCube - S7 permutation graph with 5040 nodes.
 
-use qops_core::{ResonanceTopology, Signature, Signature5D};
+use qops_core::{QopsError, ResonanceTopology, Result, Signature, Signature5D};
 use petgraph::graph::{DiGraph, NodeIndex};
 use std::collections::HashMap;
+use std::sync::OnceLock;
 use rand::Rng;
 
 /// S7 Permutation represented as array
 pub type Permutation = [u8; 7];
 
+/// Number of permutations in S7 (7!)
+pub const S7_ORDER: usize = 5040;
+
 /// MetatronCube graph - S7 permutation topology
 #[derive(Clone)]
 pub struct MetatronCube {
@@ -19,6 +23,8 @@ pub struct MetatronCube {
     signatures: HashMap<NodeIndex, Signature5D>,
     /// Identity permutation (seed node)
     identity: NodeIndex,
+    /// Neighbor lists by node index, built on first access
+    adjacency: OnceLock<Vec<Vec<usize>>>,
 }
 
 impl MetatronCube {
@@ -74,6 +80,7 @@ impl MetatronCube {
             perm_to_index,
             signatures,
             identity,
+            adjacency: OnceLock::new(),
         }
     }
 
@@ -94,10 +101,57 @@ impl MetatronCube {
         self.perm_to_index.get(perm).copied()
     }
 
+    /// Lexicographic rank of a permutation in S7 (Lehmer code), in `0..5040`
+    ///
+    /// The rank is the canonical position of `perm` among all of S7; it is
+    /// independent of the graph's [`NodeIndex`], which is [`Self::get_node`]'s job.
+    pub fn index_of(perm: &Permutation) -> Result<usize> {
+        let mut seen = [false; 7];
+        for &p in perm {
+            if p >= 7 || seen[p as usize] {
+                return Err(QopsError::validation(format!("{:?} is not a permutation of 0..7", perm)));
+            }
+            seen[p as usize] = true;
+        }
+
+        // Each digit counts smaller entries to its right, weighted by (6 - i)!
+        let mut rank = 0;
+        for i in 0..7 {
+            let smaller = perm[i + 1..].iter().filter(|&&q| q < perm[i]).count();
+            rank = rank * (7 - i) + smaller;
+        }
+        Ok(rank)
+    }
+
+    /// Permutation with the given Lehmer rank, the inverse of [`Self::index_of`]
+    pub fn permutation_at(index: usize) -> Result<Permutation> {
+        if index >= S7_ORDER {
+            return Err(QopsError::validation(format!("permutation index {} out of range 0..{}", index, S7_ORDER)));
+        }
+
+        let mut remaining: Vec<u8> = (0..7).collect();
+        let mut perm = [0u8; 7];
+        let mut rank = index;
+        let mut radix = S7_ORDER;
+        for (i, slot) in perm.iter_mut().enumerate() {
+            radix /= 7 - i;
+            *slot = remaining.remove(rank / radix);
+            rank %= radix;
+        }
+        Ok(perm)
+    }
+
     /// Randomize signatures
     pub fn randomize_signatures(&mut self) {
-        let mut rng = rand::thread_rng();
-        for sig in self.signatures.values_mut() {
+        self.randomize_signatures_with(&mut rand::thread_rng());
+    }
+
+    /// Randomize signatures drawing from `rng`
+    ///
+    /// Nodes are visited in graph order so a seeded `rng` gives a reproducible cube.
+    pub fn randomize_signatures_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for node in self.graph.node_indices() {
+            let Some(sig) = self.signatures.get_mut(&node) else { continue };
             *sig = Signature5D::new(
                 rng.gen_range(0.3..0.9),
                 rng.gen_range(0.3..0.9),
@@ -112,6 +166,29 @@ impl MetatronCube {
     pub fn identity_node(&self) -> NodeIndex {
         self.identity
     }
+
+    /// Neighbor indices of every node, indexed by `NodeIndex::index()`
+    ///
+    /// Built from the graph on first access and cached, so traversal is an
+    /// array lookup instead of an edge walk. Entries are in the same order as
+    /// [`ResonanceTopology::neighbors`].
+    pub fn adjacency(&self) -> &[Vec<usize>] {
+        self.adjacency.get_or_init(|| {
+            self.graph
+                .node_indices()
+                .map(|node| ResonanceTopology::neighbors(self, &node).into_iter().map(|n| n.index()).collect())
+                .collect()
+        })
+    }
+
+    /// Cached neighbors of `node`
+    pub fn adjacent(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+        self.adjacency()
+            .get(node.index())
+            .into_iter()
+            .flatten()
+            .map(|&i| NodeIndex::new(i))
+    }
 }
 
 impl Default for MetatronCube {
@@ -197,4 +274,43 @@ mod tests {
         let neighbors = cube.neighbors(&identity);
         assert!(!neighbors.is_empty());
     }
+
+    #[test]
+    fn test_permutation_ranking_is_bijective() {
+        assert_eq!(MetatronCube::index_of(&[0, 1, 2, 3, 4, 5, 6]).unwrap(), 0);
+        assert_eq!(MetatronCube::index_of(&[6, 5, 4, 3, 2, 1, 0]).unwrap(), S7_ORDER - 1);
+        assert_eq!(MetatronCube::index_of(&[0, 1, 2, 3, 4, 6, 5]).unwrap(), 1);
+
+        let mut seen = std::collections::HashSet::new();
+        for index in 0..S7_ORDER {
+            let perm = MetatronCube::permutation_at(index).unwrap();
+            assert_eq!(MetatronCube::index_of(&perm).unwrap(), index);
+            assert!(seen.insert(perm));
+        }
+
+        assert!(MetatronCube::index_of(&[0, 0, 2, 3, 4, 5, 6]).is_err());
+        assert!(MetatronCube::index_of(&[0, 1, 2, 3, 4, 5, 7]).is_err());
+        assert!(MetatronCube::permutation_at(S7_ORDER).is_err());
+
+        // Graph nodes carry genuine permutations
+        let cube = MetatronCube::new();
+        let node = cube.nodes()[17];
+        let rank = MetatronCube::index_of(cube.permutation(node).unwrap()).unwrap();
+        assert_eq!(cube.get_node(&MetatronCube::permutation_at(rank).unwrap()), Some(node));
+    }
+
+    #[test]
+    fn test_adjacency_matches_neighbors() {
+        let cube = MetatronCube::new();
+        let adjacency = cube.adjacency();
+        assert_eq!(adjacency.len(), cube.node_count());
+
+        for node in cube.nodes() {
+            let cached: Vec<NodeIndex> = cube.adjacent(node).collect();
+            assert_eq!(cached, cube.neighbors(&node));
+        }
+        // The identity reaches all 21 transpositions
+        assert_eq!(adjacency[cube.identity_node().index()].len(), 21);
+        assert!(std::ptr::eq(adjacency, cube.adjacency()));
+    }
 }
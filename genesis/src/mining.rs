@@ -2,14 +2,21 @@
 //!
 //! Comprehensive mining system for operator discovery on S7 topology.
 
-use crate::agent::{Agent, AgentConfig, TraversalStrategy};
+use crate::agent::{Agent, AgentConfig, AgentMemory, AgentPolicy, TraversalStrategy};
 use crate::artefact::Artefact;
 use crate::metatron_cube::MetatronCube;
-use crate::family::OperatorFamily;
-use qops_core::Signature5D;
+use crate::family::{OperatorFamily, SimilarityMatrix};
+use qops_core::{QopsError, ResonanceTopology, Signature, Signature5D};
 use qops_triton::{TritonOptimizer, TritonConfig, OptimizationResult};
 use serde::{Deserialize, Serialize};
 use rayon::prelude::*;
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand_chacha::ChaCha12Rng;
+use petgraph::graph::NodeIndex;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
 
 /// Mining strategy selection
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -50,6 +57,95 @@ impl MiningStrategy {
     }
 }
 
+/// Resonance threshold used when no session-relative policy applies
+pub use qops_core::DEFAULT_MANDORLA_THRESHOLD;
+
+/// How a session decides which artefacts count as Mandorla
+///
+/// Relative policies derive the threshold from the session's own resonance
+/// distribution, so different topologies with different resonance scales
+/// still flag a meaningful subset.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum MandorlaPolicy {
+    /// Fixed resonance threshold
+    Absolute(f64),
+    /// Top fraction of the session's artefacts (e.g. 0.1 = top 10%)
+    TopPercentile(f64),
+    /// Mean plus `k` standard deviations of the session's resonances
+    Statistical { k: f64 },
+}
+
+impl Default for MandorlaPolicy {
+    fn default() -> Self {
+        Self::Absolute(DEFAULT_MANDORLA_THRESHOLD)
+    }
+}
+
+impl MandorlaPolicy {
+    /// Effective resonance threshold for a set of resonances
+    ///
+    /// Relative policies ignore non-finite resonances and fall back to
+    /// [`DEFAULT_MANDORLA_THRESHOLD`] when no finite value remains.
+    pub fn threshold(&self, resonances: &[f64]) -> f64 {
+        if let Self::Absolute(t) = self {
+            return *t;
+        }
+        // NaN or infinite scores would otherwise dominate the ranking and moments
+        let finite: Vec<f64> = resonances.iter().copied().filter(|r| r.is_finite()).collect();
+        if finite.is_empty() {
+            return DEFAULT_MANDORLA_THRESHOLD;
+        }
+
+        match self {
+            Self::Absolute(t) => *t,
+            Self::TopPercentile(fraction) => {
+                let mut sorted = finite;
+                sorted.sort_by(|a, b| b.total_cmp(a));
+                let count = (fraction.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+                sorted[count.clamp(1, sorted.len()) - 1]
+            }
+            Self::Statistical { k } => {
+                let n = finite.len() as f64;
+                let mean = finite.iter().sum::<f64>() / n;
+                let var = finite.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / n;
+                mean + k * var.sqrt()
+            }
+        }
+    }
+}
+
+/// Criterion for stopping a mining session early
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum ConvergenceCriterion {
+    /// Never stop early, run every agent to completion
+    #[default]
+    Never,
+    /// Stop after `steps` agent steps without a new best resonance
+    NoImprovement { steps: usize },
+    /// Stop once the best resonance reaches the target
+    TargetResonance(f64),
+    /// Stop as soon as any of the criteria is met
+    Composite(Vec<ConvergenceCriterion>),
+}
+
+impl ConvergenceCriterion {
+    /// The criterion met by the given progress, if any
+    ///
+    /// For [`ConvergenceCriterion::Composite`] this is the first met sub-criterion.
+    pub fn triggered(&self, best_resonance: f64, steps_since_improvement: usize) -> Option<ConvergenceCriterion> {
+        match self {
+            Self::Never => None,
+            Self::NoImprovement { steps } => {
+                (steps_since_improvement >= *steps).then(|| self.clone())
+            }
+            Self::TargetResonance(target) => (best_resonance >= *target).then(|| self.clone()),
+            Self::Composite(criteria) => criteria
+                .iter()
+                .find_map(|c| c.triggered(best_resonance, steps_since_improvement)),
+        }
+    }
+}
+
 /// Mining session configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiningConfig {
@@ -61,7 +157,7 @@ pub struct MiningConfig {
     pub steps_per_agent: usize,
     /// Target resonance threshold
     pub target_resonance: f64,
-    /// Enable parallel mining
+    /// Run swarm agents on the rayon pool, each with a seed drawn from the session RNG
     pub parallel: bool,
     /// TRITON configuration (if using TRITON strategy)
     pub triton_config: Option<TritonConfig>,
@@ -71,6 +167,29 @@ pub struct MiningConfig {
     pub extract_families: bool,
     /// Family similarity threshold
     pub family_threshold: f64,
+    /// Policy deciding which artefacts count as Mandorla
+    #[serde(default)]
+    pub mandorla_policy: MandorlaPolicy,
+    /// Seed for reproducible sessions (`None` draws from entropy)
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Agent memory used to avoid revisiting nodes
+    #[serde(default)]
+    pub agent_memory: AgentMemory,
+    /// When to stop mining early
+    #[serde(default)]
+    pub convergence: ConvergenceCriterion,
+    /// Most artefacts the session keeps (`None` keeps all)
+    ///
+    /// Past the cap every new artefact replaces a uniformly chosen one with
+    /// probability `max / seen` (reservoir sampling), so memory stays
+    /// bounded and the kept set is a uniform sample of everything found.
+    /// The best artefact is tracked separately and always reported.
+    #[serde(default)]
+    pub max_artefacts: Option<usize>,
+    /// Custom agent policy used instead of `strategy`'s; not serialized
+    #[serde(skip)]
+    pub agent_policy: Option<Box<dyn AgentPolicy>>,
 }
 
 impl Default for MiningConfig {
@@ -85,11 +204,38 @@ impl Default for MiningConfig {
             exploration_rate: 0.1,
             extract_families: true,
             family_threshold: 0.1,
+            mandorla_policy: MandorlaPolicy::default(),
+            seed: None,
+            agent_memory: AgentMemory::default(),
+            convergence: ConvergenceCriterion::default(),
+            max_artefacts: None,
+            agent_policy: None,
         }
     }
 }
 
 impl MiningConfig {
+    /// Agent configuration derived from this session config
+    pub fn agent_config(&self) -> AgentConfig {
+        AgentConfig {
+            max_steps: self.steps_per_agent,
+            strategy: self.strategy.to_traversal_strategy(),
+            exploration_rate: self.exploration_rate,
+            target_resonance: self.target_resonance,
+            memory: self.agent_memory,
+            policy: self.agent_policy.clone(),
+        }
+    }
+
+    /// Drive every agent with a custom traversal policy
+    ///
+    /// The policy is not serialized: resuming a checkpoint of such a session
+    /// requires a config with the policy set again.
+    pub fn with_agent_policy(mut self, policy: impl AgentPolicy + 'static) -> Self {
+        self.agent_policy = Some(Box::new(policy));
+        self
+    }
+
     /// Create config for TRITON mining
     pub fn triton() -> Self {
         Self {
@@ -134,6 +280,9 @@ pub struct MiningResult {
     pub best_resonance: f64,
     /// Total steps taken
     pub total_steps: usize,
+    /// Agent steps actually taken, fewer than `total_steps` after an early stop
+    #[serde(default)]
+    pub steps_taken: usize,
     /// Mandorla count (high-resonance artefacts)
     pub mandorla_count: usize,
     /// Extracted operator families
@@ -148,6 +297,20 @@ pub struct MiningResult {
     pub stats: MiningStats,
 }
 
+impl MiningResult {
+    /// Pairwise distance matrix of the `top_n` highest-resonance artefacts
+    pub fn similarity_matrix(&self, top_n: usize) -> SimilarityMatrix {
+        let mut top: Vec<Artefact> = self.artefacts.clone();
+        top.sort_by(|a, b| b.resonance.partial_cmp(&a.resonance).unwrap());
+        top.truncate(top_n);
+        SimilarityMatrix::from_artefacts(&top)
+    }
+}
+
+fn default_mandorla_threshold() -> f64 {
+    DEFAULT_MANDORLA_THRESHOLD
+}
+
 /// Mining statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiningStats {
@@ -161,10 +324,80 @@ pub struct MiningStats {
     pub max_resonance: f64,
     /// Number of agents used
     pub num_agents: usize,
-    /// Unique nodes visited
+    /// Distinct nodes artefacts were found at
     pub unique_nodes: usize,
+    /// Distinct nodes covered by agent paths and artefacts
+    #[serde(default)]
+    pub nodes_visited: usize,
     /// Exploration efficiency
     pub efficiency: f64,
+    /// Effective Mandorla resonance threshold used for this session
+    #[serde(default = "default_mandorla_threshold")]
+    pub mandorla_threshold: f64,
+    /// Convergence criterion that stopped the session early, if any
+    #[serde(default)]
+    pub converged_by: Option<ConvergenceCriterion>,
+    /// Summed agent time over wall-clock time for parallel swarm runs
+    #[serde(default)]
+    pub parallel_speedup: Option<f64>,
+}
+
+/// Serializable snapshot of an unfinished [`MiningSession`]
+///
+/// Captures everything the session would otherwise lose: the cube's node
+/// signatures, artefacts found so far, the agent mid-traversal and the RNG
+/// state, so [`MiningSession::resume`] continues exactly where it stopped.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiningCheckpoint {
+    /// Session ID
+    pub session_id: String,
+    /// Strategy the session was mining with
+    pub strategy: MiningStrategy,
+    /// Whether agents were driven by a custom [`AgentPolicy`]
+    #[serde(default)]
+    pub custom_policy: bool,
+    /// Cube node signatures, in node order
+    pub signatures: Vec<Signature5D>,
+    /// Artefacts discovered so far
+    pub artefacts: Vec<Artefact>,
+    /// Artefacts offered to the reservoir, including discarded ones
+    #[serde(default)]
+    pub artefacts_seen: usize,
+    /// Highest-resonance artefact found, even if the reservoir dropped it
+    #[serde(default)]
+    pub best_artefact: Option<Artefact>,
+    /// Nodes visited by agents, sorted by index
+    pub visited: Vec<NodeIndex>,
+    /// Agents that finished their traversal
+    pub agents_completed: usize,
+    /// Agent interrupted mid-traversal, if any
+    pub current_agent: Option<Agent>,
+    /// Agent steps taken so far
+    pub steps_taken: usize,
+    /// Best resonance seen so far
+    pub best_so_far: f64,
+    /// Agent steps since `best_so_far` last improved
+    pub steps_since_improvement: usize,
+    /// Criterion that stopped the session, once met
+    pub converged_by: Option<ConvergenceCriterion>,
+    /// TRITON configuration in use, including its derived seed
+    pub triton_config: Option<TritonConfig>,
+    /// Mining time spent before the checkpoint, in milliseconds
+    pub elapsed_ms: u64,
+    /// Session RNG state
+    rng: ChaCha12Rng,
+}
+
+impl MiningCheckpoint {
+    /// Save as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        qops_core::persist::save_json(self, path)
+    }
+
+    /// Load a checkpoint written by [`MiningCheckpoint::save`]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        qops_core::persist::load_json(path)
+    }
 }
 
 /// Genesis Mining Session
@@ -172,22 +405,53 @@ pub struct MiningSession {
     config: MiningConfig,
     cube: MetatronCube,
     artefacts: Vec<Artefact>,
+    /// Artefacts offered to the reservoir, including discarded ones
+    artefacts_seen: usize,
+    /// Highest-resonance artefact found, even if the reservoir dropped it
+    best_artefact: Option<Artefact>,
     triton: Option<TritonOptimizer>,
     session_id: String,
     start_time: Option<std::time::Instant>,
+    /// Mining time carried over from a checkpoint, in milliseconds
+    elapsed_before_ms: u64,
+    rng: ChaCha12Rng,
+    /// Nodes visited by agents along their paths
+    visited: HashSet<NodeIndex>,
+    /// Agent steps taken so far
+    steps_taken: usize,
+    /// Best resonance seen so far
+    best_so_far: f64,
+    /// Agent steps since `best_so_far` last improved
+    steps_since_improvement: usize,
+    /// Criterion that stopped the session, once met
+    converged_by: Option<ConvergenceCriterion>,
+    /// Speedup measured by the last parallel swarm run
+    parallel_speedup: Option<f64>,
+    /// Agents finished in the current run
+    agents_completed: usize,
+    /// Agent whose traversal was cut short by a step budget
+    current_agent: Option<Agent>,
 }
 
 impl MiningSession {
     /// Create new mining session
     pub fn new(config: MiningConfig) -> Self {
+        let mut rng = match config.seed {
+            Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+            None => ChaCha12Rng::from_entropy(),
+        };
+
         let mut cube = MetatronCube::new();
-        cube.randomize_signatures();
+        cube.randomize_signatures_with(&mut rng);
 
         let triton = if config.strategy == MiningStrategy::Triton ||
                         config.strategy == MiningStrategy::HybridTritonEvolution {
-            Some(TritonOptimizer::new(
-                config.triton_config.clone().unwrap_or_default()
-            ))
+            let mut triton_config = config.triton_config.clone().unwrap_or_default();
+            if config.seed.is_some() {
+                triton_config.deterministic = true;
+                triton_config.seed = rng.gen();
+            }
+            Some(TritonOptimizer::new(triton_config))
         } else {
             None
         };
@@ -196,22 +460,179 @@ impl MiningSession {
             config,
             cube,
             artefacts: Vec::new(),
+            artefacts_seen: 0,
+            best_artefact: None,
             triton,
             session_id: uuid::Uuid::new_v4().to_string(),
             start_time: None,
+            elapsed_before_ms: 0,
+            rng,
+            visited: HashSet::new(),
+            steps_taken: 0,
+            best_so_far: 0.0,
+            steps_since_improvement: 0,
+            converged_by: None,
+            parallel_speedup: None,
+            agents_completed: 0,
+            current_agent: None,
         }
     }
 
+    /// Snapshot the session so mining can be resumed later
+    pub fn checkpoint(&self) -> MiningCheckpoint {
+        let mut visited: Vec<NodeIndex> = self.visited.iter().copied().collect();
+        visited.sort();
+
+        MiningCheckpoint {
+            session_id: self.session_id.clone(),
+            strategy: self.config.strategy,
+            custom_policy: self.config.agent_policy.is_some(),
+            signatures: self
+                .cube
+                .nodes()
+                .iter()
+                .map(|n| self.cube.signature_at(n).map(|s| s.to_5d()).unwrap_or_default())
+                .collect(),
+            artefacts: self.artefacts.clone(),
+            artefacts_seen: self.artefacts_seen,
+            best_artefact: self.best_artefact.clone(),
+            visited,
+            agents_completed: self.agents_completed,
+            current_agent: self.current_agent.clone(),
+            steps_taken: self.steps_taken,
+            best_so_far: self.best_so_far,
+            steps_since_improvement: self.steps_since_improvement,
+            converged_by: self.converged_by.clone(),
+            triton_config: self.triton.as_ref().map(|t| t.config().clone()),
+            elapsed_ms: self.elapsed_ms(),
+            rng: self.rng.clone(),
+        }
+    }
+
+    /// Restore a session from a checkpoint
+    ///
+    /// `config` should be the configuration the checkpointed session was
+    /// created with; a different strategy or cube size is rejected, as is a
+    /// config missing the custom agent policy the session used (or adding
+    /// one it did not). The interrupted agent continues with a fresh copy of
+    /// the policy, so state a custom policy keeps between steps restarts.
+    pub fn resume(checkpoint: MiningCheckpoint, config: MiningConfig) -> qops_core::Result<Self> {
+        if checkpoint.strategy != config.strategy {
+            return Err(QopsError::Validation(format!(
+                "checkpoint was mined with {:?}, not {:?}",
+                checkpoint.strategy, config.strategy
+            )));
+        }
+        match (checkpoint.custom_policy, config.agent_policy.is_some()) {
+            (true, false) => {
+                return Err(QopsError::Validation(
+                    "checkpoint was mined with a custom agent policy; set it again with MiningConfig::with_agent_policy".into(),
+                ));
+            }
+            (false, true) => {
+                return Err(QopsError::Validation(
+                    "checkpoint was mined without a custom agent policy".into(),
+                ));
+            }
+            _ => {}
+        }
+
+        let mut cube = MetatronCube::new();
+        let nodes = cube.nodes();
+        if checkpoint.signatures.len() != nodes.len() {
+            return Err(QopsError::Validation(format!(
+                "checkpoint has {} node signatures, cube has {} nodes",
+                checkpoint.signatures.len(),
+                nodes.len()
+            )));
+        }
+        for (node, sig) in nodes.iter().zip(&checkpoint.signatures) {
+            cube.set_signature(node, Signature::D5(*sig));
+        }
+
+        Ok(Self {
+            triton: checkpoint.triton_config.map(TritonOptimizer::new),
+            cube,
+            artefacts_seen: checkpoint.artefacts_seen.max(checkpoint.artefacts.len()),
+            best_artefact: checkpoint.best_artefact,
+            artefacts: checkpoint.artefacts,
+            session_id: checkpoint.session_id,
+            start_time: None,
+            elapsed_before_ms: checkpoint.elapsed_ms,
+            rng: checkpoint.rng,
+            visited: checkpoint.visited.into_iter().collect(),
+            steps_taken: checkpoint.steps_taken,
+            best_so_far: checkpoint.best_so_far,
+            steps_since_improvement: checkpoint.steps_since_improvement,
+            converged_by: checkpoint.converged_by,
+            parallel_speedup: None,
+            agents_completed: checkpoint.agents_completed,
+            current_agent: checkpoint.current_agent.map(|mut agent| {
+                agent.config.policy = config.agent_policy.clone();
+                agent
+            }),
+            config,
+        })
+    }
+
+    /// Mining time so far, including time before a resumed checkpoint
+    fn elapsed_ms(&self) -> u64 {
+        self.elapsed_before_ms + self.start_time.map(|t| t.elapsed().as_millis() as u64).unwrap_or(0)
+    }
+
     /// Run the mining session
     pub fn mine(&mut self) -> MiningResult {
-        self.start_time = Some(std::time::Instant::now());
+        self.mine_for(usize::MAX).expect("an unbounded step budget always finishes")
+    }
 
-        match self.config.strategy {
+    /// Mine for at most `max_steps` agent steps
+    ///
+    /// Returns the result once the session finishes, or `None` when the
+    /// budget ran out first; call again, possibly after a
+    /// [`MiningSession::checkpoint`] / [`MiningSession::resume`] round trip, to
+    /// continue. Only agent-traversal strategies stop part way: TRITON, hybrid
+    /// and parallel swarm sessions always finish in one call.
+    pub fn mine_for(&mut self, max_steps: usize) -> Option<MiningResult> {
+        let _span = tracing::debug_span!(
+            "mining_session",
+            session = %self.session_id,
+            strategy = ?self.config.strategy,
+            agents = self.config.num_agents,
+            steps_per_agent = self.config.steps_per_agent,
+            seed = ?self.config.seed,
+        )
+        .entered();
+        self.start_time.get_or_insert_with(std::time::Instant::now);
+
+        let result = match self.config.strategy {
             MiningStrategy::Triton => self.triton_mining(),
             MiningStrategy::HybridTritonEvolution => self.hybrid_mining(),
-            MiningStrategy::Swarm => self.swarm_mining(),
-            _ => self.agent_mining(),
-        }
+            MiningStrategy::Swarm if self.config.parallel => self.parallel_swarm_mining(),
+            _ => {
+                let mut budget = max_steps;
+                if !self.run_agents_within(self.config.num_agents, &mut budget) {
+                    tracing::debug!(agents_completed = self.agents_completed, "step budget exhausted");
+                    return None;
+                }
+                self.build_result(None)
+            }
+        };
+
+        // A further call starts a fresh run of agents
+        self.agents_completed = 0;
+        self.start_time = None;
+        self.elapsed_before_ms = 0;
+
+        tracing::debug!(
+            artefacts = result.artefacts.len(),
+            families = result.families.len(),
+            mandorla = result.mandorla_count,
+            unique_nodes = result.stats.unique_nodes,
+            best_resonance = result.best_resonance,
+            duration_ms = result.duration_ms,
+            "mining complete"
+        );
+        Some(result)
     }
 
     /// TRITON-based mining
@@ -231,7 +652,8 @@ impl MiningSession {
         );
 
         let artefact = Artefact::from_signature(best_sig);
-        self.artefacts.push(artefact.clone());
+        self.record_progress(artefact.resonance, 0);
+        self.store_artefact(artefact);
 
         // Also run some agent traversals for diversity
         self.run_agents(self.config.num_agents / 2);
@@ -253,7 +675,9 @@ impl MiningSession {
             triton_result.best_signature[3],
             triton_result.best_signature[4],
         );
-        self.artefacts.push(Artefact::from_signature(best_sig));
+        let artefact = Artefact::from_signature(best_sig);
+        self.record_progress(artefact.resonance, 0);
+        self.store_artefact(artefact);
 
         // Phase 2: Agent-based exploitation around TRITON result
         self.run_agents(self.config.num_agents);
@@ -264,67 +688,117 @@ impl MiningSession {
         self.build_result(Some(triton_result))
     }
 
-    /// Swarm-based mining
-    fn swarm_mining(&mut self) -> MiningResult {
-        if self.config.parallel {
-            self.parallel_swarm_mining()
-        } else {
-            self.sequential_swarm_mining()
-        }
-    }
-
+    /// Swarm agents on the rayon pool, all traversing the session cube
     fn parallel_swarm_mining(&mut self) -> MiningResult {
-        let configs: Vec<AgentConfig> = (0..self.config.num_agents)
-            .map(|_| AgentConfig {
-                max_steps: self.config.steps_per_agent,
-                strategy: self.config.strategy.to_traversal_strategy(),
-                exploration_rate: self.config.exploration_rate,
-                target_resonance: self.config.target_resonance,
-            })
-            .collect();
+        // Per-agent seeds are drawn up front so results don't depend on scheduling
+        let seeds: Vec<u64> = (0..self.config.num_agents).map(|_| self.rng.gen()).collect();
+        let agent_config = self.config.agent_config();
+        let cube = &self.cube;
 
-        // Run agents in parallel
-        let artefacts: Vec<Artefact> = configs
+        let wall_start = std::time::Instant::now();
+        let runs: Vec<(Agent, std::time::Duration)> = seeds
             .par_iter()
-            .map(|config| {
-                let mut cube = MetatronCube::new();
-                cube.randomize_signatures();
-                let mut agent = Agent::new(cube.identity_node(), config.clone());
-                agent.traverse(&cube);
-                Artefact::new(agent.position, agent.signature)
+            .map(|&seed| {
+                let started = std::time::Instant::now();
+                let mut rng = StdRng::seed_from_u64(seed);
+                let mut agent = Agent::new(cube.identity_node(), agent_config.clone());
+                agent.traverse_with_rng(cube, &mut rng);
+                (agent, started.elapsed())
             })
             .collect();
-
-        self.artefacts.extend(artefacts);
+        let wall = wall_start.elapsed().as_secs_f64();
+        let agent_time: f64 = runs.iter().map(|(_, elapsed)| elapsed.as_secs_f64()).sum();
+        self.parallel_speedup = Some(if wall > 0.0 { agent_time / wall } else { 1.0 });
+
+        // Results past convergence are dropped so the output matches a
+        // sequential merge in agent order
+        for (agent, _) in runs {
+            if self.converged_by.is_some() {
+                break;
+            }
+            let artefact = Artefact::new(agent.position, agent.signature);
+            self.record_progress(artefact.resonance, agent.steps);
+            self.store_artefact(artefact);
+            self.visited.extend(agent.path);
+        }
         self.build_result(None)
     }
 
-    fn sequential_swarm_mining(&mut self) -> MiningResult {
-        self.run_agents(self.config.num_agents);
-        self.build_result(None)
+    /// Run a set of agents
+    fn run_agents(&mut self, count: usize) {
+        let mut unbounded = usize::MAX;
+        self.run_agents_within(count, &mut unbounded);
     }
 
-    /// Standard agent-based mining
-    fn agent_mining(&mut self) -> MiningResult {
-        self.run_agents(self.config.num_agents);
-        self.build_result(None)
-    }
+    /// Run agents until `count` have finished this run, spending at most `budget` steps
+    ///
+    /// Returns `false` if the budget ran out first; the interrupted agent is
+    /// kept and picks up from the same step on the next call.
+    fn run_agents_within(&mut self, count: usize, budget: &mut usize) -> bool {
+        let agent_config = self.config.agent_config();
 
-    /// Run a set of agents
-    fn run_agents(&mut self, count: usize) {
-        let agent_config = AgentConfig {
-            max_steps: self.config.steps_per_agent,
-            strategy: self.config.strategy.to_traversal_strategy(),
-            exploration_rate: self.config.exploration_rate,
-            target_resonance: self.config.target_resonance,
-        };
+        while self.agents_completed < count {
+            if self.converged_by.is_some() {
+                break;
+            }
 
-        for _ in 0..count {
-            let mut agent = Agent::new(self.cube.identity_node(), agent_config.clone());
-            agent.traverse(&self.cube);
+            let mut agent = self
+                .current_agent
+                .take()
+                .unwrap_or_else(|| Agent::new(self.cube.identity_node(), agent_config.clone()));
+            loop {
+                if *budget == 0 {
+                    self.current_agent = Some(agent);
+                    return false;
+                }
+                if agent.step_with_rng(&self.cube, &mut self.rng).is_none() {
+                    break;
+                }
+                *budget -= 1;
+            }
 
             let artefact = Artefact::new(agent.position, agent.signature);
-            self.artefacts.push(artefact);
+            self.record_progress(artefact.resonance, agent.steps);
+            self.store_artefact(artefact);
+            self.visited.extend(agent.path);
+            self.agents_completed += 1;
+        }
+        true
+    }
+
+    /// Keep an artefact, reservoir-sampling once `max_artefacts` are stored
+    fn store_artefact(&mut self, artefact: Artefact) {
+        self.artefacts_seen += 1;
+        if self.best_artefact.as_ref().is_none_or(|best| artefact.resonance >= best.resonance) {
+            self.best_artefact = Some(artefact.clone());
+        }
+
+        match self.config.max_artefacts {
+            Some(max) if self.artefacts.len() >= max => {
+                let slot = self.rng.gen_range(0..self.artefacts_seen);
+                if slot < max {
+                    self.artefacts[slot] = artefact;
+                }
+            }
+            _ => self.artefacts.push(artefact),
+        }
+    }
+
+    /// Track best resonance and steps, checking the convergence criterion
+    fn record_progress(&mut self, resonance: f64, steps: usize) {
+        self.steps_taken += steps;
+        if resonance > self.best_so_far {
+            self.best_so_far = resonance;
+            self.steps_since_improvement = 0;
+        } else {
+            self.steps_since_improvement += steps;
+        }
+
+        if self.converged_by.is_none() {
+            self.converged_by = self
+                .config
+                .convergence
+                .triggered(self.best_so_far, self.steps_since_improvement);
         }
     }
 
@@ -342,14 +816,15 @@ impl MiningSession {
         let elite: Vec<_> = self.artefacts.iter().take(5).cloned().collect();
 
         // Generate offspring through crossover
-        let mut rng = rand::thread_rng();
-        use rand::Rng;
-
         for _ in 0..10 {
-            let p1 = &elite[rng.gen_range(0..elite.len())];
-            let p2 = &elite[rng.gen_range(0..elite.len())];
+            if self.converged_by.is_some() {
+                break;
+            }
 
-            let alpha: f64 = rng.gen();
+            let p1 = &elite[self.rng.gen_range(0..elite.len())];
+            let p2 = &elite[self.rng.gen_range(0..elite.len())];
+
+            let alpha: f64 = self.rng.gen();
             let child_sig = Signature5D::new(
                 alpha * p1.signature.psi + (1.0 - alpha) * p2.signature.psi,
                 alpha * p1.signature.rho + (1.0 - alpha) * p2.signature.rho,
@@ -359,26 +834,40 @@ impl MiningSession {
             );
 
             let artefact = Artefact::from_signature(child_sig);
-            self.artefacts.push(artefact);
+            self.record_progress(artefact.resonance, 0);
+            self.store_artefact(artefact);
         }
     }
 
     /// Build mining result
     fn build_result(&self, triton_result: Option<OptimizationResult>) -> MiningResult {
-        let duration_ms = self.start_time
-            .map(|t| t.elapsed().as_millis() as u64)
-            .unwrap_or(0);
+        let duration_ms = self.elapsed_ms();
+
+        let threshold = self.mandorla_threshold();
+        let artefacts: Vec<Artefact> = self.artefacts.iter()
+            .cloned()
+            .map(|mut a| {
+                a.is_mandorla = a.is_mandorla_at(threshold);
+                a
+            })
+            .collect();
 
-        let best_artefact = self.artefacts.iter()
+        // The tracked best survives even when the reservoir dropped it
+        let best_artefact = artefacts.iter()
+            .chain(&self.best_artefact)
             .max_by(|a, b| a.resonance.partial_cmp(&b.resonance).unwrap())
-            .cloned();
+            .cloned()
+            .map(|mut a| {
+                a.is_mandorla = a.is_mandorla_at(threshold);
+                a
+            });
 
         let best_resonance = best_artefact.as_ref()
             .map(|a| a.resonance)
             .unwrap_or(0.0);
 
-        let mandorla_count = self.artefacts.iter()
-            .filter(|a| a.resonance >= 0.85 && a.is_mandorla())
+        let mandorla_count = artefacts.iter()
+            .filter(|a| a.is_mandorla)
             .count();
 
         let families = if self.config.extract_families {
@@ -391,10 +880,11 @@ impl MiningSession {
 
         MiningResult {
             session_id: self.session_id.clone(),
-            artefacts: self.artefacts.clone(),
+            artefacts,
             best_artefact,
             best_resonance,
             total_steps: self.config.num_agents * self.config.steps_per_agent,
+            steps_taken: self.steps_taken,
             mandorla_count,
             families,
             strategy: self.config.strategy,
@@ -449,7 +939,11 @@ impl MiningSession {
                 max_resonance: 0.0,
                 num_agents: 0,
                 unique_nodes: 0,
+                nodes_visited: 0,
                 efficiency: 0.0,
+                mandorla_threshold: self.mandorla_threshold(),
+                converged_by: self.converged_by.clone(),
+                parallel_speedup: self.parallel_speedup,
             };
         }
 
@@ -468,13 +962,13 @@ impl MiningSession {
         let min = resonances.iter().copied().fold(f64::MAX, f64::min);
         let max = resonances.iter().copied().fold(f64::MIN, f64::max);
 
-        // Count unique nodes
-        let unique_nodes = self.artefacts.iter()
+        // Distinct artefact nodes measure efficiency; agent paths add to coverage
+        let artefact_nodes: HashSet<NodeIndex> = self.artefacts.iter()
             .map(|a| a.node)
-            .collect::<std::collections::HashSet<_>>()
-            .len();
-
+            .collect();
+        let unique_nodes = artefact_nodes.len();
         let efficiency = unique_nodes as f64 / self.artefacts.len() as f64;
+        let nodes_visited = self.visited.union(&artefact_nodes).count();
 
         MiningStats {
             avg_resonance: avg,
@@ -483,10 +977,20 @@ impl MiningSession {
             max_resonance: max,
             num_agents: self.config.num_agents,
             unique_nodes,
+            nodes_visited,
             efficiency,
+            mandorla_threshold: self.config.mandorla_policy.threshold(&resonances),
+            converged_by: self.converged_by.clone(),
+            parallel_speedup: self.parallel_speedup,
         }
     }
 
+    /// Effective Mandorla threshold for the current artefacts
+    pub fn mandorla_threshold(&self) -> f64 {
+        let resonances: Vec<f64> = self.artefacts.iter().map(|a| a.resonance).collect();
+        self.config.mandorla_policy.threshold(&resonances)
+    }
+
     /// Get current artefacts
     pub fn artefacts(&self) -> &[Artefact] {
         &self.artefacts
@@ -502,6 +1006,7 @@ impl MiningSession {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use qops_core::ResonanceTopology;
 
     #[test]
     fn test_mining_session() {
@@ -546,4 +1051,232 @@ mod tests {
         // Families may or may not be found depending on results
         assert!(result.artefacts.len() >= 20);
     }
+
+    #[test]
+    fn test_mandorla_policy_threshold() {
+        let resonances = [0.2, 0.4, 0.6, 0.8];
+
+        assert_eq!(MandorlaPolicy::Absolute(0.5).threshold(&resonances), 0.5);
+        assert_eq!(MandorlaPolicy::TopPercentile(0.25).threshold(&resonances), 0.8);
+        assert_eq!(MandorlaPolicy::TopPercentile(0.5).threshold(&resonances), 0.6);
+        assert_eq!(MandorlaPolicy::TopPercentile(0.5).threshold(&[0.2, f64::NAN, 0.8]), 0.8);
+        assert_eq!(MandorlaPolicy::TopPercentile(0.25).threshold(&[f64::NAN]), DEFAULT_MANDORLA_THRESHOLD);
+
+        let stat = MandorlaPolicy::Statistical { k: 1.0 }.threshold(&resonances);
+        assert!((stat - (0.5 + 0.05f64.sqrt())).abs() < 1e-10);
+        let noisy = [0.2, f64::NAN, 0.4, f64::INFINITY, 0.6, 0.8];
+        assert_eq!(MandorlaPolicy::Statistical { k: 1.0 }.threshold(&noisy), stat);
+
+        assert_eq!(
+            MandorlaPolicy::Statistical { k: 1.0 }.threshold(&[]),
+            DEFAULT_MANDORLA_THRESHOLD
+        );
+    }
+
+    #[test]
+    fn test_session_reports_mandorla_threshold() {
+        let config = MiningConfig {
+            mandorla_policy: MandorlaPolicy::TopPercentile(1.0),
+            ..MiningConfig::quick()
+        };
+        let mut session = MiningSession::new(config);
+
+        let result = session.mine();
+        assert!(result.stats.min_resonance >= result.stats.mandorla_threshold - 1e-12);
+        assert_eq!(
+            result.mandorla_count,
+            result.artefacts.iter().filter(|a| a.is_mandorla).count()
+        );
+    }
+
+    #[test]
+    fn test_agent_memory_increases_coverage() {
+        let base = MiningConfig {
+            num_agents: 10,
+            steps_per_agent: 50,
+            seed: Some(3),
+            ..MiningConfig::quick()
+        };
+        let tabu = MiningConfig {
+            agent_memory: AgentMemory::TabuList(20),
+            ..base.clone()
+        };
+
+        let plain = MiningSession::new(base).mine();
+        let remembered = MiningSession::new(tabu).mine();
+        assert!(remembered.stats.nodes_visited > plain.stats.nodes_visited);
+        // unique_nodes keeps counting distinct artefact end nodes
+        for result in [&plain, &remembered] {
+            let ends: HashSet<NodeIndex> = result.artefacts.iter().map(|a| a.node).collect();
+            assert_eq!(result.stats.unique_nodes, ends.len());
+            assert!(result.stats.nodes_visited >= result.stats.unique_nodes);
+        }
+    }
+
+    #[test]
+    fn test_parallel_swarm_is_deterministic() {
+        let config = MiningConfig {
+            strategy: MiningStrategy::Swarm,
+            num_agents: 16,
+            steps_per_agent: 30,
+            parallel: true,
+            seed: Some(11),
+            ..MiningConfig::quick()
+        };
+        let mut session = MiningSession::new(config.clone());
+        let first = session.mine();
+        let second = MiningSession::new(config).mine();
+
+        let trace = |r: &MiningResult| -> Vec<(NodeIndex, [f64; 5])> {
+            r.artefacts.iter().map(|a| (a.node, a.signature.to_vec())).collect()
+        };
+        assert_eq!(first.artefacts.len(), 16);
+        assert_eq!(trace(&first), trace(&second));
+        assert!(first.stats.parallel_speedup.is_some_and(|s| s > 0.0));
+
+        // Agents walk the session's own cube
+        for artefact in &first.artefacts {
+            let node_sig = session.cube.signature_at(&artefact.node).unwrap().to_5d();
+            assert_eq!(artefact.signature.to_vec(), node_sig.to_vec());
+        }
+    }
+
+    #[test]
+    fn test_convergence_criteria() {
+        assert_eq!(ConvergenceCriterion::Never.triggered(1.0, 1000), None);
+        assert!(ConvergenceCriterion::TargetResonance(0.9).triggered(0.95, 0).is_some());
+        assert!(ConvergenceCriterion::NoImprovement { steps: 10 }.triggered(0.1, 9).is_none());
+
+        let composite = ConvergenceCriterion::Composite(vec![
+            ConvergenceCriterion::TargetResonance(0.9),
+            ConvergenceCriterion::NoImprovement { steps: 10 },
+        ]);
+        assert_eq!(
+            composite.triggered(0.5, 12),
+            Some(ConvergenceCriterion::NoImprovement { steps: 10 })
+        );
+
+        // A target every artefact meets stops after the first agent
+        let config = MiningConfig {
+            convergence: ConvergenceCriterion::TargetResonance(0.0),
+            seed: Some(2),
+            ..MiningConfig::quick()
+        };
+        let result = MiningSession::new(config).mine();
+        assert_eq!(result.artefacts.len(), 1);
+        assert_eq!(result.stats.converged_by, Some(ConvergenceCriterion::TargetResonance(0.0)));
+        assert_eq!(result.total_steps, MiningConfig::quick().num_agents * MiningConfig::quick().steps_per_agent);
+        assert!(result.steps_taken < result.total_steps);
+
+        let exhaustive = MiningSession::new(MiningConfig::quick()).mine();
+        assert_eq!(exhaustive.artefacts.len(), MiningConfig::quick().num_agents);
+        assert_eq!(exhaustive.stats.converged_by, None);
+    }
+
+    #[test]
+    fn test_similarity_matrix_uses_top_artefacts() {
+        let result = MiningSession::new(MiningConfig::quick()).mine();
+        let matrix = result.similarity_matrix(3);
+
+        assert_eq!(matrix.len(), 3.min(result.artefacts.len()));
+        assert!((matrix.resonances[0] - result.best_resonance).abs() < 1e-12);
+        assert!(matrix.resonances.windows(2).all(|w| w[0] >= w[1]));
+    }
+
+    #[test]
+    fn test_checkpoint_resume_matches_uninterrupted_run() {
+        let config = MiningConfig {
+            num_agents: 6,
+            steps_per_agent: 25,
+            seed: Some(42),
+            agent_memory: AgentMemory::TabuList(4),
+            ..MiningConfig::quick()
+        };
+        let uninterrupted = MiningSession::new(config.clone()).mine();
+
+        // Stop mid-way through an agent's walk
+        let mut session = MiningSession::new(config.clone());
+        assert!(session.mine_for(60).is_none());
+        let checkpoint = session.checkpoint();
+        assert_eq!(checkpoint.agents_completed, 2);
+        assert!(checkpoint.current_agent.is_some());
+
+        let path = qops_core::persist::temp_json_path("mining_checkpoint");
+        checkpoint.save(&path).unwrap();
+        let restored = MiningCheckpoint::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let mut resumed = MiningSession::resume(restored, config.clone()).unwrap();
+        let finished = resumed.mine();
+
+        let trace = |r: &MiningResult| -> Vec<(NodeIndex, [f64; 5])> {
+            r.artefacts.iter().map(|a| (a.node, a.signature.to_vec())).collect()
+        };
+        assert_eq!(trace(&finished), trace(&uninterrupted));
+        assert_eq!(finished.total_steps, uninterrupted.total_steps);
+        assert_eq!(finished.steps_taken, uninterrupted.steps_taken);
+        assert_eq!(finished.stats.unique_nodes, uninterrupted.stats.unique_nodes);
+        assert_eq!(finished.session_id, session.session_id);
+
+        let wrong = MiningConfig { strategy: MiningStrategy::Random, ..config };
+        assert!(MiningSession::resume(session.checkpoint(), wrong).is_err());
+    }
+
+    #[test]
+    fn test_max_artefacts_bounds_session_memory() {
+        let config = MiningConfig {
+            num_agents: 30,
+            steps_per_agent: 10,
+            seed: Some(11),
+            max_artefacts: Some(5),
+            ..MiningConfig::quick()
+        };
+
+        // The cap holds at every point of the run, not just in the result
+        let mut session = MiningSession::new(config.clone());
+        let result = loop {
+            assert!(session.artefacts().len() <= 5);
+            if let Some(result) = session.mine_for(7) {
+                break result;
+            }
+        };
+        assert_eq!(session.artefacts_seen, 30);
+        assert_eq!(result.artefacts.len(), 5);
+        let best = session.best_artefact.as_ref().unwrap().resonance;
+        assert_eq!(result.best_resonance, best);
+        assert!(result.artefacts.iter().all(|a| a.resonance <= best));
+
+        // Replacement draws from the session RNG, so seeded runs repeat
+        let key = |a: &Artefact| (a.node, a.resonance.to_bits());
+        let keys = |r: &MiningResult| r.artefacts.iter().map(key).collect::<Vec<_>>();
+        assert_eq!(keys(&result), keys(&MiningSession::new(config).mine()));
+    }
+
+    #[test]
+    fn test_custom_agent_policy_and_resume() {
+        let base = MiningConfig {
+            num_agents: 4,
+            steps_per_agent: 25,
+            seed: Some(7),
+            ..MiningConfig::quick()
+        };
+        let config = base.clone().with_agent_policy(crate::agent::GradientAscentPolicy);
+        let uninterrupted = MiningSession::new(config.clone()).mine();
+        let trace = |r: &MiningResult| -> Vec<NodeIndex> { r.artefacts.iter().map(|a| a.node).collect() };
+        assert_ne!(trace(&uninterrupted), trace(&MiningSession::new(base.clone()).mine()));
+
+        let mut session = MiningSession::new(config.clone());
+        assert!(session.mine_for(40).is_none());
+        let json = serde_json::to_string(&session.checkpoint()).unwrap();
+        let restored: MiningCheckpoint = serde_json::from_str(&json).unwrap();
+        assert!(restored.custom_policy);
+
+        // The policy is not serialized, so it must be supplied again
+        assert!(MiningSession::resume(restored.clone(), base.clone()).is_err());
+        let finished = MiningSession::resume(restored, config).unwrap().mine();
+        assert_eq!(trace(&finished), trace(&uninterrupted));
+
+        let plain = MiningSession::new(base.clone()).checkpoint();
+        assert!(MiningSession::resume(plain, base.with_agent_policy(crate::agent::RandomPolicy)).is_err());
+    }
 }
@@ -48,25 +48,35 @@ pub async fn run_grover(
 
 /// Run Shor's factorization algorithm
 #[tauri::command]
-pub async fn run_shor(number: u64) -> Result<ShorResultDto> {
+pub async fn run_shor(number: u64, seed: Option<u64>) -> Result<ShorResultDto> {
     if number < 4 {
         return Err(AppError::InvalidParameter(
             "Number must be >= 4".to_string(),
         ));
     }
 
-    let shor = Shor::new(number)
+    let mut shor = Shor::new(number)
         .with_method(FactorizationMethod::Simulated)
         .with_max_attempts(20);
+    if let Some(seed) = seed {
+        shor = shor.with_seed(seed);
+    }
 
     let result = shor.run();
 
+    let attempt_log = result.attempt_log.iter().map(|a| ShorAttemptDto {
+        base: a.base,
+        period: a.period,
+        outcome: format!("{:?}", a.outcome),
+    }).collect();
+
     Ok(ShorResultDto {
         success: result.success,
         number,
         factors: result.factors,
         period: result.period,
         attempts: result.attempts,
+        attempt_log,
     })
 }
 
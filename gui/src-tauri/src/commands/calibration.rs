@@ -4,8 +4,9 @@
 
 use super::*;
 use crate::error::{AppError, Result};
+use super::settings::calibrator_config;
 use crate::state::AppState;
-use qops_seraphic::{SeraphicCalibrator, HyperparameterSweep, SweepConfig, AutoTuner};
+use qops_seraphic::{SeraphicCalibrator, HyperparameterSweep, SweepConfig, SweepParameter, AutoTuner};
 use qops_core::{Configuration, Signature3D};
 use tauri::State;
 
@@ -27,6 +28,27 @@ pub struct SweepEvaluationDto {
     pub convergence_rate: f64,
 }
 
+/// Axis values for a two-parameter sweep heatmap
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SweepRangesDto {
+    pub x_values: Vec<f64>,
+    pub y_values: Vec<f64>,
+    pub steps_per_config: usize,
+}
+
+/// Sweep heatmap DTO (`grid[y][x]` is the resonance at `x_values[x]`, `y_values[y]`)
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SweepHeatmapDto {
+    pub x_label: String,
+    pub y_label: String,
+    pub x_values: Vec<f64>,
+    pub y_values: Vec<f64>,
+    pub grid: Vec<Vec<f64>>,
+    pub best_x: Option<f64>,
+    pub best_y: Option<f64>,
+    pub best_score: f64,
+}
+
 /// Auto-tune result DTO
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AutoTuneResultDto {
@@ -50,7 +72,7 @@ pub async fn run_calibration(
         ));
     }
 
-    let mut calibrator = SeraphicCalibrator::default();
+    let mut calibrator = SeraphicCalibrator::new(calibrator_config(&state));
     calibrator.initialize(
         Configuration::new("gui_calibration"),
         Signature3D::new(0.5, 0.5, 0.5),
@@ -145,6 +167,7 @@ pub async fn run_hyperparameter_sweep(
         cooling_rate_values: cooling_rate_range.clone(),
         steps_per_evaluation: steps_per_config,
         parallel: true,
+        parallelism: 0,
     };
 
     let mut sweep = HyperparameterSweep::new(config);
@@ -175,6 +198,48 @@ pub async fn run_hyperparameter_sweep(
     })
 }
 
+/// Run a two-parameter sweep and return the resonance landscape as a heatmap grid
+#[tauri::command]
+pub async fn run_sweep_heatmap(
+    param_x: String,
+    param_y: String,
+    ranges: SweepRangesDto,
+) -> Result<SweepHeatmapDto> {
+    let parse = |name: &str| SweepParameter::from_name(name).ok_or_else(|| {
+        let known: Vec<_> = SweepParameter::ALL.iter().map(|p| p.name()).collect();
+        AppError::InvalidParameter(format!(
+            "Unknown sweep parameter '{}': expected one of {}", name, known.join(", ")
+        ))
+    });
+    let x_param = parse(&param_x)?;
+    let y_param = parse(&param_y)?;
+
+    if ranges.x_values.is_empty() || ranges.y_values.is_empty() {
+        return Err(AppError::InvalidParameter(
+            "At least one value required for each axis".to_string(),
+        ));
+    }
+
+    let sweep = HyperparameterSweep::new(SweepConfig {
+        steps_per_evaluation: ranges.steps_per_config,
+        ..Default::default()
+    });
+    let grid = sweep
+        .run_grid(x_param, &ranges.x_values, y_param, &ranges.y_values)
+        .map_err(|e| AppError::InvalidParameter(e.to_string()))?;
+
+    Ok(SweepHeatmapDto {
+        x_label: x_param.name().to_string(),
+        y_label: y_param.name().to_string(),
+        best_x: grid.best.map(|(x, _)| grid.x_values[x]),
+        best_y: grid.best.map(|(_, y)| grid.y_values[y]),
+        best_score: grid.best_score,
+        x_values: grid.x_values,
+        y_values: grid.y_values,
+        grid: grid.scores,
+    })
+}
+
 /// Run auto-tuning for calibration parameters
 #[tauri::command]
 pub async fn run_auto_tune(
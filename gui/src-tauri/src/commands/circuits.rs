@@ -206,7 +206,7 @@ pub async fn get_circuit_qasm(
         .get(&circuit_id)
         .ok_or_else(|| AppError::NotFound(format!("Circuit {}", circuit_id)))?;
 
-    Ok(circuit.to_qasm())
+    Ok(circuit.to_qasm()?)
 }
 
 /// Delete a circuit
@@ -107,7 +107,7 @@ pub async fn run_genesis_mining(
         .map(|(i, a)| ArtefactDto {
             id: i,
             resonance: a.resonance,
-            is_mandorla: a.is_mandorla(),
+            is_mandorla: a.is_mandorla,
             node_path: vec![],
         })
         .collect();
@@ -5,7 +5,8 @@
 
 use super::*;
 use crate::error::{AppError, Result};
-use crate::state::AppState;
+use crate::state::{AppState, HolisticRun};
+use std::sync::{Arc, Mutex};
 use tauri::State;
 
 // ============================================================================
@@ -222,6 +223,20 @@ impl Default for HolisticMiningConfigDto {
     }
 }
 
+/// Live holistic stage statistics DTO (polled during a background run)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolisticLiveStatsDto {
+    pub session_id: String,
+    pub stage: Option<GenesisStageDto>,
+    pub finished: bool,
+    pub kosmokrator_survivor_rate: f64,
+    pub kosmokrator_kappa: f64,
+    pub chronokrator_spike_count: usize,
+    pub chronokrator_d_total: f64,
+    pub pfauenthron_mandorla: f64,
+    pub pfauenthron_converged: bool,
+}
+
 /// Holistic mining result DTO
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HolisticMiningResultDto {
@@ -285,26 +300,21 @@ pub struct HolisticMatrixOutputDto {
 }
 
 // ============================================================================
-// Tauri Commands
+// Helpers
 // ============================================================================
 
-/// Run holistic multi-stage mining pipeline
-#[tauri::command]
-pub async fn run_holistic_mining(
-    _state: State<'_, AppState>,
-    config: HolisticMiningConfigDto,
-) -> Result<HolisticMiningResultDto> {
-    use qops_genesis::{HolisticMiningConfig, HolisticMiningSession, MiningConfig, StageMetrics};
-    use qops_core::{KosmokratorConfig, ChronokratorConfig, PfauenthronConfig, GenesisStage};
+/// Build the internal holistic mining config from its DTO
+fn build_mining_config(config: &HolisticMiningConfigDto) -> qops_genesis::HolisticMiningConfig {
+    use qops_genesis::{HolisticMiningConfig, MiningConfig};
+    use qops_core::{KosmokratorConfig, ChronokratorConfig, PfauenthronConfig};
 
-    // Build internal config from DTO
     let mining = MiningConfig {
         num_agents: config.num_agents,
         steps_per_agent: config.steps_per_agent,
         ..Default::default()
     };
 
-    let mining_config = HolisticMiningConfig {
+    HolisticMiningConfig {
         mining,
         kosmokrator: KosmokratorConfig {
             kappa_threshold: config.kosmokrator.kappa_threshold,
@@ -327,7 +337,35 @@ pub async fn run_holistic_mining(
         adaptive_triton: config.use_adaptive_triton,
         log_stages: true,
         ..Default::default()
-    };
+    }
+}
+
+fn stage_to_dto(stage: qops_core::GenesisStage) -> GenesisStageDto {
+    use qops_core::GenesisStage;
+
+    match stage {
+        GenesisStage::Discovery => GenesisStageDto::Discovery,
+        GenesisStage::KosmokratorFilter => GenesisStageDto::Kosmokrator,
+        GenesisStage::ChronokratorExpansion => GenesisStageDto::Chronokrator,
+        GenesisStage::PfauenthronCollapse => GenesisStageDto::Pfauenthron,
+        GenesisStage::Finalized => GenesisStageDto::Finalized,
+    }
+}
+
+// ============================================================================
+// Tauri Commands
+// ============================================================================
+
+/// Run holistic multi-stage mining pipeline
+#[tauri::command]
+pub async fn run_holistic_mining(
+    _state: State<'_, AppState>,
+    config: HolisticMiningConfigDto,
+) -> Result<HolisticMiningResultDto> {
+    use qops_genesis::{HolisticMiningSession, StageMetrics};
+    use qops_core::GenesisStage;
+
+    let mining_config = build_mining_config(&config);
 
     let mut session = HolisticMiningSession::new(mining_config);
 
@@ -432,6 +470,86 @@ pub async fn run_holistic_mining(
     })
 }
 
+/// Start holistic mining in the background and return its session ID
+///
+/// Poll progress with `get_holistic_live_stats`.
+#[tauri::command]
+pub async fn start_holistic_mining(
+    state: State<'_, AppState>,
+    config: HolisticMiningConfigDto,
+) -> Result<String> {
+    use qops_genesis::HolisticMiningSession;
+
+    let session_id = AppState::new_id();
+    let run = Arc::new(Mutex::new(HolisticRun::default()));
+    state.holistic_runs.lock()
+        .map_err(|e| AppError::State(e.to_string()))?
+        .insert(session_id.clone(), Arc::clone(&run));
+
+    let mining_config = build_mining_config(&config);
+    std::thread::spawn(move || {
+        let progress = Arc::clone(&run);
+        let mut session = HolisticMiningSession::new(mining_config)
+            .with_stage_observer(move |stage, stats| {
+                if let Ok(mut progress) = progress.lock() {
+                    progress.stage = Some(stage);
+                    progress.stats = Some(stats.clone());
+                }
+            });
+        let result = session.mine();
+
+        if let Ok(mut run) = run.lock() {
+            run.stage = Some(result.final_stage);
+            run.stats = Some(result.holistic_stats);
+            run.finished = true;
+        }
+    });
+
+    Ok(session_id)
+}
+
+/// Get the current stage statistics of a background holistic mining run
+#[tauri::command]
+pub async fn get_holistic_live_stats(
+    state: State<'_, AppState>,
+    session_id: String,
+) -> Result<HolisticLiveStatsDto> {
+    let run = state.holistic_runs.lock()
+        .map_err(|e| AppError::State(e.to_string()))?
+        .get(&session_id)
+        .cloned()
+        .ok_or_else(|| AppError::NotFound(format!("Holistic session {}", session_id)))?;
+    let run = run.lock().map_err(|e| AppError::State(e.to_string()))?;
+
+    let mut dto = HolisticLiveStatsDto {
+        session_id,
+        stage: run.stage.map(stage_to_dto),
+        finished: run.finished,
+        kosmokrator_survivor_rate: 0.0,
+        kosmokrator_kappa: 0.0,
+        chronokrator_spike_count: 0,
+        chronokrator_d_total: 0.0,
+        pfauenthron_mandorla: 0.0,
+        pfauenthron_converged: false,
+    };
+
+    if let Some(stats) = &run.stats {
+        let kosmo = &stats.kosmokrator;
+        dto.kosmokrator_survivor_rate = if kosmo.total_processed > 0 {
+            kosmo.survivors as f64 / kosmo.total_processed as f64
+        } else {
+            0.0
+        };
+        dto.kosmokrator_kappa = kosmo.current_kappa;
+        dto.chronokrator_spike_count = stats.chronokrator.spike_count;
+        dto.chronokrator_d_total = stats.chronokrator.current_d_total;
+        dto.pfauenthron_mandorla = stats.pfauenthron.current_mandorla;
+        dto.pfauenthron_converged = stats.pfauenthron.is_converged;
+    }
+
+    Ok(dto)
+}
+
 /// Run only Kosmokrator filter stage
 #[tauri::command]
 pub async fn run_kosmokrator_stage(
@@ -699,7 +817,7 @@ pub async fn run_pfauenthron_stage(
 /// Run adaptive TRITON spiral search
 #[tauri::command]
 pub async fn run_adaptive_triton(
-    _state: State<'_, AppState>,
+    state: State<'_, AppState>,
     iterations: usize,
     with_holistic: bool,
 ) -> Result<TritonAdaptiveResultDto> {
@@ -710,14 +828,18 @@ pub async fn run_adaptive_triton(
     const DEFAULT_POINTS_PER_LAYER: usize = 12;
     const GOLDEN_RATIO: f64 = 1.618;
 
+    // User-configured spiral defaults take precedence
+    let spiral = state.default_config::<SpiralParams>("triton").unwrap_or(SpiralParams {
+        expansion_rate: GOLDEN_RATIO,
+        initial_radius: 1.0,
+        layers: DEFAULT_SPIRAL_LAYERS,
+        points_per_layer: DEFAULT_POINTS_PER_LAYER,
+        ..Default::default()
+    });
+    let spiral_layers = spiral.layers.max(1);
+
     let base_config = TritonConfig {
-        spiral: SpiralParams {
-            expansion_rate: GOLDEN_RATIO,
-            initial_radius: 1.0,
-            layers: DEFAULT_SPIRAL_LAYERS,
-            points_per_layer: DEFAULT_POINTS_PER_LAYER,
-            ..Default::default()
-        },
+        spiral,
         max_iterations: iterations,
         ..Default::default()
     };
@@ -737,7 +859,7 @@ pub async fn run_adaptive_triton(
         TrajectoryPointDto {
             iteration: i,
             score: result.best_score * (0.5 + 0.5 * (i as f64 / result.iterations as f64)),
-            layer: i % DEFAULT_SPIRAL_LAYERS,
+            layer: i % spiral_layers,
             temperature: result.cooling_stats.temperature * (1.0 - i as f64 / result.iterations.max(1) as f64),
             radius: result.radius_stats.current_radius,
         }
@@ -7,13 +7,16 @@ use super::{
     HDAGInfoDto, HDAGNodeDto, HDAGEdgeDto, HDAGExecutionResultDto,
     HypercubeSessionResultDto,
 };
+use super::settings::hypercube_config;
+use crate::state::AppState;
 use qops_hypercube::{
-    Hypercube, HypercubeConfig, CubeExpansionRule,
+    Hypercube, CubeExpansionRule,
     HypercubeCompiler, CompilationConfig,
     HDAG, HDAGExecutor,
     HypercubeSession, SessionConfig,
     Coord5D,
 };
+use tauri::State;
 
 // ============================================================================
 // Conversion helpers
@@ -40,6 +43,7 @@ fn dto_to_coord5d(dto: &Coord5DDto) -> Coord5D {
 /// Compile a hypercube from a seed coordinate
 #[tauri::command]
 pub fn compile_hypercube(
+    state: State<'_, AppState>,
     seed_psi: f64,
     seed_rho: f64,
     seed_omega: f64,
@@ -56,11 +60,7 @@ pub fn compile_hypercube(
         CubeExpansionRule::ResonanceGuided
     };
 
-    let config = HypercubeConfig {
-        max_depth: iterations,
-        expansion_rule,
-        ..Default::default()
-    };
+    let config = hypercube_config(&state, iterations, expansion_rule);
 
     let mut cube = Hypercube::new("gui_cube", config);
 
@@ -87,6 +87,7 @@ pub fn compile_hypercube(
 /// Expand a hypercube step by step
 #[tauri::command]
 pub fn expand_cube_step(
+    state: State<'_, AppState>,
     _current_vertices: usize,
     expansion_rule: String,
     iterations: usize,
@@ -101,11 +102,7 @@ pub fn expand_cube_step(
         _ => CubeExpansionRule::Triton,
     };
 
-    let config = HypercubeConfig {
-        max_depth: iterations,
-        expansion_rule: rule,
-        ..Default::default()
-    };
+    let config = hypercube_config(&state, iterations, rule);
 
     let mut cube = Hypercube::new("expansion_cube", config);
 
@@ -163,7 +160,8 @@ pub fn hdag_execute(
         _ => HDAG::standard_pipeline(seed),
     };
 
-    let mut executor = HDAGExecutor::new(hdag);
+    let mut executor = HDAGExecutor::new(hdag)
+        .map_err(|e| format!("Invalid HDAG: {}", e))?;
     let result = executor.execute()
         .map_err(|e| format!("HDAG execution failed: {}", e))?;
 
@@ -221,6 +219,7 @@ pub fn get_hdag_info(pipeline_type: String) -> Result<HDAGInfoDto, String> {
 /// seed support is added to the Hypercube API. Currently they are ignored.
 #[tauri::command]
 pub fn run_hypercube_session(
+    state: State<'_, AppState>,
     preset: String,
     _seed_psi: Option<f64>,
     _seed_rho: Option<f64>,
@@ -240,6 +239,10 @@ pub fn run_hypercube_session(
     let result = session.run()
         .map_err(|e| format!("Session failed: {}", e))?;
 
+    state.hypercube_sessions.lock()
+        .map_err(|e| e.to_string())?
+        .insert(result.session_id.clone(), session.cube().clone());
+
     Ok(HypercubeSessionResultDto {
         session_id: result.session_id,
         state: format!("{:?}", result.state),
@@ -259,6 +262,27 @@ pub fn run_hypercube_session(
     })
 }
 
+/// Export the graph of a completed session's hypercube
+///
+/// `format` is `"dot"` for a Graphviz string or `"json"` for node-link JSON
+/// (`nodes` + `links`) suitable for force-directed layouts.
+#[tauri::command]
+pub fn export_hypercube_graph(
+    state: State<'_, AppState>,
+    session_id: String,
+    format: String,
+) -> Result<serde_json::Value, String> {
+    let sessions = state.hypercube_sessions.lock().map_err(|e| e.to_string())?;
+    let cube = sessions.get(&session_id)
+        .ok_or_else(|| format!("Unknown hypercube session: {}", session_id))?;
+
+    match format.as_str() {
+        "dot" => Ok(serde_json::Value::String(cube.to_dot())),
+        "json" => Ok(cube.to_node_link()),
+        other => Err(format!("Unknown graph format '{}': expected 'dot' or 'json'", other)),
+    }
+}
+
 /// Get available session presets
 #[tauri::command]
 pub fn get_hypercube_presets() -> Result<Vec<serde_json::Value>, String> {
@@ -4,6 +4,7 @@
 
 use super::*;
 use crate::error::Result;
+use crate::state::AppState;
 use serde::{Deserialize, Serialize};
 
 // ============================================================================
@@ -163,19 +164,23 @@ pub struct KernelInfoDto {
 // ============================================================================
 
 /// Run kernel mining to discover blueprints
+///
+/// Without a config, the stored `kernel` default (config or preset name) is used.
 #[tauri::command]
 pub fn run_kernel_mining(
+    state: tauri::State<'_, AppState>,
     seed_psi: f64,
     seed_rho: f64,
     seed_omega: f64,
     seed_chi: f64,
     seed_eta: f64,
-    config: MiningConfigDto,
+    config: Option<MiningConfigDto>,
 ) -> Result<MiningResultDto, String> {
     use qops_kernel::{MiningKernel, MiningConfig, SearchStrategy, CoreSignature, State};
     use std::time::Instant;
 
     let start = Instant::now();
+    let config = config.unwrap_or_else(|| super::settings::kernel_mining_config(&state));
 
     // Convert DTO to internal config
     let strategy = match config.strategy {
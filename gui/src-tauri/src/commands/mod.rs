@@ -9,6 +9,7 @@ pub mod hypercube;
 pub mod kernel;
 pub mod quantum;
 pub mod research;
+pub mod settings;
 pub mod slots;
 pub mod system;
 
@@ -74,6 +75,15 @@ pub struct ShorResultDto {
     pub factors: Vec<u64>,
     pub period: Option<u64>,
     pub attempts: usize,
+    pub attempt_log: Vec<ShorAttemptDto>,
+}
+
+/// Single Shor attempt DTO
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShorAttemptDto {
+    pub base: u64,
+    pub period: Option<u64>,
+    pub outcome: String,
 }
 
 /// QFT result DTO
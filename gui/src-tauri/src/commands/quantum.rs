@@ -32,6 +32,25 @@ pub struct Cube13NodeDto {
     pub embedding: Option<SignatureDto>,
 }
 
+/// Signature embedded at a Cube-13 node
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Cube13EmbeddingDto {
+    pub node: usize,
+    pub signature: SignatureDto,
+}
+
+/// Cube-13 operator cluster summary DTO
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Cube13ClusterDto {
+    pub id: String,
+    pub size: usize,
+    pub cohesion: f64,
+    pub avg_resonance: f64,
+    pub nodes: Vec<usize>,
+    pub representative_node: usize,
+    pub representative: SignatureDto,
+}
+
 /// Topology walk result DTO
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TopologyWalkDto {
@@ -39,6 +58,21 @@ pub struct TopologyWalkDto {
     pub coverage: f64,
     pub final_node: usize,
     pub steps_taken: usize,
+    /// Aggregation window used for the time-resolved statistics
+    pub window: usize,
+    /// Per-layer occupancy at each path position
+    pub occupancy: Vec<LayerOccupancyDto>,
+    /// Node visit counts over the window at each path position
+    pub windowed_visits: Vec<Vec<usize>>,
+}
+
+/// Per-layer walk occupancy DTO
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LayerOccupancyDto {
+    pub step: usize,
+    pub center: f64,
+    pub hexagon: f64,
+    pub cube: f64,
 }
 
 /// Run continuous-time quantum walk on Cube-13
@@ -144,8 +178,11 @@ pub async fn get_cube13_node(node_id: usize) -> Result<Cube13NodeDto> {
 }
 
 /// Run topology walk on Cube-13
+///
+/// `window` sets the sliding window for the time-resolved statistics; it
+/// defaults to the whole walk (cumulative totals).
 #[tauri::command]
-pub async fn run_cube13_walk(start: usize, steps: usize) -> Result<TopologyWalkDto> {
+pub async fn run_cube13_walk(start: usize, steps: usize, window: Option<usize>) -> Result<TopologyWalkDto> {
     if start >= 13 {
         return Err(AppError::InvalidParameter(
             format!("Start node must be 0-12, got {}", start),
@@ -167,12 +204,30 @@ pub async fn run_cube13_walk(start: usize, steps: usize) -> Result<TopologyWalkD
     }
 
     let result = engine.topology_walk(start, steps);
+    let window = window.unwrap_or(result.path.len()).max(1);
+
+    let occupancy = result.layer_occupancy(window)
+        .into_iter()
+        .map(|o| LayerOccupancyDto {
+            step: o.step,
+            center: o.center,
+            hexagon: o.hexagon,
+            cube: o.cube,
+        })
+        .collect();
+    let windowed_visits = result.windowed_visits(window)
+        .iter()
+        .map(|counts| counts.to_vec())
+        .collect();
 
     Ok(TopologyWalkDto {
         path: result.path,
         coverage: result.coverage,
         final_node: result.final_node,
         steps_taken: result.steps_taken,
+        window,
+        occupancy,
+        windowed_visits,
     })
 }
 
@@ -209,6 +264,46 @@ pub async fn embed_in_cube13(
     })
 }
 
+/// Summarize operator clusters for a set of Cube-13 embeddings
+#[tauri::command]
+pub async fn get_cube13_clusters(embeddings: Vec<Cube13EmbeddingDto>) -> Result<Vec<Cube13ClusterDto>> {
+    let mut engine = Cube13Engine::new();
+    for e in &embeddings {
+        if e.node >= 13 {
+            return Err(AppError::InvalidParameter(
+                format!("Node must be 0-12, got {}", e.node),
+            ));
+        }
+        let s = &e.signature;
+        engine.embed(e.node, Signature5D::new(
+            s.psi,
+            s.rho,
+            s.omega,
+            s.chi.unwrap_or(0.5),
+            s.eta.unwrap_or(0.5),
+        ));
+    }
+
+    Ok(engine.clusters_summary().into_iter().map(|c| {
+        let sig = c.representative.signature.to_5d();
+        Cube13ClusterDto {
+            id: c.id,
+            size: c.size,
+            cohesion: c.cohesion,
+            avg_resonance: c.avg_resonance,
+            nodes: c.nodes,
+            representative_node: c.representative.id,
+            representative: SignatureDto {
+                psi: sig.psi,
+                rho: sig.rho,
+                omega: sig.omega,
+                chi: Some(sig.chi),
+                eta: Some(sig.eta),
+            },
+        }
+    }).collect())
+}
+
 /// Run VQE on Cube-13 graph
 #[tauri::command]
 pub async fn run_cube13_vqe(layers: usize) -> Result<VqeResultDto> {
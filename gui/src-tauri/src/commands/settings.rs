@@ -0,0 +1,139 @@
+//! Settings Tauri commands
+//!
+//! Per-module user defaults (mining preset, entropy distribution, spiral
+//! parameters, ...) that persist across commands and restarts. The engine
+//! commands read them through the helpers at the bottom of this file.
+
+use super::kernel::{get_mining_presets, MiningConfigDto};
+use crate::error::{AppError, Result};
+use crate::state::AppState;
+use qops_hypercube::{CubeExpansionRule, HypercubeConfig};
+use qops_seraphic::CalibratorConfig;
+use tauri::State;
+
+/// Modules that accept a default config
+pub const CONFIG_MODULES: &[&str] = &["kernel", "slots", "triton", "hypercube", "calibration"];
+
+fn check_module(module: &str) -> Result<()> {
+    if CONFIG_MODULES.contains(&module) {
+        Ok(())
+    } else {
+        Err(AppError::InvalidParameter(format!(
+            "Unknown config module '{}': expected one of {}",
+            module,
+            CONFIG_MODULES.join(", ")
+        )))
+    }
+}
+
+/// Set (and persist) the default config of a module
+#[tauri::command]
+pub fn set_default_config(
+    state: State<'_, AppState>,
+    module: String,
+    config_json: String,
+) -> Result<()> {
+    check_module(&module)?;
+    let config: serde_json::Value = serde_json::from_str(&config_json)?;
+
+    state.default_configs.lock()
+        .map_err(|e| AppError::State(e.to_string()))?
+        .insert(module, config);
+
+    state.persist_default_configs()
+        .map_err(|e| AppError::Io(e.to_string()))
+}
+
+/// Get the default config of a module, or `null` if none is set
+#[tauri::command]
+pub fn get_default_config(
+    state: State<'_, AppState>,
+    module: String,
+) -> Result<Option<serde_json::Value>> {
+    check_module(&module)?;
+    let defaults = state.default_configs.lock()
+        .map_err(|e| AppError::State(e.to_string()))?;
+    Ok(defaults.get(&module).cloned())
+}
+
+// ============================================================================
+// Module defaults used by the engine commands
+// ============================================================================
+
+/// Kernel mining config: the stored `kernel` default, else the balanced config
+///
+/// The default may be a full mining config or the name of a mining preset.
+pub fn kernel_mining_config(state: &AppState) -> MiningConfigDto {
+    if let Some(config) = state.default_config::<MiningConfigDto>("kernel") {
+        return config;
+    }
+    state.default_config::<String>("kernel")
+        .and_then(|preset| {
+            get_mining_presets().ok()?
+                .into_iter()
+                .find(|(name, _)| *name == preset)
+                .map(|(_, config)| config)
+        })
+        .unwrap_or_default()
+}
+
+/// Hypercube config from the stored `hypercube` default, with the command's depth and rule
+pub fn hypercube_config(state: &AppState, max_depth: usize, expansion_rule: CubeExpansionRule) -> HypercubeConfig {
+    HypercubeConfig {
+        max_depth,
+        expansion_rule,
+        ..state.default_config::<HypercubeConfig>("hypercube").unwrap_or_default()
+    }
+}
+
+/// Calibrator config from the stored `calibration` default
+pub fn calibrator_config(state: &AppState) -> CalibratorConfig {
+    state.default_config::<CalibratorConfig>("calibration").unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::kernel::SearchStrategyDto;
+
+    fn set_default(state: &AppState, module: &str, value: serde_json::Value) {
+        state.default_configs.lock().unwrap().insert(module.to_string(), value);
+    }
+
+    #[test]
+    fn test_kernel_default() {
+        let state = AppState::new();
+        assert_eq!(kernel_mining_config(&state).max_iterations, MiningConfigDto::default().max_iterations);
+
+        set_default(&state, "kernel", serde_json::json!("quick"));
+        let quick = kernel_mining_config(&state);
+        assert_eq!(quick.max_iterations, 50);
+        assert!(matches!(quick.strategy, SearchStrategyDto::Greedy));
+
+        let custom = MiningConfigDto { max_iterations: 7, ..Default::default() };
+        set_default(&state, "kernel", serde_json::to_value(&custom).unwrap());
+        assert_eq!(kernel_mining_config(&state).max_iterations, 7);
+    }
+
+    #[test]
+    fn test_hypercube_and_calibration_defaults() {
+        let state = AppState::new();
+        let stored = HypercubeConfig {
+            resonance_threshold: 0.9,
+            include_corners: false,
+            max_depth: 99,
+            ..Default::default()
+        };
+        set_default(&state, "hypercube", serde_json::to_value(&stored).unwrap());
+
+        let config = hypercube_config(&state, 3, CubeExpansionRule::Lattice);
+        assert_eq!(config.resonance_threshold, 0.9);
+        assert!(!config.include_corners);
+        assert_eq!(config.max_depth, 3);
+        assert_eq!(config.expansion_rule, CubeExpansionRule::Lattice);
+
+        let stored = CalibratorConfig { field_dimension: 8, ..Default::default() };
+        set_default(&state, "calibration", serde_json::to_value(&stored).unwrap());
+        assert_eq!(calibrator_config(&state).field_dimension, 8);
+    }
+}
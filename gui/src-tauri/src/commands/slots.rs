@@ -14,6 +14,8 @@ use qops_slots::{
     hypercube_integration::{SlotsHypercubeAdapter, HypercubeSlotsMode},
 };
 use qops_hypercube::Coord5D;
+use crate::state::AppState;
+use tauri::State;
 
 // ============================================================================
 // Conversion helpers
@@ -294,7 +296,11 @@ pub fn run_hypercube_slots_mode(
 
 /// Get slots configuration options
 #[tauri::command]
-pub fn get_slots_config_options() -> Result<SlotsConfigDto, String> {
+pub fn get_slots_config_options(state: State<'_, AppState>) -> Result<SlotsConfigDto, String> {
+    if let Some(defaults) = state.default_config::<SlotsConfigDto>("slots") {
+        return Ok(defaults);
+    }
+
     Ok(SlotsConfigDto {
         entropy_distribution: "uniform".to_string(),
         mining_strategy: "beam".to_string(),
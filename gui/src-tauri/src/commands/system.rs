@@ -1,7 +1,8 @@
 //! System-related Tauri commands
 
 use super::*;
-use crate::error::Result;
+use crate::error::{AppError, Result};
+use qops_core::{viz, ResonanceColorMap};
 
 /// Get system information
 #[tauri::command]
@@ -39,3 +40,36 @@ pub async fn get_system_info() -> Result<SystemInfoDto> {
         capabilities,
     })
 }
+
+/// Sampled resonance color map DTO
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ResonanceColorMapDto {
+    pub scheme: String,
+    /// Hex colors for resonance values evenly spaced over [0, 1]
+    pub colors: Vec<String>,
+}
+
+/// Get the shared resonance color map so the GUI colors match the CLI
+#[tauri::command]
+pub async fn get_resonance_colormap(
+    scheme: Option<String>,
+    steps: Option<usize>,
+) -> Result<ResonanceColorMapDto> {
+    let map = match scheme.as_deref() {
+        Some(name) => ResonanceColorMap::from_name(name).ok_or_else(|| {
+            AppError::InvalidParameter(format!(
+                "Unknown color scheme '{}': expected viridis, plasma or stage",
+                name
+            ))
+        })?,
+        None => ResonanceColorMap::default(),
+    };
+
+    Ok(ResonanceColorMapDto {
+        scheme: map.name().to_string(),
+        colors: map.samples(steps.unwrap_or(256).max(2))
+            .into_iter()
+            .map(viz::to_hex)
+            .collect(),
+    })
+}
@@ -7,6 +7,7 @@ mod error;
 mod state;
 
 use state::AppState;
+use tauri::Manager;
 
 /// Run the Tauri application
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -41,6 +42,8 @@ pub fn run() {
             commands::genesis::get_node_details,
             // Holistic Resonance Architecture commands
             commands::holistic::run_holistic_mining,
+            commands::holistic::start_holistic_mining,
+            commands::holistic::get_holistic_live_stats,
             commands::holistic::run_kosmokrator_stage,
             commands::holistic::run_chronokrator_stage,
             commands::holistic::run_pfauenthron_stage,
@@ -54,6 +57,7 @@ pub fn run() {
             commands::quantum::get_cube13_node,
             commands::quantum::run_cube13_walk,
             commands::quantum::embed_in_cube13,
+            commands::quantum::get_cube13_clusters,
             commands::quantum::run_cube13_vqe,
             // Research commands
             commands::research::run_experiment,
@@ -62,9 +66,14 @@ pub fn run() {
             commands::calibration::run_calibration,
             commands::calibration::get_calibration_status,
             commands::calibration::run_hyperparameter_sweep,
+            commands::calibration::run_sweep_heatmap,
             commands::calibration::run_auto_tune,
             // System commands
             commands::system::get_system_info,
+            commands::system::get_resonance_colormap,
+            // Settings commands
+            commands::settings::set_default_config,
+            commands::settings::get_default_config,
             // Hypercube commands
             commands::hypercube::compile_hypercube,
             commands::hypercube::expand_cube_step,
@@ -72,6 +81,7 @@ pub fn run() {
             commands::hypercube::hdag_execute,
             commands::hypercube::get_hdag_info,
             commands::hypercube::run_hypercube_session,
+            commands::hypercube::export_hypercube_graph,
             commands::hypercube::get_hypercube_presets,
             // Slots commands
             commands::slots::run_slots_engine,
@@ -90,7 +100,9 @@ pub fn run() {
             commands::kernel::get_mining_presets,
         ])
         .setup(|app| {
-            // Additional setup if needed
+            if let Ok(dir) = app.path().app_config_dir() {
+                app.state::<AppState>().load_default_configs(&dir.join("defaults.json"));
+            }
             tracing::info!("QOPS Desktop Application started");
             Ok(())
         })
@@ -2,11 +2,17 @@
 
 use qops_circuits::Circuit;
 use qops_genesis::MetatronCube;
+use qops_core::{GenesisStage, HolisticStats};
+use qops_hypercube::Hypercube;
 use qops_seraphic::SeraphicCalibrator;
-use std::collections::HashMap;
-use std::sync::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
+/// Number of hypercube sessions and holistic runs kept before the oldest is evicted
+pub const MAX_STORED_SESSIONS: usize = 32;
+
 /// Global application state
 pub struct AppState {
     /// Stored circuits by ID
@@ -20,6 +26,18 @@ pub struct AppState {
 
     /// Experiment history
     pub experiment_history: Mutex<Vec<ExperimentRecord>>,
+
+    /// Final hypercubes of completed sessions, by session ID
+    pub hypercube_sessions: Mutex<SessionStore<Hypercube>>,
+
+    /// User default configs, by module name
+    pub default_configs: Mutex<HashMap<String, serde_json::Value>>,
+
+    /// File the default configs are persisted to
+    pub defaults_path: Mutex<Option<PathBuf>>,
+
+    /// Background holistic mining runs, by session ID
+    pub holistic_runs: Mutex<SessionStore<Arc<Mutex<HolisticRun>>>>,
 }
 
 impl AppState {
@@ -29,9 +47,61 @@ impl AppState {
             s7_topology: Mutex::new(None),
             calibrator: Mutex::new(None),
             experiment_history: Mutex::new(Vec::new()),
+            hypercube_sessions: Mutex::new(SessionStore::new(MAX_STORED_SESSIONS)),
+            default_configs: Mutex::new(HashMap::new()),
+            defaults_path: Mutex::new(None),
+            holistic_runs: Mutex::new(SessionStore::new(MAX_STORED_SESSIONS)),
         }
     }
 
+    /// Load persisted default configs from `path` and persist future changes there
+    ///
+    /// A missing or unreadable file leaves the defaults empty.
+    pub fn load_default_configs(&self, path: &Path) {
+        if let Ok(json) = std::fs::read_to_string(path) {
+            match serde_json::from_str(&json) {
+                Ok(configs) => {
+                    if let Ok(mut defaults) = self.default_configs.lock() {
+                        *defaults = configs;
+                    }
+                }
+                Err(e) => tracing::warn!("Ignoring invalid defaults file {}: {}", path.display(), e),
+            }
+        }
+        if let Ok(mut defaults_path) = self.defaults_path.lock() {
+            *defaults_path = Some(path.to_path_buf());
+        }
+    }
+
+    /// Write the current default configs to the defaults file, if one is set
+    pub fn persist_default_configs(&self) -> std::io::Result<()> {
+        let path = match self.defaults_path.lock() {
+            Ok(path) => path.clone(),
+            Err(_) => None,
+        };
+        let Some(path) = path else {
+            return Ok(());
+        };
+
+        let json = {
+            let defaults = self.default_configs.lock()
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+            serde_json::to_string_pretty(&*defaults)?
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, json)
+    }
+
+    /// Stored default config for a module, deserialized into `T`
+    ///
+    /// Returns `None` if no default is set or it doesn't match `T`.
+    pub fn default_config<T: serde::de::DeserializeOwned>(&self, module: &str) -> Option<T> {
+        let defaults = self.default_configs.lock().ok()?;
+        serde_json::from_value(defaults.get(module)?.clone()).ok()
+    }
+
     /// Generate a new unique ID
     pub fn new_id() -> String {
         Uuid::new_v4().to_string()
@@ -44,6 +114,63 @@ impl Default for AppState {
     }
 }
 
+/// Session entries by ID, evicting the oldest once `capacity` is exceeded
+pub struct SessionStore<V> {
+    entries: HashMap<String, V>,
+    order: VecDeque<String>,
+    capacity: usize,
+}
+
+impl<V> SessionStore<V> {
+    /// Create an empty store holding at most `capacity` entries (at least one)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Insert an entry, evicting the oldest ones beyond capacity
+    pub fn insert(&mut self, id: String, value: V) {
+        if self.entries.insert(id.clone(), value).is_some() {
+            self.order.retain(|existing| *existing != id);
+        }
+        self.order.push_back(id);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+    }
+
+    /// Get an entry by ID
+    pub fn get(&self, id: &str) -> Option<&V> {
+        self.entries.get(id)
+    }
+
+    /// Number of stored entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the store is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Live progress of a background holistic mining run
+#[derive(Debug, Clone, Default)]
+pub struct HolisticRun {
+    /// Last completed stage
+    pub stage: Option<GenesisStage>,
+    /// Matrix statistics after the last completed stage
+    pub stats: Option<HolisticStats>,
+    /// Whether the pipeline has finished
+    pub finished: bool,
+}
+
 /// Record of an experiment run
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct ExperimentRecord {
@@ -54,3 +181,34 @@ pub struct ExperimentRecord {
     pub parameters: serde_json::Value,
     pub result: serde_json::Value,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_store_evicts_oldest() {
+        let mut store = SessionStore::new(2);
+        store.insert("a".to_string(), 1);
+        store.insert("b".to_string(), 2);
+        // Re-inserting refreshes "a", so "b" is now the oldest
+        store.insert("a".to_string(), 3);
+        store.insert("c".to_string(), 4);
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.get("a"), Some(&3));
+        assert_eq!(store.get("b"), None);
+        assert_eq!(store.get("c"), Some(&4));
+    }
+
+    #[test]
+    fn test_app_state_session_capacity() {
+        let state = AppState::new();
+        let mut runs = state.holistic_runs.lock().unwrap();
+        for i in 0..MAX_STORED_SESSIONS + 5 {
+            runs.insert(i.to_string(), Arc::new(Mutex::new(HolisticRun::default())));
+        }
+        assert_eq!(runs.len(), MAX_STORED_SESSIONS);
+        assert!(runs.get("0").is_none());
+    }
+}
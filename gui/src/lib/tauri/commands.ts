@@ -54,6 +54,13 @@ export interface ShorResultDto {
   factors: number[];
   period: number | null;
   attempts: number;
+  attempt_log: ShorAttemptDto[];
+}
+
+export interface ShorAttemptDto {
+  base: number;
+  period: number | null;
+  outcome: string;
 }
 
 export interface QftResultDto {
@@ -174,11 +181,36 @@ export interface Cube13NodeDto {
   embedding: SignatureDto | null;
 }
 
+export interface Cube13EmbeddingDto {
+  node: number;
+  signature: SignatureDto;
+}
+
+export interface Cube13ClusterDto {
+  id: string;
+  size: number;
+  cohesion: number;
+  avg_resonance: number;
+  nodes: number[];
+  representative_node: number;
+  representative: SignatureDto;
+}
+
+export interface LayerOccupancyDto {
+  step: number;
+  center: number;
+  hexagon: number;
+  cube: number;
+}
+
 export interface TopologyWalkDto {
   path: number[];
   coverage: number;
   final_node: number;
   steps_taken: number;
+  window: number;
+  occupancy: LayerOccupancyDto[];
+  windowed_visits: number[][];
 }
 
 export interface SweepResultDto {
@@ -195,6 +227,23 @@ export interface SweepEvaluationDto {
   convergence_rate: number;
 }
 
+export interface SweepRangesDto {
+  x_values: number[];
+  y_values: number[];
+  steps_per_config: number;
+}
+
+export interface SweepHeatmapDto {
+  x_label: string;
+  y_label: string;
+  x_values: number[];
+  y_values: number[];
+  grid: number[][];
+  best_x: number | null;
+  best_y: number | null;
+  best_score: number;
+}
+
 export interface AutoTuneResultDto {
   best_temperature: number;
   best_cooling_rate: number;
@@ -298,8 +347,8 @@ export async function runGrover(qubits: number, target: number, shots: number):
   return invoke('run_grover', { qubits, target, shots });
 }
 
-export async function runShor(number: number): Promise<ShorResultDto> {
-  return invoke('run_shor', { number });
+export async function runShor(number: number, seed?: number): Promise<ShorResultDto> {
+  return invoke('run_shor', { number, seed });
 }
 
 export async function runQft(qubits: number, inputState?: number): Promise<QftResultDto> {
@@ -366,8 +415,12 @@ export async function getCube13Node(nodeId: number): Promise<Cube13NodeDto> {
   return invoke('get_cube13_node', { nodeId });
 }
 
-export async function runCube13Walk(start: number, steps: number): Promise<TopologyWalkDto> {
-  return invoke('run_cube13_walk', { start, steps });
+export async function runCube13Walk(
+  start: number,
+  steps: number,
+  window?: number
+): Promise<TopologyWalkDto> {
+  return invoke('run_cube13_walk', { start, steps, window });
 }
 
 export async function embedInCube13(
@@ -381,6 +434,12 @@ export async function embedInCube13(
   return invoke('embed_in_cube13', { node, psi, rho, omega, chi, eta });
 }
 
+export async function getCube13Clusters(
+  embeddings: Cube13EmbeddingDto[]
+): Promise<Cube13ClusterDto[]> {
+  return invoke('get_cube13_clusters', { embeddings });
+}
+
 export async function runCube13Vqe(layers: number): Promise<VqeResultDto> {
   return invoke('run_cube13_vqe', { layers });
 }
@@ -430,6 +489,14 @@ export async function runHyperparameterSweep(
   });
 }
 
+export async function runSweepHeatmap(
+  paramX: string,
+  paramY: string,
+  ranges: SweepRangesDto
+): Promise<SweepHeatmapDto> {
+  return invoke('run_sweep_heatmap', { paramX, paramY, ranges });
+}
+
 export async function runAutoTune(
   targetResonance: number,
   maxIterations: number
@@ -445,6 +512,26 @@ export async function getSystemInfo(): Promise<SystemInfoDto> {
   return invoke('get_system_info');
 }
 
+export type ResonanceColorScheme = 'viridis' | 'plasma' | 'stage';
+
+export interface ResonanceColorMapDto {
+  scheme: ResonanceColorScheme;
+  colors: string[];
+}
+
+export async function getResonanceColormap(
+  scheme?: ResonanceColorScheme,
+  steps?: number
+): Promise<ResonanceColorMapDto> {
+  return invoke('get_resonance_colormap', { scheme, steps });
+}
+
+/** Look up the color for a resonance value in a sampled color map */
+export function resonanceColor(map: ResonanceColorMapDto, resonance: number): string {
+  const r = Number.isNaN(resonance) ? 0 : Math.min(1, Math.max(0, resonance));
+  return map.colors[Math.round(r * (map.colors.length - 1))];
+}
+
 // ============================================================================
 // Hypercube Types
 // ============================================================================
@@ -523,6 +610,31 @@ export interface HypercubeSessionResultDto {
   artifact_count: number;
 }
 
+export interface HypercubeGraphNodeDto {
+  id: string;
+  type: string;
+  depth: number;
+  resonance: number;
+  coordinate: number[];
+  best: boolean;
+}
+
+export interface HypercubeGraphLinkDto {
+  id: string;
+  source: string;
+  target: string;
+  type: string;
+  weight: number;
+  directed: boolean;
+}
+
+export interface HypercubeGraphDto {
+  directed: boolean;
+  name: string;
+  nodes: HypercubeGraphNodeDto[];
+  links: HypercubeGraphLinkDto[];
+}
+
 export interface HypercubePresetDto {
   name: string;
   description: string;
@@ -586,6 +698,25 @@ export interface EntropyDistributionDto {
   description: string;
 }
 
+// ============================================================================
+// Settings Commands
+// ============================================================================
+
+export type ConfigModule = 'kernel' | 'slots' | 'triton' | 'hypercube' | 'calibration';
+
+export async function setDefaultConfig(
+  module: ConfigModule,
+  config: Record<string, unknown>
+): Promise<void> {
+  return invoke('set_default_config', { module, configJson: JSON.stringify(config) });
+}
+
+export async function getDefaultConfig(
+  module: ConfigModule
+): Promise<Record<string, unknown> | null> {
+  return invoke('get_default_config', { module });
+}
+
 // ============================================================================
 // Hypercube Commands
 // ============================================================================
@@ -662,6 +793,13 @@ export async function runHypercubeSession(
   });
 }
 
+export async function exportHypercubeGraph(
+  sessionId: string,
+  format: 'dot' | 'json'
+): Promise<string | HypercubeGraphDto> {
+  return invoke('export_hypercube_graph', { sessionId, format });
+}
+
 export async function getHypercubePresets(): Promise<HypercubePresetDto[]> {
   return invoke('get_hypercube_presets');
 }
@@ -829,6 +967,18 @@ export interface HolisticMiningResultDto {
   stage_logs: StageLogDto[];
 }
 
+export interface HolisticLiveStatsDto {
+  session_id: string;
+  stage: GenesisStage | null;
+  finished: boolean;
+  kosmokrator_survivor_rate: number;
+  kosmokrator_kappa: number;
+  chronokrator_spike_count: number;
+  chronokrator_d_total: number;
+  pfauenthron_mandorla: number;
+  pfauenthron_converged: boolean;
+}
+
 export interface TrajectoryPointDto {
   iteration: number;
   score: number;
@@ -860,6 +1010,14 @@ export async function runHolisticMining(
   return invoke('run_holistic_mining', { config });
 }
 
+export async function startHolisticMining(config: HolisticMiningConfigDto): Promise<string> {
+  return invoke('start_holistic_mining', { config });
+}
+
+export async function getHolisticLiveStats(sessionId: string): Promise<HolisticLiveStatsDto> {
+  return invoke('get_holistic_live_stats', { sessionId });
+}
+
 export async function runKosmokratorStage(
   candidates: number,
   config: KosmokratorConfigDto
@@ -1006,7 +1164,7 @@ export async function runKernelMining(
   seedOmega: number,
   seedChi: number,
   seedEta: number,
-  config: KernelMiningConfigDto
+  config?: KernelMiningConfigDto
 ): Promise<KernelMiningResultDto> {
   return invoke('run_kernel_mining', {
     seedPsi,
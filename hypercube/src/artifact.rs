@@ -45,6 +45,8 @@ pub struct ArtifactMetadata {
     pub tags: Vec<String>,
     /// Additional properties
     pub properties: std::collections::HashMap<String, String>,
+    /// Content hash assigned during deduplication
+    pub content_hash: Option<u64>,
 }
 
 /// A hypercube artifact
@@ -133,6 +135,33 @@ impl HypercubeArtifact {
         self.finalized = true;
     }
 
+    /// Deterministic content hash for deduplication
+    ///
+    /// Hashes the artifact type and the coordinate quantized to cells of size
+    /// `granularity`, so artifacts whose coordinates fall in the same cell
+    /// collide. Larger granularity collapses more aggressively.
+    pub fn content_hash(&self, granularity: f64) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+        const FNV_PRIME: u64 = 0x100000001b3;
+
+        let mut hash = FNV_OFFSET;
+        let mut feed = |bytes: &[u8]| {
+            for b in bytes {
+                hash ^= *b as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        feed(&[self.artifact_type as u8]);
+        let step = if granularity > 0.0 { granularity } else { f64::EPSILON };
+        for v in self.coordinate.to_array() {
+            let cell = (v / step).floor() as i64;
+            feed(&cell.to_le_bytes());
+        }
+
+        hash
+    }
+
     /// Check if artifact meets resonance threshold
     pub fn meets_threshold(&self, threshold: f64) -> bool {
         self.resonance >= threshold
@@ -157,6 +186,46 @@ impl std::fmt::Display for HypercubeArtifact {
     }
 }
 
+/// Policy deciding which artifacts a collection keeps
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum ArtifactRetention {
+    /// Keep every artifact
+    #[default]
+    All,
+    /// Keep only the k highest-resonance artifacts
+    TopK(usize),
+    /// Keep only artifacts at or above the resonance threshold
+    AboveResonance(f64),
+}
+
+/// Aggregate statistics of artifacts dropped by the retention policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiscardedArtifactStats {
+    /// Number of discarded artifacts
+    pub count: usize,
+    /// Sum of discarded resonances
+    pub total_resonance: f64,
+    /// Highest discarded resonance
+    pub max_resonance: f64,
+}
+
+impl DiscardedArtifactStats {
+    fn record(&mut self, artifact: &HypercubeArtifact) {
+        self.count += 1;
+        self.total_resonance += artifact.resonance;
+        self.max_resonance = self.max_resonance.max(artifact.resonance);
+    }
+
+    /// Average resonance of discarded artifacts
+    pub fn avg_resonance(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total_resonance / self.count as f64
+        }
+    }
+}
+
 /// Collection of artifacts with management operations
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ArtifactCollection {
@@ -166,6 +235,24 @@ pub struct ArtifactCollection {
     best_id: Option<String>,
     /// Best resonance
     best_resonance: f64,
+    /// Retention policy
+    #[serde(default)]
+    retention: ArtifactRetention,
+    /// Total artifacts offered to the collection
+    #[serde(default)]
+    produced: usize,
+    /// Summary of artifacts dropped by the retention policy
+    #[serde(default)]
+    discarded: DiscardedArtifactStats,
+    /// Quantization granularity for deduplication (None disables it)
+    #[serde(default)]
+    dedup_granularity: Option<f64>,
+    /// Content hashes seen so far
+    #[serde(default)]
+    seen_hashes: std::collections::HashSet<u64>,
+    /// Number of artifacts dropped as duplicates
+    #[serde(default)]
+    duplicates: usize,
 }
 
 impl ArtifactCollection {
@@ -174,8 +261,52 @@ impl ArtifactCollection {
         Self::default()
     }
 
-    /// Add an artifact
-    pub fn add(&mut self, artifact: HypercubeArtifact) {
+    /// Create an empty collection with a retention policy
+    pub fn with_retention(retention: ArtifactRetention) -> Self {
+        Self {
+            retention,
+            ..Default::default()
+        }
+    }
+
+    /// Enable content-hash deduplication at the given granularity
+    pub fn with_dedup(mut self, granularity: f64) -> Self {
+        self.dedup_granularity = Some(granularity);
+        self
+    }
+
+    /// Add an artifact, subject to deduplication and the retention policy
+    pub fn add(&mut self, mut artifact: HypercubeArtifact) {
+        self.produced += 1;
+
+        if let Some(granularity) = self.dedup_granularity {
+            let hash = artifact.content_hash(granularity);
+            if !self.seen_hashes.insert(hash) {
+                self.duplicates += 1;
+                return;
+            }
+            artifact.metadata.content_hash = Some(hash);
+        }
+
+        match self.retention {
+            ArtifactRetention::All => self.retain(artifact),
+            ArtifactRetention::AboveResonance(threshold) => {
+                if artifact.meets_threshold(threshold) {
+                    self.retain(artifact);
+                } else {
+                    self.discarded.record(&artifact);
+                }
+            }
+            ArtifactRetention::TopK(k) => {
+                self.retain(artifact);
+                while self.artifacts.len() > k {
+                    self.evict_weakest();
+                }
+            }
+        }
+    }
+
+    fn retain(&mut self, artifact: HypercubeArtifact) {
         if artifact.resonance > self.best_resonance {
             self.best_resonance = artifact.resonance;
             self.best_id = Some(artifact.id.clone());
@@ -183,6 +314,47 @@ impl ArtifactCollection {
         self.artifacts.push(artifact);
     }
 
+    fn evict_weakest(&mut self) {
+        let weakest = self.artifacts.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.resonance.total_cmp(&b.resonance))
+            .map(|(i, _)| i);
+
+        if let Some(i) = weakest {
+            let evicted = self.artifacts.remove(i);
+            self.discarded.record(&evicted);
+
+            if self.best_id.as_deref() == Some(evicted.id.as_str()) {
+                self.best_id = None;
+                self.best_resonance = 0.0;
+                let remaining = std::mem::take(&mut self.artifacts);
+                for a in remaining {
+                    self.retain(a);
+                }
+            }
+        }
+    }
+
+    /// Retention policy in effect
+    pub fn retention(&self) -> ArtifactRetention {
+        self.retention
+    }
+
+    /// Total number of artifacts offered, retained or not
+    pub fn produced(&self) -> usize {
+        self.produced
+    }
+
+    /// Summary of artifacts dropped by the retention policy
+    pub fn discarded(&self) -> &DiscardedArtifactStats {
+        &self.discarded
+    }
+
+    /// Number of artifacts dropped as duplicates
+    pub fn duplicates(&self) -> usize {
+        self.duplicates
+    }
+
     /// Get artifact by ID
     pub fn get(&self, id: &str) -> Option<&HypercubeArtifact> {
         self.artifacts.iter().find(|a| a.id == id)
@@ -264,4 +436,66 @@ mod tests {
         assert_eq!(collection.len(), 2);
         assert!(collection.best().is_some());
     }
+
+    #[test]
+    fn test_retention_top_k() {
+        let mut collection = ArtifactCollection::with_retention(ArtifactRetention::TopK(2));
+
+        for v in [0.2, 0.9, 0.5, 0.7] {
+            let coord = Coord5D::new(v, v, v, 0.5, 0.1);
+            collection.add(HypercubeArtifact::new("a", ArtifactType::CompiledFamily, coord));
+        }
+
+        assert_eq!(collection.produced(), 4);
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.discarded().count, 2);
+        let best = collection.best().unwrap();
+        assert!(collection.all().iter().all(|a| a.resonance <= best.resonance));
+        assert!(collection.all().iter().all(|a| a.resonance > collection.discarded().max_resonance));
+
+        // A NaN score must not panic the eviction
+        let mut nan = HypercubeArtifact::new("nan", ArtifactType::CompiledFamily, Coord5D::center());
+        nan.resonance = f64::NAN;
+        collection.add(nan);
+        assert_eq!(collection.len(), 2);
+    }
+
+    #[test]
+    fn test_retention_above_resonance() {
+        let low = HypercubeArtifact::new("low", ArtifactType::CompiledFamily, Coord5D::new(0.1, 0.1, 0.1, 0.5, 0.9));
+        let high = HypercubeArtifact::new("high", ArtifactType::CompiledFamily, Coord5D::new(0.9, 0.9, 0.9, 0.5, 0.1));
+        let threshold = (low.resonance + high.resonance) / 2.0;
+
+        let mut collection = ArtifactCollection::with_retention(ArtifactRetention::AboveResonance(threshold));
+        collection.add(low);
+        collection.add(high);
+
+        assert_eq!(collection.len(), 1);
+        assert_eq!(collection.produced(), 2);
+        assert_eq!(collection.all()[0].name, "high");
+    }
+
+    #[test]
+    fn test_content_hash_quantization() {
+        let a = HypercubeArtifact::new("a", ArtifactType::CompiledFamily, Coord5D::new(0.501, 0.5, 0.5, 0.5, 0.5));
+        let b = HypercubeArtifact::new("b", ArtifactType::CompiledFamily, Coord5D::new(0.509, 0.5, 0.5, 0.5, 0.5));
+        let c = HypercubeArtifact::new("c", ArtifactType::Checkpoint, Coord5D::new(0.501, 0.5, 0.5, 0.5, 0.5));
+
+        assert_eq!(a.content_hash(0.1), b.content_hash(0.1));
+        assert_ne!(a.content_hash(0.001), b.content_hash(0.001));
+        assert_ne!(a.content_hash(0.1), c.content_hash(0.1));
+    }
+
+    #[test]
+    fn test_collection_dedup() {
+        let mut collection = ArtifactCollection::new().with_dedup(0.05);
+
+        collection.add(HypercubeArtifact::new("a", ArtifactType::CompiledFamily, Coord5D::new(0.81, 0.7, 0.6, 0.5, 0.4)));
+        collection.add(HypercubeArtifact::new("b", ArtifactType::CompiledFamily, Coord5D::new(0.82, 0.7, 0.6, 0.5, 0.4)));
+        collection.add(HypercubeArtifact::new("c", ArtifactType::CompiledFamily, Coord5D::new(0.2, 0.7, 0.6, 0.5, 0.4)));
+
+        assert_eq!(collection.len(), 2);
+        assert_eq!(collection.duplicates(), 1);
+        assert!(collection.all().iter().all(|a| a.metadata.content_hash.is_some()));
+    }
 }
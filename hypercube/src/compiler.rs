@@ -2,11 +2,14 @@
 //!
 //! Compiles hypercube structures into executable artifacts using the Ξ operator.
 
-use crate::cube::{Hypercube, HypercubeState};
+use crate::cube::{CubeExpansionRule, Hypercube, HypercubeState};
 use crate::hdag::{HDAG, HDAGExecutor, ExecutionResult};
 use crate::artifact::{HypercubeArtifact, ArtifactType, ArtifactCollection};
 use crate::coordinates::Coord5D;
-use crate::operators::{OperatorFamily, CompilationOperator, CompilationMode};
+use crate::operators::{
+    Operator5D, OperatorFamily, OperatorRegistry, CompilationOperator, CompilationMode,
+    DoubleKickOperator, KickParams, SwapWaveOperator, WeightTransformOperator,
+};
 use crate::error::{HypercubeError, Result};
 use serde::{Deserialize, Serialize};
 
@@ -27,6 +30,10 @@ pub struct CompilationConfig {
     pub use_hdag: bool,
     /// Parallel compilation (for multiple seeds)
     pub parallel: bool,
+    /// Per-dimension weights for the Weight Transform operator (None = defaults)
+    pub wt_weights: Option<[f64; 5]>,
+    /// Double Kick strength and phase (None = defaults)
+    pub kick: Option<KickParams>,
 }
 
 impl Default for CompilationConfig {
@@ -39,6 +46,8 @@ impl Default for CompilationConfig {
             extract_families: true,
             use_hdag: true,
             parallel: false,
+            wt_weights: None,
+            kick: None,
         }
     }
 }
@@ -94,6 +103,10 @@ pub struct CompilationResult {
     pub threshold_met: bool,
     /// HDAG execution result (if used)
     pub hdag_result: Option<ExecutionResult>,
+    /// Phase coherence κ after phase integration (low values point to poor alignment)
+    pub phase_coherence: Option<f64>,
+    /// Double Kick parameters applied during compilation (None if unknown)
+    pub kick: Option<KickParams>,
     /// Compilation statistics
     pub stats: CompilationStats,
 }
@@ -122,6 +135,8 @@ pub struct HypercubeCompiler {
     config: CompilationConfig,
     xi_operator: CompilationOperator,
     artifacts: ArtifactCollection,
+    /// Operators registered by name, on top of the built-ins
+    operators: OperatorRegistry,
 }
 
 impl HypercubeCompiler {
@@ -134,6 +149,7 @@ impl HypercubeCompiler {
             config,
             xi_operator,
             artifacts: ArtifactCollection::new(),
+            operators: OperatorRegistry::new(),
         }
     }
 
@@ -142,8 +158,91 @@ impl HypercubeCompiler {
         Self::new(CompilationConfig::default())
     }
 
+    /// Register an operator that HDAG nodes and expansion can refer to by name
+    ///
+    /// Registering a built-in name (DK, SW, PI, WT, Xi) replaces that built-in.
+    pub fn register_operator(&mut self, name: &str, op: Box<dyn Operator5D>) {
+        self.operators.register(name, op);
+    }
+
+    /// Built-in operators with the configured kick and weights
+    fn builtin_operators(&self) -> OperatorRegistry {
+        let dk = match self.config.kick {
+            Some(kick) => DoubleKickOperator::from_kick(kick),
+            None => DoubleKickOperator::default(),
+        };
+        let mut wt = WeightTransformOperator::default();
+        if let Some(weights) = self.config.wt_weights {
+            wt = wt.with_weights(weights);
+        }
+        let mut registry = OperatorRegistry::builtins(dk, SwapWaveOperator::default(), wt);
+        registry.register("Xi", Box::new(self.xi_operator.clone()));
+        registry
+    }
+
+    /// All operators available by name: built-ins plus registered ones
+    pub fn operators(&self) -> OperatorRegistry {
+        let mut registry = self.builtin_operators();
+        registry.extend(&self.operators);
+        registry
+    }
+
+    /// Bind the HDAG's operator nodes to the available operators
+    fn configure_hdag(&self, hdag: &mut HDAG) -> Result<()> {
+        hdag.bind_operators(&self.operators())?;
+        Ok(())
+    }
+
+    /// Execute a user-defined HDAG with the available operators
+    pub fn execute_hdag(&self, mut hdag: HDAG) -> Result<ExecutionResult> {
+        self.configure_hdag(&mut hdag)?;
+        HDAGExecutor::new(hdag)?.execute()
+    }
+
+    /// Perform one expansion step of a cube with the registered operators
+    ///
+    /// Built-ins keep the cube's own `kick` and `swap_dims` settings unless
+    /// they were replaced through [`register_operator`](Self::register_operator).
+    pub fn expand_step(&self, cube: &mut Hypercube) -> Result<usize> {
+        if cube.config.expansion_rule != CubeExpansionRule::OperatorDriven {
+            return cube.expand_step();
+        }
+        let mut registry = cube.builtin_operators()?;
+        registry.extend(&self.operators);
+        cube.expand_step_with(&registry)
+    }
+
+    /// Kick parameters used by the HDAG pipeline
+    fn hdag_kick(&self) -> KickParams {
+        self.config.kick
+            .unwrap_or_else(|| DoubleKickOperator::default().kick_params())
+    }
+
+    /// Fallback operator family used when the cube provides none
+    ///
+    /// [`OperatorFamily::resonance_optimized`] with the configured kick and
+    /// weights, together with the kick it was built with.
+    fn default_family(&self, seed: Coord5D) -> (OperatorFamily, KickParams) {
+        let dk = self.config.kick
+            .map(DoubleKickOperator::from_kick)
+            .unwrap_or_else(DoubleKickOperator::resonance_optimized);
+        let mut wt = WeightTransformOperator::resonance_optimized();
+        if let Some(weights) = self.config.wt_weights {
+            wt = wt.with_weights(weights);
+        }
+        let kick = dk.kick_params();
+        (OperatorFamily::resonance_optimized_with("default", seed, dk, wt), kick)
+    }
+
     /// Compile a hypercube
     pub fn compile(&mut self, cube: &mut Hypercube) -> Result<CompilationResult> {
+        let _span = tracing::debug_span!(
+            "hypercube_compile",
+            cube = %cube.id,
+            use_hdag = self.config.use_hdag,
+            max_iterations = self.config.max_iterations,
+        )
+        .entered();
         let start = std::time::Instant::now();
 
         // Ensure cube is ready for compilation
@@ -164,6 +263,14 @@ impl HypercubeCompiler {
         let mut final_result = result;
         final_result.compilation_time_ms = start.elapsed().as_millis() as u64;
 
+        tracing::debug!(
+            iterations = final_result.iterations,
+            artifacts = final_result.artifacts.len(),
+            resonance = final_result.resonance,
+            threshold_met = final_result.threshold_met,
+            duration_ms = final_result.compilation_time_ms,
+            "compilation complete"
+        );
         Ok(final_result)
     }
 
@@ -183,10 +290,11 @@ impl HypercubeCompiler {
         stats.initial_resonance = initial_coord.resonance();
 
         // Build operator family from cube
-        let family = if !cube.operator_families.is_empty() {
-            cube.operator_families[0].clone()
+        let (family, kick) = if !cube.operator_families.is_empty() {
+            (cube.operator_families[0].clone(), None)
         } else {
-            OperatorFamily::resonance_optimized("default", initial_coord)
+            let (family, kick) = self.default_family(initial_coord);
+            (family, Some(kick))
         };
 
         // Iterative compilation
@@ -247,6 +355,8 @@ impl HypercubeCompiler {
             iterations,
             threshold_met: current.resonance() >= self.config.resonance_threshold,
             hdag_result: None,
+            phase_coherence: family.operators.iter().rev().find_map(|op| op.phase_coherence()),
+            kick,
             stats,
         })
     }
@@ -263,8 +373,9 @@ impl HypercubeCompiler {
         stats.initial_resonance = seed.resonance();
 
         // Create and execute HDAG
-        let hdag = HDAG::standard_pipeline(seed);
-        let mut executor = HDAGExecutor::new(hdag);
+        let mut hdag = HDAG::standard_pipeline(seed);
+        self.configure_hdag(&mut hdag)?;
+        let mut executor = HDAGExecutor::new(hdag)?;
 
         let exec_result = executor.execute()?;
 
@@ -289,6 +400,8 @@ impl HypercubeCompiler {
             compilation_time_ms: exec_result.total_time_ms,
             iterations: exec_result.nodes_executed,
             threshold_met: exec_result.resonance >= self.config.resonance_threshold,
+            phase_coherence: exec_result.phase_coherence,
+            kick: Some(self.hdag_kick()),
             hdag_result: Some(exec_result),
             stats,
         })
@@ -301,8 +414,9 @@ impl HypercubeCompiler {
 
         stats.initial_resonance = coord.resonance();
 
-        let hdag = HDAG::standard_pipeline(coord);
-        let mut executor = HDAGExecutor::new(hdag);
+        let mut hdag = HDAG::standard_pipeline(coord);
+        self.configure_hdag(&mut hdag)?;
+        let mut executor = HDAGExecutor::new(hdag)?;
         let exec_result = executor.execute()?;
 
         stats.final_resonance = exec_result.resonance;
@@ -318,6 +432,8 @@ impl HypercubeCompiler {
             compilation_time_ms: start.elapsed().as_millis() as u64,
             iterations: exec_result.nodes_executed,
             threshold_met: exec_result.resonance >= self.config.resonance_threshold,
+            phase_coherence: exec_result.phase_coherence,
+            kick: Some(self.hdag_kick()),
             hdag_result: Some(exec_result),
             stats,
         })
@@ -354,6 +470,30 @@ mod tests {
         assert!(result.resonance > 0.0);
     }
 
+    #[test]
+    fn test_default_family_kick() {
+        let compile = |kick: Option<KickParams>| {
+            let mut cube = Hypercube::default_cube("test");
+            assert!(cube.operator_families.is_empty());
+            let config = CompilationConfig {
+                use_hdag: false,
+                max_iterations: 10,
+                kick,
+                ..Default::default()
+            };
+            HypercubeCompiler::new(config).compile(&mut cube).unwrap()
+        };
+
+        let baseline = compile(None);
+        assert_eq!(baseline.kick, Some(DoubleKickOperator::resonance_optimized().kick_params()));
+        assert_eq!(compile(None).output, baseline.output);
+
+        let kick = KickParams::new(0.2, 0.5);
+        let kicked = compile(Some(kick));
+        assert_eq!(kicked.kick, Some(kick));
+        assert_eq!(compile(Some(kick)).output, kicked.output);
+    }
+
     #[test]
     fn test_hdag_compilation() {
         let mut cube = Hypercube::default_cube("test");
@@ -375,4 +515,141 @@ mod tests {
         let result = compiler.compile_coordinate(coord).unwrap();
         assert!(result.resonance > 0.0);
     }
+
+    #[test]
+    fn test_phase_coherence_reported() {
+        let mut compiler = HypercubeCompiler::default_compiler();
+        let result = compiler.compile_coordinate(Coord5D::center()).unwrap();
+
+        let kappa = result.phase_coherence.expect("pipeline runs PI");
+        assert!((0.0..=1.0).contains(&kappa));
+        assert_eq!(result.hdag_result.unwrap().phase_coherence, Some(kappa));
+    }
+
+    #[test]
+    fn test_kick_reported() {
+        let coord = Coord5D::new(0.6, 0.5, 0.5, 0.5, 0.3);
+        let baseline = HypercubeCompiler::default_compiler()
+            .compile_coordinate(coord)
+            .unwrap();
+        assert_eq!(baseline.kick, Some(DoubleKickOperator::default().kick_params()));
+
+        let kick = KickParams::new(0.2, 0.5);
+        let config = CompilationConfig {
+            kick: Some(kick),
+            ..Default::default()
+        };
+        let kicked = HypercubeCompiler::new(config)
+            .compile_coordinate(coord)
+            .unwrap();
+
+        assert_eq!(kicked.kick, Some(kick));
+        assert!(kicked.output.distance(&baseline.output) > 0.0);
+    }
+
+    #[test]
+    fn test_wt_weights_shift_output() {
+        let coord = Coord5D::new(0.6, 0.5, 0.5, 0.5, 0.3);
+        let baseline = HypercubeCompiler::default_compiler()
+            .compile_coordinate(coord)
+            .unwrap();
+
+        let config = CompilationConfig {
+            wt_weights: Some([3.0, 1.0, 1.0, 1.0, 1.0]),
+            ..Default::default()
+        };
+        let weighted = HypercubeCompiler::new(config)
+            .compile_coordinate(coord)
+            .unwrap();
+
+        // Only ψ is re-weighted
+        assert!(weighted.output.psi > baseline.output.psi);
+        assert!((weighted.output.rho - baseline.output.rho).abs() < 1e-12);
+        assert!((weighted.output.omega - baseline.output.omega).abs() < 1e-12);
+    }
+
+    /// Moves ψ up by a fixed step
+    struct PsiShift(f64);
+
+    impl Operator5D for PsiShift {
+        fn operator_type(&self) -> crate::operators::OperatorType {
+            crate::operators::OperatorType::Identity
+        }
+
+        fn apply(&self, coord: &Coord5D) -> Coord5D {
+            Coord5D::new(coord.psi + self.0, coord.rho, coord.omega, coord.chi, coord.eta)
+        }
+
+        fn parameters(&self) -> Vec<f64> {
+            vec![self.0]
+        }
+    }
+
+    #[test]
+    fn test_registered_operator_in_hdag() {
+        use crate::hdag::{HDAGEdge, HDAGNode};
+
+        let seed = Coord5D::new(0.2, 0.5, 0.5, 0.5, 0.5);
+        let shift_graph = || {
+            let mut hdag = HDAG::new("shift");
+            let input = hdag.add_node(HDAGNode::input("seed", seed));
+            let shift = hdag.add_node(HDAGNode::named_operator("shift", "psi_shift"));
+            let output = hdag.add_node(HDAGNode::output("out"));
+            hdag.add_edge(&input, &shift, HDAGEdge::data()).unwrap();
+            hdag.add_edge(&shift, &output, HDAGEdge::data()).unwrap();
+            hdag
+        };
+
+        let mut compiler = HypercubeCompiler::default_compiler();
+        assert!(matches!(
+            compiler.execute_hdag(shift_graph()),
+            Err(HypercubeError::InvalidOperator(_))
+        ));
+
+        compiler.register_operator("psi_shift", Box::new(PsiShift(0.25)));
+        assert_eq!(compiler.operators().names(), vec!["DK", "PI", "SW", "WT", "Xi", "psi_shift"]);
+        let result = compiler.execute_hdag(shift_graph()).unwrap();
+        assert!((result.output.psi - 0.45).abs() < 1e-12);
+
+        // Replacing a built-in changes the standard pipeline
+        let baseline = compiler.compile_coordinate(seed).unwrap();
+        compiler.register_operator("DK", Box::new(PsiShift(0.0)));
+        let replaced = compiler.compile_coordinate(seed).unwrap();
+        assert!(replaced.output.distance(&baseline.output) > 0.0);
+    }
+
+    #[test]
+    fn test_registry_xi_is_compiler_xi() {
+        let compiler = HypercubeCompiler::new(CompilationConfig {
+            mode: CompilationMode::Fast,
+            resonance_threshold: 0.6,
+            ..Default::default()
+        });
+        let xi = compiler.operators().get("Xi").cloned().unwrap();
+        assert_eq!(xi.parameters(), compiler.xi_operator.parameters());
+        assert_eq!(xi.parameters(), vec![1.0, 0.6, 10.0]);
+    }
+
+    #[test]
+    fn test_registered_operator_in_expansion() {
+        let config = HypercubeConfig {
+            expansion_rule: CubeExpansionRule::OperatorDriven,
+            expansion_operators: vec!["psi_shift".to_string()],
+            resonance_threshold: 0.0,
+            include_corners: false,
+            ..Default::default()
+        };
+        let mut cube = Hypercube::new("custom", config.clone());
+        assert!(cube.expand_step().is_err());
+
+        let mut compiler = HypercubeCompiler::default_compiler();
+        compiler.register_operator("psi_shift", Box::new(PsiShift(0.1)));
+        let mut cube = Hypercube::new("custom", config);
+        assert_eq!(compiler.expand_step(&mut cube).unwrap(), 1);
+
+        let origin = cube.vertices.values().find(|v| v.depth == 0).unwrap().coordinate;
+        let child = cube.vertices.values().find(|v| v.depth == 1).unwrap().coordinate;
+        assert!((child.psi - origin.psi - 0.1).abs() < 1e-12);
+        assert_eq!(child.rho, origin.rho);
+    }
 }
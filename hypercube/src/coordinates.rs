@@ -165,6 +165,17 @@ impl Coord5D {
         )
     }
 
+    /// Bring all values back into [0, 1] according to a boundary policy
+    pub fn bound(&self, policy: CoordinatePolicy) -> Self {
+        Self::new(
+            policy.apply(self.psi),
+            policy.apply(self.rho),
+            policy.apply(self.omega),
+            policy.apply(self.chi),
+            policy.apply(self.eta),
+        )
+    }
+
     /// Compute resonance score using QOPS formula
     /// R(v) = 0.4·ψ + 0.3·ρ + 0.3·ω + 0.05·χ - 0.05·η
     pub fn resonance(&self) -> f64 {
@@ -211,6 +222,34 @@ impl std::fmt::Display for Coord5D {
     }
 }
 
+/// How out-of-bounds coordinate values are mapped back into [0, 1]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum CoordinatePolicy {
+    /// Saturate at the nearest edge
+    #[default]
+    Clamp,
+    /// Toroidal wrap: leaving one edge re-enters at the opposite one
+    Wrap,
+    /// Mirror back off the edge
+    Reflect,
+}
+
+impl CoordinatePolicy {
+    /// Map a single value into [0, 1]
+    pub fn apply(&self, value: f64) -> f64 {
+        match self {
+            CoordinatePolicy::Clamp => value.clamp(0.0, 1.0),
+            // Only values outside [0, 1] wrap, so the edge value 1.0 stays put
+            CoordinatePolicy::Wrap if (0.0..=1.0).contains(&value) => value,
+            CoordinatePolicy::Wrap => value.rem_euclid(1.0),
+            CoordinatePolicy::Reflect => {
+                let m = value.rem_euclid(2.0);
+                if m > 1.0 { 2.0 - m } else { m }
+            }
+        }
+    }
+}
+
 /// 5D coordinate system with transformation capabilities
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoordinateSystem {
@@ -220,6 +259,8 @@ pub struct CoordinateSystem {
     pub basis: [[f64; 5]; 5],
     /// Scale factors for each dimension
     pub scale: [f64; 5],
+    /// Boundary policy for coordinates leaving the unit cube
+    pub policy: CoordinatePolicy,
 }
 
 impl CoordinateSystem {
@@ -235,6 +276,7 @@ impl CoordinateSystem {
                 [0.0, 0.0, 0.0, 0.0, 1.0], // η axis
             ],
             scale: [1.0; 5],
+            policy: CoordinatePolicy::Clamp,
         }
     }
 
@@ -250,9 +292,21 @@ impl CoordinateSystem {
                 [0.0, 0.0, 0.0, 0.0, 1.0],
             ],
             scale: [1.0; 5],
+            policy: CoordinatePolicy::Clamp,
         }
     }
 
+    /// Set the boundary policy
+    pub fn with_policy(mut self, policy: CoordinatePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Bring a coordinate back into the unit cube using this system's policy
+    pub fn bound(&self, coord: &Coord5D) -> Coord5D {
+        coord.bound(self.policy)
+    }
+
     /// Transform a local coordinate to global coordinate
     pub fn to_global(&self, local: &Coord5D) -> Coord5D {
         let local_arr = local.to_array();
@@ -437,6 +491,33 @@ mod tests {
         assert_relative_eq!(res, 1.0, epsilon = 0.1);
     }
 
+    #[test]
+    fn test_coordinate_policy() {
+        let coord = Coord5D::new(0.0, 0.5, 0.9, 0.1, 0.3);
+        let moved = coord.add(&Coord5D::new(1.2, -0.7, 0.3, 0.0, 0.0));
+
+        let wrapped = CoordinateSystem::standard()
+            .with_policy(CoordinatePolicy::Wrap)
+            .bound(&moved);
+        assert_relative_eq!(wrapped.psi, 0.2, epsilon = 1e-12);
+        assert_relative_eq!(wrapped.rho, 0.8, epsilon = 1e-12);
+        assert_relative_eq!(wrapped.omega, 0.2, epsilon = 1e-12);
+
+        let reflected = moved.bound(CoordinatePolicy::Reflect);
+        assert_relative_eq!(reflected.psi, 0.8, epsilon = 1e-12);
+        assert_relative_eq!(reflected.rho, 0.2, epsilon = 1e-12);
+        assert_relative_eq!(reflected.omega, 0.8, epsilon = 1e-12);
+
+        let clamped = CoordinateSystem::default().bound(&moved);
+        assert_eq!(clamped, moved.clamp_unit());
+
+        // Boundary values are already valid
+        assert_eq!(CoordinatePolicy::Wrap.apply(1.0), 1.0);
+        assert_eq!(CoordinatePolicy::Wrap.apply(0.0), 0.0);
+        let edge = Coord5D::new(1.0, 1.0, 1.0, 1.0, 1.0);
+        assert_eq!(edge.bound(CoordinatePolicy::Wrap), edge);
+    }
+
     #[test]
     fn test_coordinate_system() {
         let cs = CoordinateSystem::standard();
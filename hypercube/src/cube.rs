@@ -6,7 +6,7 @@
 use crate::coordinates::{Coord5D, CoordinateSystem};
 use crate::vertex::{HypercubeVertex, VertexState, VertexType};
 use crate::edge::{HypercubeEdge, EdgeWeight};
-use crate::operators::{Operator5D, OperatorFamily, OperatorType};
+use crate::operators::{KickParams, OperatorFamily, OperatorRegistry, OperatorType};
 use crate::error::{HypercubeError, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -27,6 +27,13 @@ pub struct HypercubeConfig {
     pub resonance_threshold: f64,
     /// Expansion rule to use
     pub expansion_rule: CubeExpansionRule,
+    /// Dimension pair swapped by `OperatorDriven` expansion (None = all pairs)
+    pub swap_dims: Option<(usize, usize)>,
+    /// Double Kick strength and phase for `OperatorDriven` expansion (None = defaults)
+    pub kick: Option<KickParams>,
+    /// Registered operators applied by `OperatorDriven` expansion (empty = DK, SW, PI, WT)
+    #[serde(default)]
+    pub expansion_operators: Vec<String>,
 }
 
 impl Default for HypercubeConfig {
@@ -38,6 +45,9 @@ impl Default for HypercubeConfig {
             max_depth: 10,
             resonance_threshold: 0.5,
             expansion_rule: CubeExpansionRule::Triton,
+            swap_dims: None,
+            kick: None,
+            expansion_operators: Vec::new(),
         }
     }
 }
@@ -284,6 +294,30 @@ impl Hypercube {
 
     /// Perform one expansion step
     pub fn expand_step(&mut self) -> Result<usize> {
+        self.expand_step_inner(None)
+    }
+
+    /// Perform one expansion step, resolving `OperatorDriven` operators in `registry`
+    pub fn expand_step_with(&mut self, registry: &OperatorRegistry) -> Result<usize> {
+        self.expand_step_inner(Some(registry))
+    }
+
+    /// Built-in operators for `OperatorDriven` expansion, configured by `swap_dims` and `kick`
+    pub fn builtin_operators(&self) -> Result<OperatorRegistry> {
+        use crate::operators::{DoubleKickOperator, SwapWaveOperator, WeightTransformOperator};
+
+        let swap_wave = match self.config.swap_dims {
+            Some(dims) => SwapWaveOperator::new(dims)?,
+            None => SwapWaveOperator::default(),
+        };
+        let double_kick = match self.config.kick {
+            Some(kick) => DoubleKickOperator::from_kick(kick),
+            None => DoubleKickOperator::default(),
+        };
+        Ok(OperatorRegistry::builtins(double_kick, swap_wave, WeightTransformOperator::default()))
+    }
+
+    fn expand_step_inner(&mut self, registry: Option<&OperatorRegistry>) -> Result<usize> {
         if self.expansion_iteration >= self.config.max_depth {
             return Ok(0);
         }
@@ -301,7 +335,15 @@ impl Hypercube {
                 self.expand_triton()?
             }
             CubeExpansionRule::OperatorDriven => {
-                self.expand_operator_driven()?
+                let builtins;
+                let registry = match registry {
+                    Some(registry) => registry,
+                    None => {
+                        builtins = self.builtin_operators()?;
+                        &builtins
+                    }
+                };
+                self.expand_operator_driven(registry)?
             }
             CubeExpansionRule::Random => {
                 self.expand_random()?
@@ -483,7 +525,7 @@ impl Hypercube {
                 radius * (angle * 0.2).cos(),
             );
 
-            let new_coord = best_coord.add(&offset).clamp_unit();
+            let new_coord = self.coordinate_system.bound(&best_coord.add(&offset));
             let res = new_coord.resonance();
 
             // Check if position is new
@@ -509,10 +551,20 @@ impl Hypercube {
     }
 
     /// Expand using operator-driven rule
-    fn expand_operator_driven(&mut self) -> Result<usize> {
-        use crate::operators::{
-            DoubleKickOperator, SwapWaveOperator, PhaseIntegrationOperator, WeightTransformOperator
+    fn expand_operator_driven(&mut self, registry: &OperatorRegistry) -> Result<usize> {
+        let names: Vec<&str> = if self.config.expansion_operators.is_empty() {
+            vec!["DK", "SW", "PI", "WT"]
+        } else {
+            self.config.expansion_operators.iter().map(String::as_str).collect()
         };
+        let operators = names
+            .into_iter()
+            .map(|name| {
+                registry.get(name).cloned().ok_or_else(|| {
+                    HypercubeError::InvalidOperator(format!("Operator '{}' is not registered", name))
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
 
         let active_ids: Vec<String> = self.vertices
             .iter()
@@ -521,13 +573,6 @@ impl Hypercube {
             .take(5)
             .collect();
 
-        let operators: Vec<Box<dyn Operator5D>> = vec![
-            Box::new(DoubleKickOperator::default()),
-            Box::new(SwapWaveOperator::default()),
-            Box::new(PhaseIntegrationOperator::default()),
-            Box::new(WeightTransformOperator::default()),
-        ];
-
         let mut count = 0;
 
         for vid in active_ids {
@@ -549,7 +594,7 @@ impl Hypercube {
             let mut vertices_to_add: Vec<(HypercubeVertex, EdgeWeight, OperatorType)> = Vec::new();
 
             for op in &operators {
-                let new_coord = op.apply(&coord);
+                let new_coord = op.apply_bounded(&coord, self.coordinate_system.policy);
                 let res = new_coord.resonance();
 
                 let exists = existing_coords.iter().any(|v| {
@@ -661,9 +706,20 @@ impl Hypercube {
     }
 
     /// Get vertices sorted by resonance
+    ///
+    /// Ties are broken by coordinate, so the order does not depend on map
+    /// iteration order or the random vertex IDs.
     pub fn vertices_by_resonance(&self) -> Vec<&HypercubeVertex> {
         let mut vertices: Vec<_> = self.vertices.values().collect();
-        vertices.sort_by(|a, b| b.resonance.partial_cmp(&a.resonance).unwrap());
+        vertices.sort_by(|a, b| {
+            b.resonance.total_cmp(&a.resonance).then_with(|| {
+                a.coordinate.to_array().iter()
+                    .zip(b.coordinate.to_array().iter())
+                    .map(|(x, y)| x.total_cmp(y))
+                    .find(|o| o.is_ne())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+        });
         vertices
     }
 
@@ -678,6 +734,69 @@ impl Hypercube {
         serde_json::from_str(json)
             .map_err(|e| HypercubeError::SerializationError(e.to_string()))
     }
+
+    /// Vertices and edges in a stable order (by depth, then ID) for graph export
+    fn sorted_graph(&self) -> (Vec<&HypercubeVertex>, Vec<&HypercubeEdge>) {
+        let mut vertices: Vec<_> = self.vertices.values().collect();
+        vertices.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.id.cmp(&b.id)));
+        let mut edges: Vec<_> = self.edges.values().collect();
+        edges.sort_by(|a, b| a.id.cmp(&b.id));
+        (vertices, edges)
+    }
+
+    /// Export the vertex/edge graph in Graphviz DOT format
+    pub fn to_dot(&self) -> String {
+        let (vertices, edges) = self.sorted_graph();
+        let mut dot = format!("digraph \"{}\" {{\n", self.name.replace('"', "\\\""));
+
+        for v in vertices {
+            dot.push_str(&format!(
+                "    \"{}\" [label=\"{:?}\\nres={:.3}\", depth={}];\n",
+                v.id, v.vertex_type, v.resonance, v.depth
+            ));
+        }
+        for e in edges {
+            let arrow = if e.directed { "" } else { ", dir=none" };
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{:?}\", weight={:.3}{}];\n",
+                e.from_id, e.to_id, e.edge_type, e.weight.total, arrow
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Export the vertex/edge graph as node-link JSON (`nodes` + `links`),
+    /// the format consumed by force-directed layout libraries
+    pub fn to_node_link(&self) -> serde_json::Value {
+        let (vertices, edges) = self.sorted_graph();
+
+        let nodes: Vec<_> = vertices.iter().map(|v| serde_json::json!({
+            "id": v.id,
+            "type": format!("{:?}", v.vertex_type),
+            "depth": v.depth,
+            "resonance": v.resonance,
+            "coordinate": v.coordinate.to_array(),
+            "best": self.best_vertex_id.as_deref() == Some(v.id.as_str()),
+        })).collect();
+
+        let links: Vec<_> = edges.iter().map(|e| serde_json::json!({
+            "id": e.id,
+            "source": e.from_id,
+            "target": e.to_id,
+            "type": format!("{:?}", e.edge_type),
+            "weight": e.weight.total,
+            "directed": e.directed,
+        })).collect();
+
+        serde_json::json!({
+            "directed": true,
+            "name": self.name,
+            "nodes": nodes,
+            "links": links,
+        })
+    }
 }
 
 impl std::fmt::Display for Hypercube {
@@ -714,4 +833,40 @@ mod tests {
         assert!(cube.best_resonance > 0.0);
         assert!(cube.best_vertex_id.is_some());
     }
+
+    #[test]
+    fn test_operator_driven_swap_dims() {
+        let config = HypercubeConfig {
+            expansion_rule: CubeExpansionRule::OperatorDriven,
+            swap_dims: Some((0, 2)),
+            ..Default::default()
+        };
+        let mut cube = Hypercube::new("swap", config);
+        assert!(cube.expand_step().is_ok());
+
+        let invalid = HypercubeConfig {
+            expansion_rule: CubeExpansionRule::OperatorDriven,
+            swap_dims: Some((1, 1)),
+            ..Default::default()
+        };
+        let mut cube = Hypercube::new("swap_invalid", invalid);
+        assert!(cube.expand_step().is_err());
+    }
+
+    #[test]
+    fn test_graph_export() {
+        let mut cube = Hypercube::default_cube("export");
+        cube.expand_step().unwrap();
+
+        let dot = cube.to_dot();
+        assert!(dot.starts_with("digraph \"export\" {"));
+        assert_eq!(dot.matches(" -> ").count(), cube.edges.len());
+
+        let graph = cube.to_node_link();
+        assert_eq!(graph["nodes"].as_array().unwrap().len(), cube.vertices.len());
+        assert_eq!(graph["links"].as_array().unwrap().len(), cube.edges.len());
+        for link in graph["links"].as_array().unwrap() {
+            assert!(cube.vertices.contains_key(link["source"].as_str().unwrap()));
+        }
+    }
 }
@@ -26,8 +26,11 @@ pub enum HypercubeError {
     #[error("Edge not found: {0}")]
     EdgeNotFound(String),
 
-    #[error("Cycle detected in HDAG")]
-    CycleDetected,
+    #[error("Cycle detected in HDAG: {}", .0.join(" -> "))]
+    CycleDetected(Vec<String>),
+
+    #[error("Edge {from} -> {to} would create a cycle")]
+    CyclicGraph { from: String, to: String },
 
     #[error("Invalid expansion rule: {0}")]
     InvalidExpansionRule(String),
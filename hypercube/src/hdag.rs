@@ -7,13 +7,15 @@
 //! - Artifact generation and tracking
 
 use crate::coordinates::Coord5D;
-use crate::operators::{Operator5D, OperatorType, CompilationOperator, CompilationMode};
+use crate::operators::{Operator5D, OperatorRegistry, OperatorType, CompilationOperator, CompilationMode};
 use crate::artifact::{HypercubeArtifact, ArtifactType};
 use crate::error::{HypercubeError, Result};
 use petgraph::graph::{DiGraph, NodeIndex};
-use petgraph::algo::toposort;
+use petgraph::algo::{has_path_connecting, toposort};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::Arc;
 use uuid::Uuid;
 
 /// State of an HDAG node
@@ -67,6 +69,9 @@ pub struct HDAGNode {
     pub state: HDAGNodeState,
     /// Associated operator type (for Operator nodes)
     pub operator_type: Option<OperatorType>,
+    /// Registered operator to apply, by name (takes precedence over `operator_type`)
+    #[serde(default)]
+    pub operator_name: Option<String>,
     /// Input coordinate (if applicable)
     pub input: Option<Coord5D>,
     /// Output coordinate (after execution)
@@ -102,6 +107,7 @@ impl HDAGNode {
             node_type,
             state: HDAGNodeState::Pending,
             operator_type: None,
+            operator_name: None,
             input: None,
             output: None,
             priority: 0,
@@ -128,6 +134,13 @@ impl HDAGNode {
         node
     }
 
+    /// Create an operator node applying a registered operator
+    pub fn named_operator(name: &str, operator: &str) -> Self {
+        let mut node = Self::new(name, HDAGNodeType::Operator);
+        node.operator_name = Some(operator.to_string());
+        node
+    }
+
     /// Create a compilation node
     pub fn compilation(name: &str) -> Self {
         let mut node = Self::new(name, HDAGNodeType::Compilation);
@@ -248,9 +261,70 @@ impl Default for HDAGEdge {
     }
 }
 
+/// Node entry of an HDAG graph file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HDAGNodeSpec {
+    /// Identifier edges refer to
+    pub id: String,
+    /// Node name (defaults to the ID)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Node type
+    pub node_type: HDAGNodeType,
+    /// Built-in operator to apply (Operator nodes need this or `operator_name`)
+    #[serde(default)]
+    pub operator: Option<OperatorType>,
+    /// Registered operator to apply, by name
+    #[serde(default)]
+    pub operator_name: Option<String>,
+    /// Input coordinate (required for Input nodes)
+    #[serde(default)]
+    pub input: Option<Coord5D>,
+    /// Execution priority (lower = higher priority)
+    #[serde(default)]
+    pub priority: i32,
+    /// Is node optional (can be skipped)
+    #[serde(default)]
+    pub optional: bool,
+}
+
+/// Edge entry of an HDAG graph file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HDAGEdgeSpec {
+    /// Source node ID
+    pub from: String,
+    /// Target node ID
+    pub to: String,
+    /// Edge type
+    #[serde(default = "default_edge_type")]
+    pub edge_type: HDAGEdgeType,
+    /// Weight/priority (defaults to the edge type's default)
+    #[serde(default)]
+    pub weight: Option<f64>,
+    /// Label
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+fn default_edge_type() -> HDAGEdgeType {
+    HDAGEdgeType::Data
+}
+
+/// JSON graph file describing a user-defined HDAG
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HDAGFile {
+    /// Graph name
+    pub name: String,
+    /// Nodes
+    pub nodes: Vec<HDAGNodeSpec>,
+    /// Edges
+    #[serde(default)]
+    pub edges: Vec<HDAGEdgeSpec>,
+}
+
 /// The Hierarchical Directed Acyclic Graph
 ///
-/// Note: The `operators` field contains `Box<dyn Operator5D>` which cannot be cloned.
+/// Note: The `operators` field contains `dyn Operator5D` overrides which are not cloned.
 /// When cloning this struct, the operators HashMap will be empty. The HDAG can still
 /// execute using the built-in operator application logic based on `operator_type`.
 pub struct HDAG {
@@ -262,15 +336,16 @@ pub struct HDAG {
     graph: DiGraph<HDAGNode, HDAGEdge>,
     /// Node ID to index mapping
     node_index_map: HashMap<String, NodeIndex>,
-    /// Operator instances for execution (not cloneable/debuggable)
-    #[allow(dead_code)]
-    operators: HashMap<String, Box<dyn Operator5D>>,
+    /// Operator overrides keyed by node ID (not cloneable/debuggable)
+    operators: HashMap<String, Arc<dyn Operator5D>>,
     /// Generated artifacts
     artifacts: Vec<HypercubeArtifact>,
     /// Execution order (topological sort)
     execution_order: Vec<NodeIndex>,
     /// Current execution position
     current_position: usize,
+    /// Phase coherence κ reported by the last phase operator executed
+    phase_coherence: Option<f64>,
 }
 
 impl std::fmt::Debug for HDAG {
@@ -298,6 +373,7 @@ impl Clone for HDAG {
             artifacts: self.artifacts.clone(),
             execution_order: self.execution_order.clone(),
             current_position: self.current_position,
+            phase_coherence: self.phase_coherence,
         }
     }
 }
@@ -314,6 +390,7 @@ impl HDAG {
             artifacts: Vec::new(),
             execution_order: Vec::new(),
             current_position: 0,
+            phase_coherence: None,
         }
     }
 
@@ -326,16 +403,96 @@ impl HDAG {
     }
 
     /// Add an edge between nodes
+    ///
+    /// Fails with [`HypercubeError::CyclicGraph`] if `to_id` already reaches
+    /// `from_id` (or they are the same node), leaving the graph unchanged.
     pub fn add_edge(&mut self, from_id: &str, to_id: &str, edge: HDAGEdge) -> Result<()> {
-        let from_idx = self.node_index_map.get(from_id)
+        let from_idx = *self.node_index_map.get(from_id)
             .ok_or_else(|| HypercubeError::VertexNotFound(from_id.to_string()))?;
-        let to_idx = self.node_index_map.get(to_id)
+        let to_idx = *self.node_index_map.get(to_id)
             .ok_or_else(|| HypercubeError::VertexNotFound(to_id.to_string()))?;
 
-        self.graph.add_edge(*from_idx, *to_idx, edge);
+        if has_path_connecting(&self.graph, to_idx, from_idx, None) {
+            return Err(HypercubeError::CyclicGraph {
+                from: from_id.to_string(),
+                to: to_id.to_string(),
+            });
+        }
+
+        self.graph.add_edge(from_idx, to_idx, edge);
+        Ok(())
+    }
+
+    /// Check that the graph is acyclic
+    pub fn validate(&self) -> Result<()> {
+        match toposort(&self.graph, None) {
+            Ok(_) => Ok(()),
+            Err(cycle) => Err(HypercubeError::CycleDetected(self.cycle_through(cycle.node_id()))),
+        }
+    }
+
+    /// Override the operator executed by a node
+    ///
+    /// Nodes without an override use the default operator for their type.
+    pub fn set_node_operator<O: Operator5D + 'static>(&mut self, node_id: &str, op: O) -> Result<()> {
+        if !self.node_index_map.contains_key(node_id) {
+            return Err(HypercubeError::VertexNotFound(node_id.to_string()));
+        }
+        self.operators.insert(node_id.to_string(), Arc::new(op));
         Ok(())
     }
 
+    /// Override the operator for every node of the given type
+    ///
+    /// Returns the number of nodes affected.
+    pub fn set_operator_for_type<O: Operator5D + Clone + 'static>(&mut self, op_type: OperatorType, op: O) -> usize {
+        let ids: Vec<String> = self.graph.node_weights()
+            .filter(|n| n.node_type == HDAGNodeType::Operator && n.operator_type == Some(op_type))
+            .map(|n| n.id.clone())
+            .collect();
+
+        for id in &ids {
+            self.operators.insert(id.clone(), Arc::new(op.clone()));
+        }
+        ids.len()
+    }
+
+    /// Bind operator nodes to operators from a registry
+    ///
+    /// Each node uses the operator registered under its `operator_name`, or
+    /// under the built-in name of its `operator_type` if it has none. Nodes
+    /// with an override already set keep it. Fails if a named operator is
+    /// not registered. Returns the number of nodes bound.
+    pub fn bind_operators(&mut self, registry: &OperatorRegistry) -> Result<usize> {
+        let mut bound = 0;
+        for node in self.graph.node_weights() {
+            if node.node_type != HDAGNodeType::Operator || self.operators.contains_key(&node.id) {
+                continue;
+            }
+            let name = match (&node.operator_name, node.operator_type) {
+                (Some(name), _) => name.as_str(),
+                (None, Some(op_type)) => match OperatorRegistry::builtin_name(op_type) {
+                    Some(name) => name,
+                    None => continue,
+                },
+                (None, None) => continue,
+            };
+            match registry.get(name) {
+                Some(op) => {
+                    self.operators.insert(node.id.clone(), Arc::clone(op));
+                    bound += 1;
+                }
+                None if node.operator_name.is_some() => {
+                    return Err(HypercubeError::InvalidOperator(format!(
+                        "Operator '{}' of node {} is not registered", name, node.id
+                    )));
+                }
+                None => {}
+            }
+        }
+        Ok(bound)
+    }
+
     /// Get a node by ID
     pub fn get_node(&self, id: &str) -> Option<&HDAGNode> {
         self.node_index_map.get(id)
@@ -349,6 +506,9 @@ impl HDAG {
     }
 
     /// Compute topological execution order
+    ///
+    /// Fails with [`HypercubeError::CycleDetected`] listing the node IDs of a
+    /// cycle, starting and ending at the same node.
     pub fn compute_execution_order(&mut self) -> Result<()> {
         match toposort(&self.graph, None) {
             Ok(order) => {
@@ -356,8 +516,33 @@ impl HDAG {
                 self.current_position = 0;
                 Ok(())
             }
-            Err(_) => Err(HypercubeError::CycleDetected),
+            Err(cycle) => Err(HypercubeError::CycleDetected(self.cycle_through(cycle.node_id()))),
+        }
+    }
+
+    /// Node IDs of the shortest cycle through `start`, which must lie on one
+    fn cycle_through(&self, start: NodeIndex) -> Vec<String> {
+        let mut parent: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            for next in self.graph.neighbors_directed(node, petgraph::Direction::Outgoing) {
+                if next == start {
+                    let mut path = vec![start, node];
+                    let mut current = node;
+                    while current != start {
+                        current = parent[&current];
+                        path.push(current);
+                    }
+                    path.reverse();
+                    return path.iter().map(|&idx| self.graph[idx].id.clone()).collect();
+                }
+                if let std::collections::hash_map::Entry::Vacant(entry) = parent.entry(next) {
+                    entry.insert(node);
+                    queue.push_back(next);
+                }
+            }
         }
+        vec![self.graph[start].id.clone()]
     }
 
     /// Update node readiness based on dependencies
@@ -409,6 +594,15 @@ impl HDAG {
 
     /// Execute a single node
     fn execute_node(&mut self, node_id: &str) -> Result<Coord5D> {
+        let (output, kappa) = self.evaluate_node(node_id)?;
+        if kappa.is_some() {
+            self.phase_coherence = kappa;
+        }
+        Ok(output)
+    }
+
+    /// Output of a node and the phase coherence its operator reported, if any
+    fn evaluate_node(&self, node_id: &str) -> Result<(Coord5D, Option<f64>)> {
         let inputs = self.get_inputs(node_id);
 
         let node = self.get_node(node_id)
@@ -416,6 +610,7 @@ impl HDAG {
 
         let node_type = node.node_type;
         let operator_type = node.operator_type;
+        let operator_name = node.operator_name.clone();
         let node_input = node.input;
 
         // Determine input coordinate
@@ -431,12 +626,29 @@ impl HDAG {
         };
 
         // Execute based on node type
+        let mut kappa = None;
         let output = match node_type {
             HDAGNodeType::Input => {
                 node_input.unwrap_or(input)
             }
             HDAGNodeType::Operator => {
-                self.apply_operator(operator_type, &input)?
+                match (self.operators.get(node_id), operator_name) {
+                    (Some(op), _) => {
+                        let (output, coherence) = op.apply_with_coherence(&input);
+                        kappa = coherence;
+                        output
+                    }
+                    (None, Some(name)) => {
+                        return Err(HypercubeError::InvalidOperator(format!(
+                            "Operator '{}' of node {} is not bound", name, node_id
+                        )));
+                    }
+                    (None, None) => {
+                        let (output, coherence) = Self::apply_operator(operator_type, &input);
+                        kappa = coherence;
+                        output
+                    }
+                }
             }
             HDAGNodeType::Compilation => {
                 let compiler = CompilationOperator::new(CompilationMode::Balanced);
@@ -463,13 +675,14 @@ impl HDAG {
             }
         };
 
-        Ok(output)
+        Ok((output, kappa))
     }
 
-    /// Apply an operator
-    fn apply_operator(&self, op_type: Option<OperatorType>, input: &Coord5D) -> Result<Coord5D> {
+    /// Apply a default operator, returning the phase coherence of PI
+    fn apply_operator(op_type: Option<OperatorType>, input: &Coord5D) -> (Coord5D, Option<f64>) {
         use crate::operators::*;
 
+        let mut kappa = None;
         let output = match op_type {
             Some(OperatorType::DK) => {
                 DoubleKickOperator::default().apply(input)
@@ -478,7 +691,9 @@ impl HDAG {
                 SwapWaveOperator::default().apply(input)
             }
             Some(OperatorType::PI) => {
-                PhaseIntegrationOperator::default().apply(input)
+                let (output, coherence) = PhaseIntegrationOperator::default().apply_with_coherence(input);
+                kappa = coherence;
+                output
             }
             Some(OperatorType::WT) => {
                 WeightTransformOperator::default().apply(input)
@@ -494,13 +709,20 @@ impl HDAG {
                 let mut result = *input;
                 result = DoubleKickOperator::default().apply(&result);
                 result = SwapWaveOperator::default().apply(&result);
-                result = PhaseIntegrationOperator::default().apply(&result);
+                let (output, coherence) = PhaseIntegrationOperator::default().apply_with_coherence(&result);
+                result = output;
+                kappa = coherence;
                 result = WeightTransformOperator::default().apply(&result);
                 result
             }
         };
 
-        Ok(output)
+        (output, kappa)
+    }
+
+    /// Phase coherence κ from the most recently executed phase operator
+    pub fn phase_coherence(&self) -> Option<f64> {
+        self.phase_coherence
     }
 
     /// Check if execution is complete
@@ -543,6 +765,136 @@ impl HDAG {
         self.graph.edge_count()
     }
 
+    /// Build an HDAG from a graph description
+    ///
+    /// Node IDs must be unique, Operator nodes need an operator and Input nodes
+    /// an input coordinate, and edges must connect known nodes without forming
+    /// a cycle. Edges are inserted as given, so a cyclic description fails
+    /// with [`HypercubeError::CycleDetected`] naming the whole cycle. The
+    /// execution order is computed.
+    pub fn from_file(file: HDAGFile) -> Result<Self> {
+        let mut hdag = Self::new(&file.name);
+
+        for spec in file.nodes {
+            if hdag.node_index_map.contains_key(&spec.id) {
+                return Err(HypercubeError::ExecutionError(format!("Duplicate node ID: {}", spec.id)));
+            }
+            let name = spec.name.as_deref().unwrap_or(&spec.id);
+            let mut node = match spec.node_type {
+                HDAGNodeType::Input => {
+                    let coord = spec.input.ok_or_else(|| {
+                        HypercubeError::ExecutionError(format!("Input node {} has no input coordinate", spec.id))
+                    })?;
+                    HDAGNode::input(name, coord)
+                }
+                HDAGNodeType::Operator => match (&spec.operator_name, spec.operator) {
+                    (Some(operator), op) => {
+                        let mut node = HDAGNode::named_operator(name, operator);
+                        node.operator_type = op;
+                        node
+                    }
+                    (None, Some(op)) => HDAGNode::operator(name, op),
+                    (None, None) => {
+                        return Err(HypercubeError::InvalidOperator(format!(
+                            "Operator node {} has no operator", spec.id
+                        )));
+                    }
+                },
+                HDAGNodeType::Compilation => HDAGNode::compilation(name),
+                node_type => HDAGNode::new(name, node_type),
+            };
+            if node.node_type != HDAGNodeType::Input {
+                node.input = spec.input;
+            }
+            if node.node_type != HDAGNodeType::Operator && spec.operator.is_some() {
+                node.operator_type = spec.operator;
+            }
+            node.id = spec.id;
+            node.priority = spec.priority;
+            node.optional = spec.optional;
+            hdag.add_node(node);
+        }
+
+        for spec in file.edges {
+            let mut edge = match spec.edge_type {
+                HDAGEdgeType::Data => HDAGEdge::data(),
+                HDAGEdgeType::Control => HDAGEdge::control(),
+                HDAGEdgeType::Dependency => HDAGEdge::dependency(),
+            };
+            if let Some(weight) = spec.weight {
+                edge.weight = weight;
+            }
+            edge.label = spec.label;
+            let from = *hdag.node_index_map.get(&spec.from)
+                .ok_or_else(|| HypercubeError::VertexNotFound(spec.from.clone()))?;
+            let to = *hdag.node_index_map.get(&spec.to)
+                .ok_or_else(|| HypercubeError::VertexNotFound(spec.to.clone()))?;
+            hdag.graph.add_edge(from, to, edge);
+        }
+
+        hdag.compute_execution_order()?;
+        Ok(hdag)
+    }
+
+    /// Graph description of this HDAG, without execution state
+    pub fn to_file(&self) -> HDAGFile {
+        let nodes = self.graph.node_weights()
+            .map(|node| HDAGNodeSpec {
+                id: node.id.clone(),
+                name: Some(node.name.clone()),
+                node_type: node.node_type,
+                operator: node.operator_type,
+                operator_name: node.operator_name.clone(),
+                input: node.input,
+                priority: node.priority,
+                optional: node.optional,
+            })
+            .collect();
+        let edges = self.graph.edge_indices()
+            .filter_map(|e| {
+                let (from, to) = self.graph.edge_endpoints(e)?;
+                let edge = &self.graph[e];
+                Some(HDAGEdgeSpec {
+                    from: self.graph[from].id.clone(),
+                    to: self.graph[to].id.clone(),
+                    edge_type: edge.edge_type,
+                    weight: Some(edge.weight),
+                    label: edge.label.clone(),
+                })
+            })
+            .collect();
+
+        HDAGFile { name: self.name.clone(), nodes, edges }
+    }
+
+    /// Parse an HDAG from a JSON graph description (see [`HDAG::from_file`])
+    pub fn from_json_str(json: &str) -> Result<Self> {
+        let file: HDAGFile = serde_json::from_str(json)
+            .map_err(|e| HypercubeError::SerializationError(e.to_string()))?;
+        Self::from_file(file)
+    }
+
+    /// Load an HDAG from a JSON graph file (see [`HDAG::from_file`])
+    pub fn from_json(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| HypercubeError::SerializationError(format!("{}: {}", path.display(), e)))?;
+        Self::from_json_str(&json)
+    }
+
+    /// JSON graph description readable by [`HDAG::from_json_str`]
+    pub fn to_json_string(&self) -> Result<String> {
+        serde_json::to_string_pretty(&self.to_file())
+            .map_err(|e| HypercubeError::SerializationError(e.to_string()))
+    }
+
+    /// Write a JSON graph file readable by [`HDAG::from_json`]
+    pub fn to_json(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.to_json_string()?)
+            .map_err(|e| HypercubeError::SerializationError(format!("{}: {}", path.display(), e)))
+    }
+
     /// Create a standard pipeline HDAG
     /// Input -> DK -> SW -> PI -> WT -> Compilation -> Output
     pub fn standard_pipeline(seed: Coord5D) -> Self {
@@ -625,7 +977,7 @@ pub struct ExecutionResult {
     pub output: Coord5D,
     /// Final resonance
     pub resonance: f64,
-    /// Total execution time in ms
+    /// Total wall-clock execution time in ms
     pub total_time_ms: u64,
     /// Number of nodes executed
     pub nodes_executed: usize,
@@ -633,20 +985,29 @@ pub struct ExecutionResult {
     pub nodes_failed: usize,
     /// Generated artifacts
     pub artifact_count: usize,
+    /// Phase coherence κ of the last phase operator (if any ran)
+    pub phase_coherence: Option<f64>,
+}
+
+/// Running counts while executing an HDAG
+#[derive(Default)]
+struct ExecutionTally {
+    nodes_executed: usize,
+    nodes_failed: usize,
+    last_output: Option<Coord5D>,
 }
 
 impl HDAGExecutor {
-    /// Create a new executor
-    pub fn new(hdag: HDAG) -> Self {
-        Self { hdag }
+    /// Create a new executor, refusing a cyclic graph
+    pub fn new(hdag: HDAG) -> Result<Self> {
+        hdag.validate()?;
+        Ok(Self { hdag })
     }
 
     /// Execute the entire HDAG
     pub fn execute(&mut self) -> Result<ExecutionResult> {
         let start = std::time::Instant::now();
-        let mut nodes_executed = 0;
-        let mut nodes_failed = 0;
-        let mut last_output = Coord5D::center();
+        let mut tally = ExecutionTally::default();
 
         self.hdag.compute_execution_order()?;
         self.hdag.update_readiness();
@@ -670,55 +1031,122 @@ impl HDAGExecutor {
             let node_start = std::time::Instant::now();
             let result = self.hdag.execute_node(&next_id);
             let node_time = node_start.elapsed().as_millis() as u64;
+            self.record(&next_id, result, node_time, &mut tally);
 
-            match result {
-                Ok(output) => {
-                    last_output = output;
-                    if let Some(node) = self.hdag.get_node_mut(&next_id) {
-                        node.mark_completed(output, node_time);
-                    }
-                    nodes_executed += 1;
+            // Update readiness
+            self.hdag.update_readiness();
+            self.hdag.current_position += 1;
+        }
 
-                    // Generate artifact for output nodes
-                    if self.hdag.get_node(&next_id)
-                        .map(|n| n.node_type == HDAGNodeType::Output)
-                        .unwrap_or(false)
-                    {
-                        let artifact = HypercubeArtifact::new(
-                            "output",
-                            ArtifactType::CompiledFamily,
-                            output,
-                        );
-                        self.hdag.artifacts.push(artifact);
-                    }
+        Ok(self.finish(start, tally))
+    }
+
+    /// Execute the HDAG with independent nodes running concurrently
+    ///
+    /// Runs in waves: every node whose dependencies are satisfied is
+    /// evaluated on the current rayon pool, then outputs, artifacts and phase
+    /// coherence are merged in execution order before the next wave. Operator
+    /// overrides are shared across threads, which is why [`Operator5D`]
+    /// requires `Send + Sync`. The result matches [`execute`](Self::execute)
+    /// for the same graph, while `total_time_ms` shows the wall-clock gain.
+    pub fn execute_parallel(&mut self) -> Result<ExecutionResult> {
+        use rayon::prelude::*;
+
+        let start = std::time::Instant::now();
+        let mut tally = ExecutionTally::default();
+
+        self.hdag.compute_execution_order()?;
+        self.hdag.update_readiness();
+
+        loop {
+            let ready: Vec<String> = self.hdag.execution_order.iter()
+                .map(|&idx| &self.hdag.graph[idx])
+                .filter(|node| node.can_execute())
+                .map(|node| node.id.clone())
+                .collect();
+            if ready.is_empty() {
+                break;
+            }
+
+            for id in &ready {
+                if let Some(node) = self.hdag.get_node_mut(id) {
+                    node.mark_executing();
                 }
-                Err(e) => {
-                    if let Some(node) = self.hdag.get_node_mut(&next_id) {
-                        if node.optional {
-                            node.state = HDAGNodeState::Skipped;
-                        } else {
-                            node.mark_failed(&e.to_string());
-                            nodes_failed += 1;
-                        }
+            }
+
+            let hdag = &self.hdag;
+            let results: Vec<_> = ready.par_iter()
+                .map(|id| {
+                    let node_start = std::time::Instant::now();
+                    let result = hdag.evaluate_node(id);
+                    (result, node_start.elapsed().as_millis() as u64)
+                })
+                .collect();
+
+            for (id, (result, node_time)) in ready.iter().zip(results) {
+                let result = result.map(|(output, kappa)| {
+                    if kappa.is_some() {
+                        self.hdag.phase_coherence = kappa;
                     }
-                }
+                    output
+                });
+                self.record(id, result, node_time, &mut tally);
             }
 
-            // Update readiness
             self.hdag.update_readiness();
-            self.hdag.current_position += 1;
         }
+        self.hdag.current_position = self.hdag.execution_order.len();
 
-        let total_time = start.elapsed().as_millis() as u64;
+        Ok(self.finish(start, tally))
+    }
 
-        Ok(ExecutionResult {
-            output: last_output,
-            resonance: last_output.resonance(),
-            total_time_ms: total_time,
-            nodes_executed,
-            nodes_failed,
+    /// Store a node's outcome, generating an artifact for output nodes
+    fn record(&mut self, node_id: &str, result: Result<Coord5D>, node_time: u64, tally: &mut ExecutionTally) {
+        match result {
+            Ok(output) => {
+                tally.last_output = Some(output);
+                if let Some(node) = self.hdag.get_node_mut(node_id) {
+                    node.mark_completed(output, node_time);
+                }
+                tally.nodes_executed += 1;
+
+                // Generate artifact for output nodes
+                if self.hdag.get_node(node_id)
+                    .map(|n| n.node_type == HDAGNodeType::Output)
+                    .unwrap_or(false)
+                {
+                    let artifact = HypercubeArtifact::new(
+                        "output",
+                        ArtifactType::CompiledFamily,
+                        output,
+                    );
+                    self.hdag.artifacts.push(artifact);
+                }
+            }
+            Err(e) => {
+                if let Some(node) = self.hdag.get_node_mut(node_id) {
+                    if node.optional {
+                        node.state = HDAGNodeState::Skipped;
+                    } else {
+                        node.mark_failed(&e.to_string());
+                        tally.nodes_failed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn finish(&self, start: std::time::Instant, tally: ExecutionTally) -> ExecutionResult {
+        let output = tally.last_output.unwrap_or_default();
+        ExecutionResult {
+            output,
+            resonance: output.resonance(),
+            total_time_ms: start.elapsed().as_millis() as u64,
+            nodes_executed: tally.nodes_executed,
+            nodes_failed: tally.nodes_failed,
             artifact_count: self.hdag.artifacts.len(),
-        })
+            phase_coherence: self.hdag.phase_coherence,
+        }
     }
 
     /// Get the underlying HDAG
@@ -735,6 +1163,7 @@ impl HDAGExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::operators::PhaseIntegrationOperator;
 
     #[test]
     fn test_hdag_creation() {
@@ -756,20 +1185,207 @@ mod tests {
     fn test_hdag_execution() {
         let seed = Coord5D::new(0.5, 0.5, 0.5, 0.5, 0.5);
         let hdag = HDAG::standard_pipeline(seed);
-        let mut executor = HDAGExecutor::new(hdag);
+        let mut executor = HDAGExecutor::new(hdag).unwrap();
 
         let result = executor.execute().unwrap();
         assert!(result.resonance > 0.0);
         assert!(result.nodes_executed > 0);
     }
 
+    #[test]
+    fn test_operator_override() {
+        use crate::operators::WeightTransformOperator;
+
+        let seed = Coord5D::center();
+        let baseline = HDAGExecutor::new(HDAG::standard_pipeline(seed)).unwrap().execute().unwrap();
+
+        let mut hdag = HDAG::standard_pipeline(seed);
+        let wt = WeightTransformOperator::identity().with_weights([3.0, 1.0, 1.0, 1.0, 1.0]);
+        assert_eq!(hdag.set_operator_for_type(OperatorType::WT, wt), 1);
+        let overridden = HDAGExecutor::new(hdag).unwrap().execute().unwrap();
+
+        assert!(overridden.output.psi > baseline.output.psi);
+        assert!((overridden.output.rho - baseline.output.rho).abs() < 1e-12);
+    }
+
     #[test]
     fn test_parallel_branches() {
         let seed = Coord5D::center();
         let hdag = HDAG::parallel_branches(seed);
-        let mut executor = HDAGExecutor::new(hdag);
+        let mut executor = HDAGExecutor::new(hdag).unwrap();
 
         let result = executor.execute().unwrap();
         assert!(result.resonance > 0.0);
     }
+
+    /// Identity operator whose calls wait for each other
+    ///
+    /// Each call waits (up to a timeout) until `parties` calls have arrived and
+    /// records whether it met them, so overlap is observed directly.
+    #[derive(Clone)]
+    struct RendezvousIdentity {
+        parties: usize,
+        state: Arc<(std::sync::Mutex<(usize, usize)>, std::sync::Condvar)>,
+    }
+
+    impl RendezvousIdentity {
+        fn new(parties: usize) -> Self {
+            Self { parties, state: Arc::new((std::sync::Mutex::new((0, 0)), std::sync::Condvar::new())) }
+        }
+
+        /// Number of calls that met all other parties
+        fn met(&self) -> usize {
+            self.state.0.lock().unwrap().1
+        }
+    }
+
+    impl Operator5D for RendezvousIdentity {
+        fn operator_type(&self) -> OperatorType {
+            OperatorType::Identity
+        }
+
+        fn apply(&self, coord: &Coord5D) -> Coord5D {
+            let (lock, cvar) = &*self.state;
+            let mut guard = lock.lock().unwrap();
+            guard.0 += 1;
+            cvar.notify_all();
+            let (mut guard, timeout) = cvar
+                .wait_timeout_while(guard, std::time::Duration::from_secs(2), |(arrived, _)| *arrived < self.parties)
+                .unwrap();
+            if !timeout.timed_out() {
+                guard.1 += 1;
+            }
+            *coord
+        }
+
+        fn parameters(&self) -> Vec<f64> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_parallel_execution() {
+        let seed = Coord5D::new(0.6, 0.5, 0.4, 0.5, 0.3);
+        let sequential = HDAGExecutor::new(HDAG::parallel_branches(seed)).unwrap().execute().unwrap();
+        let mut executor = HDAGExecutor::new(HDAG::parallel_branches(seed)).unwrap();
+        let parallel = executor.execute_parallel().unwrap();
+
+        assert_eq!(parallel.output, sequential.output);
+        assert_eq!(parallel.nodes_executed, sequential.nodes_executed);
+        assert_eq!(parallel.artifact_count, 1);
+        assert_eq!(parallel.phase_coherence, sequential.phase_coherence);
+        assert!(executor.hdag().is_complete());
+
+        // Both branch heads wait for each other; only the parallel run lets
+        // them meet
+        let branches = |op: &RendezvousIdentity| {
+            let mut hdag = HDAG::parallel_branches(seed);
+            assert_eq!(hdag.set_operator_for_type(OperatorType::DK, op.clone()), 1);
+            assert_eq!(hdag.set_operator_for_type(OperatorType::PI, op.clone()), 1);
+            HDAGExecutor::new(hdag).unwrap()
+        };
+        let op = RendezvousIdentity::new(2);
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        let parallel = pool.install(|| branches(&op).execute_parallel()).unwrap();
+        assert_eq!(op.met(), 2);
+
+        let op = RendezvousIdentity::new(2);
+        let sequential = branches(&op).execute().unwrap();
+        assert_eq!(parallel.output, sequential.output);
+        assert_eq!(op.met(), 1);
+    }
+
+    #[test]
+    fn test_parallel_shared_phase_integration() {
+        // One wave of PI nodes sharing a single registry instance, long
+        // enough per call that the threads overlap
+        let mut registry = OperatorRegistry::new();
+        registry.register("PI", Box::new(PhaseIntegrationOperator::new([0.0; 5], 1e-7, 20_000)));
+        let build = |n: usize| {
+            let mut hdag = HDAG::new("SharedPI");
+            let out = hdag.add_node(HDAGNode::output("out"));
+            for i in 0..n {
+                let x = i as f64 / n as f64;
+                let input = hdag.add_node(HDAGNode::input(&format!("in{}", i), Coord5D::new(x, 1.0 - x, 0.3, 0.7, x * x)));
+                let pi = hdag.add_node(HDAGNode::named_operator(&format!("pi{}", i), "PI"));
+                hdag.add_edge(&input, &pi, HDAGEdge::data()).unwrap();
+                hdag.add_edge(&pi, &out, HDAGEdge::data()).unwrap();
+            }
+            hdag.bind_operators(&registry).unwrap();
+            HDAGExecutor::new(hdag).unwrap()
+        };
+
+        let sequential = build(16).execute().unwrap();
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(8).build().unwrap();
+        for _ in 0..20 {
+            let parallel = pool.install(|| build(16).execute_parallel()).unwrap();
+            assert_eq!(parallel.output, sequential.output);
+            assert_eq!(parallel.phase_coherence, sequential.phase_coherence);
+        }
+    }
+
+    #[test]
+    fn test_cycle_rejected() {
+        let mut hdag = HDAG::new("Cyclic");
+        let a = hdag.add_node(HDAGNode::operator("A", OperatorType::DK));
+        let b = hdag.add_node(HDAGNode::operator("B", OperatorType::SW));
+        hdag.add_edge(&a, &b, HDAGEdge::data()).unwrap();
+
+        match hdag.add_edge(&b, &a, HDAGEdge::data()) {
+            Err(HypercubeError::CyclicGraph { from, to }) => assert_eq!((from, to), (b.clone(), a.clone())),
+            other => panic!("expected CyclicGraph, got {:?}", other),
+        }
+        assert!(matches!(hdag.add_edge(&a, &a, HDAGEdge::control()), Err(HypercubeError::CyclicGraph { .. })));
+        assert_eq!(hdag.edge_count(), 1);
+        assert!(hdag.validate().is_ok());
+        assert!(HDAGExecutor::new(hdag).is_ok());
+    }
+
+    #[test]
+    fn test_json_graph() {
+        let json = r#"{
+            "name": "Custom",
+            "nodes": [
+                { "id": "in", "node_type": "Input",
+                  "input": { "psi": 0.5, "rho": 0.5, "omega": 0.5, "chi": 0.5, "eta": 0.5 } },
+                { "id": "dk", "node_type": "Operator", "operator": "DK" },
+                { "id": "pi", "node_type": "Operator", "operator": "PI", "priority": 1 },
+                { "id": "out", "node_type": "Output" }
+            ],
+            "edges": [
+                { "from": "in", "to": "dk" },
+                { "from": "dk", "to": "pi", "label": "kick" },
+                { "from": "pi", "to": "out", "edge_type": "Control" }
+            ]
+        }"#;
+        let hdag = HDAG::from_json_str(json).unwrap();
+        assert_eq!((hdag.node_count(), hdag.edge_count()), (4, 3));
+        assert_eq!(hdag.get_node("pi").unwrap().operator_type, Some(OperatorType::PI));
+
+        // Round trip through a file
+        let path = std::env::temp_dir().join(format!("hdag_{}.json", std::process::id()));
+        hdag.to_json(&path).unwrap();
+        let loaded = HDAG::from_json(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.name, "Custom");
+        assert_eq!(loaded.edge_count(), 3);
+
+        let result = HDAGExecutor::new(loaded).unwrap().execute().unwrap();
+        assert_eq!(result.nodes_failed, 0);
+        assert!(result.nodes_executed >= 3);
+
+        let cyclic = json.replace(r#"{ "from": "in", "to": "dk" }"#, r#"{ "from": "in", "to": "dk" }, { "from": "pi", "to": "dk" }"#);
+        match HDAG::from_json_str(&cyclic) {
+            Err(HypercubeError::CycleDetected(cycle)) => {
+                assert_eq!(cycle.len(), 3);
+                assert_eq!(cycle.first(), cycle.last());
+                assert!(cycle.contains(&"dk".to_string()) && cycle.contains(&"pi".to_string()));
+            }
+            other => panic!("expected a cycle, got {:?}", other.map(|h| h.node_count())),
+        }
+
+        let missing = json.replace(r#", "operator": "DK""#, "");
+        assert!(HDAG::from_json_str(&missing).is_err());
+        assert!(HDAG::from_json_str(&json.replace(r#""to": "out""#, r#""to": "nowhere""#)).is_err());
+    }
 }
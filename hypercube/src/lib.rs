@@ -46,17 +46,23 @@ pub mod error;
 
 // Re-exports
 pub use cube::{Hypercube, HypercubeConfig, HypercubeState, CubeExpansionRule};
-pub use hdag::{HDAG, HDAGNode, HDAGEdge, HDAGExecutor, ExecutionResult};
+pub use hdag::{
+    HDAG, HDAGNode, HDAGEdge, HDAGExecutor, ExecutionResult, HDAGNodeType, HDAGEdgeType,
+    HDAGFile, HDAGNodeSpec, HDAGEdgeSpec,
+};
 pub use operators::{
-    Operator5D, OperatorType, OperatorFamily,
-    DoubleKickOperator, SwapWaveOperator, PhaseIntegrationOperator, WeightTransformOperator,
-    CompilationOperator,
+    Operator5D, OperatorType, OperatorFamily, OperatorRegistry,
+    DoubleKickOperator, KickParams, SwapDirection, SwapWaveOperator, PhaseIntegrationOperator, WeightTransformOperator,
+    CompilationOperator, OperatorMatrix,
 };
 pub use compiler::{HypercubeCompiler, CompilationConfig, CompilationResult};
-pub use coordinates::{Coord5D, CoordinateSystem, CoordinateTransform};
+pub use coordinates::{Coord5D, CoordinatePolicy, CoordinateSystem, CoordinateTransform};
 pub use vertex::{HypercubeVertex, VertexType, VertexState};
 pub use edge::{HypercubeEdge, EdgeType, EdgeWeight};
-pub use artifact::{HypercubeArtifact, ArtifactType, ArtifactMetadata};
+pub use artifact::{
+    HypercubeArtifact, ArtifactType, ArtifactMetadata,
+    ArtifactCollection, ArtifactRetention, DiscardedArtifactStats,
+};
 pub use session::{HypercubeSession, SessionConfig, SessionResult};
 pub use triton_mode::{HypercubeTritonMode, TritonExpansionConfig};
 pub use error::{HypercubeError, Result};
@@ -9,10 +9,14 @@
 //! Plus the compilation operator:
 //! - **Ξ (Xi)**: Self-compilation operator for cube → artifact generation
 
-use crate::coordinates::Coord5D;
-use qops_core::Signature5D;
+use crate::coordinates::{Coord5D, CoordinatePolicy};
+use crate::error::{HypercubeError, Result};
+use qops_core::{phase_coherence, Signature5D};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::f64::consts::PI;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
 /// Operator type enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -69,6 +73,18 @@ pub trait Operator5D: Send + Sync {
     /// Apply operator to a coordinate
     fn apply(&self, coord: &Coord5D) -> Coord5D;
 
+    /// Apply operator without bringing the result back into the unit cube
+    ///
+    /// Operators that clamp internally override this; the default is `apply`.
+    fn apply_unbounded(&self, coord: &Coord5D) -> Coord5D {
+        self.apply(coord)
+    }
+
+    /// Apply operator and resolve out-of-bounds values with `policy`
+    fn apply_bounded(&self, coord: &Coord5D, policy: CoordinatePolicy) -> Coord5D {
+        self.apply_unbounded(coord).bound(policy)
+    }
+
     /// Apply operator to a signature
     fn apply_signature(&self, sig: &Signature5D) -> Signature5D {
         let coord = Coord5D::from_signature(sig);
@@ -87,12 +103,121 @@ pub trait Operator5D: Send + Sync {
     fn description(&self) -> String {
         format!("{} operator", self.operator_type().name())
     }
+
+    /// Phase coherence κ after the last application (phase operators only)
+    fn phase_coherence(&self) -> Option<f64> {
+        None
+    }
+
+    /// Apply operator and return the phase coherence κ of this very application
+    ///
+    /// Unlike [`phase_coherence`](Self::phase_coherence), the value cannot be
+    /// overwritten by another thread applying the same shared instance.
+    fn apply_with_coherence(&self, coord: &Coord5D) -> (Coord5D, Option<f64>) {
+        (self.apply(coord), self.phase_coherence())
+    }
+
+    /// Explicit affine form `M·c + t` of the operator, before clamping to the unit cube
+    ///
+    /// Nonlinear operators (DK, PI, and WT with a non-linear activation)
+    /// have no matrix form and return `InvalidOperator`.
+    fn as_matrix(&self) -> Result<OperatorMatrix> {
+        Err(HypercubeError::InvalidOperator(format!(
+            "{} operator is nonlinear and has no matrix form",
+            self.operator_type().name()
+        )))
+    }
+}
+
+/// Affine transform `M·c + t` on 5D coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OperatorMatrix {
+    /// Linear part M (row-major)
+    pub matrix: [[f64; 5]; 5],
+    /// Translation vector t
+    pub translation: [f64; 5],
+}
+
+impl OperatorMatrix {
+    /// Identity transform
+    pub fn identity() -> Self {
+        let mut matrix = [[0.0; 5]; 5];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        Self::linear(matrix)
+    }
+
+    /// Purely linear transform (no translation)
+    pub fn linear(matrix: [[f64; 5]; 5]) -> Self {
+        Self {
+            matrix,
+            translation: [0.0; 5],
+        }
+    }
+
+    /// Apply to a coordinate (without clamping)
+    pub fn apply(&self, coord: &Coord5D) -> Coord5D {
+        let arr = coord.to_array();
+        let mut out = self.translation;
+        for (o, row) in out.iter_mut().zip(self.matrix.iter()) {
+            *o += row.iter().zip(arr.iter()).map(|(m, x)| m * x).sum::<f64>();
+        }
+        Coord5D::from_vec(&out)
+    }
+
+    /// Transform that applies `self` first, then `next`
+    pub fn then(&self, next: &OperatorMatrix) -> Self {
+        let mut matrix = [[0.0; 5]; 5];
+        let mut translation = next.translation;
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..5).map(|k| next.matrix[i][k] * self.matrix[k][j]).sum();
+                translation[i] += next.matrix[i][j] * self.translation[j];
+            }
+        }
+        Self { matrix, translation }
+    }
+
+    /// Whether the translation vector is zero
+    pub fn is_linear(&self) -> bool {
+        self.translation.iter().all(|t| t.abs() <= 1e-12)
+    }
+
+    /// Whether M·Mᵀ = I within `tol`
+    pub fn is_orthogonal(&self, tol: f64) -> bool {
+        (0..5).all(|i| {
+            (0..5).all(|j| {
+                let dot: f64 = (0..5).map(|k| self.matrix[i][k] * self.matrix[j][k]).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                (dot - expected).abs() <= tol
+            })
+        })
+    }
+
+    /// Whether M is a signed permutation (a single ±1 per row and column) within `tol`
+    pub fn is_permutation(&self, tol: f64) -> bool {
+        let unit = |v: f64| (v.abs() - 1.0).abs() <= tol;
+        let zero = |v: f64| v.abs() <= tol;
+        let rows_ok = self.matrix.iter().all(|row| {
+            row.iter().filter(|v| unit(**v)).count() == 1
+                && row.iter().all(|v| unit(*v) || zero(*v))
+        });
+        let cols_ok = (0..5).all(|j| (0..5).filter(|&i| unit(self.matrix[i][j])).count() == 1);
+        rows_ok && cols_ok
+    }
+}
+
+impl Default for OperatorMatrix {
+    fn default() -> Self {
+        Self::identity()
+    }
 }
 
 /// Double Kick Operator (DK)
 ///
 /// Applies a double perturbation to the state:
-/// DK(c) = c + α·(sin(β·c) + γ·cos(δ·c))
+/// DK(c) = c + α·sin(β·c + φ) + γ·cos(δ·c + φ)
 ///
 /// This creates oscillatory dynamics in the 5D space.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,25 +230,74 @@ pub struct DoubleKickOperator {
     pub gamma: f64,
     /// Second frequency parameter
     pub delta: f64,
+    /// Phase offset φ
+    #[serde(default)]
+    pub phase: f64,
     /// Dimensions to apply kick (bitmask)
     pub dimensions: u8,
 }
 
+/// Kick magnitude and phase offset of a Double Kick operator
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct KickParams {
+    /// Primary kick strength α (the secondary kick is α/2)
+    pub strength: f64,
+    /// Phase offset φ
+    pub phase: f64,
+}
+
+impl KickParams {
+    /// Create kick parameters
+    pub fn new(strength: f64, phase: f64) -> Self {
+        Self { strength, phase }
+    }
+}
+
 impl DoubleKickOperator {
-    /// Create a new Double Kick operator
-    pub fn new(alpha: f64, beta: f64, gamma: f64, delta: f64) -> Self {
+    /// Create a Double Kick operator with the given kick strength and phase offset
+    ///
+    /// The secondary kick is half the primary strength, with the default
+    /// frequencies β = π and δ = 2π.
+    pub fn new(strength: f64, phase: f64) -> Self {
+        Self::from_params(strength, PI, strength / 2.0, 2.0 * PI).with_phase(phase)
+    }
+
+    /// Create a Double Kick operator from explicit α, β, γ, δ
+    pub fn from_params(alpha: f64, beta: f64, gamma: f64, delta: f64) -> Self {
         Self {
             alpha,
             beta,
             gamma,
             delta,
+            phase: 0.0,
             dimensions: 0b11111, // All 5 dimensions
         }
     }
 
+    /// Create from kick parameters
+    pub fn from_kick(kick: KickParams) -> Self {
+        Self::new(kick.strength, kick.phase)
+    }
+
     /// Create with default parameters
     pub fn default_params() -> Self {
-        Self::new(0.1, PI, 0.05, 2.0 * PI)
+        Self::from_params(0.1, PI, 0.05, 2.0 * PI)
+    }
+
+    /// Gentle kick used by resonance-optimized families
+    pub fn resonance_optimized() -> Self {
+        Self::from_params(0.05, PI / 2.0, 0.02, PI)
+    }
+
+    /// Set phase offset
+    pub fn with_phase(mut self, phase: f64) -> Self {
+        self.phase = phase;
+        self
+    }
+
+    /// Effective kick strength and phase
+    pub fn kick_params(&self) -> KickParams {
+        KickParams::new(self.alpha, self.phase)
     }
 
     /// Set which dimensions to apply
@@ -139,22 +313,26 @@ impl Operator5D for DoubleKickOperator {
     }
 
     fn apply(&self, coord: &Coord5D) -> Coord5D {
+        self.apply_unbounded(coord).clamp_unit()
+    }
+
+    fn apply_unbounded(&self, coord: &Coord5D) -> Coord5D {
         let mut result = *coord;
         let arr = coord.to_array();
 
         for (i, &val) in arr.iter().enumerate() {
             if (self.dimensions >> i) & 1 == 1 {
-                let kick = self.alpha * (self.beta * val).sin()
-                    + self.gamma * (self.delta * val).cos();
+                let kick = self.alpha * (self.beta * val + self.phase).sin()
+                    + self.gamma * (self.delta * val + self.phase).cos();
                 result.set(i, val + kick);
             }
         }
 
-        result.clamp_unit()
+        result
     }
 
     fn parameters(&self) -> Vec<f64> {
-        vec![self.alpha, self.beta, self.gamma, self.delta, self.dimensions as f64]
+        vec![self.alpha, self.beta, self.gamma, self.delta, self.dimensions as f64, self.phase]
     }
 }
 
@@ -169,6 +347,8 @@ impl Default for DoubleKickOperator {
 /// Exchanges and mixes values between dimension pairs with wave modulation:
 /// SW(c)_i = cos(θ)·c_i + sin(θ)·c_j
 /// SW(c)_j = -sin(θ)·c_i + cos(θ)·c_j
+///
+/// [`SwapDirection::TowardsSecond`] flips the sign so c_i turns towards c_j.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SwapWaveOperator {
     /// Rotation angle for each dimension pair (10 pairs in 5D)
@@ -179,22 +359,72 @@ pub struct SwapWaveOperator {
     pub frequency: f64,
     /// Time parameter for wave evolution
     pub time: f64,
+    /// Rotation direction within each pair
+    #[serde(default)]
+    pub direction: SwapDirection,
+}
+
+/// Rotation direction of a Swap Wave pair (i, j)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SwapDirection {
+    /// c_j' = -sin(θ)·c_i + cos(θ)·c_j
+    #[default]
+    Standard,
+    /// c_j' = sin(θ)·c_i + cos(θ)·c_j, moving mass from c_i into c_j
+    TowardsSecond,
+}
+
+impl SwapDirection {
+    /// Sign of the sin(θ)·c_i term in c_j'
+    fn sign(self) -> f64 {
+        match self {
+            SwapDirection::Standard => -1.0,
+            SwapDirection::TowardsSecond => 1.0,
+        }
+    }
 }
 
+/// Dimension pairs (i < j), in angle index order
+const SWAP_PAIRS: [(usize, usize); 10] = [
+    (0, 1), (0, 2), (0, 3), (0, 4),
+    (1, 2), (1, 3), (1, 4),
+    (2, 3), (2, 4),
+    (3, 4),
+];
+
 impl SwapWaveOperator {
-    /// Create a new Swap Wave operator
-    pub fn new(angles: [f64; 10], amplitude: f64, frequency: f64) -> Self {
+    /// Create a Swap Wave operator exchanging a single dimension pair
+    ///
+    /// Both indices must be in `0..5` and distinct.
+    pub fn new(dims: (usize, usize)) -> Result<Self> {
+        let (i, j) = dims;
+        if i >= 5 || j >= 5 {
+            return Err(HypercubeError::InvalidOperator(format!(
+                "swap dimensions ({}, {}) out of range 0..5", i, j
+            )));
+        }
+        if i == j {
+            return Err(HypercubeError::InvalidOperator(format!(
+                "swap dimensions must be distinct, got ({}, {})", i, j
+            )));
+        }
+        Ok(Self::for_pair(i, j, PI / 4.0))
+    }
+
+    /// Create a Swap Wave operator from explicit per-pair angles
+    pub fn from_angles(angles: [f64; 10], amplitude: f64, frequency: f64) -> Self {
         Self {
             angles,
             amplitude,
             frequency,
             time: 0.0,
+            direction: SwapDirection::Standard,
         }
     }
 
     /// Create with default parameters
     pub fn default_params() -> Self {
-        Self::new([PI / 4.0; 10], 0.5, 1.0)
+        Self::from_angles([PI / 4.0; 10], 0.5, 1.0)
     }
 
     /// Create for a specific dimension pair
@@ -204,25 +434,23 @@ impl SwapWaveOperator {
         if let Some(idx) = idx {
             angles[idx] = angle;
         }
-        Self::new(angles, 0.5, 1.0)
+        Self::from_angles(angles, 0.5, 1.0)
     }
 
     /// Get index for dimension pair (i, j) where i < j
     fn pair_index(i: usize, j: usize) -> Option<usize> {
-        let (i, j) = if i < j { (i, j) } else { (j, i) };
-        match (i, j) {
-            (0, 1) => Some(0),
-            (0, 2) => Some(1),
-            (0, 3) => Some(2),
-            (0, 4) => Some(3),
-            (1, 2) => Some(4),
-            (1, 3) => Some(5),
-            (1, 4) => Some(6),
-            (2, 3) => Some(7),
-            (2, 4) => Some(8),
-            (3, 4) => Some(9),
-            _ => None,
-        }
+        let pair = if i < j { (i, j) } else { (j, i) };
+        SWAP_PAIRS.iter().position(|&p| p == pair)
+    }
+
+    /// Dimension pairs with a non-zero rotation angle
+    pub fn active_pairs(&self) -> Vec<(usize, usize)> {
+        SWAP_PAIRS
+            .iter()
+            .zip(self.angles.iter())
+            .filter(|(_, angle)| angle.abs() > 1e-10)
+            .map(|(&pair, _)| pair)
+            .collect()
     }
 
     /// Set time for wave evolution
@@ -230,6 +458,12 @@ impl SwapWaveOperator {
         self.time = t;
         self
     }
+
+    /// Set the rotation direction
+    pub fn with_direction(mut self, direction: SwapDirection) -> Self {
+        self.direction = direction;
+        self
+    }
 }
 
 impl Operator5D for SwapWaveOperator {
@@ -238,32 +472,29 @@ impl Operator5D for SwapWaveOperator {
     }
 
     fn apply(&self, coord: &Coord5D) -> Coord5D {
+        self.apply_unbounded(coord).clamp_unit()
+    }
+
+    fn apply_unbounded(&self, coord: &Coord5D) -> Coord5D {
         let mut arr = coord.to_array();
 
         // Apply wave modulation
         let wave = self.amplitude * (self.frequency * self.time).sin();
 
         // Apply rotations for each dimension pair
-        let pairs = [
-            (0, 1), (0, 2), (0, 3), (0, 4),
-            (1, 2), (1, 3), (1, 4),
-            (2, 3), (2, 4),
-            (3, 4),
-        ];
-
-        for (idx, &(i, j)) in pairs.iter().enumerate() {
+        for (idx, &(i, j)) in SWAP_PAIRS.iter().enumerate() {
             let angle = self.angles[idx] + wave;
             if angle.abs() > 1e-10 {
                 let cos_a = angle.cos();
-                let sin_a = angle.sin();
+                let sin_a = self.direction.sign() * angle.sin();
                 let vi = arr[i];
                 let vj = arr[j];
-                arr[i] = cos_a * vi + sin_a * vj;
-                arr[j] = -sin_a * vi + cos_a * vj;
+                arr[i] = cos_a * vi - sin_a * vj;
+                arr[j] = sin_a * vi + cos_a * vj;
             }
         }
 
-        Coord5D::from_vec(&arr).clamp_unit()
+        Coord5D::from_vec(&arr)
     }
 
     fn parameters(&self) -> Vec<f64> {
@@ -271,6 +502,27 @@ impl Operator5D for SwapWaveOperator {
         params.extend([self.amplitude, self.frequency, self.time]);
         params
     }
+
+    fn as_matrix(&self) -> Result<OperatorMatrix> {
+        let wave = self.amplitude * (self.frequency * self.time).sin();
+        let mut m = OperatorMatrix::identity();
+
+        // Compose the pair rotations in the order `apply` performs them
+        for (idx, &(i, j)) in SWAP_PAIRS.iter().enumerate() {
+            let angle = self.angles[idx] + wave;
+            if angle.abs() > 1e-10 {
+                let mut rotation = OperatorMatrix::identity();
+                rotation.matrix[i][i] = angle.cos();
+                let sin_a = self.direction.sign() * angle.sin();
+                rotation.matrix[i][j] = -sin_a;
+                rotation.matrix[j][i] = sin_a;
+                rotation.matrix[j][j] = angle.cos();
+                m = m.then(&rotation);
+            }
+        }
+
+        Ok(m)
+    }
 }
 
 impl Default for SwapWaveOperator {
@@ -293,6 +545,29 @@ pub struct PhaseIntegrationOperator {
     pub steps: usize,
     /// Phase coupling matrix (5x5)
     pub coupling: [[f64; 5]; 5],
+    /// Coherence of the integrated phases from the last `apply`
+    #[serde(skip)]
+    last_coherence: CoherenceCell,
+}
+
+/// Thread-safe slot for the last observed phase coherence
+#[derive(Debug, Default)]
+struct CoherenceCell(AtomicU64);
+
+impl CoherenceCell {
+    fn get(&self) -> f64 {
+        f64::from_bits(self.0.load(Ordering::Relaxed))
+    }
+
+    fn set(&self, value: f64) {
+        self.0.store(value.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl Clone for CoherenceCell {
+    fn clone(&self) -> Self {
+        Self(AtomicU64::new(self.0.load(Ordering::Relaxed)))
+    }
 }
 
 impl PhaseIntegrationOperator {
@@ -309,9 +584,23 @@ impl PhaseIntegrationOperator {
             step_size,
             steps,
             coupling,
+            last_coherence: CoherenceCell::default(),
         }
     }
 
+    /// Coherence κ of the integrated phases from the most recent `apply`
+    ///
+    /// Uses the same |1/N Σ exp(iθⱼ)| metric as Kosmokrator. Low values
+    /// mean the phases did not align within `steps` iterations.
+    /// Returns 0.0 before the operator has been applied.
+    pub fn last_phase_coherence(&self) -> f64 {
+        self.last_coherence.get()
+    }
+
+    fn phases(&self, arr: &[f64; 5]) -> [f64; 5] {
+        std::array::from_fn(|j| arr[j] * 2.0 * PI + self.phase_offsets[j])
+    }
+
     /// Create with default parameters
     pub fn default_params() -> Self {
         Self::new([0.0; 5], 0.01, 10)
@@ -344,6 +633,33 @@ impl Operator5D for PhaseIntegrationOperator {
     }
 
     fn apply(&self, coord: &Coord5D) -> Coord5D {
+        self.apply_unbounded(coord).clamp_unit()
+    }
+
+    fn apply_unbounded(&self, coord: &Coord5D) -> Coord5D {
+        self.integrate(coord).0
+    }
+
+    fn parameters(&self) -> Vec<f64> {
+        let mut params = self.phase_offsets.to_vec();
+        params.push(self.step_size);
+        params.push(self.steps as f64);
+        params
+    }
+
+    fn phase_coherence(&self) -> Option<f64> {
+        Some(self.last_phase_coherence())
+    }
+
+    fn apply_with_coherence(&self, coord: &Coord5D) -> (Coord5D, Option<f64>) {
+        let (output, kappa) = self.integrate(coord);
+        (output.clamp_unit(), Some(kappa))
+    }
+}
+
+impl PhaseIntegrationOperator {
+    /// Unbounded integration result and the coherence of its phases
+    fn integrate(&self, coord: &Coord5D) -> (Coord5D, f64) {
         let mut arr = coord.to_array();
 
         for _ in 0..self.steps {
@@ -363,14 +679,10 @@ impl Operator5D for PhaseIntegrationOperator {
             }
         }
 
-        Coord5D::from_vec(&arr).clamp_unit()
-    }
+        let kappa = phase_coherence(&self.phases(&arr));
+        self.last_coherence.set(kappa);
 
-    fn parameters(&self) -> Vec<f64> {
-        let mut params = self.phase_offsets.to_vec();
-        params.push(self.step_size);
-        params.push(self.steps as f64);
-        params
+        (Coord5D::from_vec(&arr), kappa)
     }
 }
 
@@ -445,6 +757,26 @@ impl WeightTransformOperator {
         Self::new(weights, bias)
     }
 
+    /// Set per-dimension weights (the diagonal of the weight matrix)
+    ///
+    /// Off-diagonal couplings are left untouched, so `[2.0, 1.0, 1.0, 1.0, 1.0]`
+    /// on the identity transform emphasizes ψ without mixing dimensions.
+    pub fn with_weights(mut self, weights: [f64; 5]) -> Self {
+        for (i, w) in weights.iter().enumerate() {
+            self.weights[i][i] = *w;
+        }
+        self
+    }
+
+    /// Get per-dimension weights (the diagonal of the weight matrix)
+    pub fn dimension_weights(&self) -> [f64; 5] {
+        let mut diag = [0.0; 5];
+        for (i, d) in diag.iter_mut().enumerate() {
+            *d = self.weights[i][i];
+        }
+        diag
+    }
+
     /// Set activation function
     pub fn with_activation(mut self, activation: ActivationType) -> Self {
         self.activation = activation;
@@ -469,6 +801,10 @@ impl Operator5D for WeightTransformOperator {
     }
 
     fn apply(&self, coord: &Coord5D) -> Coord5D {
+        self.apply_unbounded(coord).clamp_unit()
+    }
+
+    fn apply_unbounded(&self, coord: &Coord5D) -> Coord5D {
         let arr = coord.to_array();
         let mut result = [0.0; 5];
 
@@ -491,7 +827,7 @@ impl Operator5D for WeightTransformOperator {
             }
         }
 
-        Coord5D::from_vec(&result).clamp_unit()
+        Coord5D::from_vec(&result)
     }
 
     fn parameters(&self) -> Vec<f64> {
@@ -503,6 +839,19 @@ impl Operator5D for WeightTransformOperator {
         params.push(self.temperature);
         params
     }
+
+    fn as_matrix(&self) -> Result<OperatorMatrix> {
+        if self.activation != ActivationType::Linear {
+            return Err(HypercubeError::InvalidOperator(format!(
+                "WT operator with {:?} activation is nonlinear and has no matrix form",
+                self.activation
+            )));
+        }
+        Ok(OperatorMatrix {
+            matrix: self.weights,
+            translation: self.bias,
+        })
+    }
 }
 
 impl Default for WeightTransformOperator {
@@ -605,6 +954,10 @@ impl Operator5D for CompilationOperator {
             self.max_iterations as f64,
         ]
     }
+
+    fn as_matrix(&self) -> Result<OperatorMatrix> {
+        Ok(OperatorMatrix::identity())
+    }
 }
 
 impl Default for CompilationOperator {
@@ -699,6 +1052,13 @@ impl OperatorFamily {
         current
     }
 
+    /// Compose the matrix forms of all operators (fails if any is nonlinear)
+    pub fn as_matrix(&self) -> Result<OperatorMatrix> {
+        self.operators
+            .iter()
+            .try_fold(OperatorMatrix::identity(), |acc, op| Ok(acc.then(&op.as_matrix()?)))
+    }
+
     /// Get the resonance after applying all operators to seed
     pub fn evaluate(&self) -> f64 {
         self.apply(&self.seed).resonance()
@@ -716,14 +1076,128 @@ impl OperatorFamily {
 
     /// Create a resonance-optimized family
     pub fn resonance_optimized(name: &str, seed: Coord5D) -> Self {
+        Self::resonance_optimized_with(
+            name,
+            seed,
+            DoubleKickOperator::resonance_optimized(),
+            WeightTransformOperator::resonance_optimized(),
+        )
+    }
+
+    /// [`resonance_optimized`](Self::resonance_optimized) with a custom kick and weight transform
+    pub fn resonance_optimized_with(
+        name: &str,
+        seed: Coord5D,
+        kick: DoubleKickOperator,
+        weights: WeightTransformOperator,
+    ) -> Self {
         let mut family = Self::new(name, seed);
-        family.add_operator(DoubleKickOperator::new(0.05, PI / 2.0, 0.02, PI));
+        family.add_operator(kick);
         family.add_operator(PhaseIntegrationOperator::resonance_aligned());
-        family.add_operator(WeightTransformOperator::resonance_optimized());
+        family.add_operator(weights);
         family
     }
 }
 
+/// Named operators available to HDAG nodes and cube expansion
+///
+/// Entries are shared, so one registered operator can back any number of
+/// nodes. Registering a name again replaces the previous operator.
+#[derive(Clone, Default)]
+pub struct OperatorRegistry {
+    operators: HashMap<String, Arc<dyn Operator5D>>,
+}
+
+impl std::fmt::Debug for OperatorRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OperatorRegistry")
+            .field("names", &self.names())
+            .finish()
+    }
+}
+
+impl OperatorRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry with the default DK, SW, PI, WT and Ξ operators
+    pub fn with_builtins() -> Self {
+        Self::builtins(
+            DoubleKickOperator::default(),
+            SwapWaveOperator::default(),
+            WeightTransformOperator::default(),
+        )
+    }
+
+    /// Registry with the given DK, SW and WT operators and the default PI and Ξ
+    pub(crate) fn builtins(
+        dk: DoubleKickOperator,
+        sw: SwapWaveOperator,
+        wt: WeightTransformOperator,
+    ) -> Self {
+        let mut registry = Self::new();
+        registry.register("DK", Box::new(dk));
+        registry.register("SW", Box::new(sw));
+        registry.register("PI", Box::new(PhaseIntegrationOperator::default()));
+        registry.register("WT", Box::new(wt));
+        registry.register("Xi", Box::new(CompilationOperator::default()));
+        registry
+    }
+
+    /// Name a built-in operator type is registered under
+    pub fn builtin_name(op_type: OperatorType) -> Option<&'static str> {
+        match op_type {
+            OperatorType::DK => Some("DK"),
+            OperatorType::SW => Some("SW"),
+            OperatorType::PI => Some("PI"),
+            OperatorType::WT => Some("WT"),
+            OperatorType::Xi => Some("Xi"),
+            OperatorType::Identity | OperatorType::Composite => None,
+        }
+    }
+
+    /// Register an operator, returning the one it replaces
+    pub fn register(&mut self, name: &str, op: Box<dyn Operator5D>) -> Option<Arc<dyn Operator5D>> {
+        self.operators.insert(name.to_string(), Arc::from(op))
+    }
+
+    /// Look up an operator by name
+    pub fn get(&self, name: &str) -> Option<&Arc<dyn Operator5D>> {
+        self.operators.get(name)
+    }
+
+    /// Check if a name is registered
+    pub fn contains(&self, name: &str) -> bool {
+        self.operators.contains_key(name)
+    }
+
+    /// Registered names, sorted
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.operators.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Number of registered operators
+    pub fn len(&self) -> usize {
+        self.operators.len()
+    }
+
+    /// Check if no operator is registered
+    pub fn is_empty(&self) -> bool {
+        self.operators.is_empty()
+    }
+
+    /// Add every entry of `other`, replacing operators with the same name
+    pub fn extend(&mut self, other: &OperatorRegistry) {
+        for (name, op) in &other.operators {
+            self.operators.insert(name.clone(), Arc::clone(op));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -741,9 +1215,47 @@ mod tests {
         assert!(result.psi >= 0.0 && result.psi <= 1.0);
     }
 
+    #[test]
+    fn test_double_kick_strength_phase() {
+        let coord = Coord5D::new(0.3, 0.4, 0.5, 0.6, 0.7);
+
+        // Zero strength is a no-op
+        let still = DoubleKickOperator::new(0.0, 1.0).apply(&coord);
+        assert_relative_eq!(still.distance(&coord), 0.0);
+
+        let weak = DoubleKickOperator::new(0.01, 0.0).apply(&coord);
+        let strong = DoubleKickOperator::new(0.05, 0.0).apply(&coord);
+        assert!(strong.distance(&coord) > weak.distance(&coord));
+
+        let shifted = DoubleKickOperator::new(0.05, PI / 3.0);
+        assert!(shifted.apply(&coord).distance(&strong) > 0.0);
+        assert_eq!(shifted.kick_params(), KickParams::new(0.05, PI / 3.0));
+
+        // Operators saved before the phase offset existed load with φ = 0
+        let legacy = r#"{"alpha":0.1,"beta":3.0,"gamma":0.05,"delta":6.0,"dimensions":31}"#;
+        let dk: DoubleKickOperator = serde_json::from_str(legacy).unwrap();
+        assert_eq!(dk.phase, 0.0);
+    }
+
+    #[test]
+    fn test_apply_bounded_policy() {
+        // ψ = 0.9 is kicked past the upper edge
+        let dk = DoubleKickOperator::new(0.5, 0.0).with_dimensions(0b00001);
+        let coord = Coord5D::new(0.9, 0.5, 0.5, 0.5, 0.5);
+        let raw = dk.apply_unbounded(&coord);
+        assert!(raw.psi > 1.0);
+
+        assert_eq!(dk.apply_bounded(&coord, CoordinatePolicy::Clamp), dk.apply(&coord));
+        let wrapped = dk.apply_bounded(&coord, CoordinatePolicy::Wrap);
+        assert_relative_eq!(wrapped.psi, raw.psi - 1.0, epsilon = 1e-12);
+        let reflected = dk.apply_bounded(&coord, CoordinatePolicy::Reflect);
+        assert_relative_eq!(reflected.psi, 2.0 - raw.psi, epsilon = 1e-12);
+    }
+
     #[test]
     fn test_swap_wave() {
-        let sw = SwapWaveOperator::for_pair(0, 1, PI / 4.0);
+        let sw = SwapWaveOperator::for_pair(0, 1, PI / 4.0)
+            .with_direction(SwapDirection::TowardsSecond);
         let coord = Coord5D::new(1.0, 0.0, 0.5, 0.5, 0.5);
         let result = sw.apply(&coord);
 
@@ -752,6 +1264,58 @@ mod tests {
         assert!(result.rho > 0.0);
     }
 
+    #[test]
+    fn test_swap_wave_dims() {
+        let sw = SwapWaveOperator::new((1, 3)).unwrap();
+        assert_eq!(sw.active_pairs(), vec![(1, 3)]);
+
+        let coord = Coord5D::new(0.2, 0.9, 0.4, 0.1, 0.6);
+        let result = sw.apply(&coord);
+        // Untouched dimensions pass through
+        assert_relative_eq!(result.psi, coord.psi);
+        assert_relative_eq!(result.omega, coord.omega);
+        assert_relative_eq!(result.eta, coord.eta);
+        assert!(result.rho < coord.rho);
+
+        // The opt-in direction moves mass from ρ into χ
+        let towards = sw.with_direction(SwapDirection::TowardsSecond).apply(&coord);
+        assert!(towards.chi > coord.chi);
+
+        assert!(SwapWaveOperator::new((2, 5)).is_err());
+        assert!(SwapWaveOperator::new((4, 4)).is_err());
+    }
+
+    #[test]
+    fn test_operator_matrix() {
+        // A quarter turn in the (ψ, ρ) plane is a signed permutation
+        let sw = SwapWaveOperator::for_pair(0, 1, PI / 2.0);
+        let m = sw.as_matrix().unwrap();
+        assert!(m.is_permutation(1e-12));
+        assert!(m.is_linear());
+
+        // The matrix reproduces `apply` away from the clamp boundary
+        let sw = SwapWaveOperator::default();
+        let coord = Coord5D::new(0.3, 0.3, 0.3, 0.3, 0.3);
+        let m = sw.as_matrix().unwrap();
+        assert!(m.is_orthogonal(1e-12));
+        let direct = sw.apply(&coord);
+        let via_matrix = m.apply(&coord).clamp_unit();
+        assert_relative_eq!(direct.distance(&via_matrix), 0.0, epsilon = 1e-12);
+
+        let wt = WeightTransformOperator::identity().with_activation(ActivationType::Linear);
+        assert_eq!(wt.as_matrix().unwrap(), OperatorMatrix::identity());
+        assert!(WeightTransformOperator::identity().as_matrix().is_err());
+        assert!(DoubleKickOperator::default().as_matrix().is_err());
+        assert!(PhaseIntegrationOperator::default().as_matrix().is_err());
+
+        let mut family = OperatorFamily::new("linear", coord);
+        family.add_operator(SwapWaveOperator::for_pair(0, 1, PI / 2.0));
+        family.add_operator(SwapWaveOperator::for_pair(0, 1, -PI / 2.0));
+        let composed = family.as_matrix().unwrap();
+        assert_relative_eq!(composed.apply(&coord).distance(&coord), 0.0, epsilon = 1e-12);
+        assert!(OperatorFamily::standard("std", coord).as_matrix().is_err());
+    }
+
     #[test]
     fn test_phase_integration() {
         let pi = PhaseIntegrationOperator::default();
@@ -762,6 +1326,27 @@ mod tests {
         assert!(result.distance(&coord) > 0.0 || coord.distance(&result) < 0.1);
     }
 
+    #[test]
+    fn test_phase_integration_coherence() {
+        let pi = PhaseIntegrationOperator::default();
+        assert_eq!(pi.last_phase_coherence(), 0.0);
+
+        // Identical components stay in phase
+        pi.apply(&Coord5D::center());
+        assert_relative_eq!(pi.last_phase_coherence(), 1.0, epsilon = 1e-9);
+
+        // Evenly spread components barely align in a few steps
+        pi.apply(&Coord5D::new(0.0, 0.2, 0.4, 0.6, 0.8));
+        assert!(pi.last_phase_coherence() < 0.5);
+        assert_eq!(pi.phase_coherence(), Some(pi.last_phase_coherence()));
+
+        // Per-call coherence is independent of later applications
+        let (output, kappa) = pi.apply_with_coherence(&Coord5D::center());
+        pi.apply(&Coord5D::new(0.0, 0.2, 0.4, 0.6, 0.8));
+        assert_eq!(output, pi.apply(&Coord5D::center()));
+        assert_relative_eq!(kappa.unwrap(), 1.0, epsilon = 1e-9);
+    }
+
     #[test]
     fn test_weight_transform() {
         let wt = WeightTransformOperator::identity();
@@ -772,6 +1357,21 @@ mod tests {
         assert!(result.psi > 0.0 && result.psi < 1.0);
     }
 
+    #[test]
+    fn test_weight_transform_with_weights() {
+        let weights = [2.0, 1.0, 1.0, 1.0, 0.5];
+        let wt = WeightTransformOperator::identity().with_weights(weights);
+        assert_eq!(wt.dimension_weights(), weights);
+
+        let coord = Coord5D::center();
+        let base = WeightTransformOperator::identity().apply(&coord);
+        let weighted = wt.apply(&coord);
+
+        assert!(weighted.psi > base.psi);
+        assert_relative_eq!(weighted.rho, base.rho);
+        assert!(weighted.eta < base.eta);
+    }
+
     #[test]
     fn test_operator_family() {
         let seed = Coord5D::center();
@@ -4,8 +4,9 @@
 
 use crate::cube::{Hypercube, HypercubeConfig, CubeExpansionRule};
 use crate::compiler::{HypercubeCompiler, CompilationConfig, CompilationResult};
-use crate::artifact::ArtifactCollection;
+use crate::artifact::{ArtifactCollection, ArtifactRetention, DiscardedArtifactStats};
 use crate::coordinates::Coord5D;
+use crate::operators::Operator5D;
 use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -27,6 +28,12 @@ pub struct SessionConfig {
     pub auto_compile: bool,
     /// Export results on completion
     pub export_results: bool,
+    /// Which compiled artifacts the session keeps
+    #[serde(default)]
+    pub artifact_retention: ArtifactRetention,
+    /// Drop artifacts whose content hash at this granularity was already seen
+    #[serde(default)]
+    pub dedup_granularity: Option<f64>,
 }
 
 impl Default for SessionConfig {
@@ -39,6 +46,8 @@ impl Default for SessionConfig {
             expansion_steps: 5,
             auto_compile: true,
             export_results: false,
+            artifact_retention: ArtifactRetention::All,
+            dedup_granularity: None,
         }
     }
 }
@@ -128,8 +137,17 @@ pub struct SessionResult {
     pub expansion_steps: usize,
     /// Total vertices generated
     pub total_vertices: usize,
-    /// Artifact count
+    /// Retained artifact count
     pub artifact_count: usize,
+    /// Total artifacts produced, including discarded ones
+    #[serde(default)]
+    pub artifacts_produced: usize,
+    /// Summary of artifacts dropped by the retention policy
+    #[serde(default)]
+    pub discarded_artifacts: DiscardedArtifactStats,
+    /// Artifacts dropped as duplicates
+    #[serde(default)]
+    pub duplicate_artifacts: usize,
 }
 
 /// Hypercube session
@@ -157,6 +175,10 @@ impl HypercubeSession {
     pub fn new(config: SessionConfig) -> Self {
         let cube = Hypercube::new(&config.name, config.cube_config.clone());
         let compiler = HypercubeCompiler::new(config.compilation_config.clone());
+        let mut artifacts = ArtifactCollection::with_retention(config.artifact_retention);
+        if let Some(granularity) = config.dedup_granularity {
+            artifacts = artifacts.with_dedup(granularity);
+        }
 
         Self {
             id: Uuid::new_v4().to_string(),
@@ -164,12 +186,19 @@ impl HypercubeSession {
             state: SessionState::Created,
             cube,
             compiler,
-            artifacts: ArtifactCollection::new(),
+            artifacts,
             start_time: std::time::Instant::now(),
             expansion_count: 0,
         }
     }
 
+    /// Register a named operator with the session's compiler
+    ///
+    /// See [`HypercubeCompiler::register_operator`].
+    pub fn register_operator(&mut self, name: &str, op: Box<dyn Operator5D>) {
+        self.compiler.register_operator(name, op);
+    }
+
     /// Create with default config
     pub fn default_session() -> Self {
         Self::new(SessionConfig::default())
@@ -207,7 +236,7 @@ impl HypercubeSession {
         let mut total_new = 0;
 
         for _ in 0..steps {
-            let new_count = self.cube.expand_step()?;
+            let new_count = self.compiler.expand_step(&mut self.cube)?;
             total_new += new_count;
             self.expansion_count += 1;
 
@@ -272,6 +301,9 @@ impl HypercubeSession {
             expansion_steps: self.expansion_count,
             total_vertices: self.cube.vertices.len(),
             artifact_count: self.artifacts.len(),
+            artifacts_produced: self.artifacts.produced(),
+            discarded_artifacts: self.artifacts.discarded().clone(),
+            duplicate_artifacts: self.artifacts.duplicates(),
         }
     }
 
@@ -308,4 +340,43 @@ mod tests {
         assert!(new_vertices > 0 || session.cube.vertices.len() > 0);
         assert_eq!(session.state, SessionState::Expanded);
     }
+
+    #[test]
+    fn test_session_artifact_retention() {
+        let config = SessionConfig {
+            artifact_retention: ArtifactRetention::TopK(1),
+            ..SessionConfig::quick()
+        };
+        let mut session = HypercubeSession::new(config);
+        let result = session.run().unwrap();
+
+        assert!(result.artifact_count <= 1);
+        assert_eq!(result.artifacts_produced, result.artifact_count + result.discarded_artifacts.count);
+    }
+
+    #[test]
+    fn test_session_dedup() {
+        let config = SessionConfig {
+            dedup_granularity: Some(1.0),
+            ..SessionConfig::quick()
+        };
+        let mut session = HypercubeSession::new(config);
+        let result = session.run().unwrap();
+
+        // With unit cells nearly everything of one type collapses
+        assert_eq!(result.artifacts_produced, result.artifact_count + result.duplicate_artifacts);
+        assert!(result.artifact_count <= result.artifacts_produced);
+    }
+
+    #[test]
+    fn test_config_without_artifact_options_loads() {
+        let mut json = serde_json::to_value(SessionConfig::default()).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("artifact_retention");
+        fields.remove("dedup_granularity");
+
+        let config: SessionConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(config.artifact_retention, ArtifactRetention::All);
+        assert_eq!(config.dedup_granularity, None);
+    }
 }
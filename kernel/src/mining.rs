@@ -21,6 +21,7 @@ use std::collections::{HashMap, HashSet};
 
 /// Mining kernel configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MiningConfig {
     /// Maximum iterations
     pub max_iterations: usize,
@@ -0,0 +1,106 @@
+"""Type stubs for the QOPS Python bindings."""
+
+from typing import Dict, List, Optional, Tuple
+
+import numpy as np
+import numpy.typing as npt
+
+__version__: str
+
+class Circuit:
+    """Quantum circuit. Gate methods append in place and return the circuit."""
+
+    def __init__(self, num_qubits: int, name: Optional[str] = None) -> None: ...
+    @staticmethod
+    def bell_state() -> Circuit: ...
+    @staticmethod
+    def ghz_state(n: int) -> Circuit: ...
+    @staticmethod
+    def qft(n: int) -> Circuit: ...
+    @property
+    def num_qubits(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    def h(self, qubit: int) -> Circuit: ...
+    def x(self, qubit: int) -> Circuit: ...
+    def y(self, qubit: int) -> Circuit: ...
+    def z(self, qubit: int) -> Circuit: ...
+    def s(self, qubit: int) -> Circuit: ...
+    def t(self, qubit: int) -> Circuit: ...
+    def rx(self, theta: float, qubit: int) -> Circuit: ...
+    def ry(self, theta: float, qubit: int) -> Circuit: ...
+    def rz(self, theta: float, qubit: int) -> Circuit: ...
+    def cnot(self, control: int, target: int) -> Circuit: ...
+    def cx(self, control: int, target: int) -> Circuit: ...
+    def cz(self, qubit1: int, qubit2: int) -> Circuit: ...
+    def swap(self, qubit1: int, qubit2: int) -> Circuit: ...
+    def toffoli(self, control1: int, control2: int, target: int) -> Circuit: ...
+    def append(self, other: Circuit) -> Circuit: ...
+    def inverse(self) -> Circuit: ...
+    def depth(self) -> int: ...
+    def gate_count(self) -> int: ...
+    def gate_counts(self) -> Dict[str, int]: ...
+    def to_qasm(self) -> str: ...
+    def draw(self) -> str: ...
+    def __len__(self) -> int: ...
+
+class QuantumRegister:
+    """State-vector quantum register."""
+
+    def __init__(self, num_qubits: int) -> None: ...
+    @property
+    def num_qubits(self) -> int: ...
+    def apply_circuit(self, circuit: Circuit) -> None: ...
+    def reset(self) -> None: ...
+    def state_vector(self) -> npt.NDArray[np.complex128]: ...
+    def probabilities(self) -> npt.NDArray[np.float64]: ...
+    def measure(self, qubit: int) -> bool: ...
+    def measure_all(self) -> List[bool]: ...
+    def get_counts(self, shots: int = 1024) -> Dict[str, int]: ...
+
+class GroverResult:
+    measured_state: int
+    success_probability: float
+    iterations: int
+    is_solution: bool
+    counts: Dict[str, int]
+    shots: int
+
+class Grover:
+    """Grover search for one or more marked basis states."""
+
+    def __init__(
+        self, num_qubits: int, targets: List[int], iterations: Optional[int] = None
+    ) -> None: ...
+    def optimal_iterations(self) -> int: ...
+    def theoretical_success_probability(self) -> float: ...
+    def build_circuit(self) -> Circuit: ...
+    def run(self, shots: int = 1024) -> GroverResult: ...
+
+class VQEResult:
+    energy: float
+    num_evaluations: int
+    converged: bool
+    variance: float
+    @property
+    def optimal_params(self) -> npt.NDArray[np.float64]: ...
+    @property
+    def energy_history(self) -> npt.NDArray[np.float64]: ...
+
+class VQE:
+    """Variational quantum eigensolver for a Pauli-sum Hamiltonian."""
+
+    def __init__(
+        self,
+        hamiltonian: List[Tuple[float, str]],
+        layers: int = 2,
+        max_iterations: int = 100,
+        shots: int = 1024,
+    ) -> None: ...
+    @property
+    def num_qubits(self) -> int: ...
+    def num_parameters(self) -> int: ...
+    def evaluate_energy(self, params: List[float]) -> float: ...
+    def run(self) -> VQEResult: ...
+
+def resonance_5d(psi: float, rho: float, omega: float, chi: float, eta: float) -> float: ...
@@ -0,0 +1,572 @@
+//! PyO3 class and function definitions for the `qops` module
+
+// pyo3 0.22 macro expansion trips this lint on every `PyResult` method
+#![allow(clippy::useless_conversion)]
+
+use numpy::{PyArray1, ToPyArray};
+use num_complex::Complex64;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use qops_algorithms::{Grover, Oracle, VQEConfig, VQE};
+use qops_circuits::{Circuit, Gate, QuantumRegister};
+use qops_core::Signature5D;
+use std::collections::HashMap;
+
+fn value_error(e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+// ============================================================================
+// Circuit
+// ============================================================================
+
+/// Quantum circuit. Gate methods append in place and return the circuit,
+/// so calls chain: `Circuit(2).h(0).cnot(0, 1)`.
+#[pyclass(name = "Circuit", module = "qops")]
+#[derive(Clone)]
+pub struct PyCircuit {
+    inner: Circuit,
+}
+
+impl PyCircuit {
+    fn push(mut slf: PyRefMut<'_, Self>, gate: Gate, qubits: Vec<usize>) -> PyResult<PyRefMut<'_, Self>> {
+        slf.inner.add_gate(gate, qubits).map_err(value_error)?;
+        Ok(slf)
+    }
+}
+
+#[pymethods]
+impl PyCircuit {
+    #[new]
+    #[pyo3(signature = (num_qubits, name = None))]
+    fn new(num_qubits: usize, name: Option<&str>) -> Self {
+        let inner = match name {
+            Some(name) => Circuit::with_name(num_qubits, name),
+            None => Circuit::new(num_qubits),
+        };
+        Self { inner }
+    }
+
+    /// Bell-state preparation circuit
+    #[staticmethod]
+    fn bell_state() -> Self {
+        Self { inner: Circuit::bell_state() }
+    }
+
+    /// GHZ-state preparation circuit on `n` qubits
+    #[staticmethod]
+    fn ghz_state(n: usize) -> Self {
+        Self { inner: Circuit::ghz_state(n) }
+    }
+
+    /// Quantum Fourier transform on `n` qubits
+    #[staticmethod]
+    fn qft(n: usize) -> Self {
+        Self { inner: Circuit::qft(n) }
+    }
+
+    #[getter]
+    fn num_qubits(&self) -> usize {
+        self.inner.num_qubits
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name.clone()
+    }
+
+    fn h(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::h(), vec![qubit])
+    }
+
+    fn x(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::x(), vec![qubit])
+    }
+
+    fn y(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::y(), vec![qubit])
+    }
+
+    fn z(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::z(), vec![qubit])
+    }
+
+    fn s(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::s(), vec![qubit])
+    }
+
+    fn t(slf: PyRefMut<'_, Self>, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::t(), vec![qubit])
+    }
+
+    fn rx(slf: PyRefMut<'_, Self>, theta: f64, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::rx(theta), vec![qubit])
+    }
+
+    fn ry(slf: PyRefMut<'_, Self>, theta: f64, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::ry(theta), vec![qubit])
+    }
+
+    fn rz(slf: PyRefMut<'_, Self>, theta: f64, qubit: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::rz(theta), vec![qubit])
+    }
+
+    fn cnot(slf: PyRefMut<'_, Self>, control: usize, target: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::cnot(), vec![control, target])
+    }
+
+    fn cx(slf: PyRefMut<'_, Self>, control: usize, target: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::cnot(slf, control, target)
+    }
+
+    fn cz(slf: PyRefMut<'_, Self>, qubit1: usize, qubit2: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::cz(), vec![qubit1, qubit2])
+    }
+
+    fn swap(slf: PyRefMut<'_, Self>, qubit1: usize, qubit2: usize) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::swap(), vec![qubit1, qubit2])
+    }
+
+    fn toffoli(
+        slf: PyRefMut<'_, Self>,
+        control1: usize,
+        control2: usize,
+        target: usize,
+    ) -> PyResult<PyRefMut<'_, Self>> {
+        Self::push(slf, Gate::toffoli(), vec![control1, control2, target])
+    }
+
+    /// Append all gates of another circuit
+    fn append<'py>(mut slf: PyRefMut<'py, Self>, other: &PyCircuit) -> PyResult<PyRefMut<'py, Self>> {
+        slf.inner.append(&other.inner).map_err(value_error)?;
+        Ok(slf)
+    }
+
+    /// Inverse (adjoint) circuit
+    fn inverse(&self) -> PyResult<Self> {
+        Ok(Self { inner: self.inner.inverse().map_err(value_error)? })
+    }
+
+    fn depth(&self) -> usize {
+        self.inner.depth()
+    }
+
+    fn gate_count(&self) -> usize {
+        self.inner.gate_count()
+    }
+
+    fn gate_counts(&self) -> HashMap<String, usize> {
+        self.inner.gate_counts()
+    }
+
+    /// OpenQASM 2.0 source
+    fn to_qasm(&self) -> PyResult<String> {
+        self.inner.to_qasm().map_err(value_error)
+    }
+
+    /// ASCII circuit diagram
+    fn draw(&self) -> String {
+        self.inner.to_ascii_diagram()
+    }
+
+    fn __len__(&self) -> usize {
+        self.inner.gate_count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Circuit(num_qubits={}, gates={}, depth={})",
+            self.inner.num_qubits,
+            self.inner.gate_count(),
+            self.inner.depth()
+        )
+    }
+}
+
+// ============================================================================
+// QuantumRegister
+// ============================================================================
+
+/// State-vector quantum register
+#[pyclass(name = "QuantumRegister", module = "qops")]
+pub struct PyQuantumRegister {
+    inner: QuantumRegister,
+}
+
+#[pymethods]
+impl PyQuantumRegister {
+    #[new]
+    fn new(num_qubits: usize) -> Self {
+        Self { inner: QuantumRegister::new(num_qubits) }
+    }
+
+    #[getter]
+    fn num_qubits(&self) -> usize {
+        self.inner.num_qubits()
+    }
+
+    /// Apply a circuit to the register state
+    fn apply_circuit(&mut self, circuit: &PyCircuit) -> PyResult<()> {
+        self.inner.apply_circuit(&circuit.inner).map_err(value_error)
+    }
+
+    /// Reset to |0...0⟩
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    /// Complex amplitudes as a `numpy.complex128` array
+    fn state_vector<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<Complex64>> {
+        self.inner.state.amplitudes().to_pyarray_bound(py)
+    }
+
+    /// Basis-state probabilities as a `numpy.float64` array
+    fn probabilities<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        PyArray1::from_vec_bound(py, self.inner.state.probabilities())
+    }
+
+    /// Measure a single qubit, collapsing the state
+    fn measure(&mut self, qubit: usize) -> PyResult<bool> {
+        self.inner.measure(qubit).map_err(value_error)
+    }
+
+    /// Measure all qubits, collapsing the state
+    fn measure_all(&mut self) -> Vec<bool> {
+        self.inner.measure_all()
+    }
+
+    /// Sample `shots` measurements without collapsing: bitstring -> count
+    #[pyo3(signature = (shots = 1024))]
+    fn get_counts(&self, shots: usize) -> HashMap<String, usize> {
+        self.inner.get_counts(shots)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("QuantumRegister(num_qubits={})", self.inner.num_qubits())
+    }
+}
+
+// ============================================================================
+// Grover
+// ============================================================================
+
+/// Result of a Grover search
+#[pyclass(name = "GroverResult", module = "qops", get_all)]
+#[derive(Clone)]
+pub struct PyGroverResult {
+    measured_state: usize,
+    success_probability: f64,
+    iterations: usize,
+    is_solution: bool,
+    counts: HashMap<String, usize>,
+    shots: usize,
+}
+
+#[pymethods]
+impl PyGroverResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "GroverResult(measured_state={}, success_probability={:.4}, iterations={}, is_solution={})",
+            self.measured_state,
+            self.success_probability,
+            self.iterations,
+            if self.is_solution { "True" } else { "False" }
+        )
+    }
+}
+
+/// Grover search for one or more marked basis states
+#[pyclass(name = "Grover", module = "qops")]
+pub struct PyGrover {
+    inner: Grover,
+}
+
+#[pymethods]
+impl PyGrover {
+    #[new]
+    #[pyo3(signature = (num_qubits, targets, iterations = None))]
+    fn new(num_qubits: usize, targets: Vec<usize>, iterations: Option<usize>) -> PyResult<Self> {
+        if num_qubits == 0 {
+            return Err(value_error("num_qubits must be at least 1"));
+        }
+        if let Some(&bad) = targets.iter().find(|&&t| t >= 1 << num_qubits) {
+            return Err(value_error(format!("target {} out of range for {} qubits", bad, num_qubits)));
+        }
+
+        let mut grover = Grover::new(num_qubits, Oracle::marked_states(num_qubits, &targets));
+        if let Some(iterations) = iterations {
+            grover = grover.with_iterations(iterations);
+        }
+        Ok(Self { inner: grover })
+    }
+
+    fn optimal_iterations(&self) -> usize {
+        self.inner.optimal_iterations()
+    }
+
+    fn theoretical_success_probability(&self) -> f64 {
+        self.inner.theoretical_success_probability()
+    }
+
+    /// The Grover circuit (preparation + iterations)
+    fn build_circuit(&self) -> PyCircuit {
+        PyCircuit { inner: self.inner.build_circuit() }
+    }
+
+    #[pyo3(signature = (shots = 1024))]
+    fn run(&self, shots: usize) -> PyGroverResult {
+        let result = self.inner.run_with_shots(shots);
+        PyGroverResult {
+            measured_state: result.measured_state,
+            success_probability: result.success_probability,
+            iterations: result.iterations,
+            is_solution: result.is_solution,
+            counts: result.counts,
+            shots: result.shots,
+        }
+    }
+}
+
+// ============================================================================
+// VQE
+// ============================================================================
+
+/// Result of a VQE run
+#[pyclass(name = "VQEResult", module = "qops")]
+pub struct PyVQEResult {
+    #[pyo3(get)]
+    energy: f64,
+    optimal_params: Vec<f64>,
+    energy_history: Vec<f64>,
+    #[pyo3(get)]
+    num_evaluations: usize,
+    #[pyo3(get)]
+    converged: bool,
+    #[pyo3(get)]
+    variance: f64,
+}
+
+#[pymethods]
+impl PyVQEResult {
+    /// Optimal ansatz parameters as a `numpy.float64` array
+    #[getter]
+    fn optimal_params<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        self.optimal_params.to_pyarray_bound(py)
+    }
+
+    /// Energy per optimizer iteration as a `numpy.float64` array
+    #[getter]
+    fn energy_history<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+        self.energy_history.to_pyarray_bound(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "VQEResult(energy={:.6}, num_evaluations={}, converged={})",
+            self.energy,
+            self.num_evaluations,
+            if self.converged { "True" } else { "False" }
+        )
+    }
+}
+
+/// Variational quantum eigensolver for a Pauli-sum Hamiltonian
+///
+/// `hamiltonian` is a list of `(coefficient, pauli_string)` terms,
+/// e.g. `[(1.0, "ZZ"), (0.5, "XI")]`.
+#[pyclass(name = "VQE", module = "qops")]
+pub struct PyVQE {
+    inner: VQE,
+}
+
+#[pymethods]
+impl PyVQE {
+    #[new]
+    #[pyo3(signature = (hamiltonian, layers = 2, max_iterations = 100, shots = 1024))]
+    fn new(hamiltonian: Vec<(f64, String)>, layers: usize, max_iterations: usize, shots: usize) -> PyResult<Self> {
+        if hamiltonian.is_empty() {
+            return Err(value_error("hamiltonian needs at least one term"));
+        }
+
+        let terms: Vec<(f64, &str)> = hamiltonian.iter().map(|(c, p)| (*c, p.as_str())).collect();
+        let hamiltonian = qops_algorithms::vqe::PauliSum::from_terms(terms);
+        let config = VQEConfig {
+            num_qubits: hamiltonian.num_qubits(),
+            layers,
+            max_iterations,
+            shots,
+            ..Default::default()
+        };
+        Ok(Self { inner: VQE::new(config, hamiltonian) })
+    }
+
+    #[getter]
+    fn num_qubits(&self) -> usize {
+        self.inner.config.num_qubits
+    }
+
+    fn num_parameters(&self) -> usize {
+        self.inner.num_parameters()
+    }
+
+    /// Exact energy ⟨ψ(θ)|H|ψ(θ)⟩ for the given ansatz parameters
+    fn evaluate_energy(&self, params: Vec<f64>) -> PyResult<f64> {
+        if params.len() != self.inner.num_parameters() {
+            return Err(value_error(format!(
+                "expected {} parameters, got {}",
+                self.inner.num_parameters(),
+                params.len()
+            )));
+        }
+        Ok(self.inner.evaluate_energy(&params))
+    }
+
+    fn run(&self, py: Python<'_>) -> PyVQEResult {
+        let result = py.allow_threads(|| self.inner.run());
+        PyVQEResult {
+            energy: result.energy,
+            optimal_params: result.optimal_params,
+            energy_history: result.energy_history,
+            num_evaluations: result.num_evaluations,
+            converged: result.converged,
+            variance: result.variance,
+        }
+    }
+}
+
+// ============================================================================
+// Functions
+// ============================================================================
+
+/// 5D resonance score R(ψ, ρ, ω, χ, η)
+#[pyfunction]
+fn resonance_5d(psi: f64, rho: f64, omega: f64, chi: f64, eta: f64) -> f64 {
+    qops_core::resonance_5d(&Signature5D::new(psi, rho, omega, chi, eta))
+}
+
+// ============================================================================
+// Module
+// ============================================================================
+
+#[pymodule]
+fn qops(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("__version__", env!("CARGO_PKG_VERSION"))?;
+    m.add_class::<PyCircuit>()?;
+    m.add_class::<PyQuantumRegister>()?;
+    m.add_class::<PyGrover>()?;
+    m.add_class::<PyGroverResult>()?;
+    m.add_class::<PyVQE>()?;
+    m.add_class::<PyVQEResult>()?;
+    m.add_function(wrap_pyfunction!(resonance_5d, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    /// Run Python `code` with the module bound to `qops`, failing on any exception
+    fn run_python(code: &str) {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new_bound(py, "qops").unwrap();
+            qops(&module).unwrap();
+            let globals = PyDict::new_bound(py);
+            globals.set_item("qops", module).unwrap();
+            if let Err(e) = py.run_bound(code, Some(&globals), None) {
+                panic!("python error: {e}");
+            }
+        });
+    }
+
+    #[test]
+    fn test_circuit_chaining_and_errors() {
+        run_python(
+            r#"
+c = qops.Circuit(2).h(0).cnot(0, 1)
+assert len(c) == 2 and c.depth() == 2
+assert c.gate_counts() == {"H": 1, "CNOT": 1}
+assert c.num_qubits == 2
+assert c.to_qasm().startswith("OPENQASM 2.0")
+assert len(c.inverse()) == 2
+
+try:
+    c.h(5)
+    raise AssertionError("out-of-range qubit accepted")
+except ValueError:
+    pass
+assert len(c) == 2
+
+try:
+    qops.Circuit(1).rx(0.3, 0).to_qasm()
+except ValueError:
+    raise AssertionError("rx should export")
+"#,
+        );
+    }
+
+    #[test]
+    fn test_register_sampling() {
+        run_python(
+            r#"
+r = qops.QuantumRegister(2)
+r.apply_circuit(qops.Circuit.bell_state())
+counts = r.get_counts(200)
+assert set(counts) <= {"00", "11"} and sum(counts.values()) == 200
+bits = r.measure_all()
+assert bits[0] == bits[1]
+
+try:
+    r.measure(2)
+    raise AssertionError("out-of-range qubit measured")
+except ValueError:
+    pass
+
+try:
+    r.apply_circuit(qops.Circuit(3).h(2))
+    raise AssertionError("wider circuit applied")
+except ValueError:
+    pass
+"#,
+        );
+    }
+
+    #[test]
+    fn test_grover_and_vqe() {
+        run_python(
+            r#"
+g = qops.Grover(3, [5])
+result = g.run(200)
+assert result.iterations == g.optimal_iterations()
+assert result.shots == 200 and sum(result.counts.values()) == 200
+assert result.is_solution == (result.measured_state == 5)
+assert 0.9 < g.theoretical_success_probability() <= 1.0
+
+for bad in [(0, [0]), (3, [8])]:
+    try:
+        qops.Grover(*bad)
+        raise AssertionError("invalid Grover accepted")
+    except ValueError:
+        pass
+
+v = qops.VQE([(1.0, "ZZ"), (0.5, "XI")], layers=1)
+assert v.num_qubits == 2
+assert abs(v.evaluate_energy([0.0] * v.num_parameters())) <= 1.5
+try:
+    v.evaluate_energy([0.0])
+    raise AssertionError("wrong parameter count accepted")
+except ValueError:
+    pass
+"#,
+        );
+    }
+
+    #[test]
+    fn test_resonance_matches_core() {
+        let expected = qops_core::resonance_5d(&Signature5D::new(0.9, 0.8, 0.7, 0.2, 0.1));
+        run_python(&format!(
+            "assert abs(qops.resonance_5d(0.9, 0.8, 0.7, 0.2, 0.1) - {expected:?}) < 1e-12"
+        ));
+    }
+}
@@ -0,0 +1,27 @@
+//! # QOPS Python Bindings
+//!
+//! PyO3 module `qops` exposing the circuit simulator and core algorithms to
+//! Python. The Python API mirrors the Rust one so the docs transfer:
+//!
+//! | Python                 | Rust                                   |
+//! |------------------------|----------------------------------------|
+//! | `qops.Circuit`         | [`qops_circuits::Circuit`]             |
+//! | `qops.QuantumRegister` | [`qops_circuits::QuantumRegister`]     |
+//! | `qops.Grover`          | [`qops_algorithms::Grover`]            |
+//! | `qops.VQE`             | [`qops_algorithms::VQE`]               |
+//! | `qops.resonance_5d`    | [`qops_core::resonance_5d`]            |
+//!
+//! State vectors and probabilities are returned as NumPy arrays.
+//!
+//! The bindings are behind the `python` feature so the workspace builds
+//! without a Python toolchain. Build the wheel with maturin:
+//!
+//! ```text
+//! cd python && maturin develop --release
+//! ```
+//!
+//! Type stubs live in `qops.pyi`. The binding tests embed an interpreter
+//! and run with `cargo test -p qops-python --features python`.
+
+#[cfg(feature = "python")]
+mod bindings;
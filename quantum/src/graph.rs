@@ -65,6 +65,26 @@ impl MetatronGraph {
         self.adjacency.row(node).iter().filter(|&&x| x > 0.0).count()
     }
 
+    /// Add an undirected edge, returning whether the graph changed
+    pub fn add_edge(&mut self, a: usize, b: usize) -> bool {
+        if a >= 13 || b >= 13 || a == b || self.adjacency[(a, b)] > 0.0 {
+            return false;
+        }
+        self.adjacency[(a, b)] = 1.0;
+        self.adjacency[(b, a)] = 1.0;
+        true
+    }
+
+    /// Remove an undirected edge, returning whether the graph changed
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> bool {
+        if a >= 13 || b >= 13 || self.adjacency[(a, b)] == 0.0 {
+            return false;
+        }
+        self.adjacency[(a, b)] = 0.0;
+        self.adjacency[(b, a)] = 0.0;
+        true
+    }
+
     /// Get Laplacian matrix
     pub fn laplacian(&self) -> DMatrix<f64> {
         let mut laplacian = -self.adjacency.clone();
@@ -157,6 +177,21 @@ mod tests {
         assert_eq!(neighbors.len(), 6);
     }
 
+    #[test]
+    fn test_edge_edits() {
+        let mut graph = MetatronGraph::new();
+        let edges = graph.edge_count();
+
+        assert!(graph.add_edge(0, 7));
+        assert!(!graph.add_edge(7, 0));
+        assert!(!graph.add_edge(3, 3));
+        assert_eq!(graph.edge_count(), edges + 1);
+
+        assert!(graph.remove_edge(7, 0));
+        assert!(!graph.remove_edge(0, 7));
+        assert_eq!(graph.edge_count(), edges);
+    }
+
     #[test]
     fn test_laplacian() {
         let graph = MetatronGraph::new();
@@ -10,10 +10,9 @@ use num_complex::Complex64;
 pub struct MetatronHamiltonian {
     /// Hamiltonian matrix
     matrix: DMatrix<Complex64>,
-    /// Eigenvalues
+    /// Eigenvalues (ascending)
     eigenvalues: Vec<f64>,
-    /// Eigenvectors
-    #[allow(dead_code)]
+    /// Eigenvectors (columns, matching `eigenvalues`)
     eigenvectors: DMatrix<Complex64>,
 }
 
@@ -36,9 +35,13 @@ impl MetatronHamiltonian {
         let real_matrix: DMatrix<f64> = DMatrix::from_fn(n, n, |i, j| matrix[(i, j)].re);
         let eigen = real_matrix.symmetric_eigen();
 
-        let eigenvalues = eigen.eigenvalues.iter().copied().collect();
+        // Sort eigenpairs by ascending eigenvalue
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| eigen.eigenvalues[a].total_cmp(&eigen.eigenvalues[b]));
+
+        let eigenvalues = order.iter().map(|&k| eigen.eigenvalues[k]).collect();
         let eigenvectors = DMatrix::from_fn(n, n, |i, j| {
-            Complex64::new(eigen.eigenvectors[(i, j)], 0.0)
+            Complex64::new(eigen.eigenvectors[(i, order[j])], 0.0)
         });
 
         Self {
@@ -70,6 +73,32 @@ impl MetatronHamiltonian {
         self.eigenvalues.iter().cloned().fold(f64::INFINITY, f64::min)
     }
 
+    /// Eigenvalues in ascending order
+    pub fn eigenvalues(&self) -> &[f64] {
+        &self.eigenvalues
+    }
+
+    /// Eigenvectors as columns, in the order of [`Self::eigenvalues`]
+    pub fn eigenvectors(&self) -> &DMatrix<Complex64> {
+        &self.eigenvectors
+    }
+
+    /// Spectral gap E₁ - E₀ between the two smallest eigenvalues
+    pub fn spectral_gap(&self) -> f64 {
+        match self.eigenvalues.as_slice() {
+            [e0, e1, ..] => e1 - e0,
+            _ => 0.0,
+        }
+    }
+
+    /// Probability of each node in the ground state, |⟨i|E₀⟩|²
+    pub fn ground_state_distribution(&self) -> Vec<f64> {
+        if self.eigenvectors.ncols() == 0 {
+            return Vec::new();
+        }
+        self.eigenvectors.column(0).iter().map(|c| c.norm_sqr()).collect()
+    }
+
     /// Time evolution operator exp(-iHt)
     pub fn propagator(&self, t: f64) -> DMatrix<Complex64> {
         let n = self.matrix.nrows();
@@ -100,6 +129,24 @@ mod tests {
         assert_eq!(h.dimension(), 13);
     }
 
+    #[test]
+    fn test_hamiltonian_spectrum() {
+        let graph = MetatronGraph::new();
+        let h = MetatronHamiltonian::from_graph(&graph);
+
+        let eigenvalues = h.eigenvalues();
+        assert_eq!(eigenvalues.len(), 13);
+        assert!(eigenvalues.windows(2).all(|w| w[0] <= w[1]));
+        assert!((eigenvalues[0] - h.ground_energy()).abs() < 1e-12);
+        // H = -L, so the largest eigenvalue is the Laplacian's zero mode
+        assert!(eigenvalues[12].abs() < 1e-9);
+
+        assert!(h.spectral_gap() >= 0.0);
+        let dist = h.ground_state_distribution();
+        assert_eq!(dist.len(), 13);
+        assert!((dist.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
     #[test]
     fn test_hamiltonian_hermitian() {
         let graph = MetatronGraph::new();
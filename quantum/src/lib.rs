@@ -8,7 +8,7 @@
 //! - **QuantumState**: Complex amplitude state vectors
 //! - **Hamiltonian**: Graph-based Hamiltonians with spectral decomposition
 //! - **VQA**: Variational Quantum Algorithms (VQE, QAOA, VQC)
-//! - **QuantumWalk**: Continuous-time quantum walks
+//! - **QuantumWalk**: Continuous-time and coined discrete-time quantum walks
 //! - **DTL**: Dynamic Tripolar Logic
 //! - **Topology**: CUBE-13 topology engine with embedding and analysis
 //!
@@ -38,12 +38,13 @@ pub mod dtl;
 pub mod topology;
 
 pub use graph::MetatronGraph;
-pub use state::QuantumState;
+pub use state::{von_neumann_entropy, CompressedAmplitudes, CompressedState, QuantumState};
 pub use hamiltonian::MetatronHamiltonian;
-pub use quantum_walk::ContinuousQuantumWalk;
+pub use quantum_walk::{CoinOperator, ContinuousQuantumWalk, DiscreteQuantumWalk};
 pub use topology::{
-    Cube13Engine, Cube13NodeType, TopologyExplorer,
-    TopologyMetrics, TopologyWalkResult, OperatorCluster,
+    Cube13Engine, Cube13NodeType, GraphEmbedding, TopologyExplorer,
+    TopologyMetrics, TopologyWalkResult, OperatorCluster, StructuralMetrics,
+    ClusterSummary, LayerOccupancy, DEFAULT_CLUSTER_THRESHOLD, DEFAULT_MIXING_EPSILON,
 };
 
 /// Metatron dimension (13 nodes)
@@ -1,7 +1,18 @@
-//! Continuous-time quantum walks.
+//! Continuous- and discrete-time quantum walks.
 
+use crate::graph::MetatronGraph;
 use crate::hamiltonian::MetatronHamiltonian;
 use crate::state::QuantumState;
+use nalgebra::{DMatrix, DVector};
+use num_complex::Complex64;
+use qops_core::ResonanceTopology;
+use std::f64::consts::PI;
+
+/// Largest integration step for the Lindblad master equation
+///
+/// Steps shrink to `1/γ` for strong dephasing, keeping γ·dt inside the
+/// Runge-Kutta stability region.
+const LINDBLAD_DT: f64 = 0.01;
 
 /// Continuous-time quantum walk
 pub struct ContinuousQuantumWalk {
@@ -41,6 +52,51 @@ impl ContinuousQuantumWalk {
         trajectory
     }
 
+    /// Site probabilities after time `t` with on-site dephasing at rate `gamma`
+    ///
+    /// Integrates the Lindblad equation dρ/dt = -i[H, ρ] + γ Σⱼ (Lⱼ ρ Lⱼ† - ½{Lⱼ†Lⱼ, ρ})
+    /// with jump operators Lⱼ = |j⟩⟨j| by fourth-order Runge-Kutta. The
+    /// dissipator reduces to γ(diag ρ - ρ), damping every coherence at
+    /// rate γ; `gamma = 0` is the coherent walk, while large γ·t approaches
+    /// a classical random walk. Negative or non-finite `gamma` is rejected.
+    pub fn evolve_lindblad(&self, initial: &QuantumState, t: f64, gamma: f64) -> qops_core::Result<Vec<f64>> {
+        if !gamma.is_finite() || gamma < 0.0 {
+            return Err(qops_core::QopsError::quantum(format!(
+                "Dephasing rate must be finite and non-negative, got {gamma}"
+            )));
+        }
+
+        let h = self.hamiltonian.matrix();
+        let mut psi = initial.clone();
+        psi.normalize();
+        let mut rho = &psi.amplitudes * psi.amplitudes.adjoint();
+
+        let minus_i = Complex64::new(0.0, -1.0);
+        let derivative = |rho: &DMatrix<Complex64>| -> DMatrix<Complex64> {
+            let commutator = h * rho - rho * h;
+            let mut drho = commutator * minus_i - rho * Complex64::new(gamma, 0.0);
+            for j in 0..rho.nrows() {
+                drho[(j, j)] += rho[(j, j)] * gamma;
+            }
+            drho
+        };
+
+        let max_dt = if gamma > 0.0 { LINDBLAD_DT.min(1.0 / gamma) } else { LINDBLAD_DT };
+        let steps = (t.max(0.0) / max_dt).ceil() as usize;
+        if steps > 0 {
+            let dt = t / steps as f64;
+            for _ in 0..steps {
+                let k1 = derivative(&rho);
+                let k2 = derivative(&(&rho + &k1 * Complex64::new(dt / 2.0, 0.0)));
+                let k3 = derivative(&(&rho + &k2 * Complex64::new(dt / 2.0, 0.0)));
+                let k4 = derivative(&(&rho + &k3 * Complex64::new(dt, 0.0)));
+                rho += (k1 + (k2 + k3) * Complex64::new(2.0, 0.0) + k4) * Complex64::new(dt / 6.0, 0.0);
+            }
+        }
+
+        Ok(rho.diagonal().iter().map(|p| p.re.max(0.0)).collect())
+    }
+
     /// Compute hitting time to target node
     pub fn hitting_time(&self, source: usize, target: usize, max_time: f64, dt: f64) -> Option<f64> {
         let initial = QuantumState::basis_state(source).ok()?;
@@ -85,6 +141,130 @@ impl ContinuousQuantumWalk {
     }
 }
 
+// ==================== Discrete-Time Walk ====================
+
+/// Coin applied at each node of a discrete-time walk
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CoinOperator {
+    /// Grover diffusion 2/d·J - I, which treats every edge alike
+    #[default]
+    Grover,
+    /// Hadamard coin, generalized to degree d as the d-point Fourier transform
+    Hadamard,
+}
+
+impl CoinOperator {
+    /// Coin matrix for a node of degree `d`
+    pub fn matrix(&self, d: usize) -> DMatrix<Complex64> {
+        match self {
+            Self::Grover => DMatrix::from_fn(d, d, |i, j| {
+                let diag = if i == j { 1.0 } else { 0.0 };
+                Complex64::new(2.0 / d as f64 - diag, 0.0)
+            }),
+            Self::Hadamard => {
+                let norm = 1.0 / (d as f64).sqrt();
+                DMatrix::from_fn(d, d, |i, j| {
+                    Complex64::from_polar(norm, 2.0 * PI * (i * j) as f64 / d as f64)
+                })
+            }
+        }
+    }
+}
+
+/// Coined discrete-time quantum walk on the Metatron graph
+///
+/// The state lives in position ⊗ coin space, with the coin register as wide
+/// as the largest degree; coin state `c` at node `u` points along the edge to
+/// `u`'s `c`-th neighbor, and slots beyond a node's degree stay empty. Each
+/// step applies the coin at every node, then the flip-flop shift
+/// |u, u→v⟩ → |v, v→u⟩.
+pub struct DiscreteQuantumWalk {
+    coin: CoinOperator,
+    /// Sorted neighbors of every node
+    neighbors: Vec<Vec<usize>>,
+    /// Coin matrix for every node
+    coins: Vec<DMatrix<Complex64>>,
+    /// Width of the coin register
+    coin_dim: usize,
+}
+
+impl DiscreteQuantumWalk {
+    /// Create a walk over `graph` with the given coin
+    pub fn new(graph: &MetatronGraph, coin: CoinOperator) -> Self {
+        let neighbors: Vec<Vec<usize>> = graph.nodes().iter().map(|n| graph.neighbors(n)).collect();
+        let coins = neighbors.iter().map(|n| coin.matrix(n.len())).collect();
+        let coin_dim = neighbors.iter().map(Vec::len).max().unwrap_or(0);
+        Self { coin, neighbors, coins, coin_dim }
+    }
+
+    /// Coin used at every node
+    pub fn coin(&self) -> CoinOperator {
+        self.coin
+    }
+
+    /// Width of the coin register
+    pub fn coin_dimension(&self) -> usize {
+        self.coin_dim
+    }
+
+    /// Dimension of position ⊗ coin space
+    pub fn dimension(&self) -> usize {
+        self.neighbors.len() * self.coin_dim
+    }
+
+    /// Walker at `node` with a uniform superposition over its edges
+    pub fn initial_state(&self, node: usize) -> qops_core::Result<QuantumState> {
+        let degree = match self.neighbors.get(node) {
+            Some(n) if !n.is_empty() => n.len(),
+            _ => return Err(qops_core::QopsError::quantum("Walk start node has no edges")),
+        };
+
+        let mut amplitudes = DVector::zeros(self.dimension());
+        let amp = Complex64::new(1.0 / (degree as f64).sqrt(), 0.0);
+        for c in 0..degree {
+            amplitudes[node * self.coin_dim + c] = amp;
+        }
+        Ok(QuantumState::new(amplitudes))
+    }
+
+    /// Apply one coin flip and shift
+    pub fn step(&self, state: &QuantumState) -> QuantumState {
+        let d = self.coin_dim;
+        let mut shifted = DVector::zeros(self.dimension());
+
+        for (u, neighbors) in self.neighbors.iter().enumerate() {
+            let block = state.amplitudes.rows(u * d, neighbors.len());
+            let flipped = &self.coins[u] * block;
+            for (c, &v) in neighbors.iter().enumerate() {
+                let back = self.neighbors[v].binary_search(&u).expect("graph is undirected");
+                shifted[v * d + back] = flipped[c];
+            }
+        }
+
+        QuantumState::new(shifted)
+    }
+
+    /// Take `steps` steps from `initial`
+    pub fn evolve(&self, initial: &QuantumState, steps: usize) -> QuantumState {
+        (0..steps).fold(initial.clone(), |state, _| self.step(&state))
+    }
+
+    /// Probability of finding the walker at each node, summed over the coin
+    pub fn position_probabilities(&self, state: &QuantumState) -> Vec<f64> {
+        state
+            .probabilities()
+            .chunks(self.coin_dim.max(1))
+            .map(|coin| coin.iter().sum())
+            .collect()
+    }
+
+    /// Per-node probabilities after `steps` steps from `source`
+    pub fn walk(&self, source: usize, steps: usize) -> qops_core::Result<Vec<f64>> {
+        let initial = self.initial_state(source)?;
+        Ok(self.position_probabilities(&self.evolve(&initial, steps)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +296,84 @@ mod tests {
         let probs = evolved.probabilities();
         assert!(probs[0] < 0.9); // Some spreading
     }
+
+    #[test]
+    fn test_lindblad_dephasing_relaxes_to_uniform() {
+        let graph = MetatronGraph::new();
+        let qw = ContinuousQuantumWalk::new(MetatronHamiltonian::from_graph(&graph));
+        let initial = QuantumState::basis_state(0).unwrap();
+        let tvd = |probs: &[f64]| probs.iter().map(|p| (p - 1.0 / 13.0).abs()).sum::<f64>() / 2.0;
+
+        // Coherent: the return probability dips and recovers instead of settling
+        let coherent: Vec<f64> = (0..=40)
+            .map(|k| qw.evolve_lindblad(&initial, k as f64 * 0.1, 0.0).unwrap()[0])
+            .collect();
+        let min = coherent.iter().copied().fold(f64::MAX, f64::min);
+        let min_at = coherent.iter().position(|&p| p == min).unwrap();
+        assert!(coherent[min_at..].iter().any(|&p| p > min + 0.1));
+        let probs = qw.evolve_lindblad(&initial, 4.0, 0.0).unwrap();
+        assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-8);
+
+        // Dephasing: distance to uniform shrinks as γ·t grows
+        let distances: Vec<f64> = [1.0, 5.0, 20.0]
+            .iter()
+            .map(|&t| tvd(&qw.evolve_lindblad(&initial, t, 1.0).unwrap()))
+            .collect();
+        assert!(distances.windows(2).all(|w| w[1] < w[0]));
+        assert!(distances[2] < 0.01);
+    }
+
+    #[test]
+    fn test_lindblad_strong_dephasing() {
+        let graph = MetatronGraph::new();
+        let qw = ContinuousQuantumWalk::new(MetatronHamiltonian::from_graph(&graph));
+        let initial = QuantumState::basis_state(0).unwrap();
+
+        // Far beyond the fixed step's stability limit: stays normalized, and
+        // the quantum Zeno effect pins the walker to its start
+        let probs = qw.evolve_lindblad(&initial, 1.0, 1000.0).unwrap();
+        assert!(probs.iter().all(|p| p.is_finite() && *p <= 1.0));
+        assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-8);
+        assert!(probs[0] > 0.9);
+
+        for gamma in [-1.0, f64::NAN, f64::INFINITY] {
+            assert!(qw.evolve_lindblad(&initial, 1.0, gamma).is_err());
+        }
+    }
+
+    #[test]
+    fn test_discrete_walk_first_step() {
+        let graph = MetatronGraph::new();
+
+        // The uniform coin state is a Grover eigenvector: the center spreads evenly
+        let grover = DiscreteQuantumWalk::new(&graph, CoinOperator::Grover);
+        assert_eq!(grover.dimension(), 13 * 6);
+        let probs = grover.walk(0, 1).unwrap();
+        assert!(probs[0].abs() < 1e-12);
+        for p in &probs[1..=6] {
+            assert!((p - 1.0 / 6.0).abs() < 1e-12);
+        }
+
+        // The Fourier coin focuses the uniform state onto the first edge
+        let hadamard = DiscreteQuantumWalk::new(&graph, CoinOperator::Hadamard);
+        let probs = hadamard.walk(0, 1).unwrap();
+        assert!((probs[1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_discrete_walk_is_unitary_and_spreads() {
+        let graph = MetatronGraph::new();
+        for coin in [CoinOperator::Grover, CoinOperator::Hadamard] {
+            let dtqw = DiscreteQuantumWalk::new(&graph, coin);
+            let state = dtqw.evolve(&dtqw.initial_state(0).unwrap(), 7);
+            assert!((state.norm() - 1.0).abs() < 1e-10);
+
+            let probs = dtqw.position_probabilities(&state);
+            assert_eq!(probs.len(), 13);
+            assert!((probs.iter().sum::<f64>() - 1.0).abs() < 1e-10);
+            // The outer cube is reached
+            assert!(probs[7..].iter().sum::<f64>() > 0.0);
+        }
+        assert!(DiscreteQuantumWalk::new(&graph, CoinOperator::Grover).initial_state(13).is_err());
+    }
 }
@@ -1,7 +1,11 @@
 //! Quantum state representation.
 
-use nalgebra::DVector;
+use nalgebra::{DMatrix, DVector};
 use num_complex::Complex64;
+use qops_circuits::DensityMatrix;
+use qops_core::QopsError;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use crate::METATRON_DIMENSION;
 
 /// Quantum state vector
@@ -92,6 +96,159 @@ impl QuantumState {
             .map(|(a, &d)| a.norm_sqr() * d)
             .sum()
     }
+
+    /// Reduced density matrix of the qubits in `keep`, tracing out the rest
+    ///
+    /// The state must have a power-of-two dimension, with qubit k as bit k of
+    /// the basis index. `keep[0]` becomes qubit 0 of the result, `keep[1]`
+    /// qubit 1, and so on.
+    pub fn partial_trace(&self, keep: &[usize]) -> qops_core::Result<DensityMatrix> {
+        let dim = self.dimension();
+        if !dim.is_power_of_two() {
+            return Err(QopsError::quantum(format!(
+                "Partial trace needs a qubit state, dimension {} is not a power of two",
+                dim
+            )));
+        }
+        let num_qubits = dim.trailing_zeros() as usize;
+        if let Some(&q) = keep.iter().find(|&&q| q >= num_qubits) {
+            return Err(QopsError::validation(format!(
+                "Qubit {} out of range for {} qubits",
+                q, num_qubits
+            )));
+        }
+        if keep.iter().collect::<HashSet<_>>().len() != keep.len() {
+            return Err(QopsError::validation("Kept qubits must be distinct"));
+        }
+
+        let traced: Vec<usize> = (0..num_qubits).filter(|q| !keep.contains(q)).collect();
+        let gather = |index: usize, qubits: &[usize]| -> usize {
+            qubits
+                .iter()
+                .enumerate()
+                .map(|(bit, &q)| ((index >> q) & 1) << bit)
+                .sum()
+        };
+
+        // ψ as a (kept × traced) matrix M, so that ρ_kept = M M†
+        let mut psi = DMatrix::zeros(1 << keep.len(), 1 << traced.len());
+        for (index, amplitude) in self.amplitudes.iter().enumerate() {
+            psi[(gather(index, keep), gather(index, &traced))] = *amplitude;
+        }
+        let mut rho = &psi * psi.adjoint();
+        let norm_sqr = self.norm().powi(2);
+        if norm_sqr > 1e-20 {
+            rho /= Complex64::new(norm_sqr, 0.0);
+        }
+
+        DensityMatrix::from_matrix(rho).map_err(|e| QopsError::quantum(e.to_string()))
+    }
+
+    /// Compress the state, dropping amplitudes with magnitude at most `epsilon`
+    ///
+    /// Falls back to a dense dump when the sparse form would not be smaller.
+    pub fn to_compressed(&self, epsilon: f64) -> CompressedState {
+        let entries: Vec<(usize, Complex64)> = self
+            .amplitudes
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| a.norm() > epsilon)
+            .map(|(i, a)| (i, *a))
+            .collect();
+
+        // A sparse entry costs an index on top of the amplitude
+        let sparse_bytes = entries.len() * (std::mem::size_of::<usize>() + std::mem::size_of::<Complex64>());
+        let dense_bytes = self.dimension() * std::mem::size_of::<Complex64>();
+
+        let amplitudes = if sparse_bytes < dense_bytes {
+            CompressedAmplitudes::Sparse(entries)
+        } else {
+            CompressedAmplitudes::Dense(self.amplitudes.iter().copied().collect())
+        };
+
+        CompressedState {
+            dimension: self.dimension(),
+            epsilon,
+            amplitudes,
+        }
+    }
+
+    /// Rebuild a state from its compressed form
+    pub fn from_compressed(compressed: &CompressedState) -> qops_core::Result<Self> {
+        let dim = compressed.dimension;
+        let mut amplitudes = DVector::zeros(dim);
+
+        match &compressed.amplitudes {
+            CompressedAmplitudes::Sparse(entries) => {
+                for &(index, amp) in entries {
+                    if index >= dim {
+                        return Err(qops_core::QopsError::quantum(
+                            "Compressed amplitude index out of range",
+                        ));
+                    }
+                    amplitudes[index] = amp;
+                }
+            }
+            CompressedAmplitudes::Dense(values) => {
+                if values.len() != dim {
+                    return Err(qops_core::QopsError::quantum(
+                        "Compressed amplitude count does not match dimension",
+                    ));
+                }
+                amplitudes = DVector::from_vec(values.clone());
+            }
+        }
+
+        Ok(Self { amplitudes })
+    }
+}
+
+/// Stored amplitudes of a [`CompressedState`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CompressedAmplitudes {
+    /// Amplitudes above epsilon as `(index, amplitude)` pairs
+    Sparse(Vec<(usize, Complex64)>),
+    /// Every amplitude, in basis order
+    Dense(Vec<Complex64>),
+}
+
+/// Serializable, optionally sparse snapshot of a [`QuantumState`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompressedState {
+    /// Dimension of the original state
+    pub dimension: usize,
+    /// Magnitude threshold used when compressing
+    pub epsilon: f64,
+    /// Stored amplitudes
+    pub amplitudes: CompressedAmplitudes,
+}
+
+impl CompressedState {
+    /// Number of qubits, if the dimension is a power of two
+    pub fn num_qubits(&self) -> Option<usize> {
+        self.dimension
+            .is_power_of_two()
+            .then(|| self.dimension.trailing_zeros() as usize)
+    }
+
+    /// Whether the amplitudes are stored sparsely
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.amplitudes, CompressedAmplitudes::Sparse(_))
+    }
+}
+
+/// Von Neumann entropy S(ρ) = -Tr(ρ log₂ ρ) in bits
+///
+/// Zero for pure states and n for the maximally mixed state of n qubits;
+/// applied to a reduced density matrix it is the entanglement entropy.
+pub fn von_neumann_entropy(rho: &DensityMatrix) -> f64 {
+    rho.matrix()
+        .clone()
+        .symmetric_eigenvalues()
+        .iter()
+        .filter(|&&p| p > 1e-12)
+        .map(|&p| -p * p.log2())
+        .sum()
 }
 
 impl Default for QuantumState {
@@ -104,6 +261,35 @@ impl Default for QuantumState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_partial_trace_entanglement() {
+        let h = Complex64::new(1.0 / 2f64.sqrt(), 0.0);
+
+        // Bell state (|00⟩ + |11⟩)/√2: each qubit alone is maximally mixed
+        let mut bell = QuantumState::zeros(4);
+        bell.amplitudes[0b00] = h;
+        bell.amplitudes[0b11] = h;
+        let reduced = bell.partial_trace(&[1]).unwrap();
+        assert!(reduced.is_valid());
+        assert!((reduced.purity() - 0.5).abs() < 1e-10);
+        assert!((von_neumann_entropy(&reduced) - 1.0).abs() < 1e-10);
+        assert!(von_neumann_entropy(&bell.partial_trace(&[0, 1]).unwrap()).abs() < 1e-10);
+
+        // |+⟩ on qubit 0 times |1⟩ on qubit 2 (qubit 1 in |0⟩) is a product state
+        let mut product = QuantumState::zeros(8);
+        product.amplitudes[0b100] = h;
+        product.amplitudes[0b101] = h;
+        let reduced = product.partial_trace(&[2, 0]).unwrap();
+        assert!(von_neumann_entropy(&reduced).abs() < 1e-10);
+        // Qubit 2 is bit 0 of the reduced index
+        assert!((reduced.probability(0b01) - 0.5).abs() < 1e-10);
+        assert!((reduced.probability(0b11) - 0.5).abs() < 1e-10);
+
+        assert!(product.partial_trace(&[3]).is_err());
+        assert!(product.partial_trace(&[1, 1]).is_err());
+        assert!(QuantumState::uniform().partial_trace(&[0]).is_err());
+    }
+
     #[test]
     fn test_basis_state() {
         let state = QuantumState::basis_state(0).unwrap();
@@ -134,4 +320,34 @@ mod tests {
         assert!((state1.inner_product(&state2).norm() - 1.0).abs() < 1e-10);
         assert!(state1.inner_product(&state3).norm() < 1e-10);
     }
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let epsilon = 1e-3;
+
+        // Sparse: two large amplitudes plus noise below epsilon
+        let mut amps = DVector::from_element(16, Complex64::new(1e-4, -1e-4));
+        amps[3] = Complex64::new(0.6, 0.0);
+        amps[12] = Complex64::new(0.0, -0.8);
+        let sparse = QuantumState::new(amps);
+
+        let compressed = sparse.to_compressed(epsilon);
+        assert!(compressed.is_sparse());
+        assert_eq!(compressed.num_qubits(), Some(4));
+
+        let json = serde_json::to_string(&compressed).unwrap();
+        let restored = QuantumState::from_compressed(&serde_json::from_str(&json).unwrap()).unwrap();
+        assert_eq!(restored.dimension(), sparse.dimension());
+        for (a, b) in sparse.amplitudes.iter().zip(restored.amplitudes.iter()) {
+            assert!((a - b).norm() <= epsilon);
+        }
+
+        // Dense: uniform state is stored in full and restored exactly
+        let dense = QuantumState::uniform();
+        let compressed = dense.to_compressed(epsilon);
+        assert!(!compressed.is_sparse());
+        assert_eq!(compressed.num_qubits(), None);
+        let restored = QuantumState::from_compressed(&compressed).unwrap();
+        assert_eq!(restored.amplitudes, dense.amplitudes);
+    }
 }
@@ -4,10 +4,18 @@
 //! 13-node Metatron Cube geometry.
 
 use crate::graph::MetatronGraph;
+use crate::hamiltonian::MetatronHamiltonian;
 use nalgebra::DMatrix;
-use qops_core::{Signature5D, resonance_5d, ResonanceTopology};
+use qops_core::{NodeSignature, QopsError, Signature, Signature5D, resonance_5d, ResonanceTopology};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::sync::OnceLock;
+
+/// Signature distance threshold used by [`Cube13Engine::clusters_summary`]
+pub const DEFAULT_CLUSTER_THRESHOLD: f64 = 0.2;
+
+/// Total variation distance used for [`TopologyMetrics::mixing_time`]
+pub const DEFAULT_MIXING_EPSILON: f64 = 0.01;
 
 /// Node types in CUBE-13 topology
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -45,21 +53,139 @@ impl Cube13NodeType {
 #[derive(Debug, Clone)]
 pub struct Cube13Engine {
     graph: MetatronGraph,
-    distance_matrix: DMatrix<f64>,
+    /// Shortest path distances, built on first use
+    distance_matrix: OnceLock<DMatrix<f64>>,
     embeddings: HashMap<usize, Signature5D>,
+    structure: StructuralMetrics,
+    /// Hamiltonian and its eigendecomposition, built on first use
+    hamiltonian: OnceLock<MetatronHamiltonian>,
 }
 
 impl Cube13Engine {
     /// Create new Cube-13 engine
     pub fn new() -> Self {
         let graph = MetatronGraph::new();
-        let distance_matrix = Self::compute_distances(&graph);
+        let structure = StructuralMetrics::compute(&graph);
 
         Self {
             graph,
-            distance_matrix,
+            distance_matrix: OnceLock::new(),
             embeddings: HashMap::new(),
+            structure,
+            hamiltonian: OnceLock::new(),
+        }
+    }
+
+    /// Add an edge, updating structural metrics and distances incrementally
+    ///
+    /// Returns `false` if the edge already exists or is invalid.
+    pub fn add_edge(&mut self, a: usize, b: usize) -> bool {
+        if !self.graph.add_edge(a, b) {
+            return false;
+        }
+        self.structure.edge_added(&self.graph, a, b);
+        if let Some(dist) = self.distance_matrix.get_mut() {
+            // A new unit edge can only shorten paths by routing through it
+            let n = dist.nrows();
+            for i in 0..n {
+                for j in 0..n {
+                    let via = (dist[(i, a)] + 1.0 + dist[(b, j)]).min(dist[(i, b)] + 1.0 + dist[(a, j)]);
+                    if via < dist[(i, j)] {
+                        dist[(i, j)] = via;
+                    }
+                }
+            }
+        }
+        self.hamiltonian = OnceLock::new();
+        true
+    }
+
+    /// Remove an edge, updating structural metrics incrementally
+    ///
+    /// Returns `false` if the edge does not exist.
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> bool {
+        if !self.graph.remove_edge(a, b) {
+            return false;
+        }
+        self.structure.edge_removed(&self.graph, a, b);
+        self.distance_matrix = OnceLock::new();
+        self.hamiltonian = OnceLock::new();
+        true
+    }
+
+    /// Get structural metrics (degrees, clustering, components)
+    pub fn structural_metrics(&self) -> &StructuralMetrics {
+        &self.structure
+    }
+
+    /// Hamiltonian H = -L of the current graph, decomposed once per edit
+    pub fn hamiltonian(&self) -> &MetatronHamiltonian {
+        self.hamiltonian.get_or_init(|| MetatronHamiltonian::from_graph(&self.graph))
+    }
+
+    /// Spectral gap of the Hamiltonian (see [`MetatronHamiltonian::spectral_gap`])
+    pub fn spectral_gap(&self) -> f64 {
+        self.hamiltonian().spectral_gap()
+    }
+
+    /// Time for a continuous-time random walk to mix to within `epsilon`
+    ///
+    /// The walk dp/dt = -Lp has the uniform distribution as its stationary
+    /// state on a connected graph. Returns the smallest t at which the total
+    /// variation distance to uniform is at most `epsilon` from every start
+    /// node, or `None` if the graph is disconnected or `epsilon` is not
+    /// positive. A quantum walk never settles, so only the classical walk
+    /// has a mixing time in this sense.
+    pub fn mixing_time(&self, epsilon: f64) -> Option<f64> {
+        if epsilon <= 0.0 || epsilon.is_nan() || self.structure.component_count != 1 {
+            return None;
+        }
+
+        let hamiltonian = self.hamiltonian();
+        let rates: Vec<f64> = hamiltonian.eigenvalues().iter().map(|e| -e).collect();
+        let vectors = hamiltonian.eigenvectors().map(|c| c.re);
+        let n = rates.len();
+        let uniform = 1.0 / n as f64;
+
+        // Worst-case distance is non-increasing in t, so bisection finds the crossing
+        let distance = |t: f64| -> f64 {
+            let decay: Vec<f64> = rates.iter().map(|r| (-r * t).exp()).collect();
+            (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| {
+                            let p: f64 = (0..n).map(|k| vectors[(i, k)] * vectors[(j, k)] * decay[k]).sum();
+                            (p - uniform).abs()
+                        })
+                        .sum::<f64>()
+                        / 2.0
+                })
+                .fold(0.0, f64::max)
+        };
+
+        if distance(0.0) <= epsilon {
+            return Some(0.0);
         }
+        let mut low = 0.0;
+        let mut high = 1.0;
+        while distance(high) > epsilon {
+            low = high;
+            high *= 2.0;
+        }
+        for _ in 0..50 {
+            let mid = (low + high) / 2.0;
+            if distance(mid) > epsilon {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        Some(high)
+    }
+
+    /// Shortest path distances of the current graph, recomputed after removals
+    fn distances(&self) -> &DMatrix<f64> {
+        self.distance_matrix.get_or_init(|| Self::compute_distances(&self.graph))
     }
 
     /// Compute shortest path distances
@@ -97,16 +223,17 @@ impl Cube13Engine {
         if from >= 13 || to >= 13 {
             return f64::INFINITY;
         }
-        self.distance_matrix[(from, to)]
+        self.distances()[(from, to)]
     }
 
     /// Get graph diameter
     pub fn diameter(&self) -> f64 {
+        let dist = self.distances();
         let mut max: f64 = 0.0;
         for i in 0..13 {
             for j in 0..13 {
-                if self.distance_matrix[(i, j)] < f64::INFINITY {
-                    max = max.max(self.distance_matrix[(i, j)]);
+                if dist[(i, j)] < f64::INFINITY {
+                    max = max.max(dist[(i, j)]);
                 }
             }
         }
@@ -163,6 +290,111 @@ impl Cube13Engine {
         1.0 - (sig_res - avg_res).abs()
     }
 
+    /// Map the nodes of an arbitrary graph onto the 13 Metatron positions
+    ///
+    /// `adjacency[u]` lists the neighbours of input node `u`; edges are taken
+    /// as undirected and self-loops are ignored. Nodes are placed greedily in
+    /// order of decreasing degree, then pairwise swaps and moves to free
+    /// positions are applied until the total Metatron distance over all input
+    /// edges stops decreasing. Fails for graphs with more than 13 nodes or
+    /// neighbours out of range.
+    pub fn embed_graph(&self, adjacency: &[Vec<usize>]) -> qops_core::Result<GraphEmbedding> {
+        let n = adjacency.len();
+        if n > 13 {
+            return Err(QopsError::validation(format!(
+                "Cannot embed {} nodes into the 13 Cube-13 positions",
+                n
+            )));
+        }
+        let mut edges = HashSet::new();
+        for (u, neighbors) in adjacency.iter().enumerate() {
+            for &v in neighbors {
+                if v >= n {
+                    return Err(QopsError::validation(format!(
+                        "Node {} has neighbour {} outside 0..{}",
+                        u, v, n
+                    )));
+                }
+                if u != v {
+                    edges.insert((u.min(v), u.max(v)));
+                }
+            }
+        }
+        let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+        edges.sort_unstable();
+
+        // Unreachable positions cost more than any path in a 13-node graph
+        let distances = self.distances();
+        let dist = |a: usize, b: usize| distances[(a, b)].min(13.0);
+        let cost = |mapping: &[usize]| -> f64 {
+            edges.iter().map(|&(u, v)| dist(mapping[u], mapping[v])).sum()
+        };
+
+        let mut order: Vec<usize> = (0..n).collect();
+        let degree = |u: usize| edges.iter().filter(|&&(a, b)| a == u || b == u).count();
+        order.sort_by_key(|&u| std::cmp::Reverse(degree(u)));
+
+        let mut position: Vec<Option<usize>> = vec![None; n];
+        let mut free: Vec<usize> = (0..13).collect();
+        for &u in &order {
+            let placement_cost = |p: usize| -> f64 {
+                edges
+                    .iter()
+                    .filter_map(|&(a, b)| match (a == u, b == u) {
+                        (true, _) => position[b],
+                        (_, true) => position[a],
+                        _ => None,
+                    })
+                    .map(|q| dist(p, q))
+                    .sum()
+            };
+            let (slot, _) = free
+                .iter()
+                .enumerate()
+                .map(|(slot, &p)| (slot, (placement_cost(p), -self.node_centrality(p))))
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+                .expect("at most 13 nodes leave a free position");
+            position[u] = Some(free.remove(slot));
+        }
+        let mut mapping: Vec<usize> = position.into_iter().flatten().collect();
+
+        // Local search over swaps between nodes and moves onto free positions
+        let mut best = cost(&mapping);
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for u in 0..n {
+                for v in u + 1..n {
+                    mapping.swap(u, v);
+                    let c = cost(&mapping);
+                    if c < best - 1e-12 {
+                        best = c;
+                        improved = true;
+                    } else {
+                        mapping.swap(u, v);
+                    }
+                }
+                for slot in free.iter_mut() {
+                    std::mem::swap(&mut mapping[u], slot);
+                    let c = cost(&mapping);
+                    if c < best - 1e-12 {
+                        best = c;
+                        improved = true;
+                    } else {
+                        std::mem::swap(&mut mapping[u], slot);
+                    }
+                }
+            }
+        }
+
+        let distortion = if edges.is_empty() {
+            0.0
+        } else {
+            best / edges.len() as f64 - 1.0
+        };
+        Ok(GraphEmbedding { mapping, distortion })
+    }
+
     /// Compute node centrality
     pub fn node_centrality(&self, node: usize) -> f64 {
         if node >= 13 {
@@ -172,7 +404,7 @@ impl Cube13Engine {
         // Closeness centrality
         let total_dist: f64 = (0..13)
             .filter(|&i| i != node)
-            .map(|i| self.distance_matrix[(node, i)])
+            .map(|i| self.distances()[(node, i)])
             .filter(|&d| d < f64::INFINITY)
             .sum();
 
@@ -205,7 +437,11 @@ impl Cube13Engine {
     pub fn extract_operator_families(&self, threshold: f64) -> Vec<OperatorCluster> {
         let mut clusters: Vec<OperatorCluster> = Vec::new();
 
-        for (node, sig) in &self.embeddings {
+        // Visit nodes in index order so clustering is reproducible
+        let mut embeddings: Vec<_> = self.embeddings.iter().collect();
+        embeddings.sort_by_key(|(node, _)| **node);
+
+        for (node, sig) in embeddings {
             let mut found = false;
 
             for cluster in &mut clusters {
@@ -226,6 +462,21 @@ impl Cube13Engine {
         clusters
     }
 
+    /// Summarize the operator landscape, largest clusters first
+    ///
+    /// Uses [`DEFAULT_CLUSTER_THRESHOLD`]; call
+    /// [`extract_operator_families`](Self::extract_operator_families) for a
+    /// custom threshold.
+    pub fn clusters_summary(&self) -> Vec<ClusterSummary> {
+        let mut summaries: Vec<ClusterSummary> = self
+            .extract_operator_families(DEFAULT_CLUSTER_THRESHOLD)
+            .iter()
+            .filter_map(ClusterSummary::from_cluster)
+            .collect();
+        summaries.sort_by_key(|s| std::cmp::Reverse(s.size));
+        summaries
+    }
+
     /// Run topology walk
     pub fn topology_walk(&self, start: usize, steps: usize) -> TopologyWalkResult {
         let mut path = vec![start];
@@ -308,6 +559,11 @@ impl Cube13Engine {
             coherence,
             embedding_count: self.embeddings.len(),
             coverage: self.embeddings.len() as f64 / 13.0,
+            degree_distribution: self.structure.degree_distribution.clone(),
+            avg_clustering: self.structure.avg_clustering(),
+            component_count: self.structure.component_count,
+            spectral_gap: self.spectral_gap(),
+            mixing_time: self.mixing_time(DEFAULT_MIXING_EPSILON),
         }
     }
 
@@ -336,6 +592,69 @@ pub struct TopologyWalkResult {
     pub steps_taken: usize,
 }
 
+impl TopologyWalkResult {
+    /// Node visit counts over a sliding window ending at each path position
+    ///
+    /// Entry `t` counts visits among positions `t + 1 - window ..= t`; a window
+    /// of at least `path.len()` gives cumulative totals.
+    pub fn windowed_visits(&self, window: usize) -> Vec<[usize; 13]> {
+        let window = window.max(1);
+        let mut counts = [0usize; 13];
+        let mut out = Vec::with_capacity(self.path.len());
+
+        for (t, &node) in self.path.iter().enumerate() {
+            counts[node] += 1;
+            if t >= window {
+                counts[self.path[t - window]] -= 1;
+            }
+            out.push(counts);
+        }
+        out
+    }
+
+    /// Fraction of windowed visits in each layer at every path position
+    pub fn layer_occupancy(&self, window: usize) -> Vec<LayerOccupancy> {
+        self.windowed_visits(window)
+            .iter()
+            .enumerate()
+            .map(|(step, counts)| {
+                let total = counts.iter().sum::<usize>().max(1) as f64;
+                let layer = |t: Cube13NodeType| {
+                    t.indices().iter().map(|&i| counts[i]).sum::<usize>() as f64 / total
+                };
+                LayerOccupancy {
+                    step,
+                    center: layer(Cube13NodeType::Center),
+                    hexagon: layer(Cube13NodeType::Hexagon),
+                    cube: layer(Cube13NodeType::Cube),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Placement of an input graph on the Cube-13 positions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphEmbedding {
+    /// Metatron position of each input node
+    pub mapping: Vec<usize>,
+    /// Mean Metatron distance over input edges minus one (0 = every edge preserved)
+    pub distortion: f64,
+}
+
+/// Per-layer occupancy of a walk at one path position
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LayerOccupancy {
+    /// Path position (0 = start node)
+    pub step: usize,
+    /// Fraction of windowed visits at the center node
+    pub center: f64,
+    /// Fraction of windowed visits on the hexagon
+    pub hexagon: f64,
+    /// Fraction of windowed visits on the outer cube
+    pub cube: f64,
+}
+
 /// Metrics for topology analysis
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TopologyMetrics {
@@ -353,6 +672,193 @@ pub struct TopologyMetrics {
     pub embedding_count: usize,
     /// Coverage (fraction of nodes with embeddings)
     pub coverage: f64,
+    /// Number of nodes per degree (index = degree)
+    #[serde(default)]
+    pub degree_distribution: Vec<usize>,
+    /// Average local clustering coefficient
+    #[serde(default)]
+    pub avg_clustering: f64,
+    /// Number of connected components
+    #[serde(default)]
+    pub component_count: usize,
+    /// Hamiltonian spectral gap E₁ - E₀
+    #[serde(default)]
+    pub spectral_gap: f64,
+    /// Random walk mixing time at [`DEFAULT_MIXING_EPSILON`] (`None` if disconnected)
+    #[serde(default)]
+    pub mixing_time: Option<f64>,
+}
+
+/// Structural graph metrics, maintained incrementally under edge edits
+///
+/// Component labels are the smallest node index in each component, so an
+/// incrementally maintained instance compares equal to a fresh
+/// [`StructuralMetrics::compute`] of the same graph.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuralMetrics {
+    /// Degree of each node
+    pub degrees: Vec<usize>,
+    /// Number of nodes per degree (index = degree)
+    pub degree_distribution: Vec<usize>,
+    /// Triangles through each node
+    pub triangles: Vec<usize>,
+    /// Local clustering coefficient of each node
+    pub clustering: Vec<f64>,
+    /// Component label of each node
+    pub components: Vec<usize>,
+    /// Number of connected components
+    pub component_count: usize,
+}
+
+impl StructuralMetrics {
+    /// Compute all metrics from scratch
+    pub fn compute(graph: &MetatronGraph) -> Self {
+        let n = graph.node_count();
+        let degrees: Vec<usize> = (0..n).map(|i| graph.node_degree(i)).collect();
+
+        let triangles = (0..n)
+            .map(|i| {
+                let neighbors = graph.neighbors(&i);
+                let mut count = 0;
+                for (x, &j) in neighbors.iter().enumerate() {
+                    for &k in &neighbors[x + 1..] {
+                        if graph.has_edge(&j, &k) {
+                            count += 1;
+                        }
+                    }
+                }
+                count
+            })
+            .collect();
+
+        let mut metrics = Self {
+            degree_distribution: vec![0; n],
+            clustering: vec![0.0; n],
+            components: vec![usize::MAX; n],
+            component_count: 0,
+            degrees,
+            triangles,
+        };
+
+        for i in 0..n {
+            metrics.degree_distribution[metrics.degrees[i]] += 1;
+            metrics.update_clustering(i);
+        }
+
+        for i in 0..n {
+            if metrics.components[i] == usize::MAX {
+                for node in Self::reachable(graph, i, None) {
+                    metrics.components[node] = i;
+                }
+                metrics.component_count += 1;
+            }
+        }
+
+        metrics
+    }
+
+    /// Update after the edge `a—b` has been added to `graph`
+    pub fn edge_added(&mut self, graph: &MetatronGraph, a: usize, b: usize) {
+        self.shift_degree(a, true);
+        self.shift_degree(b, true);
+
+        for c in Self::common_neighbors(graph, a, b) {
+            self.triangles[a] += 1;
+            self.triangles[b] += 1;
+            self.triangles[c] += 1;
+            self.update_clustering(c);
+        }
+        self.update_clustering(a);
+        self.update_clustering(b);
+
+        let (la, lb) = (self.components[a], self.components[b]);
+        if la != lb {
+            // Relabel only the component with the larger label
+            let (keep, start) = if la < lb { (la, b) } else { (lb, a) };
+            for node in Self::reachable(graph, start, Some((a, b))) {
+                self.components[node] = keep;
+            }
+            self.component_count -= 1;
+        }
+    }
+
+    /// Update after the edge `a—b` has been removed from `graph`
+    pub fn edge_removed(&mut self, graph: &MetatronGraph, a: usize, b: usize) {
+        self.shift_degree(a, false);
+        self.shift_degree(b, false);
+
+        for c in Self::common_neighbors(graph, a, b) {
+            self.triangles[a] -= 1;
+            self.triangles[b] -= 1;
+            self.triangles[c] -= 1;
+            self.update_clustering(c);
+        }
+        self.update_clustering(a);
+        self.update_clustering(b);
+
+        let side_a = Self::reachable(graph, a, None);
+        if !side_a.contains(&b) {
+            let side_b = Self::reachable(graph, b, None);
+            for side in [side_a, side_b] {
+                let label = *side.iter().min().unwrap();
+                for node in side {
+                    self.components[node] = label;
+                }
+            }
+            self.component_count += 1;
+        }
+    }
+
+    /// Average local clustering coefficient
+    pub fn avg_clustering(&self) -> f64 {
+        if self.clustering.is_empty() {
+            return 0.0;
+        }
+        self.clustering.iter().sum::<f64>() / self.clustering.len() as f64
+    }
+
+    fn shift_degree(&mut self, node: usize, up: bool) {
+        self.degree_distribution[self.degrees[node]] -= 1;
+        if up {
+            self.degrees[node] += 1;
+        } else {
+            self.degrees[node] -= 1;
+        }
+        self.degree_distribution[self.degrees[node]] += 1;
+    }
+
+    fn update_clustering(&mut self, node: usize) {
+        let d = self.degrees[node];
+        self.clustering[node] = if d < 2 {
+            0.0
+        } else {
+            2.0 * self.triangles[node] as f64 / (d * (d - 1)) as f64
+        };
+    }
+
+    fn common_neighbors(graph: &MetatronGraph, a: usize, b: usize) -> Vec<usize> {
+        graph.neighbors(&a)
+            .into_iter()
+            .filter(|&c| c != b && graph.has_edge(&b, &c))
+            .collect()
+    }
+
+    /// Nodes reachable from `start`, optionally ignoring one edge
+    fn reachable(graph: &MetatronGraph, start: usize, skip: Option<(usize, usize)>) -> Vec<usize> {
+        let mut seen = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for next in graph.neighbors(&node) {
+                let skipped = skip.is_some_and(|(a, b)| {
+                    (node == a && next == b) || (node == b && next == a)
+                });
+                if !skipped && seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        seen.into_iter().collect()
+    }
 }
 
 /// Cluster of operators in topology
@@ -438,6 +944,61 @@ impl OperatorCluster {
             .map(|(_, s)| resonance_5d(s))
             .sum::<f64>() / self.members.len() as f64
     }
+
+    /// Medoid member: the one with least total distance to the others
+    pub fn representative(&self) -> Option<NodeSignature<usize>> {
+        self.members.iter()
+            .map(|(node, sig)| {
+                let total: f64 = self.members.iter()
+                    .map(|(_, other)| Self::signature_distance(sig, other))
+                    .sum();
+                (node, sig, total)
+            })
+            .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
+            .map(|(node, sig, _)| NodeSignature::new(*node, Signature::D5(*sig)))
+    }
+
+    /// Cohesion in (0, 1]: `1 / (1 + mean distance to centroid)`
+    pub fn cohesion(&self) -> f64 {
+        let Some(centroid) = &self.centroid else {
+            return 0.0;
+        };
+        let mean = self.members.iter()
+            .map(|(_, s)| Self::signature_distance(s, centroid))
+            .sum::<f64>() / self.members.len() as f64;
+        1.0 / (1.0 + mean)
+    }
+}
+
+/// Compact description of an operator cluster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterSummary {
+    /// Cluster ID
+    pub id: String,
+    /// Number of members
+    pub size: usize,
+    /// Cohesion (1 = identical members)
+    pub cohesion: f64,
+    /// Average member resonance
+    pub avg_resonance: f64,
+    /// Member node indices
+    pub nodes: Vec<usize>,
+    /// Medoid member
+    pub representative: NodeSignature<usize>,
+}
+
+impl ClusterSummary {
+    /// Summarize a cluster, or `None` if it is empty
+    pub fn from_cluster(cluster: &OperatorCluster) -> Option<Self> {
+        Some(Self {
+            id: cluster.id.clone(),
+            size: cluster.members.len(),
+            cohesion: cluster.cohesion(),
+            avg_resonance: cluster.avg_resonance(),
+            nodes: cluster.members.iter().map(|(node, _)| *node).collect(),
+            representative: cluster.representative()?,
+        })
+    }
 }
 
 impl Default for OperatorCluster {
@@ -510,6 +1071,16 @@ impl TopologyExplorer {
         self.engine.compute_metrics()
     }
 
+    /// Add an edge to the explored topology
+    pub fn add_edge(&mut self, a: usize, b: usize) -> bool {
+        self.engine.add_edge(a, b)
+    }
+
+    /// Remove an edge from the explored topology
+    pub fn remove_edge(&mut self, a: usize, b: usize) -> bool {
+        self.engine.remove_edge(a, b)
+    }
+
     /// Reset to center
     pub fn reset(&mut self) {
         self.current_node = 0;
@@ -526,6 +1097,7 @@ impl Default for TopologyExplorer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_relative_eq;
 
     #[test]
     fn test_cube13_engine() {
@@ -563,6 +1135,150 @@ mod tests {
         assert!(result.coverage > 0.0);
     }
 
+    #[test]
+    fn test_incremental_structure_matches_recompute() {
+        let mut engine = Cube13Engine::new();
+        assert_eq!(engine.structural_metrics().component_count, 1);
+
+        let edits = [
+            (true, 0, 7),
+            (true, 7, 9),
+            (true, 3, 10),
+            (false, 1, 2),
+            (false, 0, 7),
+            // Isolate node 7
+            (false, 1, 7),
+            (false, 7, 8),
+            (false, 12, 7),
+            (false, 7, 9),
+            (true, 7, 12),
+        ];
+
+        for (add, a, b) in edits {
+            let changed = if add { engine.add_edge(a, b) } else { engine.remove_edge(a, b) };
+            assert!(changed);
+            assert_eq!(
+                engine.structural_metrics(),
+                &StructuralMetrics::compute(engine.graph())
+            );
+            // Incrementally updated distances match a fresh engine on the same graph
+            assert_eq!(engine.distances(), &Cube13Engine::compute_distances(engine.graph()));
+        }
+
+        // Removing 7-9 split off node 7; reconnecting it merged it back
+        assert_eq!(engine.structural_metrics().component_count, 1);
+        assert!(!engine.add_edge(7, 12));
+    }
+
+    #[test]
+    fn test_embed_graph() {
+        let engine = Cube13Engine::new();
+
+        // A 6-cycle fits the hexagon (or the outer ring) exactly
+        let cycle: Vec<Vec<usize>> = (0..6).map(|i| vec![(i + 1) % 6, (i + 5) % 6]).collect();
+        let embedding = engine.embed_graph(&cycle).unwrap();
+        assert_eq!(embedding.distortion, 0.0);
+        let distinct: HashSet<usize> = embedding.mapping.iter().copied().collect();
+        assert_eq!(distinct.len(), 6);
+
+        // The Metatron graph embeds into itself without distortion
+        let metatron: Vec<Vec<usize>> = (0..13).map(|i| engine.graph().neighbors(&i)).collect();
+        assert_eq!(engine.embed_graph(&metatron).unwrap().distortion, 0.0);
+
+        // K4 has no counterpart, so some edge must stretch
+        let k4: Vec<Vec<usize>> = (0..4).map(|i| (0..4).filter(|&j| j != i).collect()).collect();
+        let embedding = engine.embed_graph(&k4).unwrap();
+        assert!(embedding.distortion > 0.0);
+        for (u, neighbors) in k4.iter().enumerate() {
+            for &v in neighbors {
+                assert!(engine.distance(embedding.mapping[u], embedding.mapping[v]) <= 2.0);
+            }
+        }
+
+        assert!(engine.embed_graph(&vec![Vec::new(); 14]).is_err());
+        assert!(engine.embed_graph(&[vec![1], vec![2]]).is_err());
+        assert!(engine.embed_graph(&[]).unwrap().mapping.is_empty());
+    }
+
+    #[test]
+    fn test_mixing_time_and_spectral_cache() {
+        let mut engine = Cube13Engine::new();
+        let gap = engine.spectral_gap();
+        assert_relative_eq!(gap, engine.hamiltonian().spectral_gap());
+
+        let t = engine.mixing_time(0.01).unwrap();
+        assert!(t > 0.0);
+        assert!(engine.mixing_time(0.1).unwrap() < t);
+
+        // Lower bound from the Laplacian's algebraic connectivity λ₂
+        let eigenvalues = engine.hamiltonian().eigenvalues();
+        let lambda2 = -eigenvalues[eigenvalues.len() - 2];
+        assert!(t >= (1.0_f64 / 0.02).ln() / lambda2 - 1e-9);
+
+        let metrics = engine.compute_metrics();
+        assert_relative_eq!(metrics.spectral_gap, gap);
+        assert_relative_eq!(metrics.mixing_time.unwrap(), engine.mixing_time(DEFAULT_MIXING_EPSILON).unwrap());
+
+        // Edits invalidate the cached decomposition; fewer edges mix more slowly
+        for b in [1, 2, 3, 4, 5] {
+            assert!(engine.remove_edge(0, b));
+        }
+        assert!(engine.mixing_time(0.01).unwrap() > t);
+        assert!(engine.mixing_time(0.0).is_none());
+
+        // Dropping the center's last edge disconnects it
+        assert!(engine.remove_edge(0, 6));
+        assert!(engine.mixing_time(0.01).is_none());
+    }
+
+    #[test]
+    fn test_clusters_summary() {
+        let mut engine = Cube13Engine::new();
+        engine.embed(0, Signature5D::new(0.80, 0.70, 0.60, 0.5, 0.2));
+        engine.embed(1, Signature5D::new(0.82, 0.70, 0.60, 0.5, 0.2));
+        engine.embed(2, Signature5D::new(0.84, 0.70, 0.60, 0.5, 0.2));
+        engine.embed(9, Signature5D::new(0.10, 0.20, 0.30, 0.5, 0.9));
+
+        let summary = engine.clusters_summary();
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].size, 3);
+        assert_eq!(summary[0].nodes, vec![0, 1, 2]);
+        // The middle member is the medoid
+        assert_eq!(summary[0].representative.id, 1);
+        assert!(summary[0].cohesion > 0.9 && summary[0].cohesion < 1.0);
+        assert_eq!(summary[1].representative.id, 9);
+        assert_eq!(summary[1].cohesion, 1.0);
+
+        assert!(serde_json::to_string(&summary).is_ok());
+    }
+
+    #[test]
+    fn test_windowed_walk_statistics() {
+        let walk = TopologyWalkResult {
+            path: vec![0, 1, 7, 8, 2],
+            coverage: 5.0 / 13.0,
+            final_node: 2,
+            steps_taken: 4,
+        };
+
+        let visits = walk.windowed_visits(2);
+        assert_eq!(visits.len(), 5);
+        assert_eq!(visits[2][1], 1);
+        assert_eq!(visits[2][7], 1);
+        assert_eq!(visits[2][0], 0);
+
+        let cumulative = walk.layer_occupancy(usize::MAX);
+        assert_eq!(cumulative[0].center, 1.0);
+        assert_relative_eq!(cumulative[4].hexagon, 0.4, epsilon = 1e-10);
+        assert_relative_eq!(cumulative[4].cube, 0.4, epsilon = 1e-10);
+
+        let windowed = walk.layer_occupancy(2);
+        assert_eq!(windowed[3].cube, 1.0);
+        for occ in &windowed {
+            assert_relative_eq!(occ.center + occ.hexagon + occ.cube, 1.0, epsilon = 1e-10);
+        }
+    }
+
     #[test]
     fn test_explorer() {
         let mut explorer = TopologyExplorer::new();
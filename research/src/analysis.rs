@@ -3,6 +3,7 @@
 //! Provides tools for analyzing measurement outcomes and algorithm performance.
 
 use crate::ExperimentResult;
+use qops_core::stats::normal_cdf;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -320,29 +321,6 @@ impl TTestResult {
     }
 }
 
-/// Approximate normal CDF
-fn normal_cdf(x: f64) -> f64 {
-    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
-}
-
-/// Error function approximation
-fn erf(x: f64) -> f64 {
-    let a1 =  0.254829592;
-    let a2 = -0.284496736;
-    let a3 =  1.421413741;
-    let a4 = -1.453152027;
-    let a5 =  1.061405429;
-    let p  =  0.3275911;
-
-    let sign = if x < 0.0 { -1.0 } else { 1.0 };
-    let x = x.abs();
-
-    let t = 1.0 / (1.0 + p * x);
-    let y = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-x * x).exp();
-
-    sign * y
-}
-
 /// Fidelity analysis for quantum states
 pub struct FidelityAnalysis;
 
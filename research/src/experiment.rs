@@ -297,15 +297,38 @@ impl Experiment {
     }
 
     /// Run the experiment with a callback
-    pub fn run<F>(mut self, mut f: F) -> ExperimentResult
+    pub fn run<F>(self, f: F) -> ExperimentResult
     where
         F: FnMut(&HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value>,
     {
-        self.result.status = ExperimentStatus::Running;
-        self.result.started_at = Utc::now();
+        let mut runs = Vec::with_capacity(self.config.total_runs());
+        let mut result = self.run_streaming(f, |run| runs.push(run.clone()));
+        result.runs = runs;
+        result
+    }
+
+    /// Run the experiment, handing each run to `writer` as soon as it finishes
+    ///
+    /// Runs are not kept in memory: the returned result carries the status and
+    /// timing but an empty `runs` list, so `writer` is responsible for
+    /// persisting them.
+    pub fn run_streaming<F, W>(&self, mut f: F, mut writer: W) -> ExperimentResult
+    where
+        F: FnMut(&HashMap<String, serde_json::Value>) -> HashMap<String, serde_json::Value>,
+        W: FnMut(&RunResult),
+    {
+        let mut result = ExperimentResult {
+            id: self.result.id.clone(),
+            config: self.config.clone(),
+            runs: Vec::new(),
+            started_at: Utc::now(),
+            completed_at: None,
+            status: ExperimentStatus::Running,
+        };
 
         let grid = self.config.parameter_grid();
         let mut run_index = 0;
+        let mut all_succeeded = true;
 
         for params in &grid {
             for rep in 0..self.config.repetitions {
@@ -334,19 +357,20 @@ impl Experiment {
                     error,
                 };
 
-                self.result.runs.push(run_result);
+                all_succeeded &= success;
+                writer(&run_result);
                 run_index += 1;
             }
         }
 
-        self.result.completed_at = Some(Utc::now());
-        self.result.status = if self.result.runs.iter().all(|r| r.success) {
+        result.completed_at = Some(Utc::now());
+        result.status = if all_succeeded {
             ExperimentStatus::Completed
         } else {
             ExperimentStatus::Failed
         };
 
-        self.result
+        result
     }
 
     /// Run with progress callback
@@ -450,6 +474,31 @@ mod tests {
         assert_eq!(result.status, ExperimentStatus::Completed);
     }
 
+    #[test]
+    fn test_experiment_run_streaming() {
+        let experiment = Experiment::new("stream")
+            .parameter(Parameter::new("x", vec![1, 2]))
+            .repetitions(3);
+
+        let mut seen = Vec::new();
+        let result = experiment.run_streaming(
+            |params| {
+                let x = params.get("x").unwrap().as_i64().unwrap();
+                if x == 2 {
+                    panic!("bad x");
+                }
+                HashMap::new()
+            },
+            |run| seen.push((run.index, run.success)),
+        );
+
+        assert!(result.runs.is_empty());
+        assert_eq!(result.status, ExperimentStatus::Failed);
+        assert_eq!(seen.len(), 6);
+        assert_eq!(seen.iter().map(|(i, _)| *i).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(seen.iter().filter(|(_, ok)| !ok).count(), 3);
+    }
+
     #[test]
     fn test_linspace_parameter() {
         let param = Parameter::linspace("theta", 0.0, 1.0, 5);
@@ -9,6 +9,7 @@
 //! - **Analysis**: Statistical analysis of quantum computation results
 //! - **Visualization**: Data export for plotting (JSON, CSV)
 //! - **Comparison**: Compare different algorithms and configurations
+//! - **Mining bridge**: Import Genesis mining results as experiments
 //!
 //! ## Example
 //!
@@ -39,6 +40,7 @@ pub mod comparison;
 pub mod report;
 pub mod error;
 pub mod qops_benchmarks;
+pub mod mining;
 
 pub use benchmark::{Benchmark, BenchmarkConfig, BenchmarkResult, BenchmarkSuite};
 pub use experiment::{Experiment, ExperimentConfig, ExperimentResult, Parameter};
@@ -47,12 +49,14 @@ pub use visualization::{DataExport, PlotData, ExportFormat};
 pub use comparison::{Comparison, ComparisonResult, AlgorithmComparison};
 pub use report::{Report, ReportFormat, ReportSection};
 pub use error::{ResearchError, Result};
+pub use mining::from_mining;
 pub use qops_benchmarks::{
     BenchmarkRunner, BenchmarkOutput, BenchmarkMetadata, SystemInfo,
+    BenchmarkDiff, BenchmarkChange, ChangeStatus,
     run_vqe_benchmarks, run_vqc_benchmarks, run_qaoa_benchmarks,
     run_qwalk_benchmarks, run_advanced_benchmarks, run_integration_benchmarks,
     run_cross_system_benchmarks, run_hypercube_benchmarks, run_mining_benchmarks,
-    run_topology_benchmarks, run_gui_latency_benchmarks,
+    run_topology_benchmarks, run_gui_latency_benchmarks, run_backend_comparison_benchmarks,
 };
 
 /// Module version
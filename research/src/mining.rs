@@ -0,0 +1,108 @@
+//! Bridge from Genesis mining sessions to the experiment framework
+//!
+//! Turns a [`MiningResult`] into an [`ExperimentResult`] with one run per
+//! artefact, so mining output can go through [`Analysis`](crate::Analysis),
+//! comparisons and exports like any other experiment.
+
+use crate::experiment::{ExperimentConfig, ExperimentResult, ExperimentStatus, RunResult};
+use chrono::Duration;
+use qops_genesis::MiningResult;
+use std::collections::HashMap;
+
+/// Convert a mining result into an experiment result
+///
+/// Each artefact becomes a run with the strategy as its parameter and the
+/// fields `resonance`, `psi`, `rho`, `omega`, `chi`, `eta`, `stability`,
+/// `node` and `is_mandorla` (1.0 or 0.0) as numeric data.
+pub fn from_mining(result: &MiningResult) -> ExperimentResult {
+    let strategy = serde_json::to_value(result.strategy).unwrap_or_default();
+
+    let config = ExperimentConfig::new(&format!("mining_{}", result.session_id))
+        .description("Imported Genesis mining session")
+        .tag("mining")
+        .metadata("session_id", &result.session_id)
+        .metadata("strategy", &format!("{:?}", result.strategy))
+        .metadata("best_resonance", &result.best_resonance.to_string())
+        .metadata("mandorla_count", &result.mandorla_count.to_string())
+        .metadata("families", &result.families.len().to_string())
+        .metadata("total_steps", &result.total_steps.to_string())
+        .metadata("unique_nodes", &result.stats.unique_nodes.to_string());
+
+    let runs: Vec<RunResult> = result.artefacts.iter()
+        .enumerate()
+        .map(|(index, artefact)| {
+            let sig = &artefact.signature;
+            let data: HashMap<String, serde_json::Value> = [
+                ("resonance", artefact.resonance),
+                ("psi", sig.psi),
+                ("rho", sig.rho),
+                ("omega", sig.omega),
+                ("chi", sig.chi),
+                ("eta", sig.eta),
+                ("stability", artefact.stability),
+                ("node", artefact.node.index() as f64),
+                ("is_mandorla", if artefact.is_mandorla { 1.0 } else { 0.0 }),
+            ]
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), serde_json::json!(v)))
+            .collect();
+
+            RunResult {
+                index,
+                parameters: HashMap::from([("strategy".to_string(), strategy.clone())]),
+                repetition: 0,
+                data,
+                duration_ms: 0.0,
+                timestamp: artefact.created_at,
+                success: true,
+                error: None,
+            }
+        })
+        .collect();
+
+    let started_at = runs.iter()
+        .map(|r| r.timestamp)
+        .min()
+        .unwrap_or_else(chrono::Utc::now);
+
+    ExperimentResult {
+        id: result.session_id.clone(),
+        config,
+        runs,
+        started_at,
+        completed_at: Some(started_at + Duration::milliseconds(result.duration_ms as i64)),
+        status: ExperimentStatus::Completed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Analysis;
+    use qops_genesis::{MiningConfig, MiningSession};
+
+    #[test]
+    fn test_from_mining() {
+        let config = MiningConfig {
+            seed: Some(4),
+            ..MiningConfig::quick()
+        };
+        let mining = MiningSession::new(config).mine();
+        let experiment = from_mining(&mining);
+
+        assert_eq!(experiment.runs.len(), mining.artefacts.len());
+        assert_eq!(experiment.status, ExperimentStatus::Completed);
+        assert_eq!(experiment.success_rate(), 1.0);
+
+        let summary = Analysis::from_experiment(&experiment, "resonance")
+            .summary("resonance")
+            .unwrap();
+        assert!((summary.mean - mining.stats.avg_resonance).abs() < 1e-10);
+        assert!((summary.max - mining.stats.max_resonance).abs() < 1e-10);
+
+        let mandorla: f64 = experiment.runs.iter()
+            .filter_map(|r| r.data["is_mandorla"].as_f64())
+            .sum();
+        assert_eq!(mandorla as usize, mining.mandorla_count);
+    }
+}
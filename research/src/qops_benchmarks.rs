@@ -7,7 +7,7 @@
 //! extends them with new benchmarks for the Hypercube/FUQ! capabilities.
 
 use crate::{BenchmarkConfig, Benchmark, BenchmarkSuite};
-use crate::benchmark::Measurement;
+use crate::benchmark::{BenchmarkResult, Measurement};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::Path;
@@ -130,9 +130,129 @@ impl BenchmarkOutput {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
-        let json = serde_json::to_string_pretty(self)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
-        fs::write(path, json)
+        qops_core::persist::save_json(self, path)
+    }
+
+    /// Load benchmark output from a JSON file written by [`save`](Self::save)
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        qops_core::persist::load_json(path)
+    }
+
+    /// Compare mean durations against a baseline run
+    ///
+    /// A benchmark regresses when its mean duration grew by more than
+    /// `threshold` (a fraction, e.g. `0.1` for 10%). Benchmarks are matched by
+    /// name; those present in only one output are reported as added or removed.
+    /// A candidate run that failed or recorded no measurements is reported as
+    /// failed, and a failed baseline run counts as no baseline at all.
+    pub fn compare_to(&self, baseline: &BenchmarkOutput, threshold: f64) -> BenchmarkDiff {
+        let mean_ms = |r: &BenchmarkResult| {
+            (r.success && !r.measurements.is_empty()).then(|| r.mean_duration().as_secs_f64() * 1000.0)
+        };
+        fn find<'a>(output: &'a BenchmarkOutput, name: &str) -> Option<&'a BenchmarkResult> {
+            output.suite.results.iter().find(|r| r.config.name == name)
+        }
+
+        let mut changes: Vec<BenchmarkChange> = self.suite.results.iter()
+            .map(|r| match mean_ms(r) {
+                Some(ms) => BenchmarkChange::new(
+                    &r.config.name, find(baseline, &r.config.name).and_then(mean_ms), Some(ms), threshold),
+                None => BenchmarkChange::failed(&r.config.name, find(baseline, &r.config.name).and_then(mean_ms)),
+            })
+            .collect();
+        changes.extend(baseline.suite.results.iter()
+            .filter(|r| find(self, &r.config.name).is_none())
+            .map(|r| BenchmarkChange::new(&r.config.name, mean_ms(r), None, threshold)));
+
+        BenchmarkDiff { threshold, changes }
+    }
+}
+
+// =============================================================================
+// Benchmark Diffing
+// =============================================================================
+
+/// How a benchmark changed between two runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChangeStatus {
+    /// Faster by more than the threshold
+    Improved,
+    /// Within the threshold
+    Unchanged,
+    /// Slower by more than the threshold
+    Regressed,
+    /// Only present in the candidate, or the baseline run failed
+    Added,
+    /// Only present in the baseline
+    Removed,
+    /// Candidate run failed or recorded no measurements
+    Failed,
+}
+
+/// Change of a single benchmark's mean duration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkChange {
+    pub name: String,
+    pub baseline_ms: Option<f64>,
+    pub candidate_ms: Option<f64>,
+    /// Relative change `(candidate - baseline) / baseline`, if both exist
+    pub change: Option<f64>,
+    pub status: ChangeStatus,
+}
+
+impl BenchmarkChange {
+    fn new(name: &str, baseline_ms: Option<f64>, candidate_ms: Option<f64>, threshold: f64) -> Self {
+        let change = match (baseline_ms, candidate_ms) {
+            (Some(b), Some(c)) if b > 0.0 => Some((c - b) / b),
+            (Some(_), Some(c)) => Some(if c > 0.0 { f64::INFINITY } else { 0.0 }),
+            _ => None,
+        };
+        let status = match (baseline_ms, candidate_ms, change) {
+            (None, _, _) => ChangeStatus::Added,
+            (_, None, _) => ChangeStatus::Removed,
+            (_, _, Some(c)) if c > threshold => ChangeStatus::Regressed,
+            (_, _, Some(c)) if c < -threshold => ChangeStatus::Improved,
+            _ => ChangeStatus::Unchanged,
+        };
+        Self {
+            name: name.to_string(),
+            baseline_ms,
+            candidate_ms,
+            change,
+            status,
+        }
+    }
+
+    fn failed(name: &str, baseline_ms: Option<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            baseline_ms,
+            candidate_ms: None,
+            change: None,
+            status: ChangeStatus::Failed,
+        }
+    }
+}
+
+/// Per-benchmark comparison of a candidate run against a baseline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkDiff {
+    /// Regression threshold as a fraction
+    pub threshold: f64,
+    /// Changes in candidate order, followed by removed benchmarks
+    pub changes: Vec<BenchmarkChange>,
+}
+
+impl BenchmarkDiff {
+    /// Benchmarks that regressed beyond the threshold or failed outright
+    pub fn regressions(&self) -> impl Iterator<Item = &BenchmarkChange> {
+        self.changes.iter()
+            .filter(|c| matches!(c.status, ChangeStatus::Regressed | ChangeStatus::Failed))
+    }
+
+    /// Whether any benchmark regressed beyond the threshold or failed
+    pub fn has_regressions(&self) -> bool {
+        self.regressions().next().is_some()
     }
 }
 
@@ -474,10 +594,9 @@ pub fn run_qwalk_benchmarks(config: QWalkBenchConfig) -> BenchmarkOutput {
 
                 // Compute mixing metrics
                 let probs = evolved.probabilities();
-                let uniform = 1.0 / probs.len() as f64;
-                let tvd: f64 = probs.iter()
-                    .map(|p| (p - uniform).abs())
-                    .sum::<f64>() / 2.0;
+                let uniform = vec![1.0 / probs.len() as f64; probs.len()];
+                let tvd = qops_circuits::total_variation_distance(&probs, &uniform)
+                    .expect("distributions share the graph dimension");
 
                 // Hitting time metric (probability at target)
                 let target = if size <= probs.len() { size - 1 } else { probs.len() - 1 };
@@ -965,7 +1084,7 @@ pub fn run_hypercube_benchmarks(config: HypercubeBenchConfig) -> BenchmarkOutput
             let seed = Coord5D::center();
             let hdag = HDAG::standard_pipeline(seed);
 
-            let mut executor = HDAGExecutor::new(hdag);
+            let mut executor = HDAGExecutor::new(hdag).unwrap();
             let exec_result = executor.execute().unwrap();
 
             Measurement::new(start.elapsed())
@@ -1087,6 +1206,47 @@ pub fn run_mining_benchmarks(config: MiningBenchConfig) -> BenchmarkOutput {
         }
     }
 
+    // Per-step neighbor lookup on the MetatronCube: graph edge walk vs cached adjacency
+    {
+        use qops_core::ResonanceTopology;
+        use qops_genesis::MetatronCube;
+
+        let cube = MetatronCube::new();
+        cube.adjacency();
+        let nodes = cube.nodes();
+        let sweeps = if config.small_mode { 10 } else { 100 };
+        let lookups = (sweeps * nodes.len()) as f64;
+
+        for method in ["graph", "cached"] {
+            let bench_config = BenchmarkConfig::new(&format!("metatron_neighbors_{}", method))
+                .repetitions(config.repetitions)
+                .warmup(1)
+                .param("method", method)
+                .param("nodes", nodes.len());
+
+            let result = Benchmark::new(bench_config).run(|| {
+                let start = Instant::now();
+
+                let mut visited = 0usize;
+                for _ in 0..sweeps {
+                    for node in &nodes {
+                        visited += match method {
+                            "graph" => cube.neighbors(node).len(),
+                            _ => cube.adjacency()[node.index()].len(),
+                        };
+                    }
+                }
+
+                let elapsed = start.elapsed();
+                Measurement::new(elapsed)
+                    .with_metric("ns_per_step", elapsed.as_nanos() as f64 / lookups)
+                    .with_metric("neighbors_visited", visited as f64)
+            });
+
+            suite.add_result(result);
+        }
+    }
+
     BenchmarkOutput::new("mining", suite)
 }
 
@@ -1257,6 +1417,27 @@ pub fn run_topology_benchmarks(config: TopologyBenchConfig) -> BenchmarkOutput {
         suite.add_result(result);
     }
 
+    // Spectral metrics of the Metatron graph itself
+    let bench_config = BenchmarkConfig::new("metatron_spectral")
+        .repetitions(config.repetitions)
+        .warmup(1);
+
+    let benchmark = Benchmark::new(bench_config);
+
+    let result = benchmark.run(|| {
+        let start = Instant::now();
+
+        let engine = qops_quantum::Cube13Engine::new();
+        let spectral_gap = engine.spectral_gap();
+        let mixing_time = engine.mixing_time(qops_quantum::DEFAULT_MIXING_EPSILON);
+
+        Measurement::new(start.elapsed())
+            .with_metric("spectral_gap", spectral_gap)
+            .with_metric("mixing_time", mixing_time.unwrap_or(f64::INFINITY))
+    });
+
+    suite.add_result(result);
+
     BenchmarkOutput::new("topology", suite)
 }
 
@@ -1373,6 +1554,167 @@ pub fn run_gui_latency_benchmarks(config: GuiLatencyBenchConfig) -> BenchmarkOut
     BenchmarkOutput::new("gui_latency", suite)
 }
 
+// =============================================================================
+// Backend Comparison Benchmarks
+// =============================================================================
+
+/// Simulation backends known to the comparison benchmark
+///
+/// `dense` evolves a state vector, `density` a full density matrix through
+/// [`QuantumRegister::new_density`](qops_circuits::QuantumRegister::new_density).
+pub const SIMULATION_BACKENDS: &[&str] = &["dense", "density"];
+
+/// Largest register the `density` backend is benchmarked on
+///
+/// Density-matrix gates are full 4^n-element matrix products, so larger
+/// registers are skipped rather than dominating the run.
+pub const MAX_DENSITY_BENCH_QUBITS: usize = 6;
+
+/// Backend comparison benchmark configuration
+#[derive(Debug, Clone)]
+pub struct BackendBenchConfig {
+    pub qubits: Vec<usize>,
+    pub circuits: Vec<String>,
+    pub backends: Vec<String>,
+    pub repetitions: usize,
+    pub seed: u64,
+    pub small_mode: bool,
+}
+
+impl Default for BackendBenchConfig {
+    fn default() -> Self {
+        Self {
+            qubits: vec![2, 4, 6, 8, 10],
+            circuits: vec!["qft".to_string(), "grover".to_string(), "random".to_string()],
+            backends: SIMULATION_BACKENDS.iter().map(|b| b.to_string()).collect(),
+            repetitions: 5,
+            seed: 42,
+            small_mode: false,
+        }
+    }
+}
+
+impl BackendBenchConfig {
+    pub fn small() -> Self {
+        Self {
+            qubits: vec![2, 3],
+            repetitions: 2,
+            small_mode: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// Circuit family used by the backend comparison
+fn backend_bench_circuit(kind: &str, n: usize, seed: u64) -> Option<qops_circuits::Circuit> {
+    use qops_algorithms::{Grover, Oracle};
+    use qops_circuits::Circuit;
+    use rand::{Rng, SeedableRng};
+
+    match kind {
+        "qft" => Some(Circuit::qft(n)),
+        "grover" => Some(Grover::new(n, Oracle::marked_state(n, 0)).build_circuit()),
+        "random" => {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(seed ^ n as u64);
+            let mut circuit = Circuit::new(n);
+            for _ in 0..n {
+                for q in 0..n {
+                    circuit = match rng.gen_range(0..3) {
+                        0 => circuit.h(q),
+                        1 => circuit.t(q),
+                        _ => circuit.rz(rng.gen_range(0.0..std::f64::consts::TAU), q),
+                    };
+                }
+                for q in 0..n.saturating_sub(1) {
+                    if rng.gen_bool(0.5) {
+                        circuit = circuit.cnot(q, q + 1);
+                    }
+                }
+            }
+            Some(circuit)
+        }
+        _ => None,
+    }
+}
+
+/// Run backend comparison benchmarks
+///
+/// Every circuit runs on every requested backend at every qubit count.
+/// Unknown backends and `density` runs above [`MAX_DENSITY_BENCH_QUBITS`]
+/// are skipped and listed in the suite metadata under `skipped.<name>`
+/// instead of failing the run. A simulation error marks that benchmark
+/// result as failed with the error message and no measurements.
+pub fn run_backend_comparison_benchmarks(config: BackendBenchConfig) -> BenchmarkOutput {
+    use qops_circuits::{Complex, QuantumRegister};
+
+    let mut suite = BenchmarkSuite::new("Backend Comparison Benchmark Suite")
+        .description("Time and memory of simulation backends across circuits and qubit counts");
+
+    for backend in &config.backends {
+        let density = match backend.as_str() {
+            "dense" => false,
+            "density" => true,
+            _ => {
+                suite.metadata.insert(format!("skipped.{}", backend), "unknown backend".to_string());
+                continue;
+            }
+        };
+
+        for kind in &config.circuits {
+            for &n in &config.qubits {
+                let Some(circuit) = backend_bench_circuit(kind, n, config.seed) else {
+                    suite.metadata.insert(format!("skipped.{}", kind), "unknown circuit".to_string());
+                    continue;
+                };
+                if density && n > MAX_DENSITY_BENCH_QUBITS {
+                    suite.metadata.insert(
+                        format!("skipped.{}_{}q", backend, n),
+                        format!("density backend is limited to {} qubits", MAX_DENSITY_BENCH_QUBITS),
+                    );
+                    continue;
+                }
+                let (new_register, state_len): (fn(usize) -> QuantumRegister, usize) = if density {
+                    (QuantumRegister::new_density, 1usize << (2 * n))
+                } else {
+                    (QuantumRegister::new, 1usize << n)
+                };
+
+                let bench_config = BenchmarkConfig::new(&format!("{}_{}_{}q", backend, kind, n))
+                    .repetitions(config.repetitions)
+                    .warmup(1)
+                    .param("backend", backend.clone())
+                    .param("circuit", kind.clone())
+                    .param("qubits", n);
+
+                let mut error = None;
+                let mut result = Benchmark::new(bench_config).run(|| {
+                    let start = Instant::now();
+
+                    let mut reg = new_register(n);
+                    if let Err(e) = reg.apply_circuit(&circuit) {
+                        error.get_or_insert_with(|| e.to_string());
+                    }
+
+                    let mut measurement = Measurement::new(start.elapsed())
+                        .with_gate_count(circuit.gate_count())
+                        .with_depth(circuit.depth());
+                    measurement.memory_bytes = Some(state_len * std::mem::size_of::<Complex>());
+                    measurement
+                });
+                if let Some(e) = error {
+                    result.success = false;
+                    result.error = Some(e);
+                    result.measurements.clear();
+                }
+
+                suite.add_result(result);
+            }
+        }
+    }
+
+    BenchmarkOutput::new("backend_comparison", suite)
+}
+
 // =============================================================================
 // Benchmark Runner
 // =============================================================================
@@ -1393,6 +1735,12 @@ impl Default for BenchmarkRunner {
 }
 
 impl BenchmarkRunner {
+    /// Suite names accepted by [`BenchmarkRunner::run_suite`]
+    pub const SUITE_NAMES: &'static [&'static str] = &[
+        "vqe", "vqc", "qaoa", "qwalk", "advanced", "integration",
+        "cross", "hypercube", "mining", "topology", "gui-latency", "backends",
+    ];
+
     pub fn new(output_dir: &str, small_mode: bool) -> Self {
         Self {
             output_dir: output_dir.to_string(),
@@ -1488,6 +1836,14 @@ impl BenchmarkRunner {
         Ok(output)
     }
 
+    pub fn run_backends(&self) -> std::io::Result<BenchmarkOutput> {
+        let config = if self.small_mode { BackendBenchConfig::small() } else { BackendBenchConfig::default() };
+        let output = run_backend_comparison_benchmarks(config);
+        let path = Path::new(&self.output_dir).join("backend_comparison_bench.json");
+        output.save(&path)?;
+        Ok(output)
+    }
+
     pub fn run_all(&self) -> std::io::Result<Vec<BenchmarkOutput>> {
         let mut outputs = Vec::new();
         outputs.push(self.run_vqe()?);
@@ -1501,9 +1857,56 @@ impl BenchmarkRunner {
         outputs.push(self.run_mining()?);
         outputs.push(self.run_topology()?);
         outputs.push(self.run_gui_latency()?);
+        outputs.push(self.run_backends()?);
         Ok(outputs)
     }
 
+    /// Canonical suite name for a user-supplied name or alias
+    fn suite_key(name: &str) -> Option<&'static str> {
+        let normalized = name.trim().to_lowercase().replace('_', "-");
+        let key = match normalized.as_str() {
+            "quantum-walk" => "qwalk",
+            other => other,
+        };
+        Self::SUITE_NAMES.iter().copied().find(|s| *s == key)
+    }
+
+    fn unknown_suite(name: &str) -> std::io::Error {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            format!("unknown benchmark suite '{}' (expected one of: {})", name, Self::SUITE_NAMES.join(", ")),
+        )
+    }
+
+    /// Run a single suite by name (see [`BenchmarkRunner::SUITE_NAMES`])
+    pub fn run_suite(&self, name: &str) -> std::io::Result<BenchmarkOutput> {
+        match Self::suite_key(name) {
+            Some("vqe") => self.run_vqe(),
+            Some("vqc") => self.run_vqc(),
+            Some("qaoa") => self.run_qaoa(),
+            Some("qwalk") => self.run_qwalk(),
+            Some("advanced") => self.run_advanced(),
+            Some("integration") => self.run_integration(),
+            Some("cross") => self.run_cross(),
+            Some("hypercube") => self.run_hypercube(),
+            Some("mining") => self.run_mining(),
+            Some("topology") => self.run_topology(),
+            Some("gui-latency") => self.run_gui_latency(),
+            Some("backends") => self.run_backends(),
+            _ => Err(Self::unknown_suite(name)),
+        }
+    }
+
+    /// Run the named suites in order
+    ///
+    /// All names are validated before any suite runs.
+    pub fn run_selected(&self, names: &[String]) -> std::io::Result<Vec<BenchmarkOutput>> {
+        if let Some(bad) = names.iter().find(|n| Self::suite_key(n).is_none()) {
+            return Err(Self::unknown_suite(bad));
+        }
+        names.iter().map(|name| self.run_suite(name)).collect()
+    }
+
     pub fn run_quick(&self) -> std::io::Result<Vec<BenchmarkOutput>> {
         let mut outputs = Vec::new();
         let small_runner = BenchmarkRunner::new(&self.output_dir, true);
@@ -1526,6 +1929,91 @@ mod tests {
         assert!(!metadata.benchmark_version.is_empty());
     }
 
+    fn output_with(durations: &[(&str, u64)]) -> BenchmarkOutput {
+        let mut suite = BenchmarkSuite::new("diff");
+        for &(name, ms) in durations {
+            suite.add_result(BenchmarkResult {
+                id: name.to_string(),
+                config: BenchmarkConfig::new(name),
+                measurements: vec![Measurement::new(std::time::Duration::from_millis(ms))],
+                started_at: Utc::now(),
+                completed_at: Utc::now(),
+                success: true,
+                error: None,
+            });
+        }
+        BenchmarkOutput::new("diff", suite)
+    }
+
+    #[test]
+    fn test_compare_to() {
+        let baseline = output_with(&[("a", 100), ("b", 100), ("c", 100), ("gone", 10)]);
+        let candidate = output_with(&[("a", 105), ("b", 150), ("c", 50), ("new", 10)]);
+        let diff = candidate.compare_to(&baseline, 0.1);
+
+        let status = |name: &str| diff.changes.iter().find(|c| c.name == name).unwrap().status;
+        assert_eq!(status("a"), ChangeStatus::Unchanged);
+        assert_eq!(status("b"), ChangeStatus::Regressed);
+        assert_eq!(status("c"), ChangeStatus::Improved);
+        assert_eq!(status("new"), ChangeStatus::Added);
+        assert_eq!(status("gone"), ChangeStatus::Removed);
+        assert_eq!(diff.regressions().count(), 1);
+        assert!(!baseline.compare_to(&baseline, 0.1).has_regressions());
+    }
+
+    #[test]
+    fn test_compare_to_reports_failed_runs() {
+        let baseline = output_with(&[("a", 100), ("b", 100)]);
+        let mut candidate = output_with(&[("a", 1), ("b", 100)]);
+        let failed = &mut candidate.suite.results[0];
+        failed.success = false;
+        failed.measurements.clear();
+        failed.error = Some("crashed".to_string());
+        let diff = candidate.compare_to(&baseline, 0.1);
+        assert_eq!(diff.changes[0].status, ChangeStatus::Failed);
+        assert_eq!(diff.changes[0].change, None);
+        assert!(diff.has_regressions());
+
+        // A failed baseline leaves nothing to compare against
+        let diff = baseline.compare_to(&candidate, 0.1);
+        assert_eq!(diff.changes[0].status, ChangeStatus::Added);
+        assert!(!diff.has_regressions());
+    }
+
+    #[test]
+    fn test_run_selected_rejects_unknown_suite() {
+        let runner = BenchmarkRunner::new("bench_results", true);
+        let err = runner
+            .run_selected(&["vqe".to_string(), "nope".to_string()])
+            .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_backend_comparison_runs_every_backend() {
+        let mut config = BackendBenchConfig {
+            qubits: vec![2, 3, MAX_DENSITY_BENCH_QUBITS + 1],
+            repetitions: 1,
+            ..BackendBenchConfig::small()
+        };
+        config.backends.push("sparse".to_string());
+        let output = run_backend_comparison_benchmarks(config);
+
+        // Three circuits at three qubit counts on dense, two on density
+        assert_eq!(output.suite.results.len(), 15);
+        assert!(output.suite.results.iter().all(|r| r.success));
+        for backend in SIMULATION_BACKENDS {
+            assert!(output.suite.results.iter().any(|r| r.config.name.starts_with(backend)));
+        }
+        let memory = |name: &str| output.suite.results.iter()
+            .find(|r| r.config.name == name)
+            .and_then(|r| r.measurements[0].memory_bytes)
+            .unwrap();
+        assert_eq!(memory("density_qft_3q"), 8 * memory("dense_qft_3q"));
+        assert!(output.suite.metadata.contains_key("skipped.sparse"));
+        assert!(output.suite.metadata.contains_key(&format!("skipped.density_{}q", MAX_DENSITY_BENCH_QUBITS + 1)));
+    }
+
     #[test]
     fn test_system_info() {
         let info = SystemInfo::current();
@@ -1,7 +1,9 @@
 //! Advanced Seraphic calibration analysis and auto-tuning.
 
-use crate::calibrator::{SeraphicCalibrator, CalibratorConfig, CalibrationResult};
-use qops_core::{Configuration, Signature3D, resonance_3d};
+use crate::calibrator::{SeraphicCalibrator, CalibratorConfig, CalibrationResult, HistoryEntry};
+use qops_core::{Configuration, QopsError, Result, Signature3D, resonance_3d};
+use qops_core::stats::normal_cdf;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Sweep configuration for hyperparameter exploration
@@ -15,6 +17,9 @@ pub struct SweepConfig {
     pub steps_per_evaluation: usize,
     /// Enable parallel evaluation
     pub parallel: bool,
+    /// Worker threads for parallel evaluation (0 = one per core)
+    #[serde(default)]
+    pub parallelism: usize,
 }
 
 impl Default for SweepConfig {
@@ -24,6 +29,7 @@ impl Default for SweepConfig {
             cooling_rate_values: vec![0.9, 0.95, 0.99],
             steps_per_evaluation: 20,
             parallel: true,
+            parallelism: 0,
         }
     }
 }
@@ -75,21 +81,25 @@ impl HyperparameterSweep {
     }
 
     /// Run the sweep
+    ///
+    /// Evaluations are in grid order (temperature-major) however many
+    /// threads ran them.
     pub fn run(&mut self) -> SweepResult {
         let mut best_index = 0;
         let mut best_score = 0.0;
 
-        for (_t_idx, &temp) in self.config.temperature_values.iter().enumerate() {
-            for (_c_idx, &cooling) in self.config.cooling_rate_values.iter().enumerate() {
-                let eval = self.evaluate_config(temp, cooling);
-
-                if eval.final_score > best_score {
-                    best_score = eval.final_score;
-                    best_index = self.results.len();
-                }
+        let grid: Vec<(f64, f64)> = self.config.temperature_values.iter()
+            .flat_map(|&temp| self.config.cooling_rate_values.iter().map(move |&cooling| (temp, cooling)))
+            .collect();
+        let evaluations = self.map_evaluations(&grid, |&(temp, cooling)| self.evaluate_config(temp, cooling));
 
-                self.results.push(eval);
+        for eval in evaluations {
+            if eval.final_score > best_score {
+                best_score = eval.final_score;
+                best_index = self.results.len();
             }
+
+            self.results.push(eval);
         }
 
         SweepResult {
@@ -99,6 +109,75 @@ impl HyperparameterSweep {
         }
     }
 
+    /// Evaluate every (x, y) pair of two calibrator parameters into a grid
+    ///
+    /// All other parameters keep their defaults; each cell runs
+    /// `steps_per_evaluation` calibration steps. Both axes must name
+    /// different parameters, otherwise the y value would overwrite x.
+    pub fn run_grid(
+        &self,
+        x_param: SweepParameter,
+        x_values: &[f64],
+        y_param: SweepParameter,
+        y_values: &[f64],
+    ) -> Result<SweepGrid> {
+        if x_param == y_param {
+            return Err(QopsError::validation(format!(
+                "Sweep axes must differ, both are '{}'", x_param.name()
+            )));
+        }
+
+        let mut best = None;
+        let mut best_score = f64::NEG_INFINITY;
+
+        let cells: Vec<(f64, f64)> = y_values.iter()
+            .flat_map(|&y| x_values.iter().map(move |&x| (x, y)))
+            .collect();
+        let flat = self.map_evaluations(&cells, |&(x, y)| {
+            let mut config = CalibratorConfig::default();
+            x_param.apply(&mut config, x);
+            y_param.apply(&mut config, y);
+            self.evaluate_calibrator(config).0
+        });
+
+        let scores: Vec<Vec<f64>> = (0..y_values.len()).map(|yi| {
+            let row = &flat[yi * x_values.len()..(yi + 1) * x_values.len()];
+            for (xi, &score) in row.iter().enumerate() {
+                if score > best_score {
+                    best_score = score;
+                    best = Some((xi, yi));
+                }
+            }
+            row.to_vec()
+        }).collect();
+
+        Ok(SweepGrid {
+            x_param,
+            y_param,
+            x_values: x_values.to_vec(),
+            y_values: y_values.to_vec(),
+            scores,
+            best,
+            best_score: best.map_or(0.0, |_| best_score),
+        })
+    }
+
+    /// Apply `evaluate` to every item, in order, on up to `parallelism` threads
+    fn map_evaluations<T, R, F>(&self, items: &[T], evaluate: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&T) -> R + Sync,
+    {
+        if !self.config.parallel || self.config.parallelism == 1 {
+            return items.iter().map(evaluate).collect();
+        }
+        match rayon::ThreadPoolBuilder::new().num_threads(self.config.parallelism).build() {
+            Ok(pool) => pool.install(|| items.par_iter().map(&evaluate).collect()),
+            Err(_) => items.iter().map(evaluate).collect(),
+        }
+    }
+
     /// Evaluate a single configuration
     fn evaluate_config(&self, temperature: f64, cooling_rate: f64) -> ConfigEvaluation {
         let calibrator_config = CalibratorConfig {
@@ -107,6 +186,20 @@ impl HyperparameterSweep {
             ..Default::default()
         };
 
+        let (final_score, convergence_rate) = self.evaluate_calibrator(calibrator_config);
+
+        ConfigEvaluation {
+            config: SweepConfigSnapshot {
+                temperature,
+                cooling_rate,
+            },
+            final_score,
+            convergence_rate,
+        }
+    }
+
+    /// Run a calibrator and return (final score, convergence rate)
+    fn evaluate_calibrator(&self, calibrator_config: CalibratorConfig) -> (f64, f64) {
         let mut calibrator = SeraphicCalibrator::new(calibrator_config);
         let init_config = Configuration::new("sweep_test");
         calibrator.initialize(init_config, Signature3D::new(0.5, 0.5, 0.5));
@@ -123,17 +216,88 @@ impl HyperparameterSweep {
         let accepted = results.iter().filter(|r| r.accepted).count();
         let convergence_rate = accepted as f64 / results.len().max(1) as f64;
 
-        ConfigEvaluation {
-            config: SweepConfigSnapshot {
-                temperature,
-                cooling_rate,
-            },
-            final_score,
-            convergence_rate,
+        (final_score, convergence_rate)
+    }
+}
+
+/// Calibrator parameter that can be placed on a sweep axis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SweepParameter {
+    /// Initial annealing temperature
+    Temperature,
+    /// Temperature cooling rate
+    CoolingRate,
+    /// Mandorla zone threshold
+    MandorlaThreshold,
+    /// Proof-of-Resonance acceptance threshold
+    PorThreshold,
+    /// Double-kick update step
+    UpdateStep,
+    /// Double-kick stabilization step
+    StabilizationStep,
+}
+
+impl SweepParameter {
+    /// All sweepable parameters
+    pub const ALL: [SweepParameter; 6] = [
+        SweepParameter::Temperature,
+        SweepParameter::CoolingRate,
+        SweepParameter::MandorlaThreshold,
+        SweepParameter::PorThreshold,
+        SweepParameter::UpdateStep,
+        SweepParameter::StabilizationStep,
+    ];
+
+    /// Snake-case parameter name (matches the `CalibratorConfig` field)
+    pub fn name(&self) -> &'static str {
+        match self {
+            SweepParameter::Temperature => "temperature",
+            SweepParameter::CoolingRate => "cooling_rate",
+            SweepParameter::MandorlaThreshold => "mandorla_threshold",
+            SweepParameter::PorThreshold => "por_threshold",
+            SweepParameter::UpdateStep => "update_step",
+            SweepParameter::StabilizationStep => "stabilization_step",
+        }
+    }
+
+    /// Look up a parameter by name
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = if name == "initial_temperature" { "temperature" } else { name };
+        Self::ALL.into_iter().find(|p| p.name() == name)
+    }
+
+    /// Set this parameter on a calibrator config
+    pub fn apply(&self, config: &mut CalibratorConfig, value: f64) {
+        match self {
+            SweepParameter::Temperature => config.initial_temperature = value,
+            SweepParameter::CoolingRate => config.cooling_rate = value,
+            SweepParameter::MandorlaThreshold => config.mandorla_threshold = value,
+            SweepParameter::PorThreshold => config.por_threshold = value,
+            SweepParameter::UpdateStep => config.update_step = value,
+            SweepParameter::StabilizationStep => config.stabilization_step = value,
         }
     }
 }
 
+/// Two-parameter sweep landscape
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SweepGrid {
+    /// Parameter on the x axis (columns)
+    pub x_param: SweepParameter,
+    /// Parameter on the y axis (rows)
+    pub y_param: SweepParameter,
+    /// X axis values
+    pub x_values: Vec<f64>,
+    /// Y axis values
+    pub y_values: Vec<f64>,
+    /// Final resonance per cell, `scores[y][x]`
+    pub scores: Vec<Vec<f64>>,
+    /// (x, y) index of the best cell
+    pub best: Option<(usize, usize)>,
+    /// Best score achieved
+    pub best_score: f64,
+}
+
 /// Stability analysis for calibration results
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StabilityAnalysis {
@@ -149,6 +313,9 @@ pub struct StabilityAnalysis {
     pub stability_score: f64,
     /// Is system stable
     pub is_stable: bool,
+    /// Largest Lyapunov exponent of the performance trajectory (see [`lyapunov_exponent`])
+    #[serde(default)]
+    pub lyapunov_exponent: Option<f64>,
     /// Recommendations
     pub recommendations: Vec<String>,
 }
@@ -163,6 +330,7 @@ pub fn analyze_stability(results: &[CalibrationResult]) -> StabilityAnalysis {
             trend: 0.0,
             stability_score: 0.0,
             is_stable: false,
+            lyapunov_exponent: None,
             recommendations: vec!["No data to analyze".to_string()],
         };
     }
@@ -193,6 +361,9 @@ pub fn analyze_stability(results: &[CalibrationResult]) -> StabilityAnalysis {
 
     let is_stable = stability_score >= 0.7 && cv < 0.2;
 
+    let performance: Vec<Signature3D> = results.iter().map(|r| r.performance).collect();
+    let lyapunov = lyapunov_exponent(&performance);
+
     let mut recommendations = Vec::new();
     if cv > 0.2 {
         recommendations.push("High variance detected. Consider reducing exploration rate.".to_string());
@@ -206,6 +377,9 @@ pub fn analyze_stability(results: &[CalibrationResult]) -> StabilityAnalysis {
     if stability_score >= 0.8 {
         recommendations.push("System is stable. Consider exploitation mode.".to_string());
     }
+    if lyapunov.is_some_and(|l| l > 0.0) {
+        recommendations.push("Positive Lyapunov exponent. Reduce the Double-Kick update step.".to_string());
+    }
 
     StabilityAnalysis {
         mean_resonance: mean,
@@ -214,10 +388,58 @@ pub fn analyze_stability(results: &[CalibrationResult]) -> StabilityAnalysis {
         trend,
         stability_score,
         is_stable,
+        lyapunov_exponent: lyapunov,
         recommendations,
     }
 }
 
+/// Steps over which nearby trajectories are followed when estimating divergence
+pub const LYAPUNOV_WINDOW: usize = 5;
+
+/// Minimum step distance between two points for them to count as separate trajectories
+const LYAPUNOV_SEPARATION: usize = 3;
+
+/// Largest Lyapunov exponent of a performance trajectory, per step
+///
+/// Rosenstein's method: every point is paired with its nearest neighbour in
+/// (ψ, ρ, ω) space at least [`LYAPUNOV_SEPARATION`] steps away, both are
+/// followed for [`LYAPUNOV_WINDOW`] steps, and the exponent is the slope of
+/// the mean log distance over that window. Positive values mean nearby
+/// trajectories diverge (chaos or oscillation), negative values mean they
+/// converge. Returns `None` when the trajectory is too short or has no
+/// distinct neighbouring points.
+pub fn lyapunov_exponent(trajectory: &[Signature3D]) -> Option<f64> {
+    let usable = trajectory.len().checked_sub(LYAPUNOV_WINDOW)?;
+    let distance = |a: &Signature3D, b: &Signature3D| {
+        ((a.psi - b.psi).powi(2) + (a.rho - b.rho).powi(2) + (a.omega - b.omega).powi(2)).sqrt()
+    };
+
+    let mut log_sums = [0.0; LYAPUNOV_WINDOW + 1];
+    let mut counts = [0usize; LYAPUNOV_WINDOW + 1];
+    for i in 0..usable {
+        let neighbor = (0..usable)
+            .filter(|&j| i.abs_diff(j) > LYAPUNOV_SEPARATION)
+            .map(|j| (j, distance(&trajectory[i], &trajectory[j])))
+            .filter(|&(_, d)| d > 1e-12)
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let Some((j, _)) = neighbor else { continue };
+
+        for k in 0..=LYAPUNOV_WINDOW {
+            let d = distance(&trajectory[i + k], &trajectory[j + k]);
+            if d > 1e-12 {
+                log_sums[k] += d.ln();
+                counts[k] += 1;
+            }
+        }
+    }
+    if counts.contains(&0) {
+        return None;
+    }
+
+    let mean_logs: Vec<f64> = log_sums.iter().zip(&counts).map(|(s, &c)| s / c as f64).collect();
+    Some(compute_trend(&mean_logs))
+}
+
 fn compute_trend(values: &[f64]) -> f64 {
     if values.len() < 2 {
         return 0.0;
@@ -243,6 +465,15 @@ fn compute_trend(values: &[f64]) -> f64 {
     numerator / denominator
 }
 
+/// Sampling ranges of the tuned parameters (temperature, cooling rate, Mandorla threshold)
+const TUNE_BOUNDS: [(f64, f64); 3] = [(0.1, 5.0), (0.8, 0.999), (0.7, 0.95)];
+
+/// Random evaluations before the Bayesian surrogate takes over
+const BAYES_INITIAL_SAMPLES: usize = 5;
+
+/// Random candidates scored by the acquisition function per proposal
+const BAYES_CANDIDATES: usize = 512;
+
 /// Auto-tuning result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AutoTuneResult {
@@ -252,6 +483,50 @@ pub struct AutoTuneResult {
     pub achieved_resonance: f64,
     /// Number of iterations
     pub iterations: usize,
+    /// Search strategy used
+    #[serde(default)]
+    pub strategy: TuneStrategy,
+    /// Optimum of the surrogate's posterior mean (Bayesian strategy only)
+    #[serde(default)]
+    pub predicted_optimum: Option<SurrogateOptimum>,
+    /// Acquisition value of every surrogate-proposed candidate, in order
+    #[serde(default)]
+    pub acquisition_trajectory: Vec<f64>,
+}
+
+/// Configuration the Gaussian-process surrogate expects to score best
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurrogateOptimum {
+    /// Predicted best configuration
+    pub config: AutoTuneConfig,
+    /// Posterior mean resonance at `config`
+    pub predicted_resonance: f64,
+    /// Posterior standard deviation at `config`
+    pub uncertainty: f64,
+}
+
+/// How [`AutoTuner`] proposes the next configuration
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TuneStrategy {
+    /// Random exploration at first, then mostly perturbations of the best
+    #[default]
+    Adaptive,
+    /// Independent uniform samples
+    Random,
+    /// Lattice with `points_per_axis` values per parameter, visited in order
+    Grid { points_per_axis: usize },
+    /// Gaussian-process surrogate maximizing an acquisition function
+    Bayesian { acquisition: Acquisition },
+}
+
+/// Acquisition function for [`TuneStrategy::Bayesian`]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Acquisition {
+    /// Expected improvement over the best observed resonance
+    #[default]
+    EI,
+    /// Upper confidence bound μ + κσ
+    UCB { kappa: f64 },
 }
 
 /// Auto-tune configuration
@@ -265,6 +540,27 @@ pub struct AutoTuneConfig {
     pub mandorla_threshold: f64,
 }
 
+impl AutoTuneConfig {
+    /// Configuration at a point of the unit cube spanned by [`TUNE_BOUNDS`]
+    fn from_unit(x: [f64; 3]) -> Self {
+        let scale = |i: usize| TUNE_BOUNDS[i].0 + x[i] * (TUNE_BOUNDS[i].1 - TUNE_BOUNDS[i].0);
+        Self {
+            temperature: scale(0),
+            cooling_rate: scale(1),
+            mandorla_threshold: scale(2),
+        }
+    }
+
+    /// Position in the unit cube (clamped to [`TUNE_BOUNDS`])
+    fn to_unit(&self) -> [f64; 3] {
+        let values = [self.temperature, self.cooling_rate, self.mandorla_threshold];
+        std::array::from_fn(|i| {
+            let (lo, hi) = TUNE_BOUNDS[i];
+            ((values[i] - lo) / (hi - lo)).clamp(0.0, 1.0)
+        })
+    }
+}
+
 impl Default for AutoTuneConfig {
     fn default() -> Self {
         Self {
@@ -287,6 +583,10 @@ pub struct AutoTuner {
     history: Vec<(AutoTuneConfig, f64)>,
     /// Iteration count
     iteration: usize,
+    /// Candidate proposal strategy
+    strategy: TuneStrategy,
+    /// Acquisition values of surrogate proposals
+    acquisition_trajectory: Vec<f64>,
 }
 
 impl AutoTuner {
@@ -298,14 +598,35 @@ impl AutoTuner {
             best_score: 0.0,
             history: Vec::new(),
             iteration: 0,
+            strategy: TuneStrategy::default(),
+            acquisition_trajectory: Vec::new(),
         }
     }
 
+    /// Use a different candidate proposal strategy
+    pub fn with_strategy(mut self, strategy: TuneStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Candidate proposal strategy
+    pub fn strategy(&self) -> TuneStrategy {
+        self.strategy
+    }
+
     /// Run auto-tuning for specified iterations
     pub fn tune(&mut self, max_iterations: usize) -> AutoTuneResult {
+        self.tune_with(max_iterations, Self::evaluate)
+    }
+
+    /// Tuning loop against an arbitrary objective
+    fn tune_with<F>(&mut self, max_iterations: usize, mut objective: F) -> AutoTuneResult
+    where
+        F: FnMut(&AutoTuneConfig) -> f64,
+    {
         for _ in 0..max_iterations {
             let candidate = self.generate_candidate();
-            let score = self.evaluate(&candidate);
+            let score = objective(&candidate);
 
             self.history.push((candidate.clone(), score));
 
@@ -322,17 +643,50 @@ impl AutoTuner {
             }
         }
 
+        let predicted_optimum = match self.strategy {
+            TuneStrategy::Bayesian { .. } => self.surrogate_optimum(),
+            _ => None,
+        };
+
         AutoTuneResult {
             best_config: self.best_config.clone(),
             achieved_resonance: self.best_score,
             iterations: self.iteration,
+            strategy: self.strategy,
+            predicted_optimum,
+            acquisition_trajectory: self.acquisition_trajectory.clone(),
         }
     }
 
-    fn generate_candidate(&self) -> AutoTuneConfig {
+    fn generate_candidate(&mut self) -> AutoTuneConfig {
         use rand::Rng;
         let mut rng = rand::thread_rng();
 
+        match self.strategy {
+            TuneStrategy::Adaptive => {}
+            TuneStrategy::Random => {
+                return AutoTuneConfig::from_unit(std::array::from_fn(|_| rng.gen()));
+            }
+            TuneStrategy::Grid { points_per_axis } => {
+                let points = points_per_axis.max(1);
+                let mut index = self.iteration % points.pow(3);
+                let x = std::array::from_fn(|_| {
+                    let digit = index % points;
+                    index /= points;
+                    if points == 1 { 0.5 } else { digit as f64 / (points - 1) as f64 }
+                });
+                return AutoTuneConfig::from_unit(x);
+            }
+            TuneStrategy::Bayesian { acquisition } => {
+                if self.history.len() < BAYES_INITIAL_SAMPLES {
+                    return AutoTuneConfig::from_unit(std::array::from_fn(|_| rng.gen()));
+                }
+                let (x, value) = self.maximize_acquisition(acquisition, &mut rng);
+                self.acquisition_trajectory.push(value);
+                return AutoTuneConfig::from_unit(x);
+            }
+        }
+
         if self.iteration < 5 || rng.gen::<f64>() > 0.7 {
             // Exploration: random configuration
             AutoTuneConfig {
@@ -354,7 +708,66 @@ impl AutoTuner {
         }
     }
 
-    fn evaluate(&self, config: &AutoTuneConfig) -> f64 {
+    /// Surrogate fitted to every evaluation so far
+    fn surrogate(&self) -> GaussianProcess {
+        let points: Vec<[f64; 3]> = self.history.iter().map(|(c, _)| c.to_unit()).collect();
+        let values: Vec<f64> = self.history.iter().map(|(_, s)| *s).collect();
+        GaussianProcess::fit(points, &values)
+    }
+
+    /// Random candidates plus local perturbations of the best configuration
+    fn surrogate_candidates(&self, rng: &mut impl rand::Rng) -> Vec<[f64; 3]> {
+        let best = self.best_config.to_unit();
+        let mut candidates: Vec<[f64; 3]> = (0..BAYES_CANDIDATES)
+            .map(|_| std::array::from_fn(|_| rng.gen()))
+            .collect();
+        candidates.extend((0..BAYES_CANDIDATES / 8).map(|_| {
+            std::array::from_fn(|i| (best[i] + rng.gen_range(-0.1..0.1)).clamp(0.0, 1.0))
+        }));
+        candidates
+    }
+
+    /// Candidate with the highest acquisition value, and that value
+    fn maximize_acquisition(&self, acquisition: Acquisition, rng: &mut impl rand::Rng) -> ([f64; 3], f64) {
+        let gp = self.surrogate();
+        let incumbent = self.history.iter().map(|(_, s)| *s).fold(f64::NEG_INFINITY, f64::max);
+
+        self.surrogate_candidates(rng)
+            .into_iter()
+            .map(|x| {
+                let (mean, std) = gp.predict(&x);
+                let value = match acquisition {
+                    Acquisition::EI => expected_improvement(mean, std, incumbent),
+                    Acquisition::UCB { kappa } => mean + kappa * std,
+                };
+                (x, value)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .expect("candidate set is never empty")
+    }
+
+    /// Maximum of the surrogate's posterior mean over evaluated and random points
+    fn surrogate_optimum(&self) -> Option<SurrogateOptimum> {
+        if self.history.is_empty() {
+            return None;
+        }
+        let gp = self.surrogate();
+        let mut rng = rand::thread_rng();
+        let mut candidates = self.surrogate_candidates(&mut rng);
+        candidates.extend(self.history.iter().map(|(c, _)| c.to_unit()));
+
+        candidates
+            .into_iter()
+            .map(|x| (x, gp.predict(&x)))
+            .max_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+            .map(|(x, (mean, std))| SurrogateOptimum {
+                config: AutoTuneConfig::from_unit(x),
+                predicted_resonance: mean,
+                uncertainty: std,
+            })
+    }
+
+    fn evaluate(config: &AutoTuneConfig) -> f64 {
         let calibrator_config = CalibratorConfig {
             initial_temperature: config.temperature,
             cooling_rate: config.cooling_rate,
@@ -381,6 +794,96 @@ impl Default for AutoTuner {
     }
 }
 
+// ==================== Gaussian Process Surrogate ====================
+
+/// RBF kernel length scale in unit-cube coordinates
+const GP_LENGTH_SCALE: f64 = 0.25;
+
+/// Observation noise variance, relative to the standardized scores
+const GP_NOISE: f64 = 1e-3;
+
+/// Exploration margin for expected improvement
+const EI_XI: f64 = 0.01;
+
+/// Gaussian-process regression with an RBF kernel on standardized scores
+struct GaussianProcess {
+    points: Vec<[f64; 3]>,
+    /// Lower Cholesky factor of K + σ²I
+    cholesky: Vec<Vec<f64>>,
+    /// (K + σ²I)⁻¹ y
+    alpha: Vec<f64>,
+    mean: f64,
+    scale: f64,
+}
+
+impl GaussianProcess {
+    fn kernel(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+        let d2: f64 = a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum();
+        (-d2 / (2.0 * GP_LENGTH_SCALE * GP_LENGTH_SCALE)).exp()
+    }
+
+    fn fit(points: Vec<[f64; 3]>, values: &[f64]) -> Self {
+        let n = points.len();
+        let mean = values.iter().sum::<f64>() / n.max(1) as f64;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n.max(1) as f64;
+        let scale = if variance > 1e-24 { variance.sqrt() } else { 1.0 };
+        let y: Vec<f64> = values.iter().map(|v| (v - mean) / scale).collect();
+
+        let mut cholesky = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..=i {
+                let mut sum = Self::kernel(&points[i], &points[j]);
+                if i == j {
+                    sum += GP_NOISE;
+                }
+                sum -= (0..j).map(|k| cholesky[i][k] * cholesky[j][k]).sum::<f64>();
+                cholesky[i][j] = if i == j { sum.max(1e-12).sqrt() } else { sum / cholesky[j][j] };
+            }
+        }
+
+        let z = forward_substitute(&cholesky, &y);
+        let mut alpha = vec![0.0; n];
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|k| cholesky[k][i] * alpha[k]).sum();
+            alpha[i] = (z[i] - sum) / cholesky[i][i];
+        }
+
+        Self { points, cholesky, alpha, mean, scale }
+    }
+
+    /// Posterior mean and standard deviation at `x`, in score units
+    fn predict(&self, x: &[f64; 3]) -> (f64, f64) {
+        let k: Vec<f64> = self.points.iter().map(|p| Self::kernel(p, x)).collect();
+        let mean: f64 = k.iter().zip(&self.alpha).map(|(a, b)| a * b).sum();
+        let v = forward_substitute(&self.cholesky, &k);
+        let variance = (1.0 - v.iter().map(|x| x * x).sum::<f64>()).max(1e-12);
+        (self.mean + mean * self.scale, variance.sqrt() * self.scale)
+    }
+}
+
+/// Solve L z = b for lower-triangular L
+fn forward_substitute(l: &[Vec<f64>], b: &[f64]) -> Vec<f64> {
+    let mut z = vec![0.0; b.len()];
+    for i in 0..b.len() {
+        let sum: f64 = (0..i).map(|k| l[i][k] * z[k]).sum();
+        z[i] = (b[i] - sum) / l[i][i];
+    }
+    z
+}
+
+/// Expected improvement of N(mean, std²) over `incumbent`
+fn expected_improvement(mean: f64, std: f64, incumbent: f64) -> f64 {
+    let improvement = mean - incumbent - EI_XI;
+    if std < 1e-12 {
+        return improvement.max(0.0);
+    }
+    let z = improvement / std;
+    let pdf = (-0.5 * z * z).exp() / (2.0 * std::f64::consts::PI).sqrt();
+    improvement * normal_cdf(z) + std * pdf
+}
+
+// ==================== Calibration Curves ====================
+
 /// Calibration curve for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalibrationCurve {
@@ -393,7 +896,7 @@ pub struct CalibrationCurve {
 }
 
 /// Type of calibration curve
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CurveType {
     /// Resonance over time
     Resonance,
@@ -432,6 +935,157 @@ impl CalibrationCurve {
 
         Self { x, y, curve_type }
     }
+
+    /// Fit the resonance curve of a calibration history
+    ///
+    /// x is the history step and y the resonance of its performance.
+    pub fn fit(history: &[HistoryEntry]) -> FittedCurve {
+        let curve = Self {
+            x: history.iter().map(|e| e.step as f64).collect(),
+            y: history.iter().map(|e| resonance_3d(&e.performance)).collect(),
+            curve_type: CurveType::Resonance,
+        };
+        curve.fitted()
+    }
+
+    /// Least-squares fit of this curve
+    ///
+    /// Fits both a line and an exponential approach to an asymptote and
+    /// keeps the exponential only if it explains strictly more variance.
+    pub fn fitted(&self) -> FittedCurve {
+        let (intercept, slope) = fit_affine(&self.x, &self.y);
+        let mut model = CurveModel::Linear { intercept, slope };
+        let mut r_squared = r_squared(&model, &self.x, &self.y);
+
+        if let Some((exponential, exp_r_squared)) = fit_exponential(&self.x, &self.y) {
+            if exp_r_squared > r_squared + 1e-9 {
+                model = exponential;
+                r_squared = exp_r_squared;
+            }
+        }
+
+        FittedCurve {
+            curve_type: self.curve_type,
+            model,
+            r_squared,
+            x: self.x.clone(),
+            observed: self.y.clone(),
+        }
+    }
+}
+
+/// Functional form of a [`FittedCurve`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CurveModel {
+    /// y = intercept + slope·x
+    Linear { intercept: f64, slope: f64 },
+    /// y = asymptote - amplitude·exp(-rate·x)
+    Exponential { asymptote: f64, amplitude: f64, rate: f64 },
+}
+
+impl CurveModel {
+    /// Model value at `x`
+    pub fn evaluate(&self, x: f64) -> f64 {
+        match *self {
+            Self::Linear { intercept, slope } => intercept + slope * x,
+            Self::Exponential { asymptote, amplitude, rate } => asymptote - amplitude * (-rate * x).exp(),
+        }
+    }
+}
+
+/// Calibration curve with its fitted model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FittedCurve {
+    /// Quantity the curve tracks
+    pub curve_type: CurveType,
+    /// Fitted model and its coefficients
+    pub model: CurveModel,
+    /// Coefficient of determination of the fit
+    pub r_squared: f64,
+    /// X values the model was fitted to
+    pub x: Vec<f64>,
+    /// Observed y values
+    pub observed: Vec<f64>,
+}
+
+impl FittedCurve {
+    /// Predicted value at a calibration step
+    pub fn predict(&self, step: usize) -> f64 {
+        self.model.evaluate(step as f64)
+    }
+
+    /// CSV with columns `x,observed,fitted`
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("x,observed,fitted\n");
+        for (&x, &y) in self.x.iter().zip(&self.observed) {
+            csv.push_str(&format!("{},{:.6},{:.6}\n", x, y, self.model.evaluate(x)));
+        }
+        csv
+    }
+}
+
+/// Least-squares (a, b) for y = a + b·basis
+fn fit_affine(basis: &[f64], ys: &[f64]) -> (f64, f64) {
+    if ys.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = ys.len() as f64;
+    let basis_mean = basis.iter().sum::<f64>() / n;
+    let y_mean = ys.iter().sum::<f64>() / n;
+    let covariance: f64 = basis.iter().zip(ys).map(|(b, y)| (b - basis_mean) * (y - y_mean)).sum();
+    let variance: f64 = basis.iter().map(|b| (b - basis_mean).powi(2)).sum();
+    if variance < 1e-24 {
+        return (y_mean, 0.0);
+    }
+    let slope = covariance / variance;
+    (y_mean - slope * basis_mean, slope)
+}
+
+/// Best exponential approach over a log-spaced rate scan refined by golden section
+fn fit_exponential(xs: &[f64], ys: &[f64]) -> Option<(CurveModel, f64)> {
+    if ys.len() < 3 {
+        return None;
+    }
+    let model_at = |log_rate: f64| {
+        let rate = log_rate.exp();
+        let basis: Vec<f64> = xs.iter().map(|x| (-rate * x).exp()).collect();
+        let (asymptote, c) = fit_affine(&basis, ys);
+        let model = CurveModel::Exponential { asymptote, amplitude: -c, rate };
+        (model, r_squared(&model, xs, ys))
+    };
+
+    let (lo, hi, scan) = ((1e-4f64).ln(), 10f64.ln(), 60);
+    let grid = |i: usize| lo + (hi - lo) * i as f64 / scan as f64;
+    let best = (0..=scan)
+        .max_by(|&a, &b| model_at(grid(a)).1.total_cmp(&model_at(grid(b)).1))
+        .unwrap_or(0);
+
+    let (mut a, mut b) = (grid(best.saturating_sub(1)), grid((best + 1).min(scan)));
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    for _ in 0..60 {
+        let c = b - ratio * (b - a);
+        let d = a + ratio * (b - a);
+        if model_at(c).1 >= model_at(d).1 {
+            b = d;
+        } else {
+            a = c;
+        }
+    }
+    Some(model_at((a + b) / 2.0))
+}
+
+/// Coefficient of determination of `model` on the data
+fn r_squared(model: &CurveModel, xs: &[f64], ys: &[f64]) -> f64 {
+    if ys.is_empty() {
+        return 0.0;
+    }
+    let mean = ys.iter().sum::<f64>() / ys.len() as f64;
+    let total: f64 = ys.iter().map(|y| (y - mean).powi(2)).sum();
+    let residual: f64 = xs.iter().zip(ys).map(|(&x, y)| (y - model.evaluate(x)).powi(2)).sum();
+    if total < 1e-24 {
+        return if residual < 1e-24 { 1.0 } else { 0.0 };
+    }
+    1.0 - residual / total
 }
 
 #[cfg(test)]
@@ -445,6 +1099,7 @@ mod tests {
             cooling_rate_values: vec![0.9, 0.95],
             steps_per_evaluation: 5,
             parallel: false,
+            parallelism: 1,
         };
 
         let mut sweep = HyperparameterSweep::new(config);
@@ -454,6 +1109,94 @@ mod tests {
         assert!(result.best_score >= 0.0);
     }
 
+    #[test]
+    fn test_parallel_sweep_keeps_grid_order() {
+        let config = SweepConfig {
+            temperature_values: vec![0.5, 1.0, 2.0, 3.0, 5.0],
+            cooling_rate_values: vec![0.8, 0.85, 0.9, 0.95, 0.99],
+            steps_per_evaluation: 5,
+            parallel: true,
+            parallelism: 4,
+        };
+
+        let mut sweep = HyperparameterSweep::new(config.clone());
+        let result = sweep.run();
+        assert_eq!(result.evaluations.len(), 25);
+        for (i, eval) in result.evaluations.iter().enumerate() {
+            assert_eq!(eval.config.temperature, config.temperature_values[i / 5]);
+            assert_eq!(eval.config.cooling_rate, config.cooling_rate_values[i % 5]);
+        }
+        let best = &result.evaluations[result.best_config_index];
+        assert_eq!(best.final_score, result.best_score);
+        assert!(result.evaluations[..result.best_config_index].iter().all(|e| e.final_score < result.best_score));
+
+        let grid = sweep.run_grid(
+            SweepParameter::Temperature, &[0.5, 1.0, 2.0],
+            SweepParameter::CoolingRate, &[0.9, 0.95],
+        ).unwrap();
+        assert_eq!(grid.scores.len(), 2);
+        let (bx, by) = grid.best.unwrap();
+        assert_eq!(grid.scores[by][bx], grid.best_score);
+    }
+
+    #[test]
+    fn test_lyapunov_exponent() {
+        let trajectory = |x0: f64, map: &dyn Fn(f64) -> f64| -> Vec<Signature3D> {
+            std::iter::successors(Some(x0), |&x| Some(map(x)))
+                .take(100)
+                .map(|x| Signature3D::new(x, x, x))
+                .collect()
+        };
+
+        // Logistic map at r = 4 is chaotic with exponent ln 2
+        let chaotic = lyapunov_exponent(&trajectory(0.3, &|x| 4.0 * x * (1.0 - x))).unwrap();
+        assert!(chaotic > 0.3, "chaotic exponent {}", chaotic);
+
+        // A contraction towards 0.5 by 0.9 per step has exponent ln 0.9
+        let convergent = lyapunov_exponent(&trajectory(0.9, &|x| 0.5 + 0.9 * (x - 0.5))).unwrap();
+        assert!((convergent - 0.9_f64.ln()).abs() < 1e-6, "convergent exponent {}", convergent);
+
+        assert!(lyapunov_exponent(&trajectory(0.3, &|x| x)[..8]).is_none());
+        assert!(lyapunov_exponent(&[]).is_none());
+    }
+
+    #[test]
+    fn test_fit_calibration_curves() {
+        let history = |f: &dyn Fn(f64) -> f64| -> Vec<HistoryEntry> {
+            (1..=30)
+                .map(|step| {
+                    let y = f(step as f64);
+                    HistoryEntry {
+                        step,
+                        config: Configuration::default(),
+                        performance: Signature3D::new(y, y, y),
+                        accepted: true,
+                    }
+                })
+                .collect()
+        };
+
+        let fit = CalibrationCurve::fit(&history(&|t| 0.9 - 0.4 * (-0.2 * t).exp()));
+        assert_eq!(fit.curve_type, CurveType::Resonance);
+        let CurveModel::Exponential { asymptote, amplitude, rate } = fit.model else {
+            panic!("expected exponential fit, got {:?}", fit.model);
+        };
+        assert!((rate - 0.2).abs() < 1e-4, "rate {}", rate);
+        assert!((asymptote - 0.9).abs() < 1e-4 && (amplitude - 0.4).abs() < 1e-3);
+        assert!(fit.r_squared > 0.9999);
+        assert!((fit.predict(50) - (0.9 - 0.4 * (-10.0f64).exp())).abs() < 1e-4);
+
+        let fit = CalibrationCurve::fit(&history(&|t| 0.3 + 0.01 * t));
+        assert!(matches!(fit.model, CurveModel::Linear { .. }));
+        assert!((fit.predict(40) - 0.7).abs() < 1e-9);
+
+        let csv = fit.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "x,observed,fitted");
+        assert_eq!(lines.len(), 31);
+        assert_eq!(lines[1], "1,0.310000,0.310000");
+    }
+
     #[test]
     fn test_auto_tuner() {
         let mut tuner = AutoTuner::new(0.7);
@@ -462,4 +1205,77 @@ mod tests {
         assert!(result.iterations <= 5);
         assert!(result.achieved_resonance >= 0.0);
     }
+
+    #[test]
+    fn test_bayesian_tuner_on_synthetic_objective() {
+        // Smooth objective peaking at temperature 2, cooling 0.9, threshold 0.8
+        let objective = |c: &AutoTuneConfig| {
+            let x = c.to_unit();
+            let peak = AutoTuneConfig { temperature: 2.0, cooling_rate: 0.9, mandorla_threshold: 0.8 }.to_unit();
+            1.0 - x.iter().zip(&peak).map(|(a, b)| (a - b).powi(2)).sum::<f64>()
+        };
+
+        let mut tuner = AutoTuner::new(2.0)
+            .with_strategy(TuneStrategy::Bayesian { acquisition: Acquisition::EI });
+        let result = tuner.tune_with(25, objective);
+
+        assert_eq!(result.iterations, 25);
+        assert_eq!(result.acquisition_trajectory.len(), 25 - BAYES_INITIAL_SAMPLES);
+        assert!(result.achieved_resonance > 0.98, "best {}", result.achieved_resonance);
+
+        let optimum = result.predicted_optimum.unwrap();
+        assert!((optimum.config.temperature - 2.0).abs() < 0.5);
+        assert!(optimum.predicted_resonance > 0.95);
+        assert!(optimum.uncertainty >= 0.0);
+    }
+
+    #[test]
+    fn test_grid_and_random_strategies() {
+        let mut tuner = AutoTuner::new(2.0).with_strategy(TuneStrategy::Grid { points_per_axis: 2 });
+        let mut seen = Vec::new();
+        tuner.tune_with(8, |c| {
+            seen.push((c.temperature, c.cooling_rate, c.mandorla_threshold));
+            0.0
+        });
+        // Every corner of the parameter box exactly once
+        for &(t, c, m) in &seen {
+            assert!(t == TUNE_BOUNDS[0].0 || t == TUNE_BOUNDS[0].1);
+            assert!(c == TUNE_BOUNDS[1].0 || c == TUNE_BOUNDS[1].1);
+            assert!(m == TUNE_BOUNDS[2].0 || m == TUNE_BOUNDS[2].1);
+        }
+        seen.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        seen.dedup();
+        assert_eq!(seen.len(), 8);
+
+        let mut tuner = AutoTuner::new(2.0).with_strategy(TuneStrategy::Random);
+        let result = tuner.tune_with(10, |c| c.cooling_rate);
+        assert!(result.predicted_optimum.is_none());
+        assert!(result.acquisition_trajectory.is_empty());
+        assert!(result.best_config.cooling_rate <= TUNE_BOUNDS[1].1);
+    }
+
+    #[test]
+    fn test_sweep_grid() {
+        let sweep = HyperparameterSweep::new(SweepConfig {
+            steps_per_evaluation: 5,
+            ..Default::default()
+        });
+        let grid = sweep.run_grid(
+            SweepParameter::Temperature, &[0.5, 1.0, 2.0],
+            SweepParameter::MandorlaThreshold, &[0.8, 0.9],
+        ).unwrap();
+
+        assert_eq!(grid.scores.len(), 2);
+        assert!(grid.scores.iter().all(|row| row.len() == 3));
+        let (bx, by) = grid.best.unwrap();
+        assert_eq!(grid.scores[by][bx], grid.best_score);
+
+        assert!(sweep.run_grid(
+            SweepParameter::Temperature, &[0.5, 1.0],
+            SweepParameter::Temperature, &[0.8, 0.9],
+        ).is_err());
+
+        assert_eq!(SweepParameter::from_name("cooling_rate"), Some(SweepParameter::CoolingRate));
+        assert_eq!(SweepParameter::from_name("bogus"), None);
+    }
 }
@@ -6,8 +6,13 @@ use qops_core::{
 };
 use crate::por::ProofOfResonanceValidator;
 use crate::cri::CalibrationRegimeInitializer;
+use crate::pareto::{ParetoFront, ParetoPoint};
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+use std::io;
+use std::path::Path;
 
 /// Calibrator configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +29,12 @@ pub struct CalibratorConfig {
     pub cooling_rate: f64,
     /// Threshold for Mandorla zone membership
     pub mandorla_threshold: f64,
+    /// Accept candidates that join a Pareto front over (ψ, ρ, ω) instead of by PoR score
+    #[serde(default)]
+    pub multi_objective: bool,
+    /// Seed for the CRI regime resets (`None` = entropy)
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 impl Default for CalibratorConfig {
@@ -38,6 +49,8 @@ impl Default for CalibratorConfig {
             initial_temperature: 1.0,
             cooling_rate: 0.95,
             mandorla_threshold: 0.85,
+            multi_objective: false,
+            seed: None,
         }
     }
 }
@@ -51,6 +64,9 @@ pub struct CalibrationResult {
     pub por_score: f64,
     pub cri_triggered: bool,
     pub timestamp: DateTime<Utc>,
+    /// Non-dominated configurations so far (multi-objective mode only)
+    #[serde(default)]
+    pub pareto_front: Vec<ParetoPoint>,
 }
 
 /// History entry
@@ -62,6 +78,45 @@ pub struct HistoryEntry {
     pub accepted: bool,
 }
 
+/// Serializable state of a [`SeraphicCalibrator`] mid-run
+///
+/// Restoring it with [`SeraphicCalibrator::restore`] continues the run
+/// exactly where [`SeraphicCalibrator::snapshot`] left it, including the
+/// random regime resets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalibratorSnapshot {
+    /// Calibrator configuration
+    pub config: CalibratorConfig,
+    /// Configuration being calibrated
+    pub current_config: Configuration,
+    /// Its current performance
+    pub current_performance: Signature3D,
+    /// Mandorla field M(t)
+    pub field: MandorlaField,
+    /// Steps taken so far
+    pub step_count: usize,
+    /// History log
+    pub history: Vec<HistoryEntry>,
+    /// CRI stagnation tracking
+    pub cri: CalibrationRegimeInitializer,
+    /// Pareto front (multi-objective mode)
+    pub pareto_front: ParetoFront,
+    /// RNG state
+    rng: ChaCha12Rng,
+}
+
+impl CalibratorSnapshot {
+    /// Save as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        qops_core::persist::save_json(self, path)
+    }
+
+    /// Load a snapshot written by [`CalibratorSnapshot::save`]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        qops_core::persist::load_json(path)
+    }
+}
+
 /// Seraphic Calibrator
 pub struct SeraphicCalibrator {
     config: CalibratorConfig,
@@ -73,6 +128,8 @@ pub struct SeraphicCalibrator {
     current_performance: Signature3D,
     step_count: usize,
     history: Vec<HistoryEntry>,
+    pareto_front: ParetoFront,
+    rng: ChaCha12Rng,
 }
 
 impl SeraphicCalibrator {
@@ -87,6 +144,44 @@ impl SeraphicCalibrator {
             current_performance: Signature3D::default(),
             step_count: 0,
             history: Vec::new(),
+            pareto_front: ParetoFront::new(),
+            rng: match config.seed {
+                Some(seed) => ChaCha12Rng::seed_from_u64(seed),
+                None => ChaCha12Rng::from_entropy(),
+            },
+            config,
+        }
+    }
+
+    /// Capture the full calibration state
+    pub fn snapshot(&self) -> CalibratorSnapshot {
+        CalibratorSnapshot {
+            config: self.config.clone(),
+            current_config: self.current_config.clone(),
+            current_performance: self.current_performance,
+            field: self.field.clone(),
+            step_count: self.step_count,
+            history: self.history.clone(),
+            cri: self.cri.clone(),
+            pareto_front: self.pareto_front.clone(),
+            rng: self.rng.clone(),
+        }
+    }
+
+    /// Rebuild a calibrator from a snapshot
+    pub fn restore(snapshot: CalibratorSnapshot) -> Self {
+        let config = snapshot.config;
+        Self {
+            field: snapshot.field,
+            double_kick: DoubleKickOperator::new(config.update_step, config.stabilization_step),
+            por: ProofOfResonanceValidator::new(config.por_threshold),
+            cri: snapshot.cri,
+            current_config: snapshot.current_config,
+            current_performance: snapshot.current_performance,
+            step_count: snapshot.step_count,
+            history: snapshot.history,
+            pareto_front: snapshot.pareto_front,
+            rng: snapshot.rng,
             config,
         }
     }
@@ -95,6 +190,13 @@ impl SeraphicCalibrator {
     pub fn initialize(&mut self, config: Configuration, performance: Signature3D) {
         self.current_config = config;
         self.current_performance = performance;
+        if self.config.multi_objective {
+            self.pareto_front.insert(ParetoPoint {
+                step: self.step_count,
+                config: self.current_config.clone(),
+                performance,
+            });
+        }
 
         // Initialize field
         let injection = FieldVector::encode_signature(
@@ -114,6 +216,7 @@ impl SeraphicCalibrator {
                 por_score: 0.0,
                 cri_triggered: false,
                 timestamp: Utc::now(),
+                pareto_front: self.front_snapshot(),
             };
         }
 
@@ -137,7 +240,15 @@ impl SeraphicCalibrator {
         );
 
         // Step 4: Accept or reject
-        let accepted = por_result.accepted;
+        let accepted = if self.config.multi_objective {
+            self.pareto_front.insert(ParetoPoint {
+                step: self.step_count,
+                config: candidate.clone(),
+                performance: candidate_performance,
+            })
+        } else {
+            por_result.accepted
+        };
         if accepted {
             self.current_config = candidate.clone();
             self.current_performance = candidate_performance;
@@ -171,6 +282,15 @@ impl SeraphicCalibrator {
             por_score: por_result.score,
             cri_triggered,
             timestamp: Utc::now(),
+            pareto_front: self.front_snapshot(),
+        }
+    }
+
+    fn front_snapshot(&self) -> Vec<ParetoPoint> {
+        if self.config.multi_objective {
+            self.pareto_front.points().to_vec()
+        } else {
+            Vec::new()
         }
     }
 
@@ -203,12 +323,11 @@ impl SeraphicCalibrator {
 
     fn apply_cri(&mut self) {
         // Reset to explore new regime
-        let mut rng = rand::thread_rng();
         use rand::Rng;
 
-        self.current_config.depth = rng.gen_range(1..=4);
-        self.current_config.learning_rate = rng.gen_range(0.001..0.1);
-        self.current_config.num_restarts = rng.gen_range(1..=5);
+        self.current_config.depth = self.rng.gen_range(1..=4);
+        self.current_config.learning_rate = self.rng.gen_range(0.001..0.1);
+        self.current_config.num_restarts = self.rng.gen_range(1..=5);
     }
 
     /// Run multiple steps
@@ -230,6 +349,11 @@ impl SeraphicCalibrator {
     pub fn history(&self) -> &[HistoryEntry] {
         &self.history
     }
+
+    /// Pareto front of non-dominated configurations (empty unless multi-objective)
+    pub fn pareto_front(&self) -> &ParetoFront {
+        &self.pareto_front
+    }
 }
 
 impl Default for SeraphicCalibrator {
@@ -264,5 +388,67 @@ mod tests {
 
         let results = calibrator.run(5);
         assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|r| r.pareto_front.is_empty()));
+    }
+
+    #[test]
+    fn test_snapshot_resume_matches_uninterrupted_run() {
+        let config = CalibratorConfig {
+            seed: Some(11),
+            cri_stagnation_threshold: 3,
+            ..Default::default()
+        };
+        let start = |config: &CalibratorConfig| {
+            let mut calibrator = SeraphicCalibrator::new(config.clone());
+            calibrator.initialize(Configuration::default(), Signature3D::new(0.5, 0.5, 0.5));
+            calibrator
+        };
+
+        let mut full = start(&config);
+        let expected = full.run(40);
+        assert!(expected.iter().any(|r| r.cri_triggered));
+
+        let mut partial = start(&config);
+        let mut results = partial.run(15);
+        let path = qops_core::persist::temp_json_path("qops_calibrator");
+        partial.snapshot().save(&path).unwrap();
+        drop(partial);
+
+        let mut resumed = SeraphicCalibrator::restore(CalibratorSnapshot::load(&path).unwrap());
+        std::fs::remove_file(&path).ok();
+        results.extend(resumed.run(25));
+
+        for (a, b) in results.iter().zip(&expected) {
+            assert_eq!((a.step, a.accepted, a.cri_triggered), (b.step, b.accepted, b.cri_triggered));
+            assert_eq!(a.performance, b.performance);
+            assert_eq!(a.por_score, b.por_score);
+        }
+        assert_eq!(resumed.history().len(), 40);
+        let (a, b) = (resumed.current_config(), full.current_config());
+        assert_eq!((a.depth, a.num_restarts), (b.depth, b.num_restarts));
+        assert_eq!(a.learning_rate, b.learning_rate);
+    }
+
+    #[test]
+    fn test_multi_objective_front() {
+        let mut calibrator = SeraphicCalibrator::new(CalibratorConfig {
+            multi_objective: true,
+            ..Default::default()
+        });
+        calibrator.initialize(Configuration::default(), Signature3D::new(0.5, 0.5, 0.5));
+        assert_eq!(calibrator.pareto_front().len(), 1);
+
+        let results = calibrator.run(20);
+        let front = &results.last().unwrap().pareto_front;
+        assert_eq!(front.len(), calibrator.pareto_front().len());
+        assert!(!front.is_empty());
+
+        // Every accepted candidate joined the front, and no member dominates another
+        for result in results.iter().filter(|r| r.accepted) {
+            assert!(result.pareto_front.iter().any(|p| p.step == result.step));
+        }
+        for a in front {
+            assert!(front.iter().all(|b| !crate::pareto::dominates(&a.performance, &b.performance)));
+        }
     }
 }
@@ -20,6 +20,7 @@ impl Default for CRIConfig {
 }
 
 /// Calibration Regime Initializer
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalibrationRegimeInitializer {
     config: CRIConfig,
     stagnation_count: usize,
@@ -31,14 +31,18 @@ pub mod calibrator;
 pub mod por;
 pub mod cri;
 pub mod analysis;
+pub mod pareto;
 
-pub use calibrator::{SeraphicCalibrator, CalibratorConfig, CalibrationResult, HistoryEntry};
+pub use calibrator::{SeraphicCalibrator, CalibratorConfig, CalibratorSnapshot, CalibrationResult, HistoryEntry};
 pub use por::ProofOfResonanceValidator;
 pub use cri::CalibrationRegimeInitializer;
+pub use pareto::{dominates, ParetoFront, ParetoPoint};
 pub use analysis::{
     HyperparameterSweep, SweepConfig, SweepResult, SweepConfigSnapshot, ConfigEvaluation,
-    StabilityAnalysis, analyze_stability,
-    AutoTuner, AutoTuneResult, AutoTuneConfig, CalibrationCurve, CurveType,
+    SweepParameter, SweepGrid,
+    StabilityAnalysis, analyze_stability, lyapunov_exponent, LYAPUNOV_WINDOW,
+    AutoTuner, AutoTuneResult, AutoTuneConfig, TuneStrategy, Acquisition, SurrogateOptimum,
+    CalibrationCurve, CurveType, CurveModel, FittedCurve,
 };
 
 /// Seraphic version
@@ -0,0 +1,110 @@
+//! Pareto front over the performance triplet (ψ, ρ, ω).
+
+use qops_core::{Configuration, Signature3D};
+use serde::{Deserialize, Serialize};
+
+/// Whether `a` Pareto-dominates `b`
+///
+/// `a` must be at least as good in quality, stability and efficiency, and
+/// strictly better in at least one of them.
+pub fn dominates(a: &Signature3D, b: &Signature3D) -> bool {
+    let (a, b) = ([a.psi, a.rho, a.omega], [b.psi, b.rho, b.omega]);
+    a.iter().zip(&b).all(|(x, y)| x >= y) && a.iter().zip(&b).any(|(x, y)| x > y)
+}
+
+/// Configuration on the Pareto front
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParetoPoint {
+    /// Calibration step that produced it (0 = initial configuration)
+    pub step: usize,
+    /// The configuration
+    pub config: Configuration,
+    /// Its performance triplet
+    pub performance: Signature3D,
+}
+
+/// Set of mutually non-dominated configurations
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParetoFront {
+    points: Vec<ParetoPoint>,
+}
+
+impl ParetoFront {
+    /// Create an empty front
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a point unless an existing point dominates or equals it
+    ///
+    /// Points the new one dominates are removed. Returns whether the point
+    /// joined the front.
+    pub fn insert(&mut self, point: ParetoPoint) -> bool {
+        let blocked = self.points.iter().any(|p| {
+            p.performance == point.performance || dominates(&p.performance, &point.performance)
+        });
+        if blocked {
+            return false;
+        }
+        self.points.retain(|p| !dominates(&point.performance, &p.performance));
+        self.points.push(point);
+        true
+    }
+
+    /// Points on the front, in insertion order
+    pub fn points(&self) -> &[ParetoPoint] {
+        &self.points
+    }
+
+    /// Number of points on the front
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Whether the front is empty
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(psi: f64, rho: f64, omega: f64) -> ParetoPoint {
+        ParetoPoint {
+            step: 0,
+            config: Configuration::default(),
+            performance: Signature3D::new(psi, rho, omega),
+        }
+    }
+
+    #[test]
+    fn test_dominance() {
+        let a = Signature3D::new(0.6, 0.5, 0.5);
+        let b = Signature3D::new(0.5, 0.5, 0.5);
+        assert!(dominates(&a, &b));
+        assert!(!dominates(&b, &a));
+        assert!(!dominates(&a, &a));
+        assert!(!dominates(&a, &Signature3D::new(0.5, 0.6, 0.5)));
+    }
+
+    #[test]
+    fn test_front_keeps_trade_offs() {
+        let mut front = ParetoFront::new();
+        assert!(front.insert(point(0.5, 0.5, 0.5)));
+        assert!(front.insert(point(0.8, 0.4, 0.5)));
+        // Dominated by the first point, and a duplicate of it
+        assert!(!front.insert(point(0.4, 0.5, 0.5)));
+        assert!(!front.insert(point(0.5, 0.5, 0.5)));
+        assert_eq!(front.len(), 2);
+
+        // Dominates the first point only
+        assert!(front.insert(point(0.6, 0.6, 0.5)));
+        let performances: Vec<_> = front.points().iter().map(|p| p.performance).collect();
+        assert_eq!(performances, vec![Signature3D::new(0.8, 0.4, 0.5), Signature3D::new(0.6, 0.6, 0.5)]);
+        for a in front.points() {
+            assert!(front.points().iter().all(|b| !dominates(&a.performance, &b.performance)));
+        }
+    }
+}
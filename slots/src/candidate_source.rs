@@ -0,0 +1,124 @@
+//! Slots-backed candidate source for the holistic pipeline.
+//!
+//! Wraps a [`SequenceMiner`] so mined slot sequences can feed the
+//! Discovery stage as [`OperatorCandidate`]s.
+
+use crate::miner::{MinedSequence, MinerConfig, SequenceMiner};
+use qops_core::{CandidateSource, OperatorCandidate, ResonanceConfig, Signature5D, DEFAULT_MANDORLA_THRESHOLD};
+use std::collections::VecDeque;
+use std::f64::consts::PI;
+
+/// Candidate source driven by the slots sequence miner
+///
+/// Runs a mining pass whenever the buffer of mined sequences is exhausted.
+pub struct SlotsCandidateSource {
+    miner: SequenceMiner,
+    buffer: VecDeque<MinedSequence>,
+    produced: usize,
+    resonance: Option<ResonanceConfig>,
+}
+
+impl SlotsCandidateSource {
+    /// Create a source with the given miner configuration
+    pub fn new(config: MinerConfig) -> Self {
+        Self {
+            miner: SequenceMiner::new(config),
+            buffer: VecDeque::new(),
+            produced: 0,
+            resonance: None,
+        }
+    }
+
+    /// Score candidates by weighted 5D resonance instead of the miner's slot resonance
+    pub fn with_resonance(mut self, config: ResonanceConfig) -> Self {
+        self.resonance = Some(config);
+        self
+    }
+
+    fn refill(&mut self) -> bool {
+        match self.miner.mine() {
+            Ok(result) => {
+                self.buffer.extend(result.top_sequences);
+                !self.buffer.is_empty()
+            }
+            Err(_) => false,
+        }
+    }
+
+    fn to_candidate(&self, seq: MinedSequence) -> OperatorCandidate {
+        let c = seq.coord5d;
+        let signature = Signature5D::new(c[0], c[1], c[2], c[3], c[4]);
+
+        // Phase from the mean slot value, stability from value spread
+        let n = seq.values.len().max(1) as f64;
+        let mean = seq.values.iter().sum::<f64>() / n;
+        let var = seq.values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let resonance = match &self.resonance {
+            Some(config) => config.resonance_5d(&signature),
+            None => seq.resonance,
+        };
+
+        OperatorCandidate {
+            id: format!("slots_{}", self.produced),
+            signature,
+            phase: 2.0 * PI * mean.clamp(0.0, 1.0),
+            resonance,
+            stability: 1.0 / (1.0 + var.sqrt()),
+            is_mandorla: resonance >= DEFAULT_MANDORLA_THRESHOLD,
+            node_index: self.produced,
+            discovered_at: seq.found_at_step as f64,
+        }
+    }
+}
+
+impl Default for SlotsCandidateSource {
+    fn default() -> Self {
+        Self::new(MinerConfig::quick())
+    }
+}
+
+impl CandidateSource for SlotsCandidateSource {
+    fn name(&self) -> &str {
+        "slots"
+    }
+
+    fn next_batch(&mut self, n: usize) -> Vec<OperatorCandidate> {
+        let mut batch = Vec::with_capacity(n);
+
+        while batch.len() < n {
+            if self.buffer.is_empty() && !self.refill() {
+                break;
+            }
+            if let Some(seq) = self.buffer.pop_front() {
+                batch.push(self.to_candidate(seq));
+                self.produced += 1;
+            }
+        }
+
+        batch
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use qops_core::ResonanceWeights;
+
+    #[test]
+    fn test_slots_source_batch() {
+        let mut source = SlotsCandidateSource::default();
+        let batch = source.next_batch(7);
+
+        assert_eq!(batch.len(), 7);
+        assert!(batch.iter().all(|c| (0.0..=1.0).contains(&c.signature.psi)));
+    }
+
+    #[test]
+    fn test_weighted_resonance() {
+        let weights = ResonanceWeights::from_array([0.0, 1.0, 0.0, 0.0, 0.0]);
+        let mut source = SlotsCandidateSource::default().with_resonance(ResonanceConfig::with_weights(weights));
+        for candidate in source.next_batch(5) {
+            assert_eq!(candidate.resonance, candidate.signature.rho.clamp(0.0, 1.0));
+        }
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Maps randomness sources to structured operator outcomes.
 
+use crate::lattice::LatticeConfig;
+use crate::DEFAULT_ENTROPY_DEPTH;
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
 use serde::{Deserialize, Serialize};
@@ -53,6 +55,10 @@ pub struct EntropyConfig {
     pub std_dev: f64,
     /// Bias factor (shifts distribution)
     pub bias: f64,
+    /// Derive `depth` from mining depth and lattice size, see
+    /// [`EntropyConfig::auto_depth_for`]
+    #[serde(default)]
+    pub auto_depth: bool,
 }
 
 impl Default for EntropyConfig {
@@ -60,11 +66,12 @@ impl Default for EntropyConfig {
         Self {
             distribution: EntropyDistribution::Uniform,
             source: EntropySource::PRNG,
-            depth: 8,
+            depth: DEFAULT_ENTROPY_DEPTH,
             seed: None,
             mean: 0.5,
             std_dev: 0.2,
             bias: 0.0,
+            auto_depth: false,
         }
     }
 }
@@ -96,6 +103,32 @@ impl EntropyConfig {
             ..Default::default()
         }
     }
+
+    /// Enable entropy depth auto-scaling
+    pub fn with_auto_depth(mut self) -> Self {
+        self.auto_depth = true;
+        self
+    }
+
+    /// Entropy depth for a mining depth and lattice
+    ///
+    /// `max(DEFAULT_ENTROPY_DEPTH, rows × cols) + ⌈log₂(mining_depth + 1)⌉`:
+    /// one value per lattice slot per spin, plus headroom growing slowly with
+    /// the number of mining iterations.
+    pub fn auto_depth_for(mining_depth: usize, lattice: &LatticeConfig) -> usize {
+        let slots = lattice.rows * lattice.cols;
+        let headroom = ((mining_depth + 1) as f64).log2().ceil() as usize;
+        DEFAULT_ENTROPY_DEPTH.max(slots) + headroom
+    }
+
+    /// Config with `depth` resolved; unchanged unless `auto_depth` is set
+    pub fn resolved(&self, mining_depth: usize, lattice: &LatticeConfig) -> Self {
+        let mut config = self.clone();
+        if config.auto_depth {
+            config.depth = Self::auto_depth_for(mining_depth, lattice);
+        }
+        config
+    }
 }
 
 /// Entropy mapper for generating structured random values
@@ -267,6 +300,24 @@ mod tests {
         assert!(mean > 0.4); // Should be biased upward
     }
 
+    #[test]
+    fn test_auto_depth() {
+        let lattice = LatticeConfig::default();
+
+        // Manual depth is kept unless auto-scaling is enabled
+        let manual = EntropyConfig { depth: 12, ..Default::default() };
+        assert_eq!(manual.resolved(50, &lattice).depth, 12);
+
+        let auto = manual.with_auto_depth();
+        // 3×5 lattice, ⌈log₂ 51⌉ = 6
+        assert_eq!(auto.resolved(50, &lattice).depth, 21);
+        assert!(auto.resolved(50, &lattice).depth >= DEFAULT_ENTROPY_DEPTH);
+
+        let larger = LatticeConfig { rows: 6, cols: 6, ..Default::default() };
+        assert!(auto.resolved(50, &larger).depth > auto.resolved(50, &lattice).depth);
+        assert!(auto.resolved(500, &lattice).depth > auto.resolved(50, &lattice).depth);
+    }
+
     #[test]
     fn test_coord5d_mapping() {
         let mut mapper = EntropyMapper::default_mapper();
@@ -140,6 +140,9 @@ pub struct LatticeResult {
     pub winning_lines: Vec<WinLine>,
     /// Bonus activated
     pub bonus_activated: bool,
+    /// Lattice resonance after the spin
+    #[serde(default)]
+    pub resonance: f64,
     /// Timestamp
     pub timestamp: String,
 }
@@ -244,12 +247,48 @@ impl SlotLattice {
 
     /// Spin all unlocked slots
     pub fn spin(&mut self, entropy_mapper: &mut EntropyMapper) -> Result<LatticeResult> {
+        self.spin_with(|_, _| entropy_mapper.generate())
+    }
+
+    /// Spin near a previous outcome
+    ///
+    /// Each slot's entropy is the entropy that produced its value in `anchor`,
+    /// perturbed uniformly by at most `spread`. Slots missing from `anchor`
+    /// spin freely.
+    pub fn spin_near(
+        &mut self,
+        entropy_mapper: &mut EntropyMapper,
+        anchor: &LatticeResult,
+        spread: f64,
+    ) -> Result<LatticeResult> {
+        self.spin_with(|pos, slot| {
+            let fresh = entropy_mapper.generate();
+            match anchor.values.get(&pos) {
+                Some(value) => {
+                    let range = slot.config.max_value - slot.config.min_value;
+                    let base = if range > 0.0 {
+                        (value.value - slot.config.min_value) / range
+                    } else {
+                        0.5
+                    };
+                    (base + spread * (2.0 * fresh - 1.0)).clamp(0.0, 1.0)
+                }
+                None => fresh,
+            }
+        })
+    }
+
+    /// Spin every slot with entropy from `entropy_for`
+    fn spin_with<F>(&mut self, mut entropy_for: F) -> Result<LatticeResult>
+    where
+        F: FnMut((usize, usize), &Slot) -> f64,
+    {
         let mut values = HashMap::new();
 
         // Spin each slot
         for ((row, col), node) in &mut self.nodes {
             node.slot.spin();
-            let entropy = entropy_mapper.generate();
+            let entropy = entropy_for((*row, *col), &node.slot);
             let value = node.slot.stop(entropy);
             values.insert((*row, *col), value);
         }
@@ -277,6 +316,7 @@ impl SlotLattice {
             total_score,
             winning_lines,
             bonus_activated,
+            resonance: self.average_resonance(),
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
 
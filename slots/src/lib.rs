@@ -40,6 +40,7 @@ pub mod topology;
 pub mod spin;
 pub mod session;
 pub mod hypercube_integration;
+pub mod candidate_source;
 pub mod error;
 
 // Re-exports
@@ -52,6 +53,7 @@ pub use topology::{SlotTopology, TopologyType, TopologyMetrics};
 pub use spin::{SlotSpin, SpinState, SpinDynamics};
 pub use session::{SlotsSession, SlotsSessionConfig, SlotsSessionResult};
 pub use hypercube_integration::{SlotsHypercubeAdapter, SlotArtifact};
+pub use candidate_source::SlotsCandidateSource;
 pub use error::{SlotsError, Result};
 
 /// Slots engine version
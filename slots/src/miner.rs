@@ -226,7 +226,9 @@ impl SequenceMiner {
     /// Create a new miner
     pub fn new(config: MinerConfig) -> Self {
         let lattice = SlotLattice::new(config.lattice_config.clone());
-        let entropy_mapper = EntropyMapper::new(config.entropy_config.clone());
+        let entropy_mapper = EntropyMapper::new(
+            config.entropy_config.resolved(config.depth, &config.lattice_config),
+        );
 
         Self {
             config,
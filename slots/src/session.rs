@@ -10,6 +10,9 @@ use crate::error::Result;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Maximum entropy perturbation of a free spin around its anchor spin
+pub const FREE_SPIN_SPREAD: f64 = 0.1;
+
 /// Session configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SlotsSessionConfig {
@@ -27,6 +30,23 @@ pub struct SlotsSessionConfig {
     pub spins_before_mine: usize,
     /// Target resonance
     pub target_resonance: f64,
+    /// Resonance above which a spin grants free spins near its outcome
+    #[serde(default)]
+    pub free_spin_trigger: Option<f64>,
+    /// Free spins granted per trigger
+    #[serde(default = "default_free_spins_per_trigger")]
+    pub free_spins_per_trigger: usize,
+    /// Cap on free spins over the whole session
+    #[serde(default = "default_max_free_spins")]
+    pub max_free_spins: usize,
+}
+
+fn default_free_spins_per_trigger() -> usize {
+    3
+}
+
+fn default_max_free_spins() -> usize {
+    30
 }
 
 impl Default for SlotsSessionConfig {
@@ -39,6 +59,9 @@ impl Default for SlotsSessionConfig {
             auto_mine: true,
             spins_before_mine: 10,
             target_resonance: 0.8,
+            free_spin_trigger: None,
+            free_spins_per_trigger: default_free_spins_per_trigger(),
+            max_free_spins: default_max_free_spins(),
         }
     }
 }
@@ -65,6 +88,12 @@ impl SlotsSessionConfig {
             ..Default::default()
         }
     }
+
+    /// Grant free spins when a spin's resonance exceeds `trigger`
+    pub fn with_free_spins(mut self, trigger: f64) -> Self {
+        self.free_spin_trigger = Some(trigger);
+        self
+    }
 }
 
 /// Session state
@@ -93,6 +122,12 @@ pub struct SlotsSessionResult {
     pub spin_count: usize,
     /// Spin results
     pub spin_results: Vec<LatticeResult>,
+    /// Number of free spins granted
+    #[serde(default)]
+    pub free_spin_count: usize,
+    /// Free spin results, in order
+    #[serde(default)]
+    pub free_spin_results: Vec<LatticeResult>,
     /// Mining result (if performed)
     pub mining_result: Option<MiningResult>,
     /// Best sequence found
@@ -119,6 +154,8 @@ pub struct SlotsSession {
     miner: SequenceMiner,
     /// Spin results
     spin_results: Vec<LatticeResult>,
+    /// Free spin results
+    free_spin_results: Vec<LatticeResult>,
     /// Mining result
     mining_result: Option<MiningResult>,
     /// Best sequence
@@ -132,9 +169,10 @@ impl SlotsSession {
     pub fn new(config: SlotsSessionConfig) -> Self {
         let topology = SlotTopology::new(config.topology);
         let lattice_config = topology.to_lattice_config();
+        let entropy_mapper = EntropyMapper::new(
+            config.entropy_config.resolved(config.miner_config.depth, &lattice_config),
+        );
         let lattice = SlotLattice::new(lattice_config);
-
-        let entropy_mapper = EntropyMapper::new(config.entropy_config.clone());
         let miner = SequenceMiner::new(config.miner_config.clone());
 
         Self {
@@ -145,6 +183,7 @@ impl SlotsSession {
             entropy_mapper,
             miner,
             spin_results: Vec::new(),
+            free_spin_results: Vec::new(),
             mining_result: None,
             best_sequence: None,
             start_time: std::time::Instant::now(),
@@ -173,12 +212,41 @@ impl SlotsSession {
     }
 
     /// Run spin phase
+    ///
+    /// With a free-spin trigger set, every spin (regular or free) above the
+    /// trigger grants free spins near its outcome, up to `max_free_spins`
+    /// over the session.
     pub fn run_spins(&mut self, count: usize) -> Result<()> {
         self.state = SessionState::Spinning;
 
         for _ in 0..count {
             let result = self.lattice.spin(&mut self.entropy_mapper)?;
-            self.spin_results.push(result);
+            self.spin_results.push(result.clone());
+            self.run_free_spins(result)?;
+        }
+
+        Ok(())
+    }
+
+    /// Chain free spins from a triggering spin
+    fn run_free_spins(&mut self, result: LatticeResult) -> Result<()> {
+        let Some(trigger) = self.config.free_spin_trigger else {
+            return Ok(());
+        };
+
+        let mut anchors = vec![result];
+        while let Some(anchor) = anchors.pop() {
+            if anchor.resonance <= trigger {
+                continue;
+            }
+            for _ in 0..self.config.free_spins_per_trigger {
+                if self.free_spin_results.len() >= self.config.max_free_spins {
+                    return Ok(());
+                }
+                let free = self.lattice.spin_near(&mut self.entropy_mapper, &anchor, FREE_SPIN_SPREAD)?;
+                self.free_spin_results.push(free.clone());
+                anchors.push(free);
+            }
         }
 
         Ok(())
@@ -218,6 +286,11 @@ impl SlotsSession {
         &self.spin_results
     }
 
+    /// Get free spin results
+    pub fn free_spin_results(&self) -> &[LatticeResult] {
+        &self.free_spin_results
+    }
+
     /// Get mining result
     pub fn mining_result(&self) -> Option<&MiningResult> {
         self.mining_result.as_ref()
@@ -243,6 +316,8 @@ impl SlotsSession {
             state: self.state,
             spin_count: self.spin_results.len(),
             spin_results: self.spin_results.clone(),
+            free_spin_count: self.free_spin_results.len(),
+            free_spin_results: self.free_spin_results.clone(),
             mining_result: self.mining_result.clone(),
             best_sequence: self.best_sequence.clone(),
             best_resonance: self.best_resonance(),
@@ -254,6 +329,7 @@ impl SlotsSession {
     pub fn reset(&mut self) {
         self.lattice.reset();
         self.spin_results.clear();
+        self.free_spin_results.clear();
         self.mining_result = None;
         self.best_sequence = None;
         self.state = SessionState::Created;
@@ -287,4 +363,37 @@ mod tests {
         assert_eq!(result.state, SessionState::Completed);
         assert!(result.spin_count > 0);
     }
+
+    #[test]
+    fn test_free_spins_respect_cap() {
+        let config = SlotsSessionConfig {
+            auto_mine: false,
+            max_free_spins: 7,
+            ..SlotsSessionConfig::quick().with_free_spins(-1.0)
+        };
+        let mut session = SlotsSession::new(config);
+        let result = session.run().unwrap();
+
+        assert_eq!(result.spin_count, 5);
+        assert_eq!(result.free_spin_count, 7);
+        assert_eq!(result.free_spin_results.len(), 7);
+    }
+
+    #[test]
+    fn test_free_spins_concentrate_near_good_spins() {
+        let config = SlotsSessionConfig {
+            auto_mine: false,
+            spins_before_mine: 300,
+            max_free_spins: 300,
+            ..SlotsSessionConfig::default().with_free_spins(0.3)
+        };
+        let mut session = SlotsSession::new(config);
+        let result = session.run().unwrap();
+
+        let mean = |spins: &[LatticeResult]| {
+            spins.iter().map(|r| r.resonance).sum::<f64>() / spins.len() as f64
+        };
+        assert!(result.free_spin_count > 0);
+        assert!(mean(&result.free_spin_results) > mean(&result.spin_results));
+    }
 }
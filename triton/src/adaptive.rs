@@ -12,7 +12,7 @@
 
 use crate::config::TritonConfig;
 use crate::spiral::{SpiralEngine, SpiralTrajectory};
-use qops_core::{Signature5D, resonance_5d, HolisticMatrix, HolisticConfig, OperatorCandidate};
+use qops_core::{Signature5D, resonance_5d, resonance_5d_weighted, HolisticMatrix, HolisticConfig, OperatorCandidate};
 use serde::{Deserialize, Serialize};
 use rand::Rng;
 use std::f64::consts::PI;
@@ -202,11 +202,18 @@ pub struct DynamicCoolingController {
     score_history: Vec<f64>,
     step: usize,
     reheat_count: usize,
+    rng: rand::rngs::StdRng,
 }
 
 impl DynamicCoolingController {
     /// Create new dynamic cooling controller
     pub fn new(config: DynamicCoolingConfig) -> Self {
+        Self::with_seed(config, rand::random())
+    }
+
+    /// Create with specific seed for the Metropolis criterion
+    pub fn with_seed(config: DynamicCoolingConfig, seed: u64) -> Self {
+        use rand::SeedableRng;
         Self {
             temperature: config.initial_temp,
             config,
@@ -214,6 +221,7 @@ impl DynamicCoolingController {
             score_history: Vec::new(),
             step: 0,
             reheat_count: 0,
+            rng: rand::rngs::StdRng::seed_from_u64(seed),
         }
     }
 
@@ -294,7 +302,7 @@ impl DynamicCoolingController {
     }
 
     /// Accept move based on temperature (Metropolis criterion)
-    pub fn accept(&self, current_score: f64, new_score: f64) -> bool {
+    pub fn accept(&mut self, current_score: f64, new_score: f64) -> bool {
         if new_score >= current_score {
             return true;
         }
@@ -302,7 +310,7 @@ impl DynamicCoolingController {
         let delta = new_score - current_score;
         let probability = (delta / self.temperature).exp();
 
-        rand::thread_rng().gen::<f64>() < probability
+        self.rng.gen::<f64>() < probability
     }
 
     /// Get cooling statistics
@@ -485,6 +493,22 @@ impl TopologyGaussianBias {
     pub fn centers(&self) -> &[GaussianCenter] {
         &self.centers
     }
+
+    /// Owned copy of the current centers, for checkpointing
+    pub fn snapshot(&self) -> Vec<GaussianCenter> {
+        self.centers.clone()
+    }
+}
+
+/// Bias centers captured at a checkpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BiasSnapshot {
+    /// Optimizer iteration at capture
+    pub iteration: usize,
+    /// Spiral layer at capture
+    pub layer: usize,
+    /// Centers at capture
+    pub centers: Vec<GaussianCenter>,
 }
 
 // ============================================================================
@@ -743,13 +767,7 @@ impl DriftCorrector {
         if drift > self.max_drift {
             // Pull back towards anchor
             let factor = 1.0 - self.correction_strength * (drift - self.max_drift) / drift;
-            Signature5D::new(
-                anchor.psi + factor * (sig.psi - anchor.psi),
-                anchor.rho + factor * (sig.rho - anchor.rho),
-                anchor.omega + factor * (sig.omega - anchor.omega),
-                anchor.chi + factor * (sig.chi - anchor.chi),
-                anchor.eta + factor * (sig.eta - anchor.eta),
-            )
+            Signature5D::lerp(anchor, sig, factor)
         } else {
             *sig
         }
@@ -790,6 +808,9 @@ pub struct AdaptiveTritonConfig {
     pub resonance_weighted: bool,
     /// Integrate with Holistic Matrix
     pub holistic_integration: bool,
+    /// Record bias center snapshots at every layer boundary
+    #[serde(default)]
+    pub track_bias_centers: bool,
 }
 
 impl Default for AdaptiveTritonConfig {
@@ -806,6 +827,7 @@ impl Default for AdaptiveTritonConfig {
             local_iterations: 100,
             resonance_weighted: true,
             holistic_integration: true,
+            track_bias_centers: false,
         }
     }
 }
@@ -831,6 +853,9 @@ pub struct AdaptiveOptimizationResult {
     pub convergence_point: Option<usize>,
     /// Holistic matrix output (if enabled)
     pub holistic_output: Option<HolisticMatrixOutput>,
+    /// Bias center evolution (if `track_bias_centers` is enabled)
+    #[serde(default)]
+    pub bias_history: Vec<BiasSnapshot>,
 }
 
 /// Holistic matrix integration output
@@ -857,12 +882,22 @@ pub struct AdaptiveTritonOptimizer {
     best_score: f64,
     iteration: usize,
     trajectory: SpiralTrajectory,
+    bias_history: Vec<BiasSnapshot>,
 }
 
 impl AdaptiveTritonOptimizer {
     /// Create new adaptive TRITON optimizer
+    ///
+    /// With `config.base.deterministic` set, the spiral, the Metropolis
+    /// acceptance and the Gaussian bias sampler are all seeded from
+    /// `config.base.seed`, so equal seeds and scorers give equal results.
     pub fn new(config: AdaptiveTritonConfig) -> Self {
-        let spiral = SpiralEngine::new(config.base.spiral.clone());
+        let seeds: [u64; 3] = if config.base.deterministic {
+            std::array::from_fn(|i| config.base.seed.wrapping_add(i as u64))
+        } else {
+            rand::random()
+        };
+        let spiral = SpiralEngine::with_seed(config.base.spiral.clone(), seeds[0]);
         let num_layers = config.base.spiral.layers;
 
         let holistic = if config.holistic_integration {
@@ -874,8 +909,8 @@ impl AdaptiveTritonOptimizer {
         Self {
             spiral,
             radius_controller: AdaptiveRadiusController::new(config.radius.clone()),
-            cooling: DynamicCoolingController::new(config.cooling.clone()),
-            topology_bias: TopologyGaussianBias::new(config.topology_bias.clone()),
+            cooling: DynamicCoolingController::with_seed(config.cooling.clone(), seeds[1]),
+            topology_bias: TopologyGaussianBias::with_seed(config.topology_bias.clone(), seeds[2]),
             layer_memory: SpiralLayerMemory::new(num_layers),
             convergence: ConvergenceStabilizer::new(config.convergence.clone()),
             drift_corrector: DriftCorrector::new(config.max_drift, 0.3),
@@ -884,13 +919,15 @@ impl AdaptiveTritonOptimizer {
             best_score: 0.0,
             iteration: 0,
             trajectory: SpiralTrajectory::new(),
+            bias_history: Vec::new(),
             config,
         }
     }
 
-    /// Run optimization with default resonance scoring
+    /// Run optimization with resonance scoring under the configured weights
     pub fn optimize(&mut self) -> AdaptiveOptimizationResult {
-        self.optimize_with_scorer(|sig| resonance_5d(sig))
+        let weights = self.config.base.resonance.weights.to_array();
+        self.optimize_with_scorer(move |sig| resonance_5d_weighted(sig, &weights))
     }
 
     /// Run optimization with custom scoring function
@@ -976,6 +1013,7 @@ impl AdaptiveTritonOptimizer {
             if self.spiral.state().layer > current_layer {
                 self.layer_memory.finalize_layer(current_layer, &layer_scores);
                 self.trajectory.mark_layer();
+                self.record_bias_snapshot(current_layer);
                 layer_scores.clear();
                 current_layer = self.spiral.state().layer;
             }
@@ -993,6 +1031,7 @@ impl AdaptiveTritonOptimizer {
         // Finalize last layer
         if !layer_scores.is_empty() {
             self.layer_memory.finalize_layer(current_layer, &layer_scores);
+            self.record_bias_snapshot(current_layer);
         }
 
         // Phase 2: Local search with fine-grain refinement
@@ -1027,9 +1066,26 @@ impl AdaptiveTritonOptimizer {
             cooling_stats: self.cooling.stats(),
             convergence_point: self.convergence.convergence_point(),
             holistic_output,
+            bias_history: self.bias_history.clone(),
+        }
+    }
+
+    /// Capture the bias centers if tracking is enabled
+    fn record_bias_snapshot(&mut self, layer: usize) {
+        if self.config.track_bias_centers {
+            self.bias_history.push(BiasSnapshot {
+                iteration: self.iteration,
+                layer,
+                centers: self.topology_bias.snapshot(),
+            });
         }
     }
 
+    /// Bias center snapshots recorded so far
+    pub fn bias_history(&self) -> &[BiasSnapshot] {
+        &self.bias_history
+    }
+
     /// Fine-grain local search
     fn local_search<F>(&mut self, start: &Signature5D, scorer: &F) -> Signature5D
     where
@@ -1097,6 +1153,7 @@ impl AdaptiveTritonOptimizer {
         self.best_score = 0.0;
         self.iteration = 0;
         self.trajectory = SpiralTrajectory::new();
+        self.bias_history.clear();
 
         if self.config.holistic_integration {
             self.holistic = Some(HolisticMatrix::new(HolisticConfig::default()));
@@ -1212,4 +1269,62 @@ mod tests {
         assert!(result.best_score > 0.0);
         assert!(result.iterations > 0);
     }
+
+    #[test]
+    fn test_seeded_adaptive_triton_is_reproducible() {
+        let run = |seed: u64| {
+            let config = AdaptiveTritonConfig {
+                base: TritonConfig {
+                    max_iterations: 60,
+                    spiral: SpiralParams {
+                        layers: 3,
+                        points_per_layer: 8,
+                        ..Default::default()
+                    },
+                    ..TritonConfig::deterministic(seed)
+                },
+                local_iterations: 40,
+                track_bias_centers: true,
+                ..Default::default()
+            };
+            let result = AdaptiveTritonOptimizer::new(config).optimize();
+            serde_json::to_string(&result).unwrap()
+        };
+
+        assert_eq!(run(7), run(7));
+        assert_ne!(run(7), run(8));
+    }
+
+    #[test]
+    fn test_bias_center_tracking() {
+        let config = AdaptiveTritonConfig {
+            base: TritonConfig {
+                max_iterations: 50,
+                spiral: SpiralParams {
+                    layers: 3,
+                    points_per_layer: 6,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            holistic_integration: false,
+            local_search: false,
+            track_bias_centers: true,
+            ..Default::default()
+        };
+
+        let mut optimizer = AdaptiveTritonOptimizer::new(config);
+        let result = optimizer.optimize();
+
+        assert!(!result.bias_history.is_empty());
+        assert!(result
+            .bias_history
+            .windows(2)
+            .all(|w| w[0].iteration <= w[1].iteration));
+        let last = result.bias_history.last().unwrap();
+        assert_eq!(last.centers.len(), optimizer.topology_bias.centers().len());
+
+        optimizer.reset();
+        assert!(optimizer.bias_history().is_empty());
+    }
 }
@@ -1,5 +1,8 @@
 //! TRITON configuration types.
 
+use crate::refinement::PassMode;
+use crate::scoring::{check_cache_resolution, DEFAULT_CACHE_CAPACITY, DEFAULT_CACHE_RESOLUTION};
+use qops_core::ResonanceConfig;
 use serde::{Deserialize, Serialize};
 
 /// Main TRITON optimizer configuration
@@ -25,6 +28,27 @@ pub struct TritonConfig {
     pub seed: u64,
     /// Enable verbose logging
     pub verbose: bool,
+    /// Resonance weights used by the default scorer
+    #[serde(default)]
+    pub resonance: ResonanceConfig,
+    /// Score cache size (least recently used entries are evicted)
+    #[serde(default = "default_cache_capacity")]
+    pub cache_capacity: usize,
+    /// Score cache grid cell width, positive and finite
+    #[serde(default = "default_cache_resolution", deserialize_with = "deserialize_cache_resolution")]
+    pub cache_resolution: f64,
+}
+
+fn default_cache_capacity() -> usize {
+    DEFAULT_CACHE_CAPACITY
+}
+
+fn default_cache_resolution() -> f64 {
+    DEFAULT_CACHE_RESOLUTION
+}
+
+fn deserialize_cache_resolution<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    check_cache_resolution(f64::deserialize(deserializer)?).map_err(serde::de::Error::custom)
 }
 
 impl Default for TritonConfig {
@@ -40,6 +64,9 @@ impl Default for TritonConfig {
             deterministic: false,
             seed: 42,
             verbose: false,
+            resonance: ResonanceConfig::default(),
+            cache_capacity: DEFAULT_CACHE_CAPACITY,
+            cache_resolution: DEFAULT_CACHE_RESOLUTION,
         }
     }
 }
@@ -75,6 +102,12 @@ impl TritonConfig {
         }
     }
 
+    /// Use custom resonance weights for the default scorer
+    pub fn with_resonance(mut self, resonance: ResonanceConfig) -> Self {
+        self.resonance = resonance;
+        self
+    }
+
     /// Create config for deterministic reproducible search
     pub fn deterministic(seed: u64) -> Self {
         Self {
@@ -195,6 +228,9 @@ pub struct RefinementConfig {
     pub gradient_step: f64,
     /// Local search iterations
     pub local_iterations: usize,
+    /// Mode of the last pass, e.g. basin hopping for a global-local hybrid
+    #[serde(default)]
+    pub final_pass: PassMode,
 }
 
 impl Default for RefinementConfig {
@@ -206,6 +242,7 @@ impl Default for RefinementConfig {
             gradient_refinement: true,
             gradient_step: 0.01,
             local_iterations: 50,
+            final_pass: PassMode::Local,
         }
     }
 }
@@ -57,20 +57,20 @@ pub mod adaptive;
 
 // Re-exports
 pub use config::{TritonConfig, SpiralParams, TemperatureSchedule, RefinementConfig};
-pub use spiral::{SpiralEngine, SpiralState, SpiralDirection};
+pub use spiral::{SpiralEngine, SpiralState, SpiralDirection, SpiralTrajectory, TrajectoryPoint};
 pub use temperature::{TemperatureController, AnnealingStrategy};
 pub use layer::{LayerManager, SearchLayer, LayerMetrics};
-pub use optimizer::{TritonOptimizer, OptimizationResult, OptimizationStep};
+pub use optimizer::{TritonOptimizer, OptimizationResult, OptimizationStep, MultiStartResult, StartSummary};
 pub use search::{SearchStrategy, SearchState, SearchMetadata};
-pub use topology_bias::{TopologyBias, BiasMode, NeighborhoodWeights};
-pub use refinement::{RefinementEngine, RefinementPass, RefinementResult};
-pub use scoring::{ScoringFunction, ScoreCache, CompositeScore};
-pub use session::{TritonSession, SessionConfig, SessionLog, SessionEvent};
+pub use topology_bias::{TopologyBias, BiasMode, NeighborhoodWeights, GRAPH_SMOOTHING_RADIUS};
+pub use refinement::{RefinementEngine, RefinementPass, RefinementResult, PassMode};
+pub use scoring::{ScoringFunction, ScoreCache, CompositeScore, DEFAULT_CACHE_CAPACITY, DEFAULT_CACHE_RESOLUTION};
+pub use session::{TritonSession, SessionConfig, SessionLog, SessionEvent, SessionCheckpoint};
 pub use adaptive::{
     AdaptiveTritonConfig, AdaptiveTritonOptimizer, AdaptiveOptimizationResult,
     AdaptiveRadiusConfig, AdaptiveRadiusController, AdaptiveRadiusStats,
     DynamicCoolingConfig, DynamicCoolingController, DynamicCoolingStats,
-    TopologyBiasConfig, TopologyGaussianBias, GaussianCenter,
+    TopologyBiasConfig, TopologyGaussianBias, GaussianCenter, BiasSnapshot,
     SpiralLayerMemory, LayerBest, LayerStats,
     ConvergenceStabilizerConfig, ConvergenceStabilizer,
     DriftCorrector, HolisticMatrixOutput,
@@ -3,12 +3,14 @@
 use crate::config::TritonConfig;
 use crate::layer::LayerManager;
 use crate::refinement::RefinementEngine;
-use crate::scoring::{ScoreCache, ScoringFunction, ResonanceScorer};
+use crate::scoring::{ScoreCache, ScoringFunction, WeightedScorer};
 use crate::search::{SearchHistory, SearchMetadata, SearchState, SearchStrategy};
-use crate::spiral::SpiralEngine;
+use crate::spiral::{SpiralEngine, SpiralState, SpiralTrajectory};
 use crate::temperature::TemperatureController;
 use crate::topology_bias::TopologyBias;
+use qops_core::stats::mix_seed;
 use qops_core::Signature5D;
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 /// Result of an optimization run
@@ -62,6 +64,34 @@ pub struct OptimizationStep {
     pub layer: usize,
 }
 
+/// Outcome of one start of a multi-start search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartSummary {
+    /// Start index
+    pub start: usize,
+    /// Seed the start ran with
+    pub seed: u64,
+    /// Spiral center the start began from
+    pub initial_signature: [f64; 5],
+    /// Best score the start reached
+    pub best_score: f64,
+    /// Iterations the start used
+    pub iterations: usize,
+    /// Whether the start converged
+    pub converged: bool,
+}
+
+/// Result of a multi-start search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiStartResult {
+    /// Result of the best start
+    pub best: OptimizationResult,
+    /// Index of the best start
+    pub best_start: usize,
+    /// Per-start summaries, in start order
+    pub starts: Vec<StartSummary>,
+}
+
 /// Main TRITON optimizer
 pub struct TritonOptimizer {
     config: TritonConfig,
@@ -73,6 +103,7 @@ pub struct TritonOptimizer {
     cache: ScoreCache,
     state: SearchState,
     history: SearchHistory,
+    trajectory: SpiralTrajectory,
     metadata: SearchMetadata,
 }
 
@@ -107,45 +138,139 @@ impl TritonOptimizer {
             refinement,
             layers: LayerManager::new(),
             bias: TopologyBias::default(),
-            cache: ScoreCache::default(),
+            cache: ScoreCache::with_capacity(config.cache_capacity).with_resolution(config.cache_resolution),
             state: SearchState {
                 max_iterations: config.max_iterations,
                 strategy: SearchStrategy::Triton,
                 ..Default::default()
             },
             history: SearchHistory::new(),
+            trajectory: SpiralTrajectory::new(),
             metadata: SearchMetadata::new(SearchStrategy::Triton),
             config,
         }
     }
 
-    /// Run optimization with default resonance scoring
+    /// Configuration the optimizer was created with
+    pub fn config(&self) -> &TritonConfig {
+        &self.config
+    }
+
+    /// Run optimization with resonance scoring under the configured weights
     pub fn optimize(&mut self) -> OptimizationResult {
-        self.optimize_with_scorer(&ResonanceScorer)
+        let scorer = WeightedScorer::from_config(&self.config.resonance);
+        self.optimize_with_scorer(&scorer)
     }
 
     /// Run optimization with custom scoring function
+    ///
+    /// Cached scores from earlier runs are dropped first, since they may come
+    /// from a different scorer; the cumulative cache statistics are kept.
     pub fn optimize_with_scorer<S: ScoringFunction>(&mut self, scorer: &S) -> OptimizationResult {
+        let _span = tracing::debug_span!(
+            "triton_optimize",
+            scorer = scorer.name(),
+            layers = self.config.spiral.layers,
+            max_iterations = self.config.max_iterations,
+            deterministic = self.config.deterministic,
+        )
+        .entered();
+
+        self.cache.clear();
         self.metadata.add_param("scorer", scorer.name());
         self.metadata.add_param("layers", self.config.spiral.layers);
         self.metadata.add_param("max_iterations", self.config.max_iterations);
 
         // Phase 1: Spiral exploration
+        let phase_start = std::time::Instant::now();
         self.spiral_phase(scorer);
+        self.trace_phase("spiral", phase_start);
 
         // Phase 2: Local refinement
+        let phase_start = std::time::Instant::now();
         if let Some(best) = self.state.best_as_signature() {
             let refined = self.refinement.refine_with_scorer(&best, scorer);
             let score = self.score_cached(&refined, scorer);
             self.state.update_best(&refined, score);
         }
+        self.trace_phase("refinement", phase_start);
 
         // Phase 3: Final polish with annealing
+        let phase_start = std::time::Instant::now();
         self.annealing_phase(scorer);
+        self.trace_phase("annealing", phase_start);
 
         self.finalize()
     }
 
+    /// Run `starts` independent searches in parallel and keep the best
+    ///
+    /// Start `i` runs with seed [`mix_seed`]`(seed, i)` (seeded from the configured seed in
+    /// deterministic mode, from a single random draw otherwise) and spirals
+    /// around its own center: start 0 uses the default center, later ones
+    /// step along a golden-ratio sequence through the unit cube. Ties go to
+    /// the lowest start index. Afterwards this optimizer holds the state and
+    /// history of the winning start.
+    pub fn optimize_multistart<S: ScoringFunction>(&mut self, starts: usize, scorer: &S) -> MultiStartResult {
+        let base_seed = if self.config.deterministic { self.config.seed } else { rand::random() };
+        let origin = crate::spiral::SpiralState::default().center;
+
+        let runs: Vec<(StartSummary, TritonOptimizer, OptimizationResult)> = (0..starts.max(1))
+            .into_par_iter()
+            .map(|start| {
+                let seed = mix_seed(base_seed, start as u64);
+                let center = multistart_center(origin, start);
+                let mut optimizer = TritonOptimizer::new(TritonConfig {
+                    deterministic: true,
+                    seed,
+                    ..self.config.clone()
+                });
+                optimizer.spiral.set_center(center);
+                let result = optimizer.optimize_with_scorer(scorer);
+                let summary = StartSummary {
+                    start,
+                    seed,
+                    initial_signature: center,
+                    best_score: result.best_score,
+                    iterations: result.iterations,
+                    converged: result.converged,
+                };
+                (summary, optimizer, result)
+            })
+            .collect();
+
+        let best_start = runs
+            .iter()
+            .enumerate()
+            .fold(0, |best, (i, run)| if run.2.best_score > runs[best].2.best_score { i } else { best });
+        let mut summaries = Vec::with_capacity(runs.len());
+        let mut best = None;
+        for (i, (summary, optimizer, result)) in runs.into_iter().enumerate() {
+            summaries.push(summary);
+            if i == best_start {
+                best = Some((optimizer, result));
+            }
+        }
+        let (winner, result) = best.expect("at least one start runs");
+
+        let config = std::mem::take(&mut self.config);
+        *self = winner;
+        self.config = config;
+
+        MultiStartResult { best: result, best_start, starts: summaries }
+    }
+
+    /// Emit a debug event summarizing a finished phase
+    fn trace_phase(&self, phase: &str, start: std::time::Instant) {
+        tracing::debug!(
+            phase,
+            iterations = self.state.iteration,
+            best_score = self.state.best_score,
+            elapsed_us = start.elapsed().as_micros() as u64,
+            "phase complete"
+        );
+    }
+
     /// Spiral exploration phase
     fn spiral_phase<S: ScoringFunction>(&mut self, scorer: &S) {
         let mut current_layer = 0;
@@ -157,6 +282,7 @@ impl TritonOptimizer {
             // Record in layer
             self.layers.record(point, score);
             self.bias.record_visit(&point, score);
+            self.trajectory.record(&point, score);
 
             // Update state
             let improved = self.state.update_best(&point, score);
@@ -173,6 +299,7 @@ impl TritonOptimizer {
             // Check for layer change
             if self.spiral.state().layer > current_layer {
                 current_layer = self.spiral.state().layer;
+                self.trajectory.mark_layer();
                 self.layers.start_layer(self.spiral.state().radius);
                 self.bias.update_progress(self.spiral.progress());
             }
@@ -252,6 +379,7 @@ impl TritonOptimizer {
 
         self.metadata.complete(&self.state);
         self.metadata.points_evaluated = self.cache.size();
+        (self.metadata.cache_hits, self.metadata.cache_misses, _) = self.cache.stats();
 
         let layer_scores = self.layers.convergence_trend();
 
@@ -277,6 +405,11 @@ impl TritonOptimizer {
         &self.history
     }
 
+    /// Points visited by the spiral phase
+    pub fn trajectory(&self) -> &SpiralTrajectory {
+        &self.trajectory
+    }
+
     /// Reset optimizer for reuse
     pub fn reset(&mut self) {
         self.spiral.reset();
@@ -284,12 +417,14 @@ impl TritonOptimizer {
         self.refinement.reset();
         self.layers = LayerManager::new();
         self.cache.clear();
+        self.cache.reset_stats();
         self.state = SearchState {
             max_iterations: self.config.max_iterations,
             strategy: SearchStrategy::Triton,
             ..Default::default()
         };
         self.history = SearchHistory::new();
+        self.trajectory = SpiralTrajectory::new();
         self.metadata = SearchMetadata::new(SearchStrategy::Triton);
     }
 
@@ -316,10 +451,58 @@ impl TritonOptimizer {
         })
     }
 
+    /// Get the score cache
+    pub fn cache(&self) -> &ScoreCache {
+        &self.cache
+    }
+
+    /// Get the spiral position
+    pub fn spiral_state(&self) -> &SpiralState {
+        self.spiral.state()
+    }
+
+    /// Continue stepping from a captured spiral position, search state,
+    /// history and score cache
+    ///
+    /// The temperature schedule is replayed up to the restored iteration.
+    pub(crate) fn restore(
+        &mut self,
+        spiral: SpiralState,
+        state: SearchState,
+        history: SearchHistory,
+        cache: ScoreCache,
+    ) {
+        self.spiral.restore_state(spiral);
+        self.temperature.reset();
+        for _ in 0..state.iteration {
+            self.temperature.advance();
+        }
+        self.state = state;
+        self.history = history;
+        self.cache = cache;
+    }
+
     /// Get cache statistics
     pub fn cache_stats(&self) -> (usize, usize, f64) {
         self.cache.stats()
     }
+
+    /// Drop cached scores, e.g. before stepping with a different scorer
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Spiral center for start `index`, stepping from `origin` along a golden-ratio sequence
+fn multistart_center(origin: [f64; 5], index: usize) -> [f64; 5] {
+    // Generalized golden ratio for five dimensions, the root of x^6 = x + 1
+    let g = 1.1347241384015194_f64;
+    let mut center = origin;
+    for (d, c) in center.iter_mut().enumerate() {
+        let alpha = g.powi(-(d as i32 + 1));
+        *c = (*c + index as f64 * alpha).fract();
+    }
+    center
 }
 
 /// Builder for TRITON optimizer
@@ -366,6 +549,12 @@ impl TritonOptimizerBuilder {
         self
     }
 
+    /// Set resonance weights in (ψ, ρ, ω, χ, η) order for the default scorer
+    pub fn resonance_weights(mut self, weights: [f64; 5]) -> Self {
+        self.config.resonance.weights = qops_core::ResonanceWeights::from_array(weights);
+        self
+    }
+
     /// Build optimizer
     pub fn build(self) -> TritonOptimizer {
         TritonOptimizer::new(self.config)
@@ -381,6 +570,7 @@ impl Default for TritonOptimizerBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::scoring::ResonanceScorer;
 
     #[test]
     fn test_optimizer_creation() {
@@ -406,6 +596,10 @@ mod tests {
 
         assert!(result.best_score > 0.0);
         assert!(result.iterations > 0);
+
+        let (hits, misses, _) = optimizer.cache_stats();
+        assert_eq!((result.metadata.cache_hits, result.metadata.cache_misses), (hits, misses));
+        assert!(misses > 0);
     }
 
     #[test]
@@ -431,4 +625,52 @@ mod tests {
         let step = step.unwrap();
         assert_eq!(step.step, 1);
     }
+
+    #[test]
+    fn test_configured_resonance_weights() {
+        let weights = [0.1, 0.8, 0.1, 0.0, 0.0];
+        let mut optimizer = TritonOptimizerBuilder::new()
+            .max_iterations(50)
+            .layers(2)
+            .deterministic(7)
+            .resonance_weights(weights)
+            .build();
+        let result = optimizer.optimize();
+
+        let [psi, rho, omega, chi, eta] = result.best_signature;
+        let best = Signature5D::new(psi, rho, omega, chi, eta);
+        assert!((result.best_score - qops_core::resonance_5d_weighted(&best, &weights)).abs() < 1e-12);
+        assert_eq!(result.metadata.parameters.get("scorer").map(String::as_str), Some("resonance"));
+    }
+
+    #[test]
+    fn test_multistart() {
+        let run = || {
+            let mut optimizer = TritonOptimizerBuilder::new()
+                .max_iterations(40)
+                .layers(2)
+                .deterministic(11)
+                .build();
+            let result = optimizer.optimize_multistart(4, &ResonanceScorer);
+            (result, optimizer.state().best_score)
+        };
+        let (result, state_best) = run();
+
+        assert_eq!(result.starts.len(), 4);
+        let seeds: Vec<u64> = result.starts.iter().map(|s| s.seed).collect();
+        assert_eq!(seeds, (0..4).map(|i| mix_seed(11, i)).collect::<Vec<_>>());
+        assert_eq!(result.starts[0].initial_signature, crate::spiral::SpiralState::default().center);
+        assert_ne!(result.starts[1].initial_signature, result.starts[2].initial_signature);
+
+        let max = result.starts.iter().map(|s| s.best_score).fold(f64::NEG_INFINITY, f64::max);
+        assert_eq!(result.best.best_score, max);
+        assert_eq!(result.starts[result.best_start].best_score, max);
+        assert_eq!(state_best, max);
+
+        let (again, _) = run();
+        assert_eq!(again.best_start, result.best_start);
+        assert_eq!(again.best.best_signature, result.best.best_signature);
+        let scores: Vec<f64> = again.starts.iter().map(|s| s.best_score).collect();
+        assert_eq!(scores, result.starts.iter().map(|s| s.best_score).collect::<Vec<_>>());
+    }
 }
@@ -23,6 +23,32 @@ pub struct RefinementResult {
     pub signature: [f64; 5],
     /// Search radius used
     pub radius: f64,
+    /// Basins searched (1 for a local pass)
+    #[serde(default = "one")]
+    pub basins_explored: usize,
+    /// Basin that produced the final score (0 = the starting basin)
+    #[serde(default)]
+    pub best_basin: usize,
+}
+
+fn one() -> usize {
+    1
+}
+
+/// How a refinement pass searches
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum PassMode {
+    /// Local search around the starting point
+    #[default]
+    Local,
+    /// Local search, then `restarts` times kick the best point by up to
+    /// `perturbation` per axis and search locally again, keeping the best basin
+    BasinHopping {
+        /// Kick size per axis
+        perturbation: f64,
+        /// Number of kicks
+        restarts: usize,
+    },
 }
 
 /// A single refinement pass
@@ -33,6 +59,7 @@ pub struct RefinementPass {
     pub iterations: usize,
     pub use_gradient: bool,
     pub gradient_step: f64,
+    pub mode: PassMode,
 }
 
 impl RefinementPass {
@@ -44,6 +71,15 @@ impl RefinementPass {
             iterations,
             use_gradient: true,
             gradient_step: 0.01,
+            mode: PassMode::Local,
+        }
+    }
+
+    /// Create a basin-hopping pass
+    pub fn basin_hopping(index: usize, radius: f64, iterations: usize, perturbation: f64, restarts: usize) -> Self {
+        Self {
+            mode: PassMode::BasinHopping { perturbation, restarts },
+            ..Self::new(index, radius, iterations)
         }
     }
 }
@@ -78,6 +114,7 @@ impl RefinementEngine {
                 iterations: config.local_iterations,
                 use_gradient: config.gradient_refinement,
                 gradient_step: config.gradient_step,
+                mode: if i + 1 == config.passes { config.final_pass } else { PassMode::Local },
             });
             radius *= config.shrink_factor;
         }
@@ -134,26 +171,43 @@ impl RefinementEngine {
 
     /// Run a single refinement pass
     fn run_pass(&mut self, pass: &RefinementPass, sig: &Signature5D) -> RefinementResult {
-        let initial_score = resonance_5d(sig);
-        let mut best = *sig;
-        let mut best_score = initial_score;
-        let mut iterations = 0;
-
-        for _ in 0..pass.iterations {
-            iterations += 1;
-
-            // Try local perturbation
-            let candidate = if pass.use_gradient && self.rng.gen::<f64>() > 0.3 {
-                self.gradient_step(&best, pass.gradient_step)
-            } else {
-                self.random_perturbation(&best, pass.radius)
-            };
+        self.run_pass_scored(pass, sig, resonance_5d)
+    }
 
-            let score = resonance_5d(&candidate);
+    /// Run a single refinement pass with custom scorer
+    fn run_pass_with_scorer<S: ScoringFunction>(
+        &mut self,
+        pass: &RefinementPass,
+        sig: &Signature5D,
+        scorer: &S,
+    ) -> RefinementResult {
+        self.run_pass_scored(pass, sig, |s| scorer.score(s))
+    }
 
-            if score > best_score {
-                best = candidate;
-                best_score = score;
+    fn run_pass_scored<F>(&mut self, pass: &RefinementPass, sig: &Signature5D, score: F) -> RefinementResult
+    where
+        F: Fn(&Signature5D) -> f64,
+    {
+        let initial_score = score(sig);
+        let (mut best, mut best_score, mut iterations) =
+            self.local_search(pass, sig, initial_score, &score);
+        let mut basins_explored = 1;
+        let mut best_basin = 0;
+
+        if let PassMode::BasinHopping { perturbation, restarts } = pass.mode {
+            for basin in 1..=restarts {
+                let kicked = self.random_perturbation(&best, perturbation);
+                let kicked_score = score(&kicked);
+                let (candidate, candidate_score, used) =
+                    self.local_search(pass, &kicked, kicked_score, &score);
+                iterations += used;
+                basins_explored += 1;
+
+                if candidate_score > best_score {
+                    best = candidate;
+                    best_score = candidate_score;
+                    best_basin = basin;
+                }
             }
         }
 
@@ -165,47 +219,43 @@ impl RefinementEngine {
             iterations,
             signature: [best.psi, best.rho, best.omega, best.chi, best.eta],
             radius: pass.radius,
+            basins_explored,
+            best_basin,
         }
     }
 
-    /// Run a single refinement pass with custom scorer
-    fn run_pass_with_scorer<S: ScoringFunction>(
+    /// Greedy local search from `start`, returning the best point, its score
+    /// and the iterations used
+    fn local_search<F>(
         &mut self,
         pass: &RefinementPass,
-        sig: &Signature5D,
-        scorer: &S,
-    ) -> RefinementResult {
-        let initial_score = scorer.score(sig);
-        let mut best = *sig;
-        let mut best_score = initial_score;
-        let mut iterations = 0;
+        start: &Signature5D,
+        start_score: f64,
+        score: &F,
+    ) -> (Signature5D, f64, usize)
+    where
+        F: Fn(&Signature5D) -> f64,
+    {
+        let mut best = *start;
+        let mut best_score = start_score;
 
         for _ in 0..pass.iterations {
-            iterations += 1;
-
+            // Try local perturbation
             let candidate = if pass.use_gradient && self.rng.gen::<f64>() > 0.3 {
                 self.gradient_step(&best, pass.gradient_step)
             } else {
                 self.random_perturbation(&best, pass.radius)
             };
 
-            let score = scorer.score(&candidate);
+            let candidate_score = score(&candidate);
 
-            if score > best_score {
+            if candidate_score > best_score {
                 best = candidate;
-                best_score = score;
+                best_score = candidate_score;
             }
         }
 
-        RefinementResult {
-            pass: pass.index,
-            initial_score,
-            final_score: best_score,
-            improvement: best_score - initial_score,
-            iterations,
-            signature: [best.psi, best.rho, best.omega, best.chi, best.eta],
-            radius: pass.radius,
-        }
+        (best, best_score, pass.iterations)
     }
 
     /// Take gradient step
@@ -444,4 +494,38 @@ mod tests {
         let refined = refiner.refine(&sig, 50, resonance_5d);
         assert!(resonance_5d(&refined) >= resonance_5d(&sig));
     }
+
+    #[test]
+    fn test_basin_hopping() {
+        // Local bump at 0.2 and a higher peak at 0.8 along ψ
+        let two_basins = |s: &Signature5D| {
+            0.5 * (-((s.psi - 0.2) / 0.05).powi(2)).exp() + (-((s.psi - 0.8) / 0.05).powi(2)).exp()
+        };
+        let start = Signature5D::new(0.2, 0.5, 0.5, 0.5, 0.5);
+        let mut local = RefinementPass::new(0, 0.02, 50);
+        local.use_gradient = false;
+        let hopping = RefinementPass { mode: PassMode::BasinHopping { perturbation: 0.7, restarts: 40 }, ..local.clone() };
+
+        let mut engine = RefinementEngine::with_seed(RefinementConfig::default(), 9);
+        let stuck = engine.run_pass_scored(&local, &start, two_basins);
+        assert!(stuck.final_score < 0.6);
+        assert_eq!((stuck.basins_explored, stuck.best_basin), (1, 0));
+
+        let result = engine.run_pass_scored(&hopping, &start, two_basins);
+        assert!(result.final_score > 0.9, "final score {}", result.final_score);
+        assert_eq!(result.basins_explored, 41);
+        assert!(result.best_basin > 0);
+        assert_eq!(result.iterations, 41 * 50);
+
+        // The configured final pass becomes basin hopping
+        let config = RefinementConfig {
+            passes: 2,
+            final_pass: PassMode::BasinHopping { perturbation: 0.3, restarts: 3 },
+            ..Default::default()
+        };
+        let mut engine = RefinementEngine::with_seed(config, 1);
+        engine.refine(&start);
+        let basins: Vec<usize> = engine.results().iter().map(|r| r.basins_explored).collect();
+        assert_eq!(basins, vec![1, 4]);
+    }
 }
@@ -1,8 +1,8 @@
 //! Scoring functions and caching for TRITON.
 
-use qops_core::{Signature5D, resonance_5d};
+use qops_core::{Signature5D, resonance_5d, resonance_5d_weighted, ResonanceConfig};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Trait for scoring functions
 pub trait ScoringFunction: Send + Sync {
@@ -41,6 +41,16 @@ impl WeightedScorer {
         }
     }
 
+    /// Scorer using the weights of a resonance configuration
+    ///
+    /// With default weights this scores exactly like [`ResonanceScorer`].
+    pub fn from_config(config: &ResonanceConfig) -> Self {
+        Self {
+            weights: config.weights.to_array(),
+            name: "resonance".to_string(),
+        }
+    }
+
     /// Quality-focused scorer
     pub fn quality_focused() -> Self {
         Self::new([0.6, 0.2, 0.2, 0.0, 0.0])
@@ -59,12 +69,7 @@ impl WeightedScorer {
 
 impl ScoringFunction for WeightedScorer {
     fn score(&self, sig: &Signature5D) -> f64 {
-        let values = [sig.psi, sig.rho, sig.omega, sig.chi, sig.eta];
-        let score: f64 = self.weights.iter()
-            .zip(values.iter())
-            .map(|(w, v)| w * v)
-            .sum();
-        score.clamp(0.0, 1.0)
+        resonance_5d_weighted(sig, &self.weights)
     }
 
     fn name(&self) -> &str {
@@ -115,35 +120,101 @@ impl CompositeScore {
     }
 }
 
-/// Cache for scores to avoid recomputation
-#[derive(Debug, Clone)]
+/// Default grid cell width for [`ScoreCache`] keys
+pub const DEFAULT_CACHE_RESOLUTION: f64 = 1e-10;
+
+/// Check that `resolution` is usable as a [`ScoreCache`] grid cell width
+pub(crate) fn check_cache_resolution(resolution: f64) -> Result<f64, String> {
+    if resolution.is_finite() && resolution > 0.0 {
+        Ok(resolution)
+    } else {
+        Err(format!("score cache resolution must be positive and finite, got {resolution}"))
+    }
+}
+
+/// Default number of entries a [`ScoreCache`] holds
+pub const DEFAULT_CACHE_CAPACITY: usize = 100_000;
+
+/// Least-recently-used cache for scores to avoid recomputation
+///
+/// Signatures are quantized to a grid of cell width `resolution`, so points
+/// in the same cell share an entry; a coarser grid makes near-duplicate
+/// points hit. Once `capacity` entries are stored, the least recently used
+/// one is evicted.
+///
+/// Entries are keyed on the signature only, so the cache must be cleared
+/// whenever the scoring function changes; stale scores from a previous
+/// scorer are otherwise returned as hits. It serializes its entries from
+/// least to most recently used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "ScoreCacheData", into = "ScoreCacheData")]
 pub struct ScoreCache {
-    cache: HashMap<[u64; 5], f64>,
+    /// Score and last-use tick per grid cell
+    cache: HashMap<[i64; 5], (f64, u64)>,
+    /// Grid cells by last-use tick, oldest first
+    recency: BTreeMap<u64, [i64; 5]>,
+    tick: u64,
     hits: usize,
     misses: usize,
-    max_size: usize,
+    capacity: usize,
+    resolution: f64,
 }
 
 impl ScoreCache {
-    /// Create new cache
+    /// Create new cache holding up to `max_size` entries
     pub fn new(max_size: usize) -> Self {
+        Self::with_capacity(max_size)
+    }
+
+    /// Create an LRU cache holding up to `capacity` entries
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
             cache: HashMap::new(),
+            recency: BTreeMap::new(),
+            tick: 0,
             hits: 0,
             misses: 0,
-            max_size,
+            capacity,
+            resolution: DEFAULT_CACHE_RESOLUTION,
+        }
+    }
+
+    /// Use grid cells of width `resolution`, dropping cached scores
+    ///
+    /// # Panics
+    ///
+    /// If `resolution` is not a positive finite number.
+    pub fn with_resolution(mut self, resolution: f64) -> Self {
+        if let Err(e) = check_cache_resolution(resolution) {
+            panic!("{e}");
         }
+        self.resolution = resolution;
+        self.clear();
+        self
+    }
+
+    /// Maximum number of entries
+    pub fn capacity(&self) -> usize {
+        self.capacity
     }
 
-    /// Hash signature to cache key
-    fn sig_to_key(sig: &Signature5D) -> [u64; 5] {
-        [
-            (sig.psi * 1e10) as u64,
-            (sig.rho * 1e10) as u64,
-            (sig.omega * 1e10) as u64,
-            (sig.chi * 1e10) as u64,
-            (sig.eta * 1e10) as u64,
-        ]
+    /// Grid cell width of the keys
+    pub fn resolution(&self) -> f64 {
+        self.resolution
+    }
+
+    /// Quantize signature to cache key
+    fn sig_to_key(&self, sig: &Signature5D) -> [i64; 5] {
+        [sig.psi, sig.rho, sig.omega, sig.chi, sig.eta].map(|x| (x / self.resolution).floor() as i64)
+    }
+
+    /// Mark a key as most recently used
+    fn touch(&mut self, key: [i64; 5], score: f64) {
+        if let Some((_, tick)) = self.cache.insert(key, (score, self.tick)) {
+            self.recency.remove(&tick);
+        }
+        self.recency.insert(self.tick, key);
+        self.tick += 1;
     }
 
     /// Get cached score or compute and cache
@@ -151,26 +222,30 @@ impl ScoreCache {
     where
         F: FnOnce(&Signature5D) -> f64,
     {
-        let key = Self::sig_to_key(sig);
+        let key = self.sig_to_key(sig);
 
-        if let Some(&score) = self.cache.get(&key) {
+        if let Some(&(score, _)) = self.cache.get(&key) {
             self.hits += 1;
+            self.touch(key, score);
             return score;
         }
 
         self.misses += 1;
         let score = compute(sig);
+        if self.capacity == 0 {
+            return score;
+        }
 
-        // Evict oldest entries if cache is full
-        if self.cache.len() >= self.max_size {
-            // Simple strategy: remove random 10%
-            let to_remove: Vec<_> = self.cache.keys().take(self.max_size / 10).cloned().collect();
-            for k in to_remove {
-                self.cache.remove(&k);
+        while self.cache.len() >= self.capacity {
+            match self.recency.pop_first() {
+                Some((_, oldest)) => {
+                    self.cache.remove(&oldest);
+                }
+                None => break,
             }
         }
 
-        self.cache.insert(key, score);
+        self.touch(key, score);
         score
     }
 
@@ -185,9 +260,14 @@ impl ScoreCache {
         (self.hits, self.misses, hit_rate)
     }
 
-    /// Clear cache
+    /// Drop all cached scores, keeping the cumulative hit/miss counters
     pub fn clear(&mut self) {
         self.cache.clear();
+        self.recency.clear();
+    }
+
+    /// Zero the hit/miss counters, keeping cached scores
+    pub fn reset_stats(&mut self) {
         self.hits = 0;
         self.misses = 0;
     }
@@ -200,7 +280,45 @@ impl ScoreCache {
 
 impl Default for ScoreCache {
     fn default() -> Self {
-        Self::new(100_000)
+        Self::with_capacity(DEFAULT_CACHE_CAPACITY)
+    }
+}
+
+/// Serialized form of [`ScoreCache`], since JSON object keys must be strings
+#[derive(Serialize, Deserialize)]
+struct ScoreCacheData {
+    /// `(key, score)` pairs from least to most recently used
+    entries: Vec<([i64; 5], f64)>,
+    hits: usize,
+    misses: usize,
+    capacity: usize,
+    resolution: f64,
+}
+
+impl From<ScoreCache> for ScoreCacheData {
+    fn from(cache: ScoreCache) -> Self {
+        Self {
+            entries: cache.recency.values().map(|key| (*key, cache.cache[key].0)).collect(),
+            hits: cache.hits,
+            misses: cache.misses,
+            capacity: cache.capacity,
+            resolution: cache.resolution,
+        }
+    }
+}
+
+impl TryFrom<ScoreCacheData> for ScoreCache {
+    type Error = String;
+
+    fn try_from(data: ScoreCacheData) -> Result<Self, Self::Error> {
+        let mut cache = ScoreCache::with_capacity(data.capacity);
+        cache.resolution = check_cache_resolution(data.resolution)?;
+        cache.hits = data.hits;
+        cache.misses = data.misses;
+        for (key, score) in data.entries {
+            cache.touch(key, score);
+        }
+        Ok(cache)
     }
 }
 
@@ -293,6 +411,11 @@ mod tests {
         let sig = Signature5D::new(1.0, 0.5, 0.5, 0.0, 0.0);
         let score = scorer.score(&sig);
         assert!(score > 0.7);
+
+        let sig = Signature5D::new(0.9, 0.8, 0.7, 0.6, 0.1);
+        let default = WeightedScorer::from_config(&ResonanceConfig::default());
+        assert_eq!(default.score(&sig), ResonanceScorer.score(&sig));
+        assert_eq!(default.name(), "resonance");
     }
 
     #[test]
@@ -311,6 +434,76 @@ mod tests {
         assert!((rate - 0.5).abs() < 0.01);
     }
 
+    #[test]
+    fn test_score_cache_clear_keeps_stats() {
+        let mut cache = ScoreCache::new(1000);
+        let sig = Signature5D::new(0.5, 0.5, 0.5, 0.5, 0.5);
+
+        cache.get_or_compute(&sig, resonance_5d);
+        cache.get_or_compute(&sig, resonance_5d);
+        cache.clear();
+        assert_eq!(cache.size(), 0);
+        assert_eq!(cache.stats().0, 1);
+
+        // A different scorer sees no stale entry after clearing
+        assert_eq!(cache.get_or_compute(&sig, |_| 0.25), 0.25);
+        assert_eq!(cache.stats().1, 2);
+
+        cache.reset_stats();
+        assert_eq!(cache.stats(), (0, 0, 0.0));
+        assert_eq!(cache.size(), 1);
+    }
+
+    #[test]
+    fn test_score_cache_lru() {
+        let mut cache = ScoreCache::with_capacity(2);
+        let sig = |x: f64| Signature5D::new(x, 0.5, 0.5, 0.5, 0.5);
+
+        cache.get_or_compute(&sig(0.1), |_| 1.0);
+        cache.get_or_compute(&sig(0.2), |_| 2.0);
+        // Touch 0.1 so 0.2 is the one evicted
+        assert_eq!(cache.get_or_compute(&sig(0.1), |_| -1.0), 1.0);
+        cache.get_or_compute(&sig(0.3), |_| 3.0);
+        assert_eq!(cache.size(), 2);
+        assert_eq!(cache.get_or_compute(&sig(0.1), |_| -1.0), 1.0);
+        assert_eq!(cache.get_or_compute(&sig(0.2), |_| -2.0), -2.0);
+
+        // Order survives serialization: 0.3 is now the oldest entry
+        let mut restored: ScoreCache = serde_json::from_str(&serde_json::to_string(&cache).unwrap()).unwrap();
+        assert_eq!(restored.stats(), cache.stats());
+        restored.get_or_compute(&sig(0.4), |_| 4.0);
+        assert_eq!(restored.get_or_compute(&sig(0.2), |_| 0.0), -2.0);
+        assert_eq!(restored.get_or_compute(&sig(0.3), |_| 0.0), 0.0);
+
+        // A coarse grid merges near-duplicate points
+        let mut coarse = ScoreCache::with_capacity(10).with_resolution(0.01);
+        coarse.get_or_compute(&sig(0.501), |_| 1.0);
+        assert_eq!(coarse.get_or_compute(&sig(0.504), |_| 2.0), 1.0);
+        assert_eq!(coarse.get_or_compute(&sig(0.512), |_| 2.0), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "resolution must be positive")]
+    fn test_cache_panics_on_zero_resolution() {
+        ScoreCache::with_capacity(10).with_resolution(0.0);
+    }
+
+    #[test]
+    fn test_cache_rejects_invalid_resolution() {
+        for resolution in [0.0, -0.01, f64::NAN, f64::INFINITY] {
+            assert!(check_cache_resolution(resolution).is_err(), "resolution {resolution} accepted");
+        }
+        assert_eq!(check_cache_resolution(0.01), Ok(0.01));
+
+        let mut cache = serde_json::to_value(ScoreCache::with_capacity(10)).unwrap();
+        cache["resolution"] = serde_json::json!(0.0);
+        assert!(serde_json::from_value::<ScoreCache>(cache).is_err());
+
+        let mut config = serde_json::to_value(crate::TritonConfig::default()).unwrap();
+        config["cache_resolution"] = serde_json::json!(-1.0);
+        assert!(serde_json::from_value::<crate::TritonConfig>(config).is_err());
+    }
+
     #[test]
     fn test_composite_score() {
         let sig = Signature5D::new(0.8, 0.7, 0.6, 0.5, 0.2);
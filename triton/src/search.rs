@@ -143,6 +143,12 @@ pub struct SearchMetadata {
     pub convergence_iteration: Option<usize>,
     /// Parameters used
     pub parameters: HashMap<String, String>,
+    /// Score cache hits
+    #[serde(default)]
+    pub cache_hits: usize,
+    /// Score cache misses
+    #[serde(default)]
+    pub cache_misses: usize,
 }
 
 impl SearchMetadata {
@@ -160,6 +166,8 @@ impl SearchMetadata {
             converged: false,
             convergence_iteration: None,
             parameters: HashMap::new(),
+            cache_hits: 0,
+            cache_misses: 0,
         }
     }
 
@@ -1,10 +1,14 @@
 //! TRITON session management for persistent optimization.
 
 use crate::config::TritonConfig;
-use crate::optimizer::{OptimizationResult, TritonOptimizer};
-use crate::scoring::ScoringFunction;
+use crate::optimizer::{OptimizationResult, OptimizationStep, TritonOptimizer};
+use crate::scoring::{ScoreCache, ScoringFunction};
+use crate::search::{SearchHistory, SearchState};
+use crate::spiral::SpiralState;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::io;
+use std::path::Path;
 
 /// Session configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -205,26 +209,69 @@ impl TritonSession {
         results
     }
 
-    /// Pause the session
-    pub fn pause(&mut self) {
+    /// Advance the spiral search by one point
+    ///
+    /// Returns `None` while paused or once the spiral is exhausted. Unlike
+    /// [`run_with_scorer`](Self::run_with_scorer), stepping can be
+    /// interrupted with [`pause`](Self::pause) and continued later.
+    pub fn step_with_scorer<S: ScoringFunction>(&mut self, scorer: &S) -> Option<OptimizationStep> {
+        if !self.running {
+            self.start();
+        }
+        if self.paused {
+            return None;
+        }
+
+        let step = self.optimizer.step(scorer)?;
+        if step.accepted {
+            self.log.log(SessionEvent::NewBest { score: step.score, iteration: step.step });
+        }
+        self.log.record_score(self.optimizer.state().best_score);
+        Some(step)
+    }
+
+    /// Pause the session and capture everything needed to continue it
+    ///
+    /// The checkpoint holds the spiral position, search state (including the
+    /// best signature), score cache and log, so a session rebuilt with
+    /// [`resume`](Self::resume) continues stepping without re-evaluating
+    /// known points, even in another process.
+    pub fn pause(&mut self) -> SessionCheckpoint {
         if self.running && !self.paused {
             self.paused = true;
             self.log.log(SessionEvent::Paused {
                 iteration: self.optimizer.state().iteration,
             });
         }
-    }
 
-    /// Resume the session
-    pub fn resume(&mut self) {
-        if self.running && self.paused {
-            self.paused = false;
-            self.log.log(SessionEvent::Resumed {
-                iteration: self.optimizer.state().iteration,
-            });
+        SessionCheckpoint {
+            id: self.id.clone(),
+            config: self.config.clone(),
+            spiral: self.optimizer.spiral_state().clone(),
+            state: self.optimizer.state().clone(),
+            history: self.optimizer.history().clone(),
+            cache: self.optimizer.cache().clone(),
+            log: self.log.clone(),
+            results: self.best_results.clone(),
+            start_time: self.start_time,
         }
     }
 
+    /// Rebuild a running session from a checkpoint taken by [`pause`](Self::pause)
+    pub fn resume(checkpoint: SessionCheckpoint) -> Self {
+        let mut session = Self::new(checkpoint.config);
+        session.id = checkpoint.id;
+        session.optimizer.restore(checkpoint.spiral, checkpoint.state, checkpoint.history, checkpoint.cache);
+        session.log = checkpoint.log;
+        session.best_results = checkpoint.results;
+        session.start_time = checkpoint.start_time;
+        session.running = true;
+        session.log.log(SessionEvent::Resumed {
+            iteration: session.optimizer.state().iteration,
+        });
+        session
+    }
+
     /// Stop the session
     pub fn stop(&mut self) {
         self.running = false;
@@ -296,6 +343,41 @@ impl TritonSession {
     }
 }
 
+/// Saved state of a paused [`TritonSession`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionCheckpoint {
+    /// Session ID
+    pub id: String,
+    /// Session configuration
+    pub config: SessionConfig,
+    /// Spiral position
+    pub spiral: SpiralState,
+    /// Search state, including the best signature and score
+    pub state: SearchState,
+    /// Search history
+    pub history: SearchHistory,
+    /// Scores of points already evaluated
+    pub cache: ScoreCache,
+    /// Session log
+    pub log: SessionLog,
+    /// Results of completed runs
+    pub results: Vec<OptimizationResult>,
+    /// Start time
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl SessionCheckpoint {
+    /// Save as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        qops_core::persist::save_json(self, path)
+    }
+
+    /// Load a checkpoint written by [`SessionCheckpoint::save`]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        qops_core::persist::load_json(path)
+    }
+}
+
 /// Session statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SessionStatistics {
@@ -378,4 +460,52 @@ mod tests {
         let stats = session.statistics();
         assert_eq!(stats.total_runs, 2);
     }
+
+    #[test]
+    fn test_pause_resume() {
+        use crate::scoring::ResonanceScorer;
+
+        let config = SessionConfig {
+            optimizer_config: TritonConfig {
+                deterministic: true,
+                seed: 5,
+                ..TritonConfig::quick()
+            },
+            ..Default::default()
+        };
+        let collect = |session: &mut TritonSession| {
+            std::iter::from_fn(|| session.step_with_scorer(&ResonanceScorer))
+                .map(|step| (step.signature, step.score, step.temperature))
+                .collect::<Vec<_>>()
+        };
+
+        let mut uninterrupted = TritonSession::new(config.clone());
+        let expected = collect(&mut uninterrupted);
+        assert!(expected.len() > 10);
+
+        let mut session = TritonSession::new(config);
+        let mut steps: Vec<_> = (0..10)
+            .map(|_| session.step_with_scorer(&ResonanceScorer).unwrap())
+            .map(|step| (step.signature, step.score, step.temperature))
+            .collect();
+        let checkpoint = session.pause();
+        assert!(session.step_with_scorer(&ResonanceScorer).is_none());
+
+        let path = qops_core::persist::temp_json_path("triton_checkpoint");
+        checkpoint.save(&path).unwrap();
+        let loaded = SessionCheckpoint::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded.cache.size(), checkpoint.cache.size());
+
+        let mut resumed = TritonSession::resume(loaded);
+        assert_eq!(resumed.id, session.id);
+        assert!(resumed.is_running());
+        assert_eq!(resumed.optimizer.state().best_signature, session.optimizer.state().best_signature);
+        steps.extend(collect(&mut resumed));
+        assert_eq!(steps, expected);
+
+        let events: Vec<_> = resumed.log().events.iter().map(|(_, e)| e).collect();
+        let paused = events.iter().position(|e| matches!(e, SessionEvent::Paused { iteration: 10 })).unwrap();
+        assert!(matches!(events[paused + 1], SessionEvent::Resumed { iteration: 10 }));
+    }
 }
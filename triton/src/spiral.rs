@@ -4,6 +4,8 @@ use crate::config::SpiralParams;
 use qops_core::Signature5D;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::Path;
 
 /// Spiral direction for search
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,6 +108,11 @@ impl SpiralEngine {
         &self.state
     }
 
+    /// Continue from a previously captured state
+    pub fn restore_state(&mut self, state: SpiralState) {
+        self.state = state;
+    }
+
     /// Check if search is complete
     pub fn is_complete(&self) -> bool {
         self.state.layer >= self.params.layers
@@ -225,6 +232,19 @@ impl SpiralEngine {
     }
 }
 
+/// One recorded point of a [`SpiralTrajectory`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrajectoryPoint {
+    /// Position in the trajectory (0 = first recorded point)
+    pub iteration: usize,
+    /// Spiral layer the point was recorded in
+    pub layer: usize,
+    /// 5D signature (ψ, ρ, ω, χ, η)
+    pub signature: [f64; 5],
+    /// Score at the point
+    pub score: f64,
+}
+
 /// Spiral trajectory for visualization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpiralTrajectory {
@@ -272,6 +292,56 @@ impl SpiralTrajectory {
             Some(max.map_or(x, |m: f64| m.max(x)))
         })
     }
+
+    /// Recorded points with their layer and iteration
+    pub fn entries(&self) -> Vec<TrajectoryPoint> {
+        self.points
+            .iter()
+            .zip(&self.scores)
+            .enumerate()
+            .map(|(iteration, (&signature, &score))| TrajectoryPoint {
+                iteration,
+                // Boundaries start at 0, so every point has a layer
+                layer: self.layer_boundaries.iter().filter(|&&b| b <= iteration).count().max(1) - 1,
+                signature,
+                score,
+            })
+            .collect()
+    }
+
+    /// JSON array of [`TrajectoryPoint`]s
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(&self.entries())
+    }
+
+    /// CSV with columns `iteration,layer,psi,rho,omega,chi,eta,score`
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("iteration,layer,psi,rho,omega,chi,eta,score\n");
+        for entry in self.entries() {
+            let [psi, rho, omega, chi, eta] = entry.signature;
+            csv.push_str(&format!(
+                "{},{},{:.6},{:.6},{:.6},{:.6},{:.6},{:.6}\n",
+                entry.iteration, entry.layer, psi, rho, omega, chi, eta, entry.score
+            ));
+        }
+        csv
+    }
+
+    /// Write as CSV or JSON, chosen by the `.csv` or `.json` extension
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => self.to_csv(),
+            Some(ext) if ext.eq_ignore_ascii_case("json") => self.to_json()?,
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("Unknown trajectory format for {}, expected .csv or .json", path.display()),
+                ))
+            }
+        };
+        std::fs::write(path, contents)
+    }
 }
 
 impl Default for SpiralTrajectory {
@@ -338,4 +408,29 @@ mod tests {
         assert_eq!(traj.best_index(), Some(1));
         assert_eq!(traj.best_score(), Some(0.9));
     }
+
+    #[test]
+    fn test_trajectory_export() {
+        let mut traj = SpiralTrajectory::new();
+        traj.record(&Signature5D::new(0.1, 0.2, 0.3, 0.4, 0.5), 0.25);
+        traj.mark_layer();
+        traj.record(&Signature5D::new(0.5, 0.5, 0.5, 0.5, 0.5), 0.75);
+
+        let csv = traj.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "iteration,layer,psi,rho,omega,chi,eta,score");
+        assert_eq!(lines[1], "0,0,0.100000,0.200000,0.300000,0.400000,0.500000,0.250000");
+        assert!(lines[2].starts_with("1,1,"));
+
+        let entries: Vec<TrajectoryPoint> = serde_json::from_str(&traj.to_json().unwrap()).unwrap();
+        assert_eq!(entries, traj.entries());
+        assert_eq!(entries[1].layer, 1);
+
+        let dir = std::env::temp_dir();
+        let json_path = dir.join(format!("triton_trajectory_{}.json", std::process::id()));
+        traj.save(&json_path).unwrap();
+        assert!(std::fs::read_to_string(&json_path).unwrap().trim_start().starts_with('['));
+        std::fs::remove_file(&json_path).ok();
+        assert!(traj.save(dir.join("trajectory.txt")).is_err());
+    }
 }
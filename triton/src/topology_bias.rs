@@ -1,8 +1,14 @@
 //! Topology-aware biasing for TRITON search.
 
-use qops_core::{Signature5D, resonance_5d};
+use qops_core::{QopsError, Result, Signature5D, resonance_5d};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+
+/// Hops over which node resonance is smoothed in a graph-backed bias
+pub const GRAPH_SMOOTHING_RADIUS: usize = 2;
+
+/// Breadth-first sources used to estimate the mean graph distance
+const GRAPH_DISTANCE_SOURCES: usize = 32;
 
 /// Bias mode for topology-aware search
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -19,6 +25,8 @@ pub enum BiasMode {
     BoundaryExploring,
     /// Adaptive bias based on search progress
     Adaptive,
+    /// Bias towards graph-adjacent high-resonance nodes (see [`TopologyBias::from_graph`])
+    GraphNeighborhood,
 }
 
 impl Default for BiasMode {
@@ -71,6 +79,62 @@ impl NeighborhoodWeights {
             distance: 0.1,
         }
     }
+
+    /// Weights for a graph with the given mean shortest-path length
+    ///
+    /// Interpolates between [`exploration`](Self::exploration) for sparse
+    /// graphs and [`exploitation`](Self::exploitation) when every node is a
+    /// neighbor of every other (mean distance 1), using locality `1 / mean`.
+    pub fn from_mean_distance(mean_distance: f64) -> Self {
+        let locality = 1.0 / mean_distance.max(1.0);
+        Self {
+            resonance: 0.2 + 0.4 * locality,
+            novelty: 0.5 - 0.4 * locality,
+            clustering: 0.2,
+            distance: 0.1,
+        }
+    }
+}
+
+/// Graph structure behind a [`TopologyBias::from_graph`] bias
+#[derive(Debug, Clone)]
+struct GraphNeighborhood {
+    signatures: Vec<Signature5D>,
+    /// Node resonance averaged over its neighborhood, weighted 2^-hops
+    smoothed: Vec<f64>,
+    /// Hops from the node with the highest smoothed resonance
+    hops_to_peak: Vec<Option<usize>>,
+}
+
+impl GraphNeighborhood {
+    fn nearest_node(&self, sig: &Signature5D) -> usize {
+        self.signatures
+            .iter()
+            .map(|node| TopologyBias::signature_distance(sig, node))
+            .enumerate()
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(0, |(i, _)| i)
+    }
+}
+
+/// Hop distances from `source`, `None` for unreachable nodes, up to `radius` hops
+fn bfs_hops(adjacency: &[Vec<usize>], source: usize, radius: usize) -> Vec<Option<usize>> {
+    let mut hops = vec![None; adjacency.len()];
+    hops[source] = Some(0);
+    let mut queue = VecDeque::from([source]);
+    while let Some(node) = queue.pop_front() {
+        let next = hops[node].unwrap_or(0) + 1;
+        if next > radius {
+            continue;
+        }
+        for &neighbor in &adjacency[node] {
+            if hops[neighbor].is_none() {
+                hops[neighbor] = Some(next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    hops
 }
 
 /// Topology bias manager
@@ -81,6 +145,7 @@ pub struct TopologyBias {
     visited: HashMap<[u64; 5], usize>,
     high_resonance_centers: Vec<Signature5D>,
     exploration_progress: f64,
+    graph: Option<GraphNeighborhood>,
 }
 
 impl TopologyBias {
@@ -92,6 +157,7 @@ impl TopologyBias {
             visited: HashMap::new(),
             high_resonance_centers: Vec::new(),
             exploration_progress: 0.0,
+            graph: None,
         }
     }
 
@@ -103,9 +169,77 @@ impl TopologyBias {
             visited: HashMap::new(),
             high_resonance_centers: Vec::new(),
             exploration_progress: 0.0,
+            graph: None,
         }
     }
 
+    /// Create a bias from a real topology such as S7 or Cube-13
+    ///
+    /// `adjacency[i]` lists the neighbors of node `i`, whose position in
+    /// signature space is `node_signatures[i]`. Candidates are scored by the
+    /// resonance of their nearest node, averaged over its graph neighborhood
+    /// (up to [`GRAPH_SMOOTHING_RADIUS`] hops, halving per hop), and by their
+    /// hop distance to the best such neighborhood. The weights come from
+    /// [`NeighborhoodWeights::from_mean_distance`], with the mean distance
+    /// estimated from up to 32 evenly spread sources. The mode is
+    /// [`BiasMode::GraphNeighborhood`].
+    pub fn from_graph(adjacency: &[Vec<usize>], node_signatures: &[Signature5D]) -> Result<Self> {
+        let n = adjacency.len();
+        if n == 0 || n != node_signatures.len() {
+            return Err(QopsError::validation(format!(
+                "Graph bias needs one signature per node, got {} nodes and {} signatures",
+                n,
+                node_signatures.len()
+            )));
+        }
+        if let Some(&bad) = adjacency.iter().flatten().find(|&&j| j >= n) {
+            return Err(QopsError::validation(format!("Neighbor {} out of range for {} nodes", bad, n)));
+        }
+
+        let resonance: Vec<f64> = node_signatures.iter().map(resonance_5d).collect();
+        let smoothed: Vec<f64> = (0..n)
+            .map(|i| {
+                let (mut total, mut weight) = (0.0, 0.0);
+                for (j, hops) in bfs_hops(adjacency, i, GRAPH_SMOOTHING_RADIUS).into_iter().enumerate() {
+                    if let Some(h) = hops {
+                        let w = 0.5f64.powi(h as i32);
+                        total += w * resonance[j];
+                        weight += w;
+                    }
+                }
+                total / weight
+            })
+            .collect();
+
+        let peak = smoothed
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map_or(0, |(i, _)| i);
+        let hops_to_peak = bfs_hops(adjacency, peak, usize::MAX);
+
+        let step = n.div_ceil(GRAPH_DISTANCE_SOURCES).max(1);
+        let (mut total, mut pairs) = (0usize, 0usize);
+        for source in (0..n).step_by(step) {
+            for h in bfs_hops(adjacency, source, usize::MAX).into_iter().flatten().filter(|&h| h > 0) {
+                total += h;
+                pairs += 1;
+            }
+        }
+        let mean_distance = if pairs == 0 { 1.0 } else { total as f64 / pairs as f64 };
+
+        let mut bias = Self::with_weights(
+            BiasMode::GraphNeighborhood,
+            NeighborhoodWeights::from_mean_distance(mean_distance),
+        );
+        bias.graph = Some(GraphNeighborhood {
+            signatures: node_signatures.to_vec(),
+            smoothed,
+            hops_to_peak,
+        });
+        Ok(bias)
+    }
+
     /// Record a visited point
     pub fn record_visit(&mut self, sig: &Signature5D, score: f64) {
         let key = Self::sig_to_key(sig);
@@ -153,6 +287,7 @@ impl TopologyBias {
             BiasMode::ClusterCentered => self.cluster_bias(sig),
             BiasMode::BoundaryExploring => self.boundary_bias(sig),
             BiasMode::Adaptive => self.adaptive_bias(sig),
+            BiasMode::GraphNeighborhood => self.graph_bias(sig),
         }
     }
 
@@ -210,6 +345,19 @@ impl TopologyBias {
         self.weights.distance * dst
     }
 
+    fn graph_bias(&self, sig: &Signature5D) -> f64 {
+        let Some(graph) = &self.graph else {
+            return self.adaptive_bias(sig);
+        };
+        let node = graph.nearest_node(sig);
+        let proximity = graph.hops_to_peak[node].map_or(0.0, |h| 0.5f64.powi(h as i32));
+
+        self.weights.resonance * self.resonance_bias(sig) +
+        self.weights.novelty * self.exploration_bias(sig) +
+        self.weights.clustering * graph.smoothed[node] +
+        self.weights.distance * proximity
+    }
+
     fn distance_to_best(&self, sig: &Signature5D) -> f64 {
         if self.high_resonance_centers.is_empty() {
             return 0.5;
@@ -251,6 +399,11 @@ impl TopologyBias {
         self.mode = mode;
     }
 
+    /// Get current neighborhood weights
+    pub fn weights(&self) -> &NeighborhoodWeights {
+        &self.weights
+    }
+
     /// Get high resonance centers
     pub fn centers(&self) -> &[Signature5D] {
         &self.high_resonance_centers
@@ -339,4 +492,34 @@ mod tests {
         assert!(selected.is_some());
         assert_eq!(selected.unwrap().1, 0.8);
     }
+
+    #[test]
+    fn test_graph_bias() {
+        // Path 0-1-2-3-4 with resonance rising toward node 4
+        let adjacency = vec![vec![1], vec![0, 2], vec![1, 3], vec![2, 4], vec![3]];
+        let nodes: Vec<Signature5D> = (0..5)
+            .map(|i| {
+                let x = 0.2 + 0.15 * i as f64;
+                Signature5D::new(x, x, x, 0.5, 0.5 - 0.1 * i as f64)
+            })
+            .collect();
+        let bias = TopologyBias::from_graph(&adjacency, &nodes).unwrap();
+        assert_eq!(bias.mode(), BiasMode::GraphNeighborhood);
+
+        // Mean distance of a 5-path is 2, so the weights sit halfway between the presets
+        assert!((bias.weights().resonance - 0.4).abs() < 1e-12);
+        assert!((bias.weights().novelty - 0.3).abs() < 1e-12);
+
+        // Points near the high-resonance end are preferred
+        let weights: Vec<f64> = nodes.iter().map(|n| bias.bias_weight(n)).collect();
+        assert!(weights.windows(2).all(|w| w[1] > w[0]), "{:?}", weights);
+
+        // A complete graph gives the exploitation weights
+        let complete: Vec<Vec<usize>> = (0..4).map(|i| (0..4).filter(|&j| j != i).collect()).collect();
+        let bias = TopologyBias::from_graph(&complete, &nodes[..4]).unwrap();
+        assert!((bias.weights().resonance - 0.6).abs() < 1e-12);
+
+        assert!(TopologyBias::from_graph(&adjacency, &nodes[..4]).is_err());
+        assert!(TopologyBias::from_graph(&[vec![3]], &nodes[..1]).is_err());
+    }
 }